@@ -1,17 +1,21 @@
 use crate::{
-    error::check_inf_operands,
     fbig::FBig,
     helper_macros,
     repr::{Context, Repr, Word},
     round::{Round, Rounded},
-    utils::{digit_len, shl_digits, shl_digits_in_place, split_digits, split_digits_ref},
+    utils::{
+        digit_len, shl_digits, shl_digits_in_place, split_digits, split_digits_ref, sticky_digits,
+    },
 };
 use core::{
     cmp::Ordering,
     ops::{Add, AddAssign, Sub, SubAssign},
 };
 
-use dashu_base::Sign::{self, *};
+use dashu_base::{
+    Approximation::Exact,
+    Sign::{self, *},
+};
 use dashu_int::{IBig, UBig};
 
 impl<R: Round, const B: Word> Add for FBig<R, B> {
@@ -91,10 +95,10 @@ helper_macros::impl_binop_assign_by_taking!(impl SubAssign<Self>, sub_assign, su
 
 macro_rules! impl_add_sub_primitive_with_fbig {
     ($($t:ty)*) => {$(
-        helper_macros::impl_commutative_binop_with_primitive!(impl Add<$t>, add);
-        helper_macros::impl_binop_assign_with_primitive!(impl AddAssign<$t>, add_assign);
-        helper_macros::impl_commutative_binop_with_primitive!(impl Sub<$t>, sub);
-        helper_macros::impl_binop_assign_with_primitive!(impl SubAssign<$t>, sub_assign);
+        helper_macros::impl_commutative_binop_with_primitive!(impl Add<$t>, add, from_int_operand);
+        helper_macros::impl_binop_assign_with_primitive!(impl AddAssign<$t>, add_assign, from_int_operand);
+        helper_macros::impl_commutative_binop_with_primitive!(impl Sub<$t>, sub, from_int_operand);
+        helper_macros::impl_binop_assign_with_primitive!(impl SubAssign<$t>, sub_assign, from_int_operand);
     )*};
 }
 impl_add_sub_primitive_with_fbig!(u8 u16 u32 u64 u128 usize UBig i8 i16 i32 i64 i128 isize IBig);
@@ -104,9 +108,10 @@ fn add_val_val<R: Round, const B: Word>(
     mut rhs: FBig<R, B>,
     rhs_sign: Sign,
 ) -> FBig<R, B> {
-    check_inf_operands(&lhs.repr, &rhs.repr);
-
     let context = Context::max(lhs.context, rhs.context);
+    if let Some(special) = context.ieee_add(&lhs.repr, &rhs.repr, rhs_sign) {
+        return FBig::new(special, context);
+    }
     rhs.repr.significand *= rhs_sign;
     let sum = if lhs.repr.is_zero() {
         rhs.repr
@@ -131,9 +136,10 @@ fn add_val_ref<R: Round, const B: Word>(
     rhs: &FBig<R, B>,
     rhs_sign: Sign,
 ) -> FBig<R, B> {
-    check_inf_operands(&lhs.repr, &rhs.repr);
-
     let context = Context::max(lhs.context, rhs.context);
+    if let Some(special) = context.ieee_add(&lhs.repr, &rhs.repr, rhs_sign) {
+        return FBig::new(special, context);
+    }
     let sum = if lhs.repr.is_zero() {
         let mut repr = rhs.repr.clone();
         repr.significand *= rhs_sign;
@@ -162,9 +168,10 @@ fn add_ref_val<R: Round, const B: Word>(
     mut rhs: FBig<R, B>,
     rhs_sign: Sign,
 ) -> FBig<R, B> {
-    check_inf_operands(&lhs.repr, &rhs.repr);
-
     let context = Context::max(lhs.context, rhs.context);
+    if let Some(special) = context.ieee_add(&lhs.repr, &rhs.repr, rhs_sign) {
+        return FBig::new(special, context);
+    }
     rhs.repr.significand *= rhs_sign;
     let sum = if lhs.repr.is_zero() {
         rhs.repr
@@ -189,9 +196,10 @@ fn add_ref_ref<R: Round, const B: Word>(
     rhs: &FBig<R, B>,
     rhs_sign: Sign,
 ) -> FBig<R, B> {
-    check_inf_operands(&lhs.repr, &rhs.repr);
-
     let context = Context::max(lhs.context, rhs.context);
+    if let Some(special) = context.ieee_add(&lhs.repr, &rhs.repr, rhs_sign) {
+        return FBig::new(special, context);
+    }
     let sum = if lhs.repr.is_zero() {
         let mut repr = rhs.repr.clone();
         repr.significand *= rhs_sign;
@@ -232,59 +240,88 @@ impl<R: Round> Context<R> {
         // use one extra digit to prevent cancellation in rounding
         let rnd_precision = self.precision + is_sub as usize;
 
-        // align to precision again
-        let digits = digit_len::<B>(&significand);
-        match digits.cmp(&rnd_precision) {
-            Ordering::Equal => {}
-            Ordering::Greater => {
-                // Shrink if the result has more digits than desired precision
-                /*
-                 * lhs:         |=========0000|
-                 * rhs:              |========|xxxxx|
-                 * sum:        |==============|xxxxx|
-                 * precision:  |<----->|
-                 * shrink:     |=======|xxxxxxxxxxxx|
-                 */
-                let shift = digits - rnd_precision;
-                let (signif_hi, mut signif_lo) = split_digits::<B>(significand, shift);
-                significand = signif_hi;
-                exponent += shift as isize;
-                shl_digits_in_place::<B>(&mut signif_lo, low.1);
-                low.0 += signif_lo;
-                low.1 += shift;
-            }
-            Ordering::Less => {
-                // Expand to low parts if the result has less digits than desired precision.
-                // It's necessary only when lhs and rhs has different sign and a cancellation might happen.
-                /*
-                 * lhs:  |=========0000|
-                 * rhs:  |=============|xxxxx|
-                 * sum:          |=====|xxxxx|
-                 * precision+1:  |<------>|
-                 * shift:              |<>|
-                 * expanded:     |========|xx|
-                 */
-                if !low.0.is_zero() && is_sub {
-                    let (low_val, low_prec) = low;
-                    let shift = low_prec.min(rnd_precision - digits);
-                    let (pad, low_val) = split_digits::<B>(low_val, low_prec - shift);
-                    shl_digits_in_place::<B>(&mut significand, shift);
-                    exponent -= shift as isize;
-                    significand += pad;
-                    low = (low_val, low_prec - shift);
+        // Expand to low parts if the result has less digits than desired precision.
+        // It's necessary when lhs and rhs has different sign and a cancellation happens, or
+        // when the smaller operand is replaced by its sign.
+        /*
+         * lhs:  |=========0000|
+         * rhs:  |=============|xxxxx|
+         * sum:          |=====|xxxxx|
+         * precision+1:  |<------>|
+         * shift:              |<>|
+         * expanded:     |========|xx|
+         */
+        let mut digits = digit_len::<B>(&significand);
+        let mut min_digits = rnd_precision;
+        while digits < min_digits && !low.0.is_zero() {
+            let (low_val, low_prec) = low;
+            let shift = if significand.is_zero() {
+                // the high part is cancelled completely, skip the leading zeros of the low part
+                low_prec.min(low_prec + rnd_precision - digit_len::<B>(&low_val))
+            } else {
+                low_prec.min(rnd_precision - digits)
+            };
+            let (pad, low_val) = split_digits::<B>(low_val, low_prec - shift);
+            shl_digits_in_place::<B>(&mut significand, shift);
+            exponent -= shift as isize;
+            significand += pad;
+            low = (low_val, low_prec - shift);
+
+            // a borrow from the low part removes at most one digit, so the expansion is
+            // repeated only if the cancellation continues into the low part
+            digits = digit_len::<B>(&significand);
+            min_digits = self.precision;
+        }
+
+        // Shrink if the result has more digits than desired precision.
+        /*
+         * lhs:         |=========0000|
+         * rhs:              |========|xxxxx|
+         * sum:        |==============|xxxxx|
+         * precision:  |<----->|
+         * shrink:     |=======|xxxxxxxxxxxx|
+         */
+        let target_precision = if self.ieee || self.is_bounded() {
+            // In the IEEE mode or with bounded exponent range, the result must be correctly
+            // rounded, so the extra digit used for the subtraction is also merged into the low part.
+            if digits > 0 && !low.0.is_zero() && low.0.sign() != significand.sign() {
+                // if the significand is a power of the base, the actual sum has one less digit
+                let shl_one = shl_digits::<B>(&IBig::ONE, digits - 1);
+                if significand.sign() * shl_one == significand {
+                    digits -= 1;
                 }
             }
+            self.precision
+        } else {
+            rnd_precision
         };
+        let mut shift = digits.saturating_sub(target_precision);
+        if digits > 0 {
+            // round at a higher position if the sum underflows
+            if let Some(min_exp) = self.min_exponent(exponent + digits as isize - 1) {
+                shift = shift.max((min_exp - exponent).max(0) as usize);
+            }
+        }
+        let sign = significand.sign();
+        if shift > 0 {
+            let (signif_hi, mut signif_lo) = split_digits::<B>(significand, shift);
+            significand = signif_hi;
+            exponent += shift as isize;
+            shl_digits_in_place::<B>(&mut signif_lo, low.1);
+            low.0 += signif_lo;
+            low.1 += shift;
+        }
 
         // perform rounding
-        if low.0.is_zero() {
+        let rounded = if low.0.is_zero() {
             Rounded::Exact(Repr::new(significand, exponent))
         } else {
             // By now significand should have at least full precision. After adjustment, the digits length
             // could be one more than the precision. We don't shrink the extra digit.
-            let adjust = R::round_fract::<B>(&significand, low.0, low.1);
+            let adjust = self.round_fract::<B>(&significand, low.0, low.1);
             Rounded::Inexact(Repr::new(significand + adjust, exponent), adjust)
-        }
+        };
+        self.repr_check_range(rounded, sign)
     }
 
     // lhs + rhs_sign * rhs, assuming lhs.exponent >= rhs.exponent
@@ -306,9 +343,10 @@ impl<R: Round> Context<R> {
 
         // align the exponent
         let low: (IBig, usize); // (value of low part, precision of the low part)
+        let guard_digits = sticky_digits::<B>() + 1;
         let (significand, exponent) = if self.is_limited()
-            && rdigits_est + 1 < ediff
-            && rdigits_est + 1 + rnd_precision < ldigits + ediff
+            && rdigits_est + guard_digits < ediff
+            && rdigits_est + guard_digits + rnd_precision < ldigits + ediff
         {
             // if rhs is much smaller than lhs, direct round on the rhs
             /*
@@ -321,9 +359,9 @@ impl<R: Round> Context<R> {
             // In this case, the actual significand of rhs doesn't matter,
             // we can just replace it with 1 for correct rounding
             let low_prec = if ldigits >= rnd_precision {
-                2
+                guard_digits
             } else {
-                (rnd_precision - ldigits) + 1
+                (rnd_precision - ldigits) + guard_digits
             }; // low_prec >= 2
             low = (rhs_sign * rhs.significand.signum(), low_prec);
             (lhs.significand, lhs.exponent)
@@ -406,15 +444,16 @@ impl<R: Round> Context<R> {
 
         // align the exponent
         let low: (IBig, usize);
+        let guard_digits = sticky_digits::<B>() + 1;
         let (significand, exponent) = if self.is_limited()
-            && ldigits_est + 1 < ediff
-            && ldigits_est + 1 + rnd_precision < rdigits + ediff
+            && ldigits_est + guard_digits < ediff
+            && ldigits_est + guard_digits + rnd_precision < rdigits + ediff
         {
             // if lhs is much smaller than rhs, direct round on the lhs
             let low_prec = if rdigits >= rnd_precision {
-                2
+                guard_digits
             } else {
-                (rnd_precision - rdigits) + 1
+                (rnd_precision - rdigits) + guard_digits
             };
             low = (lhs.significand.signum(), low_prec);
             (rhs_sign * rhs.significand.clone(), rhs.exponent)
@@ -462,7 +501,9 @@ impl<R: Round> Context<R> {
     /// # Ok::<(), ParseError>(())
     /// ```
     pub fn add<const B: Word>(&self, lhs: &Repr<B>, rhs: &Repr<B>) -> Rounded<FBig<R, B>> {
-        check_inf_operands(lhs, rhs);
+        if let Some(special) = self.ieee_add(lhs, rhs, Positive) {
+            return Exact(FBig::new(special, *self));
+        }
 
         let sum = if lhs.is_zero() {
             self.repr_round_ref(rhs)
@@ -500,10 +541,12 @@ impl<R: Round> Context<R> {
     /// # Ok::<(), ParseError>(())
     /// ```
     pub fn sub<const B: Word>(&self, lhs: &Repr<B>, rhs: &Repr<B>) -> Rounded<FBig<R, B>> {
-        check_inf_operands(lhs, rhs);
+        if let Some(special) = self.ieee_add(lhs, rhs, Negative) {
+            return Exact(FBig::new(special, *self));
+        }
 
         let sum = if lhs.is_zero() {
-            self.repr_round_ref(rhs).map(|v| -v)
+            self.repr_round(-rhs.clone())
         } else if rhs.is_zero() {
             self.repr_round_ref(lhs)
         } else {
@@ -0,0 +1,267 @@
+//! Implementation of the arithmetic-geometric mean and the complete elliptic integrals
+
+use core::cmp::Ordering;
+
+use crate::{
+    cmp::repr_cmp,
+    error::{check_inf, check_precision_limited, panic_out_of_domain},
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{Round, Rounded},
+    utils::top_or_min,
+};
+use dashu_base::{Abs, Approximation::*, EstimatedLog2, Sign};
+use dashu_int::IBig;
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Calculate the arithmetic-geometric mean (`agm(a, b)`) of two float numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// let b = DBig::from_str_native("5.678")?;
+    /// assert_eq!(a.agm(&b), DBig::from_str_native("3.038")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or any of the numbers is negative.
+    #[inline]
+    pub fn agm(&self, other: &Self) -> Self {
+        let context = Context::max(self.context, other.context);
+        context.agm(&self.repr, &other.repr).value()
+    }
+
+    /// Calculate the complete elliptic integral of the first kind (`K(k)`) on the float number,
+    /// where the number is the elliptic modulus `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let k = DBig::from_str_native("0.1234")?;
+    /// assert_eq!(k.elliptic_k(), DBig::from_str_native("1.5768")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not in the range `(-1, 1)`.
+    #[inline]
+    pub fn elliptic_k(&self) -> Self {
+        self.context.elliptic_k(&self.repr).value()
+    }
+
+    /// Calculate the complete elliptic integral of the second kind (`E(k)`) on the float number,
+    /// where the number is the elliptic modulus `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let k = DBig::from_str_native("0.1234")?;
+    /// assert_eq!(k.elliptic_e(), DBig::from_str_native("1.5648")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not in the range `[-1, 1]`.
+    #[inline]
+    pub fn elliptic_e(&self) -> Self {
+        self.context.elliptic_e(&self.repr).value()
+    }
+}
+
+impl<R: Round> Context<R> {
+    /// Create a context with guard digits for the evaluation of the arithmetic-geometric mean
+    #[inline]
+    fn agm_work(&self) -> Context<R> {
+        // use a simple rule for guard bits, the same as powf
+        let guard_digits = 10 + self.precision.log2_est() as usize;
+        self.work_context(self.precision + guard_digits)
+    }
+
+    /// Evaluate the arithmetic-geometric mean of two positive numbers under this context, by
+    /// the iteration `a' = (a+b)/2, b' = √(ab)`.
+    ///
+    /// The closure `step` is called with `cₙ = (aₙ₋₁ - bₙ₋₁)/2` for each iteration.
+    pub(crate) fn agm_iterate<const B: Word, F>(
+        &self,
+        mut a: FBig<R, B>,
+        mut b: FBig<R, B>,
+        mut step: F,
+    ) -> FBig<R, B>
+    where
+        F: FnMut(&FBig<R, B>),
+    {
+        // the convergence is quadratic, so the iteration stops when a and b agree
+        // on the first half of the digits, then (a+b)/2 is accurate to all the digits
+        let half = (self.precision / 2) as isize + 1;
+        loop {
+            let c = (&a - &b) / 2u8;
+            step(&c);
+            if c.repr.is_zero() || top_or_min(&c.repr) < top_or_min(&a.repr) - half {
+                return a - c;
+            }
+            let a_next = &a - &c;
+            b = (a * b).sqrt();
+            a = a_next;
+        }
+    }
+
+    /// Evaluate `√(1-k²)` under this context
+    fn complementary_modulus<const B: Word>(&self, k: &Repr<B>) -> FBig<R, B> {
+        let k2 = Context::<R>::new(0).mul(k, k).value();
+        let k2c = self.sub(&Repr::one(), &k2.repr).value();
+        k2c.sqrt()
+    }
+
+    /// Calculate the arithmetic-geometric mean (`agm(a, b)`) of two float numbers under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// let b = DBig::from_str_native("5.678")?;
+    /// assert_eq!(context.agm(&a.repr(), &b.repr()), Inexact(DBig::from_str_native("3.0")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or any of the numbers is negative.
+    pub fn agm<const B: Word>(&self, a: &Repr<B>, b: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(a);
+        check_inf(b);
+        check_precision_limited(self.precision);
+        if a.sign() == Sign::Negative || b.sign() == Sign::Negative {
+            panic_out_of_domain()
+        }
+        if a.is_zero() || b.is_zero() {
+            return Exact(FBig::ZERO);
+        }
+        if a == b {
+            return self.repr_round_ref(a).map(|v| FBig::new(v, *self));
+        }
+
+        self.round_ziv(|c| {
+            let work_context = c.agm_work();
+            let a = FBig::new(work_context.repr_round_ref(a).value(), work_context);
+            let b = FBig::new(work_context.repr_round_ref(b).value(), work_context);
+            work_context.agm_iterate(a, b, |_| {})
+        })
+    }
+
+    /// Calculate the complete elliptic integral of the first kind (`K(k)`) on the float number
+    /// under this context, where the input is the elliptic modulus `k`.
+    ///
+    /// It's evaluated as `K(k) = π/(2·agm(1, √(1-k²)))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let k = DBig::from_str_native("0.99")?;
+    /// assert_eq!(context.elliptic_k(&k.repr()), Inexact(DBig::from_str_native("3.4")?, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not in the range `(-1, 1)`.
+    pub fn elliptic_k<const B: Word>(&self, k: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(k);
+        check_precision_limited(self.precision);
+        let abs_k = Repr::<B>::new(k.significand.clone().abs(), k.exponent);
+        if repr_cmp(&abs_k, &Repr::one(), None) != Ordering::Less {
+            panic_out_of_domain()
+        }
+
+        self.round_ziv(|c| {
+            let work_context = c.agm_work();
+            let pi = work_context.pi::<B>().value();
+            if k.is_zero() {
+                return pi / 2u8;
+            }
+            let k_prime = work_context.complementary_modulus(k);
+            let agm = work_context.agm_iterate(FBig::ONE, k_prime, |_| {});
+            pi / (agm * 2u8)
+        })
+    }
+
+    /// Calculate the complete elliptic integral of the second kind (`E(k)`) on the float number
+    /// under this context, where the input is the elliptic modulus `k`.
+    ///
+    /// It's evaluated as `E(k) = K(k)·(1 - Σ2ⁿ⁻¹cₙ²)`, where `c₀ = k` and `cₙ` are the half
+    /// differences in the iteration of `agm(1, √(1-k²))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let k = DBig::from_str_native("0.99")?;
+    /// assert_eq!(context.elliptic_e(&k.repr()), Inexact(DBig::from_str_native("1.0")?, NoOp));
+    /// let one = DBig::from_str_native("-1")?;
+    /// assert_eq!(context.elliptic_e(&one.repr()), Exact(DBig::from_str_native("1")?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not in the range `[-1, 1]`.
+    pub fn elliptic_e<const B: Word>(&self, k: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(k);
+        check_precision_limited(self.precision);
+        let abs_k = Repr::<B>::new(k.significand.clone().abs(), k.exponent);
+        match repr_cmp(&abs_k, &Repr::one(), None) {
+            Ordering::Greater => panic_out_of_domain(),
+            Ordering::Equal => return Exact(FBig::ONE),
+            Ordering::Less => {}
+        };
+
+        self.round_ziv(|c| {
+            let work_context = c.agm_work();
+            if k.is_zero() {
+                return work_context.pi::<B>().value() / 2u8;
+            }
+
+            // the sum Σ2ⁿ⁻¹cₙ² is less than one, so the cancellation is bounded by K(k)
+            work_context.eval_cancelling(true, |c| {
+                let k_prime = c.complementary_modulus(k);
+                let k = FBig::new(c.repr_round_ref(k).value(), *c);
+                let mut sum = k.square() / 2u8;
+                let mut scale = IBig::ONE;
+                let agm = c.agm_iterate(FBig::ONE, k_prime, |cn| {
+                    sum += cn.square() * &scale;
+                    scale *= 2u8;
+                });
+                let elliptic_k = c.pi::<B>().value() / (agm * 2u8);
+                let k_top = top_or_min(&elliptic_k.repr);
+                (&elliptic_k - elliptic_k.clone() * sum, k_top)
+            })
+        })
+    }
+}
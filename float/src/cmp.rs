@@ -1,30 +1,38 @@
 use core::cmp::Ordering;
 
-use crate::{fbig::FBig, repr::Repr, repr::Word, round::Round, utils::shl_digits};
+use crate::{
+    fbig::FBig,
+    repr::Repr,
+    repr::Word,
+    round::{mode::Zero, Round},
+    utils::shl_digits,
+};
+use dashu_base::EstimatedLog2;
+use dashu_int::{IBig, UBig};
 
 impl<R1: Round, R2: Round, const B: Word> PartialEq<FBig<R2, B>> for FBig<R1, B> {
     #[inline]
     fn eq(&self, other: &FBig<R2, B>) -> bool {
-        match (self.repr.is_infinite(), other.repr.is_infinite()) {
-            // +inf = +inf, -inf = -inf
-            (true, true) => !((self.repr.exponent >= 0) ^ (other.repr.exponent >= 0)),
-
-            // the representation is normalized so direct comparing is okay,
-            // and the context doesn't count in comparison
-            (false, false) => self.repr == other.repr,
-
-            // inf != any exact numbers
-            (_, _) => false,
-        }
+        // the representation is normalized so direct comparing is okay,
+        // and the context doesn't count in comparison
+        self.repr == other.repr
     }
 }
 impl<R: Round, const B: Word> Eq for FBig<R, B> {}
 
-fn repr_cmp<const B: Word>(
+pub(crate) fn repr_cmp<const B: Word>(
     lhs: &Repr<B>,
     rhs: &Repr<B>,
     precision: Option<(usize, usize)>,
 ) -> Ordering {
+    // case 0: NaN is greater than all other values
+    match (lhs.is_nan(), rhs.is_nan()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        _ => {}
+    };
+
     // case 1: compare with inf
     match (lhs.is_infinite(), rhs.is_infinite()) {
         (true, true) => return lhs.exponent.cmp(&rhs.exponent),
@@ -49,6 +57,10 @@ fn repr_cmp<const B: Word>(
         Ordering::Less => return Ordering::Less,
         _ => {}
     };
+    if lhs.significand.is_zero() {
+        // +0 = -0
+        return Ordering::Equal;
+    }
     let sign = lhs.significand.sign();
 
     // case 3: compare exponent and precision
@@ -87,6 +99,16 @@ fn repr_cmp<const B: Word>(
     }
 }
 
+impl<const B: Word> PartialEq for Repr<B> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // +0 = -0, otherwise the representation is unique for each value
+        (self.is_zero() && other.is_zero())
+            || (self.significand == other.significand && self.exponent == other.exponent)
+    }
+}
+impl<const B: Word> Eq for Repr<B> {}
+
 impl<const B: Word> PartialOrd for Repr<B> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -119,4 +141,156 @@ impl<R: Round, const B: Word> Ord for FBig<R, B> {
     }
 }
 
-// TODO: implement comparison with IBig
+/// Compare a float number with an integer exactly.
+#[inline]
+fn repr_cmp_int<const B: Word>(lhs: &Repr<B>, rhs: IBig) -> Ordering {
+    repr_cmp(lhs, &Repr::new(rhs, 0), None)
+}
+
+/// Compare a float number with a binary float number exactly. The NaN is ordered in the same
+/// way as [repr_cmp].
+fn repr_cmp_binary<const B: Word>(lhs: &Repr<B>, rhs: &Repr<2>) -> Ordering {
+    match (lhs.is_nan(), rhs.is_nan()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        _ => {}
+    };
+
+    // compare with inf
+    match (lhs.is_infinite(), rhs.is_infinite()) {
+        (true, true) => return lhs.exponent.cmp(&rhs.exponent),
+        (true, false) if lhs.exponent > 0 => return Ordering::Greater,
+        (true, false) => return Ordering::Less,
+        (false, true) if rhs.exponent > 0 => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        _ => {}
+    }
+
+    // compare sign
+    match lhs.significand.signum().cmp(&rhs.significand.signum()) {
+        Ordering::Equal => {}
+        ord => return ord,
+    }
+    if lhs.significand.is_zero() {
+        return Ordering::Equal;
+    }
+    let sign = lhs.significand.sign();
+
+    // compare the estimated magnitudes, leaving a margin for the errors of the estimation
+    let (lhs_lb, lhs_ub) = lhs.log2_bounds();
+    let (rhs_lb, rhs_ub) = rhs.log2_bounds();
+    let margin = 2. + (lhs_ub.abs() + rhs_ub.abs()) * 1e-4;
+    if lhs_lb > rhs_ub + margin {
+        return sign * Ordering::Greater;
+    }
+    if lhs_ub + margin < rhs_lb {
+        return sign * Ordering::Less;
+    }
+
+    // compare the exact values, after shifting both sides to integers
+    let (mut lhs_signif, mut rhs_signif) = (lhs.significand.clone(), rhs.significand.clone());
+    if lhs.exponent >= 0 {
+        lhs_signif = shl_digits::<B>(&lhs_signif, lhs.exponent as usize);
+    } else {
+        rhs_signif = shl_digits::<B>(&rhs_signif, -lhs.exponent as usize);
+    }
+    if rhs.exponent >= 0 {
+        rhs_signif <<= rhs.exponent as usize;
+    } else {
+        lhs_signif <<= -rhs.exponent as usize;
+    }
+    lhs_signif.cmp(&rhs_signif)
+}
+
+macro_rules! impl_cmp_with_int {
+    ($int:ty, $v:ident => $to_ibig:expr) => {
+        impl<R: Round, const B: Word> PartialEq<$int> for FBig<R, B> {
+            #[inline]
+            fn eq(&self, other: &$int) -> bool {
+                let $v = other;
+                repr_cmp_int(&self.repr, $to_ibig) == Ordering::Equal
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialEq<FBig<R, B>> for $int {
+            #[inline]
+            fn eq(&self, other: &FBig<R, B>) -> bool {
+                other.eq(self)
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialOrd<$int> for FBig<R, B> {
+            /// Compare the number with an integer exactly. As in the comparison between [FBig]
+            /// instances, the NaN is greater than any integer.
+            #[inline]
+            fn partial_cmp(&self, other: &$int) -> Option<Ordering> {
+                let $v = other;
+                Some(repr_cmp_int(&self.repr, $to_ibig))
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialOrd<FBig<R, B>> for $int {
+            #[inline]
+            fn partial_cmp(&self, other: &FBig<R, B>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+impl_cmp_with_int!(IBig, v => v.clone());
+impl_cmp_with_int!(UBig, v => IBig::from(v.clone()));
+impl_cmp_with_int!(u8, v => IBig::from(*v));
+impl_cmp_with_int!(u16, v => IBig::from(*v));
+impl_cmp_with_int!(u32, v => IBig::from(*v));
+impl_cmp_with_int!(u64, v => IBig::from(*v));
+impl_cmp_with_int!(u128, v => IBig::from(*v));
+impl_cmp_with_int!(usize, v => IBig::from(*v));
+impl_cmp_with_int!(i8, v => IBig::from(*v));
+impl_cmp_with_int!(i16, v => IBig::from(*v));
+impl_cmp_with_int!(i32, v => IBig::from(*v));
+impl_cmp_with_int!(i64, v => IBig::from(*v));
+impl_cmp_with_int!(i128, v => IBig::from(*v));
+impl_cmp_with_int!(isize, v => IBig::from(*v));
+
+macro_rules! impl_cmp_with_float {
+    ($f:ty) => {
+        impl<R: Round, const B: Word> PartialEq<$f> for FBig<R, B> {
+            #[inline]
+            fn eq(&self, other: &$f) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialEq<FBig<R, B>> for $f {
+            #[inline]
+            fn eq(&self, other: &FBig<R, B>) -> bool {
+                other.eq(self)
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialOrd<$f> for FBig<R, B> {
+            /// Compare the number with a primitive float exactly. As in the comparison between
+            /// [FBig] instances, the NaN (on either side) is equal to itself and greater than any
+            /// other values, so the result is never [None].
+            #[inline]
+            fn partial_cmp(&self, other: &$f) -> Option<Ordering> {
+                // the conversion is exact, it fails only if the number is NaN
+                let other = match FBig::<Zero, 2>::try_from(*other) {
+                    Ok(f) => f.into_repr(),
+                    Err(_) => Repr::nan(),
+                };
+                Some(repr_cmp_binary(&self.repr, &other))
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialOrd<FBig<R, B>> for $f {
+            #[inline]
+            fn partial_cmp(&self, other: &FBig<R, B>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+impl_cmp_with_float!(f32);
+impl_cmp_with_float!(f64);
@@ -0,0 +1,912 @@
+//! Implementation of the complex number type [CBig]
+
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::{
+    error::{check_inf, check_precision_limited, panic_out_of_domain},
+    fbig::FBig,
+    fma::DotAccumulator,
+    repr::{Context, Repr, Word},
+    round::{mode, Round, Rounded},
+};
+use dashu_base::{Abs, Approximation::*, BitTest, DivRem, EstimatedLog2, Sign};
+use dashu_int::{error::ParseError, IBig};
+
+/// An arbitrary precision complex number, with the real and imaginary parts represented by [FBig].
+///
+/// The context used in the operations on [CBig] is the one with the maximum precision among the parts
+/// of the operands. The real part and the imaginary part of the result are rounded independently
+/// under this context. Each part is correctly rounded, except for the powers ([powi()][CBig::powi]
+/// and [pow()][CBig::pow]), which are evaluated with guard digits.
+///
+/// # Parsing and printing
+///
+/// The complex number is printed and parsed in the form `a+bi` or `a-bi`, where `a` and `b` are in the
+/// formats supported by [FBig]. When parsing, either part can be omitted (e.g. `a` or `bi`), and the
+/// imaginary unit can be written without a coefficient (e.g. `a+i` or `-i`).
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// # use dashu_float::DBig;
+/// use dashu_float::{round::mode::HalfAway, CBig};
+/// type DCBig = CBig<HalfAway, 10>;
+///
+/// let a = DCBig::from_str_native("1.2+3.4i")?;
+/// let b = CBig::from_parts(DBig::from_str_native("5.6")?, DBig::from_str_native("-7.8")?);
+/// assert_eq!(format!("{}", &a * &b), "33+9.7i");
+/// assert_eq!(format!("{}", a.conj()), "1.2-3.4i");
+/// # Ok::<(), ParseError>(())
+/// ```
+///
+/// # Binary operations
+///
+/// Besides the operations between [CBig] instances, the binary operations between [CBig] and [FBig] or
+/// [IBig] are also supported. The real operand is treated as a complex number with zero imaginary part.
+pub struct CBig<RoundingMode: Round = mode::Zero, const BASE: Word = 2> {
+    re: FBig<RoundingMode, BASE>,
+    im: FBig<RoundingMode, BASE>,
+}
+
+impl<R: Round, const B: Word> CBig<R, B> {
+    /// [CBig] with value 0 and unlimited precision
+    pub const ZERO: Self = Self::from_parts(FBig::ZERO, FBig::ZERO);
+
+    /// [CBig] with value 1 and unlimited precision
+    pub const ONE: Self = Self::from_parts(FBig::ONE, FBig::ZERO);
+
+    /// [CBig] with value i (the imaginary unit) and unlimited precision
+    pub const I: Self = Self::from_parts(FBig::ZERO, FBig::ONE);
+
+    /// Create a complex number from the real part and the imaginary part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_float::DBig;
+    /// use dashu_float::CBig;
+    ///
+    /// let z = CBig::from_parts(DBig::ONE, DBig::NEG_ONE);
+    /// assert_eq!(z.re(), &DBig::ONE);
+    /// assert_eq!(z.im(), &DBig::NEG_ONE);
+    /// ```
+    #[inline]
+    pub const fn from_parts(re: FBig<R, B>, im: FBig<R, B>) -> Self {
+        Self { re, im }
+    }
+
+    /// Get the real part of the complex number
+    #[inline]
+    pub const fn re(&self) -> &FBig<R, B> {
+        &self.re
+    }
+
+    /// Get the imaginary part of the complex number
+    #[inline]
+    pub const fn im(&self) -> &FBig<R, B> {
+        &self.im
+    }
+
+    /// Get the real part and the imaginary part of the complex number
+    #[inline]
+    pub fn into_parts(self) -> (FBig<R, B>, FBig<R, B>) {
+        (self.re, self.im)
+    }
+
+    /// Get the context used in the operations on the complex number, which is the one
+    /// with the larger precision among the contexts of the two parts.
+    #[inline]
+    pub const fn context(&self) -> Context<R> {
+        Context::max(self.re.context, self.im.context)
+    }
+
+    /// Get the maximum precision set for the complex number.
+    ///
+    /// It's equivalent to `self.context().precision()`.
+    #[inline]
+    pub const fn precision(&self) -> usize {
+        self.context().precision
+    }
+
+    /// Apply a new precision limit to both parts of the complex number.
+    ///
+    /// The parts are rounded independently if the new precision is lower.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::{round::mode::HalfAway, CBig};
+    /// type DCBig = CBig<HalfAway, 10>;
+    ///
+    /// let z = DCBig::from_str_native("1.234-5.678i")?;
+    /// assert_eq!(z.with_precision(2), DCBig::from_str_native("1.2-5.7i")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn with_precision(self, precision: usize) -> Self {
+        Self::from_parts(
+            self.re.with_precision(precision).value(),
+            self.im.with_precision(precision).value(),
+        )
+    }
+
+    /// Calculate the complex conjugate of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::{round::mode::HalfAway, CBig};
+    /// type DCBig = CBig<HalfAway, 10>;
+    ///
+    /// let z = DCBig::from_str_native("1.2+3.4i")?;
+    /// assert_eq!(z.conj(), DCBig::from_str_native("1.2-3.4i")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn conj(&self) -> Self {
+        Self::from_parts(self.re.clone(), -&self.im)
+    }
+
+    /// Calculate the absolute value (modulus) of the complex number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::{round::mode::HalfAway, CBig};
+    /// type DCBig = CBig<HalfAway, 10>;
+    ///
+    /// let z = DCBig::from_str_native("1.2+3.4i")?;
+    /// assert_eq!(z.abs(), DBig::from_str_native("3.6")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited and both parts are not zero.
+    #[inline]
+    pub fn abs(&self) -> FBig<R, B> {
+        self.context().complex_abs(self)
+    }
+
+    /// Calculate the argument (phase angle) of the complex number, which is in the range `(-π, π]`.
+    ///
+    /// The argument of zero is defined as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::{round::mode::HalfAway, CBig};
+    /// type DCBig = CBig<HalfAway, 10>;
+    ///
+    /// let z = DCBig::from_str_native("1.2+3.4i")?;
+    /// assert_eq!(z.arg(), DBig::from_str_native("1.2")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn arg(&self) -> FBig<R, B> {
+        let context = self.context();
+        context.part(context.atan2(&self.im.repr, &self.re.repr))
+    }
+
+    /// Calculate the principal square root of the complex number, whose real part is non-negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::{round::mode::HalfAway, CBig};
+    /// type DCBig = CBig<HalfAway, 10>;
+    ///
+    /// let z = DCBig::from_str_native("-5-12i")?;
+    /// assert_eq!(z.sqrt(), DCBig::from_str_native("2-3i")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn sqrt(&self) -> Self {
+        self.context().complex_sqrt(self)
+    }
+
+    /// Calculate the exponential function (`eᶻ`) on the complex number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::{round::mode::HalfAway, CBig};
+    /// type DCBig = CBig<HalfAway, 10>;
+    ///
+    /// let z = DCBig::from_str_native("1.2+3.4i")?;
+    /// assert_eq!(z.exp(), DCBig::from_str_native("-3.2-0.85i")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn exp(&self) -> Self {
+        self.context().complex_exp(self)
+    }
+
+    /// Calculate the principal value of the natural logarithm on the complex number,
+    /// whose imaginary part is in the range `(-π, π]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::{round::mode::HalfAway, CBig};
+    /// type DCBig = CBig<HalfAway, 10>;
+    ///
+    /// let z = DCBig::from_str_native("1.2+3.4i")?;
+    /// assert_eq!(z.ln(), DCBig::from_str_native("1.3+1.2i")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is zero.
+    #[inline]
+    pub fn ln(&self) -> Self {
+        self.context().complex_ln(self)
+    }
+
+    /// Raise the complex number to an integer power.
+    ///
+    /// The result is evaluated by binary exponentiation with guard digits, and a negative power
+    /// is the inverse of the positive power evaluated with more guard digits. Therefore the parts
+    /// are not guaranteed to be correctly rounded. Under unlimited precision, a non-negative power
+    /// is exact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::{round::mode::HalfAway, CBig};
+    /// type DCBig = CBig<HalfAway, 10>;
+    ///
+    /// let z = DCBig::from_str_native("1.2+3.4i")?;
+    /// assert_eq!(z.powi(3.into()), DCBig::from_str_native("-40-25i")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited and the exponent is negative.
+    #[inline]
+    pub fn powi(&self, exp: IBig) -> Self {
+        self.context().complex_powi(self, exp)
+    }
+
+    /// Raise the complex number to a complex power, using the principal value of the logarithm.
+    ///
+    /// The result is evaluated as `exp(w·ln(z))` with guard digits, so it's not guaranteed
+    /// to be correctly rounded. If the exponent is an integer, it's equivalent to [powi()][CBig::powi].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::{round::mode::HalfAway, CBig};
+    /// type DCBig = CBig<HalfAway, 10>;
+    ///
+    /// let i = DCBig::from_str_native("1.0i")?;
+    /// // iⁱ = e^(-π/2)
+    /// assert_eq!(i.pow(&i), DCBig::from_str_native("0.21")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the base is zero and the real part of
+    /// the exponent is not positive.
+    #[inline]
+    pub fn pow(&self, exp: &Self) -> Self {
+        let context = Context::max(self.context(), exp.context());
+        context.complex_pow(self, exp)
+    }
+}
+
+impl<R: Round> Context<R> {
+    /// Convert the rounded value to a part of the complex number under this context
+    #[inline]
+    fn part<const B: Word>(&self, value: Rounded<FBig<R, B>>) -> FBig<R, B> {
+        // the exact shortcuts of the functions (e.g. zero) could have other contexts
+        FBig::new(value.value().repr, *self)
+    }
+
+    /// Round a number as a part of the complex number under this context
+    #[inline]
+    fn round_part<const B: Word>(&self, x: &Repr<B>) -> FBig<R, B> {
+        FBig::new(self.repr_round_ref(x).value(), *self)
+    }
+
+    fn complex_round<const B: Word>(&self, z: &CBig<R, B>) -> CBig<R, B> {
+        CBig::from_parts(self.round_part(&z.re.repr), self.round_part(&z.im.repr))
+    }
+
+    fn complex_add<const B: Word>(&self, lhs: &CBig<R, B>, rhs: &CBig<R, B>) -> CBig<R, B> {
+        CBig::from_parts(
+            self.part(self.add(&lhs.re.repr, &rhs.re.repr)),
+            self.part(self.add(&lhs.im.repr, &rhs.im.repr)),
+        )
+    }
+
+    fn complex_sub<const B: Word>(&self, lhs: &CBig<R, B>, rhs: &CBig<R, B>) -> CBig<R, B> {
+        CBig::from_parts(
+            self.part(self.sub(&lhs.re.repr, &rhs.re.repr)),
+            self.part(self.sub(&lhs.im.repr, &rhs.im.repr)),
+        )
+    }
+
+    /// Calculate `(a+bi)(c+di) = (ac-bd) + (ad+bc)i`, where each part is rounded only once
+    fn complex_mul<const B: Word>(&self, lhs: &CBig<R, B>, rhs: &CBig<R, B>) -> CBig<R, B> {
+        let mut re = DotAccumulator::new(*self);
+        re.add_product(&lhs.re.repr, &rhs.re.repr);
+        re.sub_product(&lhs.im.repr, &rhs.im.repr);
+        let mut im = DotAccumulator::new(*self);
+        im.add_product(&lhs.re.repr, &rhs.im.repr);
+        im.add_product(&lhs.im.repr, &rhs.re.repr);
+        CBig::from_parts(self.part(re.value()), self.part(im.value()))
+    }
+
+    /// Calculate `(a+bi)/(c+di) = ((ac+bd) + (bc-ad)i) / (c²+d²)`, where the numerators
+    /// and the denominator are evaluated exactly, so each part is rounded only once
+    fn complex_div<const B: Word>(&self, lhs: &CBig<R, B>, rhs: &CBig<R, B>) -> CBig<R, B> {
+        let (a, b) = (&lhs.re.repr, &lhs.im.repr);
+        let (c, d) = (&rhs.re.repr, &rhs.im.repr);
+        if d.is_zero() {
+            return CBig::from_parts(self.part(self.div(a, c)), self.part(self.div(b, c)));
+        } else if c.is_zero() {
+            let neg_a = -a.clone();
+            return CBig::from_parts(self.part(self.div(b, d)), self.part(self.div(&neg_a, d)));
+        }
+
+        let exact = Context::<R>::new(0);
+        let mut re = DotAccumulator::new(exact);
+        re.add_product(a, c);
+        re.add_product(b, d);
+        let mut im = DotAccumulator::new(exact);
+        im.add_product(b, c);
+        im.sub_product(a, d);
+        let den = norm_exact(rhs);
+        CBig::from_parts(
+            self.part(self.div(&re.value().value().repr, &den)),
+            self.part(self.div(&im.value().value().repr, &den)),
+        )
+    }
+
+    fn complex_abs<const B: Word>(&self, z: &CBig<R, B>) -> FBig<R, B> {
+        if z.im.repr.is_zero() {
+            return self.round_part(&abs_repr(&z.re.repr));
+        } else if z.re.repr.is_zero() {
+            return self.round_part(&abs_repr(&z.im.repr));
+        }
+        self.part(self.sqrt(&norm_exact(z)))
+    }
+
+    /// Evaluate `√((|z|+|x|)/2)` under this context, where `|z|²` is given by `norm`
+    fn half_sqrt<const B: Word>(&self, norm: &Repr<B>, abs_x: &Repr<B>) -> FBig<R, B> {
+        let r = self.sqrt(norm).value();
+        let h = r + self.round_part(abs_x);
+        (h / 2u8).sqrt()
+    }
+
+    fn complex_sqrt<const B: Word>(&self, z: &CBig<R, B>) -> CBig<R, B> {
+        check_precision_limited(self.precision);
+        let (x, y) = (&z.re.repr, &z.im.repr);
+        if y.is_zero() {
+            return match x.sign() {
+                Sign::Positive => {
+                    CBig::from_parts(self.part(self.sqrt(x)), FBig::new(Repr::zero(), *self))
+                }
+                Sign::Negative => CBig::from_parts(
+                    FBig::new(Repr::zero(), *self),
+                    self.part(self.sqrt(&-x.clone())),
+                ),
+            };
+        }
+
+        // √(x+yi) = t + (y/2t)i when x >= 0, and |y|/2t + sgn(y)·t·i when x < 0,
+        // where t = √((|z|+|x|)/2). The other part is exact only if t is exact.
+        let norm = norm_exact(z);
+        let abs_x = abs_repr(x);
+        let t_exact = exact_sqrt(&norm).and_then(|r| {
+            let h = Context::<R>::new(0).add(&r, &abs_x).value().repr;
+            exact_half(h).and_then(|h| exact_sqrt(&h))
+        });
+
+        let eval_t = |sign: Sign| match &t_exact {
+            Some(t) => self.round_part(&Repr::new(sign * t.significand.clone(), t.exponent)),
+            None => self.part(self.round_ziv(|c| sign * c.half_sqrt(&norm, &abs_x))),
+        };
+        let eval_quotient = |y: &Repr<B>| match &t_exact {
+            Some(t) => {
+                let double_t = Repr::new(t.significand.clone() * 2u8, t.exponent);
+                self.part(self.div(y, &double_t))
+            }
+            None => {
+                self.part(self.round_ziv(|c| c.round_part(y) / (c.half_sqrt(&norm, &abs_x) * 2u8)))
+            }
+        };
+        match x.sign() {
+            Sign::Positive => CBig::from_parts(eval_t(Sign::Positive), eval_quotient(y)),
+            Sign::Negative => CBig::from_parts(eval_quotient(&abs_repr(y)), eval_t(y.sign())),
+        }
+    }
+
+    fn complex_exp<const B: Word>(&self, z: &CBig<R, B>) -> CBig<R, B> {
+        check_precision_limited(self.precision);
+        let (x, y) = (&z.re.repr, &z.im.repr);
+        if y.is_zero() {
+            return CBig::from_parts(self.part(self.exp(x)), FBig::new(Repr::zero(), *self));
+        } else if x.is_zero() {
+            return CBig::from_parts(self.part(self.cos(y)), self.part(self.sin(y)));
+        }
+
+        // eˣ⁺ʸⁱ = eˣ(cos y + i·sin y), where both parts are not exact when x and y are not zero
+        let re = self.round_ziv(|c| c.exp(x).value() * c.cos(y).value());
+        let im = self.round_ziv(|c| c.exp(x).value() * c.sin(y).value());
+        CBig::from_parts(self.part(re), self.part(im))
+    }
+
+    fn complex_ln<const B: Word>(&self, z: &CBig<R, B>) -> CBig<R, B> {
+        check_precision_limited(self.precision);
+        let (x, y) = (&z.re.repr, &z.im.repr);
+        let re = if y.is_zero() {
+            if x.is_zero() {
+                panic_out_of_domain()
+            }
+            self.ln(&abs_repr(x))
+        } else if x.is_zero() {
+            self.ln(&abs_repr(y))
+        } else {
+            // ln|z| = ln(1 + (x²+y²-1))/2, where x²+y²-1 is evaluated exactly
+            let norm_m1 = Context::<R>::new(0)
+                .sub(&norm_exact(z), &Repr::one())
+                .value();
+            if norm_m1.repr.is_zero() {
+                Exact(FBig::ZERO)
+            } else {
+                self.round_ziv(|c| c.ln_1p(&norm_m1.repr).value() / 2u8)
+            }
+        };
+        CBig::from_parts(self.part(re), self.part(self.atan2(y, x)))
+    }
+
+    fn complex_powi<const B: Word>(&self, base: &CBig<R, B>, exp: IBig) -> CBig<R, B> {
+        check_inf(&base.re.repr);
+        check_inf(&base.im.repr);
+
+        let (exp_sign, exp) = exp.into_parts();
+        if exp_sign == Sign::Negative {
+            // calculate the inverse of the positive power with guard digits, the parts are
+            // not guaranteed to be correctly rounded (see the documentation of CBig::powi)
+            check_precision_limited(self.precision);
+            let guard_digits = self.precision.log2_est() as usize + 2; // heuristic
+            let work_context = self.work_context(self.precision + guard_digits);
+            let pow = work_context.complex_powi(base, exp.into());
+            return self.complex_round(&work_context.complex_div(&CBig::ONE, &pow));
+        }
+        if exp.is_zero() {
+            return CBig::ONE;
+        } else if exp.is_one() {
+            return self.complex_round(base);
+        }
+
+        let work_context = if self.is_limited() {
+            // increase working precision when the exponent is large
+            let guard_digits = exp.bit_len() + self.precision.bit_len(); // heuristic
+            self.work_context(self.precision + guard_digits)
+        } else {
+            Context::<R>::new(0)
+        };
+
+        // binary exponentiation from left to right
+        let mut p = exp.bit_len() - 2;
+        let mut res = work_context.complex_mul(base, base);
+        loop {
+            if exp.bit(p) {
+                res = work_context.complex_mul(&res, base);
+            }
+            if p == 0 {
+                break;
+            }
+            p -= 1;
+            res = work_context.complex_mul(&res, &res);
+        }
+
+        self.complex_round(&res)
+    }
+
+    fn complex_pow<const B: Word>(&self, base: &CBig<R, B>, exp: &CBig<R, B>) -> CBig<R, B> {
+        check_precision_limited(self.precision);
+        if exp.im.repr.is_zero() {
+            if let Exact(n) = exp.re.to_int() {
+                return self.complex_powi(base, n);
+            }
+        }
+        if base.re.repr.is_zero() && base.im.repr.is_zero() {
+            return match exp.re.repr.sign() {
+                Sign::Positive if !exp.re.repr.is_zero() => CBig::ZERO,
+                _ => panic_out_of_domain(),
+            };
+        }
+
+        // zʷ = exp(w·ln(z)), use a simple rule for guard bits, the same as powf
+        let guard_digits = 10 + self.precision.log2_est() as usize;
+        let work_context = self.work_context(self.precision + guard_digits);
+        let ln = work_context.complex_ln(base);
+        let pow = work_context.complex_exp(&work_context.complex_mul(&ln, exp));
+        self.complex_round(&pow)
+    }
+}
+
+/// Calculate `|x|`
+#[inline]
+fn abs_repr<const B: Word>(x: &Repr<B>) -> Repr<B> {
+    Repr::new(x.significand.clone().abs(), x.exponent)
+}
+
+/// Calculate `|z|² = x² + y²` exactly
+fn norm_exact<R: Round, const B: Word>(z: &CBig<R, B>) -> Repr<B> {
+    let mut acc = DotAccumulator::new(Context::<R>::new(0));
+    acc.add_product(&z.re.repr, &z.re.repr);
+    acc.add_product(&z.im.repr, &z.im.repr);
+    acc.value().value().repr
+}
+
+/// Calculate `√x` if it's exactly representable
+fn exact_sqrt<const B: Word>(x: &Repr<B>) -> Option<Repr<B>> {
+    // the square root of a number with n digits has at most n/2+1 digits
+    match Context::<mode::Zero>::new(x.digits() / 2 + 2).sqrt(x) {
+        Exact(v) => Some(v.repr),
+        Inexact(..) => None,
+    }
+}
+
+/// Calculate `x/2` if it's exactly representable
+fn exact_half<const B: Word>(x: Repr<B>) -> Option<Repr<B>> {
+    if B % 2 == 0 {
+        Some(Repr::new(x.significand * (B / 2), x.exponent - 1))
+    } else {
+        let (q, r) = x.significand.div_rem(IBig::from(2u8));
+        r.is_zero().then(|| Repr::new(q, x.exponent))
+    }
+}
+
+// This custom implementation is necessary due to https://github.com/rust-lang/rust/issues/98374
+impl<R: Round, const B: Word> Clone for CBig<R, B> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from_parts(self.re.clone(), self.im.clone())
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.re.clone_from(&source.re);
+        self.im.clone_from(&source.im);
+    }
+}
+
+impl<R: Round, const B: Word> Default for CBig<R, B> {
+    /// Default value: 0.
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<R1: Round, R2: Round, const B: Word> PartialEq<CBig<R2, B>> for CBig<R1, B> {
+    #[inline]
+    fn eq(&self, other: &CBig<R2, B>) -> bool {
+        self.re == other.re && self.im == other.im
+    }
+}
+
+impl<R: Round, const B: Word> Eq for CBig<R, B> {}
+
+impl<R: Round, const B: Word> From<FBig<R, B>> for CBig<R, B> {
+    #[inline]
+    fn from(re: FBig<R, B>) -> Self {
+        Self::from_parts(re, FBig::ZERO)
+    }
+}
+
+impl<R: Round, const B: Word> From<IBig> for CBig<R, B> {
+    #[inline]
+    fn from(re: IBig) -> Self {
+        Self::from_parts(re.into(), FBig::ZERO)
+    }
+}
+
+impl<R: Round, const B: Word> Neg for CBig<R, B> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::from_parts(-self.re, -self.im)
+    }
+}
+
+impl<R: Round, const B: Word> Neg for &CBig<R, B> {
+    type Output = CBig<R, B>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        CBig::from_parts(-&self.re, -&self.im)
+    }
+}
+
+/// Implement `impl Op for CBig` by forwarding to the context method, including operations taking by references.
+macro_rules! impl_complex_binop {
+    (impl $trait:ident, $method:ident, $op:ident) => {
+        impl<'l, 'r, R: Round, const B: Word> $trait<&'r CBig<R, B>> for &'l CBig<R, B> {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: &CBig<R, B>) -> Self::Output {
+                let context = Context::max(self.context(), rhs.context());
+                context.$op(self, rhs)
+            }
+        }
+
+        impl<'r, R: Round, const B: Word> $trait<&'r CBig<R, B>> for CBig<R, B> {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: &CBig<R, B>) -> Self::Output {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<'l, R: Round, const B: Word> $trait<CBig<R, B>> for &'l CBig<R, B> {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: CBig<R, B>) -> Self::Output {
+                self.$method(&rhs)
+            }
+        }
+
+        impl<R: Round, const B: Word> $trait<CBig<R, B>> for CBig<R, B> {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: CBig<R, B>) -> Self::Output {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+/// Implement `impl Op<A> for CBig` and `impl Op<CBig> for A` by converting A to CBig,
+/// including operations taking by references.
+macro_rules! impl_complex_binop_with_real {
+    (impl $trait:ident<$target:ty>, $method:ident) => {
+        impl<R: Round, const B: Word> $trait<$target> for CBig<R, B> {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: $target) -> Self::Output {
+                self.$method(CBig::<R, B>::from(rhs))
+            }
+        }
+
+        impl<'l, R: Round, const B: Word> $trait<$target> for &'l CBig<R, B> {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: $target) -> Self::Output {
+                self.$method(CBig::<R, B>::from(rhs))
+            }
+        }
+
+        impl<'r, R: Round, const B: Word> $trait<&'r $target> for CBig<R, B> {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: &$target) -> Self::Output {
+                self.$method(CBig::<R, B>::from(rhs.clone()))
+            }
+        }
+
+        impl<'l, 'r, R: Round, const B: Word> $trait<&'r $target> for &'l CBig<R, B> {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: &$target) -> Self::Output {
+                self.$method(CBig::<R, B>::from(rhs.clone()))
+            }
+        }
+
+        impl<R: Round, const B: Word> $trait<CBig<R, B>> for $target {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: CBig<R, B>) -> Self::Output {
+                CBig::<R, B>::from(self).$method(rhs)
+            }
+        }
+
+        impl<'l, R: Round, const B: Word> $trait<CBig<R, B>> for &'l $target {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: CBig<R, B>) -> Self::Output {
+                CBig::<R, B>::from(self.clone()).$method(rhs)
+            }
+        }
+
+        impl<'r, R: Round, const B: Word> $trait<&'r CBig<R, B>> for $target {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: &CBig<R, B>) -> Self::Output {
+                CBig::<R, B>::from(self).$method(rhs)
+            }
+        }
+
+        impl<'l, 'r, R: Round, const B: Word> $trait<&'r CBig<R, B>> for &'l $target {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: &CBig<R, B>) -> Self::Output {
+                CBig::<R, B>::from(self.clone()).$method(rhs)
+            }
+        }
+    };
+}
+
+/// Implement `impl OpAssign<A> for CBig` by forwarding to `*z = mem::take(z).op(A)`, including &A.
+macro_rules! impl_complex_binop_assign {
+    (impl $trait:ident<$t2:ty>, $methodassign:ident, $method:ident) => {
+        impl<R: Round, const B: Word> $trait<$t2> for CBig<R, B> {
+            #[inline]
+            fn $methodassign(&mut self, rhs: $t2) {
+                *self = core::mem::take(self).$method(rhs);
+            }
+        }
+        impl<R: Round, const B: Word> $trait<&$t2> for CBig<R, B> {
+            #[inline]
+            fn $methodassign(&mut self, rhs: &$t2) {
+                *self = core::mem::take(self).$method(rhs);
+            }
+        }
+    };
+}
+
+impl_complex_binop!(impl Add, add, complex_add);
+impl_complex_binop!(impl Sub, sub, complex_sub);
+impl_complex_binop!(impl Mul, mul, complex_mul);
+impl_complex_binop!(impl Div, div, complex_div);
+
+macro_rules! impl_complex_ops_with_real {
+    ($($t:ty)*) => {$(
+        impl_complex_binop_with_real!(impl Add<$t>, add);
+        impl_complex_binop_with_real!(impl Sub<$t>, sub);
+        impl_complex_binop_with_real!(impl Mul<$t>, mul);
+        impl_complex_binop_with_real!(impl Div<$t>, div);
+        impl_complex_binop_assign!(impl AddAssign<$t>, add_assign, add);
+        impl_complex_binop_assign!(impl SubAssign<$t>, sub_assign, sub);
+        impl_complex_binop_assign!(impl MulAssign<$t>, mul_assign, mul);
+        impl_complex_binop_assign!(impl DivAssign<$t>, div_assign, div);
+    )*};
+}
+impl_complex_ops_with_real!(FBig < R, B > IBig);
+
+impl_complex_binop_assign!(impl AddAssign<CBig<R, B>>, add_assign, add);
+impl_complex_binop_assign!(impl SubAssign<CBig<R, B>>, sub_assign, sub);
+impl_complex_binop_assign!(impl MulAssign<CBig<R, B>>, mul_assign, mul);
+impl_complex_binop_assign!(impl DivAssign<CBig<R, B>>, div_assign, div);
+
+impl<R: Round, const B: Word> CBig<R, B> {
+    /// Convert a string in the native base (i.e. radix `B`) to [CBig].
+    ///
+    /// The accepted formats are `a+bi`, `a-bi`, `a` and `bi`, where `a` and `b` are in the
+    /// formats accepted by [FBig::from_str_native]. The coefficient `b` can be omitted when
+    /// it's one (e.g. `a+i` or `-i`). The parts are parsed losslessly with their own precisions.
+    ///
+    /// Note that the trailing `i` is always considered as the imaginary unit, even if it's
+    /// a valid digit in the base `B`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::{round::mode::HalfAway, CBig};
+    /// type DCBig = CBig<HalfAway, 10>;
+    ///
+    /// let z = DCBig::from_str_native("-1.2e3+4.5e-6i")?;
+    /// assert_eq!(z.re(), &DBig::from_str_native("-1.2e3")?);
+    /// assert_eq!(z.im(), &DBig::from_str_native("4.5e-6")?);
+    ///
+    /// let i = DCBig::from_str_native("-i")?;
+    /// assert_eq!(i.im(), &DBig::NEG_ONE);
+    /// assert!(DCBig::from_str_native("1+2").is_err());
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_str_native(src: &str) -> Result<Self, ParseError> {
+        let (re, im) = match src.strip_suffix('i') {
+            None => (src, None),
+            Some(s) => match find_separator::<B>(s) {
+                Some(pos) => (&s[..pos], Some(&s[pos..])),
+                None => ("", Some(s)),
+            },
+        };
+
+        let re = if re.is_empty() && im.is_some() {
+            FBig::ZERO
+        } else {
+            FBig::from_str_native(re)?
+        };
+        let im = match im {
+            None => FBig::ZERO,
+            Some("") | Some("+") => FBig::ONE,
+            Some("-") => FBig::NEG_ONE,
+            Some(s) => FBig::from_str_native(s)?,
+        };
+        Ok(Self::from_parts(re, im))
+    }
+}
+
+/// Find the position of the sign separating the real part and the imaginary part, the signs
+/// following the scale markers (see [FBig::from_str_native]) are skipped.
+fn find_separator<const B: Word>(src: &str) -> Option<usize> {
+    let unsigned = src.strip_prefix(['+', '-']).unwrap_or(src);
+    let has_prefix = unsigned.starts_with("0x") || unsigned.starts_with("0X");
+    let bytes = src.as_bytes();
+    (1..bytes.len()).rev().find(|&i| {
+        if bytes[i] != b'+' && bytes[i] != b'-' {
+            return false;
+        }
+        let is_marker = match bytes[i - 1].to_ascii_lowercase() {
+            b'@' => true,
+            b'e' => B == 10,
+            b'p' => B == 2 && has_prefix,
+            b'b' => B == 2 && !has_prefix,
+            b'o' => B == 8,
+            b'h' => B == 16,
+            _ => false,
+        };
+        !is_marker
+    })
+}
+
+impl<R: Round, const B: Word> FromStr for CBig<R, B> {
+    type Err = ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        CBig::from_str_native(s)
+    }
+}
+
+impl<R: Round, const B: Word> Display for CBig<R, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.re, f)?;
+        match self.im.repr.sign() {
+            Sign::Positive => {
+                f.write_str("+")?;
+                Display::fmt(&self.im, f)?;
+            }
+            Sign::Negative => {
+                f.write_str("-")?;
+                Display::fmt(&-&self.im, f)?;
+            }
+        }
+        f.write_str("i")
+    }
+}
+
+impl<R: Round, const B: Word> fmt::Debug for CBig<R, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CBig")
+            .field("re", &self.re)
+            .field("im", &self.im)
+            .finish()
+    }
+}
@@ -1,9 +1,617 @@
 //! Constants calculation
+//!
+//! All the constants are first evaluated as fixed point integers `N ≈ c * 2^bits` with the
+//! guarantee that `|c * 2^bits - N| < 2`, and then rounded to the target base and precision.
+//! The rounding is checked by rounding both ends of the error interval (Ziv's strategy), so the
+//! results are always correctly rounded.
+//!
+//! When `std` is enabled, the fixed point values are cached per thread, so that repeated
+//! evaluations with the same or lower precision are cheap.
 
-// TODO: provide following functions
-// e(precision)
-// pi(precision)
-// ln2(precision)
-// ln10(precision)
-// sqrt2(precision) // see https://math.stackexchange.com/questions/694699/infinite-series-for-sqrt-2/694814#694814
-// (maybe follow std::f64:consts, optionally also support Phi, Euler, ..)
+use crate::{
+    error::check_precision_limited,
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{mode, Round, Rounded},
+};
+use dashu_base::EstimatedLog2;
+use dashu_int::IBig;
+
+/// The maximum error (in units in the last place) of the estimation passed to [Context::round_ziv]
+const ZIV_ERROR_ULPS: u8 = 16;
+
+/// The maximum ratio between the final and the initial working precision in [Context::round_ziv]
+const ZIV_MAX_FACTOR: usize = 8;
+
+/// The mathematical constants supported by the library
+#[derive(Clone, Copy)]
+pub(crate) enum Constant {
+    Pi = 0,
+    E,
+    Ln2,
+    Ln10,
+    Sqrt2,
+    EulerGamma,
+    Catalan,
+}
+
+/// Number of the constants, used as the size of the cache
+#[cfg(feature = "std")]
+const CONSTANT_COUNT: usize = 7;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// Cached fixed point values of the constants: (value, bits)
+    static CACHE: core::cell::RefCell<[Option<(IBig, usize)>; CONSTANT_COUNT]> =
+        core::cell::RefCell::new(Default::default());
+}
+
+/// Extra bits used in the internal evaluation of the constants
+const GUARD_BITS: usize = 16;
+
+impl Constant {
+    /// Get the fixed point value `N` of the constant such that `|c * 2^bits - N| < 2`.
+    #[cfg(feature = "std")]
+    pub(crate) fn fixed(self, bits: usize) -> IBig {
+        let cached = CACHE.with(|cache| {
+            cache.borrow()[self as usize]
+                .as_ref()
+                .filter(|(_, cbits)| *cbits >= bits)
+                .map(|(value, cbits)| value >> (cbits - bits))
+        });
+        if let Some(value) = cached {
+            // truncating a cached value doesn't break the error bound (|error| < 2)
+            return value;
+        }
+
+        let value = self.compute(bits);
+        CACHE.with(|cache| cache.borrow_mut()[self as usize] = Some((value.clone(), bits)));
+        value
+    }
+
+    /// Get the fixed point value `N` of the constant such that `|c * 2^bits - N| < 2`.
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    pub(crate) fn fixed(self, bits: usize) -> IBig {
+        self.compute(bits)
+    }
+
+    /// Evaluate the fixed point value of the constant without caching.
+    fn compute(self, bits: usize) -> IBig {
+        match self {
+            Constant::Pi => pi_fixed(bits),
+            Constant::E => e_fixed(bits),
+            Constant::Ln2 => ln2_fixed(bits),
+            Constant::Ln10 => ln10_fixed(bits),
+            Constant::Sqrt2 => (IBig::from(2) << (2 * bits)).sqrt().into(),
+            Constant::EulerGamma => euler_gamma_fixed(bits),
+            Constant::Catalan => catalan_fixed(bits),
+        }
+    }
+}
+
+/// Intermediate result of the binary splitting: (P, Q, B, T)
+struct Split {
+    p: IBig,
+    q: IBig,
+    b: IBig,
+    t: IBig,
+}
+
+/// Evaluate the series `S = Σ a(k)/b(k) * Π_{j≤k} p(j)/q(j)` for `k` in `[n1, n2)` using binary splitting,
+/// where the function `term(k)` returns `(p(k), q(k), a(k), b(k))`.
+///
+/// The result satisfies `S = T / (B * Q)`. See Haible, Bruno, and Thomas Papanikolaou.
+/// "Fast multiprecision evaluation of series of rational numbers." (1998)
+fn binary_split<F: Fn(usize) -> (IBig, IBig, IBig, IBig)>(term: &F, n1: usize, n2: usize) -> Split {
+    debug_assert!(n1 < n2);
+    if n2 - n1 == 1 {
+        let (p, q, a, b) = term(n1);
+        let t = a * &p;
+        return Split { p, q, b, t };
+    }
+
+    let m = (n1 + n2) / 2;
+    let l = binary_split(term, n1, m);
+    let r = binary_split(term, m, n2);
+    Split {
+        t: &r.b * &r.q * l.t + &l.b * &l.p * r.t,
+        p: l.p * r.p,
+        q: l.q * r.q,
+        b: l.b * r.b,
+    }
+}
+
+/// Evaluate the series with `n` terms using binary splitting and return `floor(S * 2^bits)`
+fn series_fixed<F: Fn(usize) -> (IBig, IBig, IBig, IBig)>(term: F, n: usize, bits: usize) -> IBig {
+    let Split { q, b, t, .. } = binary_split(&term, 0, n);
+    (t << bits) / (b * q)
+}
+
+/// Calculate acoth(n) = atanh(1/n) = Σ 1/(n²ᵏ⁺¹(2k+1)) in fixed point, |error| < 1
+fn acoth_fixed(n: u32, bits: usize) -> IBig {
+    // each term contributes 2log2(n) bits
+    let terms = (bits + 4) / (2 * n.log2_bounds().0 as usize).max(1) + 2;
+    let n2 = IBig::from(n) * n;
+    let term = |k: usize| {
+        if k == 0 {
+            (IBig::ONE, IBig::from(n), IBig::ONE, IBig::ONE)
+        } else {
+            (IBig::ONE, n2.clone(), IBig::ONE, IBig::from(2 * k + 1))
+        }
+    };
+    series_fixed(term, terms, bits)
+}
+
+/// Calculate pi in fixed point using the Chudnovsky formula, |error| < 2
+fn pi_fixed(bits: usize) -> IBig {
+    /*
+     *         426880 √10005               (6k)! (13591409 + 545140134k)
+     * π = ———————————————————, S =  Σ  ———————————————————————————————————
+     *              S               k≥0  (3k)! (k!)³ (-640320)³ᵏ
+     *
+     * Each term contributes about 47.11 bits.
+     */
+    const C3_OVER_24: u64 = 10939058860032000; // 640320³ / 24
+    let terms = bits / 47 + 2;
+    let term = |k: usize| {
+        if k == 0 {
+            (IBig::ONE, IBig::ONE, IBig::from(13591409), IBig::ONE)
+        } else {
+            let k = k as u64;
+            let p = -(IBig::from(6 * k - 5) * (2 * k - 1) * (6 * k - 1));
+            let q = IBig::from(k).pow(3) * C3_OVER_24;
+            let a = IBig::from(13591409) + IBig::from(545140134u64) * k;
+            (p, q, a, IBig::ONE)
+        }
+    };
+    let Split { q, b, t, .. } = binary_split(&term, 0, terms);
+    let sqrt_c = IBig::from((IBig::from(10005) << (2 * bits)).sqrt());
+    (IBig::from(426880) * sqrt_c * q * b) / t
+}
+
+/// Calculate e = Σ 1/k! in fixed point, |error| < 2
+fn e_fixed(bits: usize) -> IBig {
+    // find the number of terms n such that n! > 2^(bits + 4)
+    let mut terms = 1;
+    let mut log2_fact = 0f32;
+    while log2_fact < (bits + 4) as f32 {
+        terms += 1;
+        log2_fact += terms.log2_bounds().0;
+    }
+
+    let term = |k: usize| {
+        let q = if k == 0 { IBig::ONE } else { IBig::from(k) };
+        (IBig::ONE, q, IBig::ONE, IBig::ONE)
+    };
+    series_fixed(term, terms + 1, bits)
+}
+
+/// Calculate log(2) in fixed point, |error| < 2
+fn ln2_fixed(bits: usize) -> IBig {
+    // log(2) = 18L(26) − 2L(4801) + 8L(8749),
+    // see Gourdon, Xavier, and Pascal Sebah. "The Logarithmic Constant: Log 2." (2004)
+    let work_bits = bits + GUARD_BITS;
+    let sum = 18 * acoth_fixed(26, work_bits) - 2 * acoth_fixed(4801, work_bits)
+        + 8 * acoth_fixed(8749, work_bits);
+    sum >> GUARD_BITS
+}
+
+/// Calculate log(10) in fixed point, |error| < 2
+fn ln10_fixed(bits: usize) -> IBig {
+    // log(10) = log(2) + log(5) = 3log(2) + 2L(9)
+    let work_bits = bits + GUARD_BITS;
+    let sum = 3 * Constant::Ln2.fixed(work_bits) + 2 * acoth_fixed(9, work_bits);
+    sum >> GUARD_BITS
+}
+
+/// Calculate the Euler–Mascheroni constant in fixed point, |error| < 2
+fn euler_gamma_fixed(bits: usize) -> IBig {
+    /*
+     * Use the algorithm B1 from Brent, Richard P., and Edwin M. McMillan.
+     * "Some new algorithms for high-precision computation of Euler's constant." (1980)
+     *
+     * A₀ = -log(n), B₀ = 1, Aⱼ = (Aⱼ₋₁n²/j + Bⱼ)/j, Bⱼ = Bⱼ₋₁n²/j²
+     * γ = ΣAⱼ / ΣBⱼ + O(e⁻⁴ⁿ)
+     *
+     * n is chosen as a power of two, so that log(n) can be derived from log(2).
+     * The number of iterations is about 3.6n, so we need log2(n) + 2 extra guard bits.
+     */
+    let min_n = ((bits + 4) as f32 * core::f32::consts::LN_2 / 4.) as usize + 1;
+    let log2_n = min_n.next_power_of_two().trailing_zeros() as usize;
+    let n2 = IBig::ONE << (2 * log2_n);
+
+    let work_bits = bits + log2_n + GUARD_BITS;
+    let mut a = -(Constant::Ln2.fixed(work_bits) * log2_n);
+    let mut b = IBig::ONE << work_bits;
+    let (mut u, mut v) = (a.clone(), b.clone());
+
+    let mut j = 1usize;
+    while !(a.is_zero() && b.is_zero()) {
+        b = b * &n2 / (j * j);
+        a = (a * &n2 / j + &b) / j;
+        u += &a;
+        v += &b;
+        j += 1;
+    }
+
+    (u << bits) / v
+}
+
+/// Calculate the Catalan's constant in fixed point, |error| < 2
+fn catalan_fixed(bits: usize) -> IBig {
+    /*
+     *     π                 3        (k!)²
+     * G = — log(2+√3) +  —  Σ  ———————————————
+     *     8                 8 k≥0 (2k)! (2k+1)²
+     *
+     *                    2          1
+     * where log(2+√3) = —— Σ   ———————————
+     *                   √3 k≥0  3ᵏ (2k+1)
+     */
+    let work_bits = bits + GUARD_BITS;
+
+    let log_terms = (work_bits as f32 / 3u8.log2_bounds().0) as usize + 2;
+    let log_term = |k: usize| {
+        let q = if k == 0 { IBig::ONE } else { IBig::from(3) };
+        (IBig::ONE, q, IBig::ONE, IBig::from(2 * k + 1))
+    };
+    let log_series = series_fixed(log_term, log_terms, work_bits);
+    let inv_sqrt3 = IBig::from(((IBig::from(4) << (2 * work_bits)) / 3u8).sqrt()); // 2/√3
+    let log = (log_series * inv_sqrt3) >> work_bits;
+
+    let sum_terms = work_bits / 2 + 2;
+    let sum_term = |k: usize| {
+        let (p, q) = if k == 0 {
+            (IBig::ONE, IBig::ONE)
+        } else {
+            (IBig::from(k), IBig::from(2 * (2 * k - 1)))
+        };
+        (p, q, IBig::ONE, IBig::from(2 * k + 1).pow(2))
+    };
+    let sum = series_fixed(sum_term, sum_terms, work_bits);
+
+    let pi = Constant::Pi.fixed(work_bits);
+    let g = ((pi * log) >> work_bits) + 3 * sum;
+    g >> (GUARD_BITS + 3)
+}
+
+impl<R: Round> Context<R> {
+    /// Round the fixed point number `n / 2^bits` under this context.
+    ///
+    /// When the conversion to base `B` requires an intermediate rounding, it's done toward
+    /// negative infinity if `ceil` is false, otherwise toward positive infinity. Therefore the
+    /// result is only reliable for the bounds of an interval.
+    fn repr_round_fixed<const B: Word>(
+        &self,
+        n: IBig,
+        bits: usize,
+        ceil: bool,
+    ) -> Rounded<Repr<B>> {
+        if B.is_power_of_two() {
+            // align the fixed point to the digits, then the conversion is exact
+            let base_bits = B.trailing_zeros() as usize;
+            let digits = (bits + base_bits - 1) / base_bits;
+            let signif = n << (digits * base_bits - bits);
+            return self.repr_round(Repr::new(signif, -(digits as isize)));
+        }
+
+        // the divisor 2^bits is not divisible by B in this case, so it's already normalized
+        let num = Repr::new(n, 0);
+        let den = Repr::new(IBig::ONE << bits, 0);
+        let int_digits = num.digits().saturating_sub(den.digits());
+        if int_digits == 0 {
+            return self.repr_div(num, &den);
+        }
+
+        // the quotient has too many digits for a direct division, so it's first divided with
+        // directed rounding under a higher precision, and then rounded to the target precision
+        let precision = self.precision + int_digits;
+        let bound = if ceil {
+            Context::<mode::Up>::new(precision).repr_div(num, &den)
+        } else {
+            Context::<mode::Down>::new(precision).repr_div(num, &den)
+        };
+        self.repr_round(bound.value())
+    }
+
+    /// Round a number known to lie in the interval `[lo, hi] / 2^bits` under this context.
+    ///
+    /// Returns [None] if the rounding can't be decided, that is when the two bounds are rounded
+    /// to different values.
+    pub(crate) fn round_fixed_bounds<const B: Word>(
+        &self,
+        lo: IBig,
+        hi: IBig,
+        bits: usize,
+    ) -> Option<Rounded<FBig<R, B>>> {
+        debug_assert!(lo <= hi);
+        let lo = self.repr_round_fixed::<B>(lo, bits, false);
+        let hi = self.repr_round_fixed::<B>(hi, bits, true);
+        if lo == hi {
+            Some(lo.map(|v| FBig::new(v, *self)))
+        } else {
+            None
+        }
+    }
+
+    /// Evaluate a function correctly rounded under this context, given a closure that evaluates
+    /// it with an error less than [ZIV_ERROR_ULPS] units in the last place under the given context.
+    ///
+    /// The working precision is increased until the rounding is decided. Because an exact result
+    /// could never be decided, the exact cases have to be handled before calling this function.
+    /// As a safeguard, the loop stops when the working precision reaches [ZIV_MAX_FACTOR] times
+    /// the initial working precision, and the last estimation is rounded.
+    pub(crate) fn round_ziv<const B: Word, F>(&self, mut eval: F) -> Rounded<FBig<R, B>>
+    where
+        F: FnMut(&Context<R>) -> FBig<R, B>,
+    {
+        check_precision_limited(self.precision);
+
+        let exact = Context::<R>::new(0);
+        // the bounds are rounded without raising the flags, since they are not the result
+        let probe = self.with_flags(false);
+        let guard_bits = self.precision.log2_est() + ZIV_ERROR_ULPS.log2_est();
+        let guard_digits = (guard_bits / B.log2_est()) as usize + 2;
+        let mut work_precision = self.precision + guard_digits;
+        let max_precision = work_precision * ZIV_MAX_FACTOR;
+        loop {
+            let v = eval(&self.work_context(work_precision));
+            if v.repr.is_zero() || work_precision >= max_precision {
+                return self.repr_round(v.repr).map(|v| FBig::new(v, *self));
+            }
+
+            // the true value lies in the interval (v - err, v + err)
+            let ulp_exp = v.repr.exponent + v.repr.digits() as isize - work_precision as isize;
+            let err = Repr::new(ZIV_ERROR_ULPS.into(), ulp_exp);
+            let lo = exact.sub(&v.repr, &err).value();
+            let hi = exact.add(&v.repr, &err).value();
+            if probe.repr_round_ref(&lo.repr) == probe.repr_round(hi.repr) {
+                // round again with the flag tracking, so that the flags are raised only once
+                return self.repr_round(lo.repr).map(|v| FBig::new(v, *self));
+            }
+            work_precision += work_precision / 2;
+        }
+    }
+
+    /// Number of bits required in the fixed point representation to
+    /// get a correctly rounded result under this context, in most cases.
+    #[inline]
+    pub(crate) fn fixed_bits<const B: Word>(&self) -> usize {
+        (self.precision as f32 * B.log2_bounds().1) as usize + GUARD_BITS
+    }
+
+    /// Evaluate the constant correctly rounded under this context
+    pub(crate) fn round_const<const B: Word>(&self, constant: Constant) -> Rounded<FBig<R, B>> {
+        check_precision_limited(self.precision);
+
+        let mut bits = self.fixed_bits::<B>();
+        loop {
+            // the true value lies in the interval (n - 2, n + 2) / 2^bits, if both ends are
+            // rounded to the same value, then the rounding is decided
+            let n = constant.fixed(bits);
+            if let Some(v) = self.round_fixed_bounds(&n - 2u8, n + 2u8, bits) {
+                return v;
+            }
+            bits += bits / 2;
+        }
+    }
+
+    /// Calculate π (the ratio of a circle's circumference to its diameter) under this context.
+    ///
+    /// It's evaluated using the Chudnovsky formula with binary splitting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(10);
+    /// assert_eq!(context.pi::<10>(), Inexact(DBig::from_str_native("3.141592654")?, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn pi<const B: Word>(&self) -> Rounded<FBig<R, B>> {
+        self.round_const(Constant::Pi)
+    }
+
+    /// Calculate e (the Euler's number) under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(10);
+    /// assert_eq!(context.e::<10>(), Inexact(DBig::from_str_native("2.718281828")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn e<const B: Word>(&self) -> Rounded<FBig<R, B>> {
+        self.round_const(Constant::E)
+    }
+
+    /// Calculate log(2) under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(10);
+    /// assert_eq!(context.ln2::<10>(), Inexact(DBig::from_str_native("0.6931471806")?, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn ln2<const B: Word>(&self) -> Rounded<FBig<R, B>> {
+        self.round_const(Constant::Ln2)
+    }
+
+    /// Calculate log(10) under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(10);
+    /// assert_eq!(context.ln10::<10>(), Inexact(DBig::from_str_native("2.302585093")?, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn ln10<const B: Word>(&self) -> Rounded<FBig<R, B>> {
+        self.round_const(Constant::Ln10)
+    }
+
+    /// Calculate √2 under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(10);
+    /// assert_eq!(context.sqrt2::<10>(), Inexact(DBig::from_str_native("1.414213562")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn sqrt2<const B: Word>(&self) -> Rounded<FBig<R, B>> {
+        self.round_const(Constant::Sqrt2)
+    }
+
+    /// Calculate γ (the Euler–Mascheroni constant) under this context.
+    ///
+    /// It's evaluated using the Brent–McMillan algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(10);
+    /// assert_eq!(
+    ///     context.euler_gamma::<10>(),
+    ///     Inexact(DBig::from_str_native("0.5772156649")?, NoOp)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn euler_gamma<const B: Word>(&self) -> Rounded<FBig<R, B>> {
+        self.round_const(Constant::EulerGamma)
+    }
+
+    /// Calculate G (the Catalan's constant) under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(10);
+    /// assert_eq!(context.catalan::<10>(), Inexact(DBig::from_str_native("0.9159655942")?, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn catalan<const B: Word>(&self) -> Rounded<FBig<R, B>> {
+        self.round_const(Constant::Catalan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acoth_fixed() {
+        // acoth(6) = 0.168236118310606465252296705108496045055...
+        let decimal_6 = (acoth_fixed(6, 200) * IBig::from(10).pow(40)) >> 200;
+        assert_eq!(
+            decimal_6,
+            IBig::from_str_radix("1682361183106064652522967051084960450557", 10).unwrap()
+        );
+
+        let binary_6 = acoth_fixed(6, 203);
+        assert_eq!(
+            binary_6,
+            IBig::from_str_radix(
+                "2162760151454160450909229890833066944953539957685348083415205",
+                10
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fixed_error_bound() {
+        // values with 100 decimal digits, truncated
+        let cases = [
+            (Constant::Pi, "31415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679"),
+            (Constant::E, "27182818284590452353602874713526624977572470936999595749669676277240766303535475945713821785251664274"),
+            (Constant::Ln2, "06931471805599453094172321214581765680755001343602552541206800094933936219696947156058633269964186875"),
+            (Constant::Ln10, "23025850929940456840179914546843642076011014886287729760333279009675726096773524802359972050895982983"),
+            (Constant::Sqrt2, "14142135623730950488016887242096980785696718753769480731766797379907324784621070388503875343276415727"),
+            (Constant::EulerGamma, "05772156649015328606065120900824024310421593359399235988057672348848677267776646709369470632917467495"),
+            (Constant::Catalan, "09159655941772190150546035149323841107741493742816721342664981196217630197762547694793565129261151062"),
+        ];
+        for (constant, digits) in cases {
+            let expected = IBig::from_str_radix(digits, 10).unwrap();
+            let scale = IBig::from(10).pow(digits.len() - 1);
+            for bits in [10, 64, 200, 330] {
+                let value = constant.compute(bits);
+                let lhs = &value * &scale;
+                let lo = (&expected << bits) - (&scale << 1);
+                let hi = ((&expected + IBig::ONE) << bits) + (&scale << 1);
+                assert!(lo < lhs && lhs < hi);
+            }
+        }
+    }
+}
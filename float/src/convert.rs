@@ -1,14 +1,15 @@
 use core::convert::{TryFrom, TryInto};
 
 use crate::{
-    error::{check_inf, panic_unlimited_precision},
+    error::{check_inf, check_precision_limited, panic_divide_by_0, panic_unlimited_precision},
     fbig::FBig,
     repr::{Context, Repr},
     round::{
+        self,
         mode::{self, HalfEven},
         Round, Rounded, Rounding,
     },
-    utils::{ilog_exact, shr_digits, split_digits_ref},
+    utils::{digit_len, ilog_exact, shr_digits, split_digits_ref},
 };
 use dashu_base::{Approximation::*, DivRemEuclid, EstimatedLog2};
 use dashu_int::{error::OutOfBoundsError, IBig, UBig, Word};
@@ -37,6 +38,61 @@ impl<R: Round> Context<R> {
         let repr = Repr::<B>::new(n, 0);
         self.repr_round(repr).map(|v| FBig::new(v, *self))
     }
+
+    /// Round a number evaluated under another context (usually with a higher precision),
+    /// and associate the result with this context.
+    #[inline]
+    pub(crate) fn round_fbig<const B: Word>(&self, x: FBig<R, B>) -> Rounded<FBig<R, B>> {
+        self.repr_round(x.repr).map(|v| FBig::new(v, *self))
+    }
+
+    /// Convert a fraction `numerator / denominator` to a [FBig] instance with precision
+    /// and rounding given by the context. The result is correctly rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(3);
+    /// assert_eq!(
+    ///     context.convert_fraction::<10>((-3).into(), 8u8.into()),
+    ///     Exact(DBig::from_str_native("-0.375")?)
+    /// );
+    /// assert_eq!(
+    ///     context.convert_fraction::<10>(2.into(), 3u8.into()),
+    ///     Inexact(DBig::from_str_native("0.667")?, AddOne)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero, or if the precision is unlimited and the
+    /// denominator is not one.
+    pub fn convert_fraction<const B: Word>(
+        &self,
+        numerator: IBig,
+        denominator: UBig,
+    ) -> Rounded<FBig<R, B>> {
+        if denominator.is_zero() {
+            panic_divide_by_0()
+        }
+        if denominator.is_one() {
+            return self.convert_int(numerator);
+        }
+        check_precision_limited(self.precision);
+        if numerator.is_zero() {
+            return Exact(FBig::new(Repr::zero(), *self));
+        }
+
+        let lhs = Repr::new(numerator, 0);
+        let rhs = Repr::new(denominator.into(), 0);
+        self.div(&lhs, &rhs)
+    }
 }
 
 impl<R: Round> TryFrom<f32> for FBig<R, 2> {
@@ -61,8 +117,8 @@ impl<R: Round> TryFrom<f32> for FBig<R, 2> {
 
         // then parse normal values
         let mantissa = if exponent == 0 {
-            exponent = -127;
-            mantissa_bits << 1
+            exponent = -126 - 23; // subnormal: minimum exponent + mantissa shift
+            mantissa_bits
         } else {
             exponent -= 127 + 23; // bias + mantissa shift
             mantissa_bits | 0x800000
@@ -99,8 +155,8 @@ impl<R: Round> TryFrom<f64> for FBig<R, 2> {
         }
 
         let mantissa = if exponent == 0 {
-            exponent = -1023;
-            mantissa_bits << 1
+            exponent = -1022 - 52; // subnormal: minimum exponent + mantissa shift
+            mantissa_bits
         } else {
             exponent -= 1023 + 52; // bias + mantissa shift
             mantissa_bits | 0x10000000000000
@@ -219,7 +275,7 @@ impl<R: Round, const B: Word> FBig<R, B> {
     /// ```
     #[inline]
     pub fn with_precision(self, precision: usize) -> Rounded<Self> {
-        let new_context = Context::new(precision);
+        let new_context = self.context.with_precision(precision);
 
         // shrink if necessary
         let repr = if self.context.precision > precision {
@@ -256,7 +312,7 @@ impl<R: Round, const B: Word> FBig<R, B> {
     pub fn with_rounding<NewR: Round>(self) -> FBig<NewR, B> {
         FBig {
             repr: self.repr,
-            context: Context::new(self.context.precision),
+            context: self.context.with_rounding(),
         }
     }
 
@@ -371,9 +427,12 @@ impl<R: Round, const B: Word> FBig<R, B> {
             });
         }
 
-        // shortcut for infinities
-        let context = Context::<R>::new(precision);
-        if self.repr.is_infinite() {
+        // shortcut for infinities and NaN
+        let context = self.context.with_precision(precision);
+        if self.repr.is_neg_zero() {
+            return Exact(FBig::new(Repr::neg_zero(), context));
+        }
+        if !self.repr.is_finite() {
             return Inexact(
                 FBig::new(
                     Repr {
@@ -431,7 +490,7 @@ impl<R: Round, const B: Word> FBig<R, B> {
         } else {
             // if the exponent is large, then we first estimate the result exponent as floor(exponent * log(B) / log(NewB)),
             // then the fractional part is multiplied with the original significand
-            let work_context = Context::<R>::new(2 * precision); // double the precision to get the precision logarithm
+            let work_context = self.context.work_context(2 * precision); // double the precision to get the precision logarithm
             let new_exp =
                 self.repr.exponent * work_context.ln(&Repr::new(Repr::<B>::BASE, 0)).value();
             let (exponent, rem) = new_exp.div_rem_euclid(work_context.ln_base::<NewB>());
@@ -485,7 +544,7 @@ impl<R: Round, const B: Word> FBig<R, B> {
         }
 
         let (hi, lo, precision) = self.split_at_point();
-        let adjust = R::round_fract::<B>(&hi, lo, precision);
+        let adjust = self.context.round_fract::<B>(&hi, lo, precision);
         Inexact(hi + adjust, adjust)
     }
 
@@ -636,6 +695,34 @@ impl<R: Round, const B: Word> FBig<R, B> {
     }
 }
 
+impl<const B: Word> FBig<mode::Dynamic, B> {
+    /// Change the rounding mode of a number with the [Dynamic][mode::Dynamic] rounding mode.
+    ///
+    /// Similar to [with_rounding][FBig::with_rounding], the underlying representation is
+    /// not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::round::{mode::Dynamic, Mode};
+    ///
+    /// let a = DBig::from_str_native("2.345")?.with_rounding::<Dynamic>();
+    /// assert_eq!(a.context().rounding_mode(), Some(Mode::HalfAway));
+    /// let b = a.with_rounding_mode(Mode::Zero).with_precision(2).value();
+    /// assert_eq!(b, DBig::from_str_native("2.3")?.with_rounding::<Dynamic>());
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn with_rounding_mode(self, mode: round::Mode) -> Self {
+        FBig {
+            repr: self.repr,
+            context: self.context.with_rounding_mode(mode),
+        }
+    }
+}
+
 impl<R: Round> FBig<R, 2> {
     /// Convert the float number to [f32] with [HalfEven] rounding mode regardless of the mode associated with this number.
     ///
@@ -656,26 +743,29 @@ impl<R: Round> FBig<R, 2> {
     /// # Ok::<(), ParseError>(())
     /// ```
     pub fn to_f32(&self) -> Rounded<f32> {
-        if self.repr.is_infinite() {
+        if self.repr.is_nan() {
+            return Exact(f32::NAN);
+        } else if self.repr.is_infinite() {
             return Inexact(self.repr.sign() * f32::INFINITY, Rounding::NoOp);
-        } else if self > &Self::try_from(f32::MAX).unwrap() {
-            return Inexact(f32::INFINITY, Rounding::AddOne);
-        } else if self < &Self::try_from(f32::MIN).unwrap() {
-            return Inexact(f32::NEG_INFINITY, Rounding::SubOne);
         }
 
-        // TODO: this implementation is a bandaid, it doesn't handles subnormal yet
-        let context = Context::<HalfEven>::new(24);
+        let context = Context::<HalfEven>::new(24)
+            .with_exponent_range(-126, 127)
+            .with_ieee(true);
         context.repr_round_ref(&self.repr).map(|v| {
-            let exp2 = if v.exponent > 127 {
-                f32::INFINITY
-            } else if v.exponent < -127 {
-                0.0
+            if v.is_infinite() {
+                v.sign() * f32::INFINITY
+            } else if v.is_neg_zero() {
+                -0.0
             } else {
-                let ebits = (v.exponent + 127) as u32;
-                f32::from_bits(ebits << 23)
-            };
-            v.significand.to_f32().value() * exp2
+                // the result is exact because the value is representable
+                let exp2 = if v.exponent < -126 {
+                    f32::from_bits(1 << (v.exponent + 149))
+                } else {
+                    f32::from_bits(((v.exponent + 127) as u32) << 23)
+                };
+                v.significand.to_f32().value() * exp2
+            }
         })
     }
 
@@ -698,25 +788,29 @@ impl<R: Round> FBig<R, 2> {
     /// # Ok::<(), ParseError>(())
     /// ```
     pub fn to_f64(&self) -> Rounded<f64> {
-        if self.repr.is_infinite() {
+        if self.repr.is_nan() {
+            return Exact(f64::NAN);
+        } else if self.repr.is_infinite() {
             return Inexact(self.repr.sign() * f64::INFINITY, Rounding::NoOp);
-        } else if self > &Self::try_from(f64::MAX).unwrap() {
-            return Inexact(f64::INFINITY, Rounding::AddOne);
-        } else if self < &Self::try_from(f64::MIN).unwrap() {
-            return Inexact(f64::NEG_INFINITY, Rounding::SubOne);
         }
 
-        let context = Context::<HalfEven>::new(53);
+        let context = Context::<HalfEven>::new(53)
+            .with_exponent_range(-1022, 1023)
+            .with_ieee(true);
         context.repr_round_ref(&self.repr).map(|v| {
-            let exp2 = if v.exponent > 1023 {
-                f64::INFINITY
-            } else if v.exponent < -1023 {
-                0.0
+            if v.is_infinite() {
+                v.sign() * f64::INFINITY
+            } else if v.is_neg_zero() {
+                -0.0
             } else {
-                let ebits = (v.exponent + 1023) as u64;
-                f64::from_bits(ebits << 52)
-            };
-            v.significand.to_f64().value() * exp2
+                // the result is exact because the value is representable
+                let exp2 = if v.exponent < -1022 {
+                    f64::from_bits(1 << (v.exponent + 1074))
+                } else {
+                    f64::from_bits(((v.exponent + 1023) as u64) << 52)
+                };
+                v.significand.to_f64().value() * exp2
+            }
         })
     }
 }
@@ -737,6 +831,21 @@ impl<R: Round, const B: Word> From<UBig> for FBig<R, B> {
     }
 }
 
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Convert an integer operand of the addition and subtraction to [FBig].
+    ///
+    /// Different from the [From] conversions, the precision is the number of all the digits of
+    /// the integer (including the trailing zeros), so that the sum is not rounded to fewer
+    /// digits than the integer has. The trailing zeros don't affect the products and quotients,
+    /// so the [From] conversions are used for the other operations.
+    #[inline]
+    pub(crate) fn from_int_operand<T: Into<IBig>>(n: T) -> Self {
+        let n = n.into();
+        let context = Context::new(digit_len::<B>(&n));
+        Self::new(Repr::new(n, 0), context)
+    }
+}
+
 macro_rules! fbig_unsigned_conversions {
     ($($t:ty)*) => {$(
         impl<R: Round, const B: Word> From<$t> for FBig<R, B> {
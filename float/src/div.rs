@@ -4,7 +4,7 @@ use crate::{
     helper_macros,
     repr::{Context, Repr, Word},
     round::{Round, Rounded},
-    utils::{digit_len, shl_digits_in_place},
+    utils::{digit_len, shl_digits, shl_digits_in_place, split_digits},
 };
 use core::ops::{Div, DivAssign};
 use dashu_base::{Approximation, DivEuclid, DivRem, DivRemEuclid, RemEuclid};
@@ -14,6 +14,9 @@ impl<R: Round, const B: Word> Div<FBig<R, B>> for FBig<R, B> {
     type Output = FBig<R, B>;
     fn div(self, rhs: FBig<R, B>) -> Self::Output {
         let context = Context::max(self.context, rhs.context);
+        if let Some(special) = context.ieee_div(&self.repr, &rhs.repr) {
+            return FBig::new(special, context);
+        }
         FBig::new(context.repr_div(self.repr, &rhs.repr).value(), context)
     }
 }
@@ -22,6 +25,9 @@ impl<'l, R: Round, const B: Word> Div<FBig<R, B>> for &'l FBig<R, B> {
     type Output = FBig<R, B>;
     fn div(self, rhs: FBig<R, B>) -> Self::Output {
         let context = Context::max(self.context, rhs.context);
+        if let Some(special) = context.ieee_div(&self.repr, &rhs.repr) {
+            return FBig::new(special, context);
+        }
         FBig::new(context.repr_div(self.repr.clone(), &rhs.repr).value(), context)
     }
 }
@@ -30,6 +36,9 @@ impl<'r, R: Round, const B: Word> Div<&'r FBig<R, B>> for FBig<R, B> {
     type Output = FBig<R, B>;
     fn div(self, rhs: &FBig<R, B>) -> Self::Output {
         let context = Context::max(self.context, rhs.context);
+        if let Some(special) = context.ieee_div(&self.repr, &rhs.repr) {
+            return FBig::new(special, context);
+        }
         FBig::new(context.repr_div(self.repr, &rhs.repr).value(), context)
     }
 }
@@ -38,6 +47,9 @@ impl<'l, 'r, R: Round, const B: Word> Div<&'r FBig<R, B>> for &'l FBig<R, B> {
     type Output = FBig<R, B>;
     fn div(self, rhs: &FBig<R, B>) -> Self::Output {
         let context = Context::max(self.context, rhs.context);
+        if let Some(special) = context.ieee_div(&self.repr, &rhs.repr) {
+            return FBig::new(special, context);
+        }
         FBig::new(context.repr_div(self.repr.clone(), &rhs.repr).value(), context)
     }
 }
@@ -176,8 +188,8 @@ impl<'l, 'r, R: Round, const B: Word> DivRemEuclid<&'r FBig<R, B>> for &'l FBig<
 
 macro_rules! impl_add_sub_primitive_with_fbig {
     ($($t:ty)*) => {$(
-        helper_macros::impl_commutative_binop_with_primitive!(impl Div<$t>, div);
-        helper_macros::impl_binop_assign_with_primitive!(impl DivAssign<$t>, div_assign);
+        helper_macros::impl_commutative_binop_with_primitive!(impl Div<$t>, div, from);
+        helper_macros::impl_binop_assign_with_primitive!(impl DivAssign<$t>, div_assign, from);
     )*};
 }
 impl_add_sub_primitive_with_fbig!(u8 u16 u32 u64 u128 usize UBig i8 i16 i32 i64 i128 isize IBig);
@@ -205,7 +217,7 @@ impl<R: Round> Context<R> {
         let (mut q, mut r) = lhs.significand.div_rem(&rhs.significand);
         let mut e = lhs.exponent - rhs.exponent;
         if r.is_zero() {
-            return Approximation::Exact(Repr::new(q, e));
+            return self.repr_round(Repr::new(q, e));
         }
 
         let ddigits = digit_len::<B>(&rhs.significand);
@@ -234,11 +246,28 @@ impl<R: Round> Context<R> {
         }
 
         if r.is_zero() {
-            Approximation::Exact(Repr::new(q, e))
+            return self.repr_round(Repr::new(q, e));
+        }
+
+        // the quotient can have one more digit than the precision
+        let qdigits = digit_len::<B>(&q);
+        let mut shift = qdigits.saturating_sub(self.precision);
+        if let Some(min_exp) = self.min_exponent(e + qdigits as isize - 1) {
+            // round at a higher position if the quotient underflows
+            shift = shift.max((min_exp - e).max(0) as usize);
+        }
+
+        let sign = q.sign();
+        let rounded = if shift > 0 {
+            let (q_hi, q_lo) = split_digits::<B>(q, shift);
+            let den = shl_digits::<B>(&rhs.significand, shift);
+            let adjust = self.round_ratio(&q_hi, q_lo * &rhs.significand + r, &den);
+            Approximation::Inexact(Repr::new(q_hi + adjust, e + shift as isize), adjust)
         } else {
-            let adjust = R::round_ratio(&q, r, &rhs.significand);
+            let adjust = self.round_ratio(&q, r, &rhs.significand);
             Approximation::Inexact(Repr::new(q + adjust, e), adjust)
-        }
+        };
+        self.repr_check_range(rounded, sign)
     }
 
     /// Divide two floating point numbers under this context.
@@ -264,16 +293,23 @@ impl<R: Round> Context<R> {
     /// `fmod` and `remquo`), please use the methods provided by traits [DivEuclid], [RemEuclid] and [DivRemEuclid].
     ///
     pub fn div<const B: Word>(&self, lhs: &Repr<B>, rhs: &Repr<B>) -> Rounded<FBig<R, B>> {
-        check_inf_operands(lhs, rhs);
+        if let Some(special) = self.ieee_div(lhs, rhs) {
+            return Approximation::Exact(FBig::new(special, *self));
+        }
 
-        let lhs_repr = if !lhs.is_zero() && lhs.digits_ub() > rhs.digits_lb() + self.precision {
-            // shrink lhs if it's larger than necessary
-            Self::new(rhs.digits() + self.precision)
-                .repr_round_ref(lhs)
-                .value()
+        let (lhs_digits, rhs_digits) = (lhs.digits(), rhs.digits());
+        if !lhs.is_zero() && lhs_digits > rhs_digits + self.precision {
+            // scale up rhs if lhs is larger than necessary. Rounding lhs instead would
+            // introduce a double rounding, which breaks the directed rounding modes.
+            let shift = lhs_digits - rhs_digits - self.precision;
+            let rhs_repr = Repr {
+                significand: shl_digits::<B>(&rhs.significand, shift),
+                exponent: rhs.exponent - shift as isize,
+            };
+            self.repr_div(lhs.clone(), &rhs_repr)
         } else {
-            lhs.clone()
-        };
-        self.repr_div(lhs_repr, rhs).map(|v| FBig::new(v, *self))
+            self.repr_div(lhs.clone(), rhs)
+        }
+        .map(|v| FBig::new(v, *self))
     }
 }
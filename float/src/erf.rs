@@ -0,0 +1,335 @@
+//! Implementation of the error function and the related functions
+
+use core::cmp::Ordering;
+
+use crate::{
+    cmp::repr_cmp,
+    error::{check_inf, check_precision_limited, panic_out_of_domain},
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{Round, Rounded},
+};
+use dashu_base::{Abs, Approximation::*, EstimatedLog2, Sign};
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Calculate the error function (`erf(x)`) on the float number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.erf(), DBig::from_str_native("0.919")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn erf(&self) -> Self {
+        self.context.erf(&self.repr).value()
+    }
+
+    /// Calculate the complementary error function (`erfc(x) = 1 - erf(x)`) on the float number.
+    ///
+    /// The result keeps full relative precision even if it's tiny.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("12.34")?;
+    /// assert_eq!(a.erfc(), DBig::from_str_native("3.359e-68")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn erfc(&self) -> Self {
+        self.context.erfc(&self.repr).value()
+    }
+
+    /// Calculate the inverse error function (`erf⁻¹(x)`) on the float number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("0.1234")?;
+    /// assert_eq!(a.erfinv(), DBig::from_str_native("0.1098")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not in the range `(-1, 1)`.
+    #[inline]
+    pub fn erfinv(&self) -> Self {
+        self.context.erfinv(&self.repr).value()
+    }
+}
+
+/// Check if `x² > n·log₂(B)`, which implies `e^(-x²) < B⁻ⁿ`
+fn square_exceeds<const B: Word>(x: &Repr<B>, n: usize) -> bool {
+    let bound = n * (B.log2_bounds().1 as usize + 1);
+    !x.is_zero() && x.log2_bounds().0 * 2. > bound.log2_bounds().1
+}
+
+impl<R: Round> Context<R> {
+    /// Create a context with guard digits for the evaluation of the error functions
+    #[inline]
+    fn erf_work(&self) -> Context<R> {
+        // use a simple rule for guard bits, the same as powf
+        let guard_digits = 10 + self.precision.log2_est() as usize;
+        self.work_context(self.precision + guard_digits)
+    }
+
+    /// Evaluate `erf(x)` by the series `2/√π·e^(-x²)·Σ 2ⁿx²ⁿ⁺¹/(1·3·...·(2n+1))`,
+    /// whose terms have the same sign so that there is no cancellation.
+    fn erf_series<const B: Word>(&self, x: &Repr<B>) -> FBig<R, B> {
+        let x2 = Context::<R>::new(0).mul(x, x).value();
+        let ratio = FBig::new(self.repr_round_ref(&x2.repr).value(), *self) * 2u8;
+
+        let mut term = FBig::new(self.repr_round_ref(x).value(), *self);
+        let mut sum = term.clone();
+        for n in 1usize.. {
+            term *= &ratio;
+            term /= 2 * n + 1;
+            if term.repr.top() < sum.repr.top() - self.precision as isize {
+                break;
+            }
+            sum = &sum + &term;
+        }
+
+        let scale = self.exp(&(-x2).repr).value() * 2u8 / self.pi::<B>().value().sqrt();
+        sum * scale
+    }
+
+    /// Evaluate `erfc(x)` for large positive `x` by the asymptotic expansion
+    /// `e^(-x²)/(x√π)·Σ (-1)ⁿ(1·3·...·(2n-1))/(2x²)ⁿ`.
+    ///
+    /// The smallest term of the expansion is about `e^(-x²)`, so it's precise enough only if
+    /// `x² > p·log(B)` where p is the precision.
+    fn erfc_asymptotic<const B: Word>(&self, x: &Repr<B>) -> FBig<R, B> {
+        let x2 = Context::<R>::new(0).mul(x, x).value();
+        let ratio = FBig::ONE / (FBig::new(self.repr_round_ref(&x2.repr).value(), *self) * 2u8);
+
+        let mut sum = FBig::new(Repr::one(), *self);
+        let mut term = sum.clone();
+        for n in 1usize.. {
+            term *= &ratio;
+            term *= 2 * n - 1;
+            if term.repr.top() < sum.repr.top() - self.precision as isize {
+                break;
+            }
+            sum = if n % 2 == 1 { sum - &term } else { sum + &term };
+        }
+
+        let x = FBig::new(self.repr_round_ref(x).value(), *self);
+        let scale = self.exp(&(-x2).repr).value() / (x * self.pi::<B>().value().sqrt());
+        sum * scale
+    }
+
+    /// Evaluate `erfc(x)` for positive `x` with relative precision under this context
+    fn erfc_positive<const B: Word>(&self, x: &Repr<B>) -> FBig<R, B> {
+        if square_exceeds(x, self.precision + 1) {
+            return self.erfc_asymptotic(x);
+        }
+
+        // erfc(x) = 1 - erf(x), where erfc(x) > e^(-x²)/(2x√π) for x > 1, so that
+        // about (x² + log(2x√π))·log_B(e) digits are cancelled
+        let x2 = self.mul(x, x).value().to_int().value();
+        let n = usize::try_from(&x2).unwrap() + 1;
+        let cancelled_bits = n as f32 * core::f32::consts::LOG2_E + n.log2_est() + 2.;
+        let cancelled = (cancelled_bits / B.log2_bounds().0) as usize + 1;
+        let work_context = self.work_context(self.precision + cancelled);
+        let erfc = FBig::ONE - work_context.erf_series(x);
+        self.round_fbig(erfc).value()
+    }
+
+    /// Evaluate `erf⁻¹(y)` for `0 < y < 1` using Newton's method, the result is
+    /// precise to about one unit in the last place under this context.
+    fn erfinv_positive<const B: Word>(&self, y: &Repr<B>) -> FBig<R, B> {
+        let work_context = self.erf_work();
+        let half_sqrt_pi = work_context.pi::<B>().value().sqrt() / 2u8;
+
+        // when y is close to 1, the residual erf(x) - y is evaluated as (1 - y) - erfc(x)
+        let double_y = Repr::<B>::new(&y.significand * 2u8, y.exponent);
+        let near_one = repr_cmp(&double_y, &Repr::one(), None) != Ordering::Less;
+        let (target, mut x) = if near_one {
+            // erfc(x) ≈ e^(-x²)/(x√π), so x² ≈ -log(1-y) - log(√(-π·log(1-y)))
+            let t = Context::<R>::new(0).sub(&Repr::one(), y).value().repr;
+            let t = FBig::new(work_context.repr_round(t).value(), work_context);
+            let w = -t.ln();
+            let correction = (&w * work_context.pi::<B>().value()).ln() / 2u8;
+            let x = (w - correction).sqrt();
+            (t, x)
+        } else {
+            // erf(x) ≈ 2x/√π
+            let y = FBig::new(work_context.repr_round_ref(y).value(), work_context);
+            let x = &y * &half_sqrt_pi;
+            (y, x)
+        };
+
+        loop {
+            let residual = if near_one {
+                &target - work_context.erfc_positive(&x.repr)
+            } else {
+                work_context.erf_series(&x.repr) - &target
+            };
+
+            // x' = x - (erf(x) - y)·√π/2·e^(x²)
+            let delta = residual * &half_sqrt_pi * x.square().exp();
+            x -= delta.clone();
+            if delta.repr.is_zero() || delta.repr.top() < x.repr.top() - self.precision as isize {
+                return x;
+            }
+        }
+    }
+
+    /// Calculate the error function (`erf(x)`) on the float number under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("-1.234")?;
+    /// assert_eq!(context.erf(&a.repr()), Inexact(DBig::from_str_native("-0.92")?, SubOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn erf<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if x.is_zero() {
+            return Exact(FBig::ZERO);
+        }
+
+        // erf(x) = ±(1 - erfc(|x|)), where erfc(|x|) < e^(-x²) doesn't affect
+        // the rounding except for its sign if it's less than B^-(p+2)
+        let digits = self.precision + 2;
+        if square_exceeds(x, digits) {
+            let one = if x.sign() == Sign::Positive {
+                Repr::one()
+            } else {
+                Repr::neg_one()
+            };
+            return self.round_with_tail(&one, -x.sign(), digits);
+        }
+
+        let abs_x = Repr::<B>::new(x.significand.clone().abs(), x.exponent);
+        self.round_ziv(|c| {
+            let work_context = c.erf_work();
+            if square_exceeds(x, work_context.precision + 1) {
+                x.sign() * (FBig::ONE - work_context.erfc_asymptotic(&abs_x))
+            } else {
+                work_context.erf_series(x)
+            }
+        })
+    }
+
+    /// Calculate the complementary error function (`erfc(x) = 1 - erf(x)`) on the float number
+    /// under this context.
+    ///
+    /// The result keeps full relative precision even if it's tiny.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.erfc(&a.repr()), Inexact(DBig::from_str_native("0.081")?, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn erfc<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if x.is_zero() {
+            return Exact(FBig::ONE);
+        }
+
+        // erfc(x) = 1 - 2x/√π + ..., where the second term doesn't affect
+        // the rounding except for its sign if |2x| < B^-(p+2)
+        let digits = self.precision + 2;
+        if x.log2_bounds().1 + 1. < -(digits as f32) * B.log2_bounds().1 {
+            return self.round_with_tail(&Repr::one(), -x.sign(), digits);
+        }
+
+        // erfc(x) = 2 - erfc(|x|) for x < 0, see the comments in erf()
+        if x.sign() == Sign::Negative && square_exceeds(x, digits) {
+            return self.round_with_tail(&Repr::new(2.into(), 0), Sign::Negative, digits);
+        }
+
+        let abs_x = Repr::<B>::new(x.significand.clone().abs(), x.exponent);
+        self.round_ziv(|c| {
+            let work_context = c.erf_work();
+            if x.sign() == Sign::Positive {
+                work_context.erfc_positive(x)
+            } else if square_exceeds(x, work_context.precision + 1) {
+                2u8 - work_context.erfc_asymptotic(&abs_x)
+            } else {
+                FBig::ONE - work_context.erf_series(x)
+            }
+        })
+    }
+
+    /// Calculate the inverse error function (`erf⁻¹(x)`) on the float number under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("-0.999")?;
+    /// assert_eq!(context.erfinv(&a.repr()), Inexact(DBig::from_str_native("-2.3")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not in the range `(-1, 1)`.
+    pub fn erfinv<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if repr_cmp(x, &Repr::one(), None) != Ordering::Less
+            || repr_cmp(x, &Repr::neg_one(), None) != Ordering::Greater
+        {
+            panic_out_of_domain()
+        }
+        if x.is_zero() {
+            return Exact(FBig::ZERO);
+        }
+
+        // erf⁻¹(x) is odd
+        let sign = x.sign();
+        let abs_x = Repr::<B>::new(x.significand.clone().abs(), x.exponent);
+        self.round_ziv(|c| sign * c.erfinv_positive(&abs_x))
+    }
+}
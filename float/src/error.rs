@@ -2,21 +2,31 @@ use crate::repr::{Repr, Word};
 
 #[inline]
 pub fn check_inf<const B: Word>(repr: &Repr<B>) {
-    if repr.is_infinite() {
+    if !repr.is_finite() {
         panic_operate_with_inf()
     }
 }
 
 #[inline]
 pub fn check_inf_operands<const B: Word>(lhs: &Repr<B>, rhs: &Repr<B>) {
-    if lhs.is_infinite() || rhs.is_infinite() {
+    if !lhs.is_finite() || !rhs.is_finite() {
         panic_operate_with_inf()
     }
 }
 
-/// Panics when operate with infinities
+/// Panics when operate with infinities or NaNs
 pub const fn panic_operate_with_inf() -> ! {
-    panic!("arithmetic operations with the infinity are not allowed!")
+    panic!("arithmetic operations with the infinity or NaN are not allowed!")
+}
+
+/// Panics when the operands have different rounding modes decided at runtime
+pub const fn panic_different_rounding() -> ! {
+    panic!("the operands must have the same rounding mode!")
+}
+
+/// Panics when the base is not supported by [DynFBig][crate::DynFBig]
+pub const fn panic_unsupported_base() -> ! {
+    panic!("the base is not supported, it must be one of 2, 8, 10 and 16!")
 }
 
 /// Panics if precision is set to 0
@@ -35,3 +45,18 @@ pub const fn panic_unlimited_precision() -> ! {
 pub const fn panic_power_negative_base() -> ! {
     panic!("powering on negative bases could result in complex number!")
 }
+
+/// Panics when the input is out of the domain of the function
+pub const fn panic_out_of_domain() -> ! {
+    panic!("the input is out of the domain of the function!")
+}
+
+/// Panics when taking the zeroth root of a number
+pub const fn panic_root_zeroth() -> ! {
+    panic!("finding 0th root is not allowed!")
+}
+
+/// Panics when dividing by zero
+pub const fn panic_divide_by_0() -> ! {
+    panic!("divisor must not be 0")
+}
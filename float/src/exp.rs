@@ -3,8 +3,11 @@ use core::convert::TryInto;
 use crate::{
     error::{check_inf, check_precision_limited, panic_power_negative_base},
     fbig::FBig,
+    gamma::split_nearest,
+    log::exact_pow,
     repr::{Context, Repr, Word},
-    round::{Round, Rounded},
+    round::{mode, Round, Rounded},
+    utils::shl_digits,
 };
 use dashu_base::{Approximation::*, BitTest, DivRemEuclid, EstimatedLog2, Sign};
 use dashu_int::IBig;
@@ -75,6 +78,38 @@ impl<R: Round, const B: Word> FBig<R, B> {
     pub fn exp_m1(&self) -> FBig<R, B> {
         self.context.exp_m1(&self.repr).value()
     }
+
+    /// Calculate the base 2 exponential function (`2ˣ`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("-1.234")?;
+    /// assert_eq!(a.exp2(), DBig::from_str_native("0.4251")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn exp2(&self) -> FBig<R, B> {
+        self.context.exp2(&self.repr).value()
+    }
+
+    /// Calculate the base 10 exponential function (`10ˣ`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("-1.234")?;
+    /// assert_eq!(a.exp10(), DBig::from_str_native("0.05834")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn exp10(&self) -> FBig<R, B> {
+        self.context.exp10(&self.repr).value()
+    }
 }
 
 // TODO: give the exact formulation of required guard bits
@@ -110,7 +145,7 @@ impl<R: Round> Context<R> {
             check_precision_limited(self.precision); // TODO: we can allow this if the inverse is exact (only when significand is one?)
 
             let guard_bits = self.precision.bit_len() * 2; // heuristic
-            let rev_context = Context::<R::Reverse>::new(self.precision + guard_bits);
+            let rev_context = self.reversed_work_context(self.precision + guard_bits);
             let pow = rev_context.powi(base, exp.into()).value();
             let inv = rev_context.repr_div(Repr::one(), &pow.repr);
             let repr = inv.and_then(|v| self.repr_round(v));
@@ -126,7 +161,7 @@ impl<R: Round> Context<R> {
         let work_context = if self.is_limited() {
             // increase working precision when the exponent is large
             let guard_digits = exp.bit_len() + self.precision.bit_len(); // heuristic
-            Context::<R>::new(self.precision + guard_digits)
+            self.work_context(self.precision + guard_digits)
         } else {
             Context::<R>::new(0)
         };
@@ -145,7 +180,7 @@ impl<R: Round> Context<R> {
             res = res.and_then(|v| work_context.square(v.repr()));
         }
 
-        res.and_then(|v| v.with_precision(self.precision))
+        res.and_then(|v| self.round_fbig(v))
     }
 
     /// Raise the floating point number to an floating point power under this context.
@@ -186,17 +221,19 @@ impl<R: Round> Context<R> {
 
         // x^y = exp(y*log(x)), use a simple rule for guard bits
         let guard_digits = 10 + self.precision.log2_est() as usize;
-        let work_context = Context::<R>::new(self.precision + guard_digits);
+        let work_context = self.work_context(self.precision + guard_digits);
 
         let res = work_context
             .ln(base)
             .and_then(|v| work_context.mul(&v.repr, exp))
             .and_then(|v| work_context.exp(&v.repr));
-        res.and_then(|v| v.with_precision(self.precision))
+        res.and_then(|v| self.round_fbig(v))
     }
 
     /// Calculate the exponential function (`eˣ`) on the floating point number under this context.
     ///
+    /// The result is correctly rounded.
+    ///
     /// # Examples
     ///
     /// ```
@@ -217,6 +254,8 @@ impl<R: Round> Context<R> {
 
     /// Calculate the exponential minus one function (`eˣ-1`) on the floating point number under this context.
     ///
+    /// The result is correctly rounded.
+    ///
     /// # Examples
     ///
     /// ```
@@ -235,6 +274,123 @@ impl<R: Round> Context<R> {
         self.exp_internal(x, true)
     }
 
+    /// Calculate the base 2 exponential function (`2ˣ`) on the floating point number under this context.
+    ///
+    /// The result is correctly rounded, and it's exact if the input is an integer and
+    /// the power is representable in base `B`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("-1.234")?;
+    /// assert_eq!(context.exp2(&a.repr()), Inexact(DBig::from_str_native("0.43")?, AddOne));
+    /// let b = DBig::from_str_native("-2")?;
+    /// assert_eq!(context.exp2(&b.repr()), Exact(DBig::from_str_native("0.25")?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn exp2<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        self.exp_int_base(x, 2)
+    }
+
+    /// Calculate the base 10 exponential function (`10ˣ`) on the floating point number under this context.
+    ///
+    /// The result is correctly rounded, and it's exact if the input is an integer and
+    /// the power is representable in base `B`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("-1.234")?;
+    /// assert_eq!(context.exp10(&a.repr()), Inexact(DBig::from_str_native("0.058")?, NoOp));
+    /// let b = DBig::from_str_native("-30")?;
+    /// assert_eq!(context.exp10(&b.repr()), Exact(DBig::from_str_native("1e-30")?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn exp10<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        self.exp_int_base(x, 10)
+    }
+
+    /// Calculate `bˣ` where b is 2 or 10
+    fn exp_int_base<const B: Word>(&self, x: &Repr<B>, b: Word) -> Rounded<FBig<R, B>> {
+        debug_assert!(b == 2 || b == 10);
+        check_inf(x);
+        check_precision_limited(self.precision);
+
+        if x.is_zero() {
+            return Exact(FBig::ONE);
+        }
+
+        // the result is exact if x is an integer and bˣ is representable
+        if x.exponent >= 0 && x.log2_bounds().1 < (isize::BITS - 1) as f32 {
+            let k: isize = shl_digits::<B>(&x.significand, x.exponent as usize)
+                .try_into()
+                .unwrap();
+            // the power is only expanded if it could be exact, a longer power is not a boundary
+            // of the rounding, so it's left to the approximation below
+            if let Some(pow) = exact_pow::<B>(b, k, self.precision + 1) {
+                return self.repr_round(pow).map(|v| FBig::new(v, *self));
+            }
+        }
+
+        // when b = B, let bˣ = Bⁿ·Bʳ where n is the nearest integer to x, then the integer part
+        // only shifts the exponent. Bʳ is rounded to odd under a higher precision to prevent
+        // double rounding, because the range of the exponent is checked on the final result.
+        if b == B {
+            let (n, r) = split_nearest(x);
+            if !n.is_zero() {
+                let n: isize = n.try_into().expect("exponent is too large");
+                return Context::<mode::ToOdd>::new(self.precision + 2)
+                    .exp_int_base(&r, b)
+                    .and_then(|v| {
+                        let v = v.into_repr();
+                        self.repr_round(Repr::new(v.significand, v.exponent + n))
+                    })
+                    .map(|v| FBig::new(v, *self));
+            }
+        }
+
+        // bˣ = 1 + x*log(b) + ..., where log(b) < 4. When x is tiny enough, only the sign of
+        // the second term matters for the rounding.
+        let digits = self.precision + 2;
+        if x.log2_bounds().1 + 2. < -((digits + 1) as f32) * B.log2_bounds().1 {
+            return self.round_with_tail(&Repr::one(), x.sign(), digits);
+        }
+
+        // bˣ = exp(x * log(b)), the error of the product is amplified by the magnitude of x
+        let int_digits = (x.log2_est() / B.log2_est()).max(0.) as usize + 1;
+        self.round_ziv(|context| {
+            let ln_context = self.work_context(context.precision + int_digits);
+            let ln_b = match b {
+                2 => ln_context.ln2(),
+                _ => ln_context.ln10(),
+            };
+            let y = ln_context.mul(x, &ln_b.value().repr).value();
+            context.exp(&y.repr).value()
+        })
+    }
+
     // TODO: change reduction to (x - s log2) / 2ⁿ, so that the final powering is always base 2, and doesn't depends on powi.
     //       the powering exp(r)^(2ⁿ) could be optimized by noticing (1+x)^2 - 1 = x^2 + 2x
     //       consider this change after having a benchmark
@@ -250,6 +406,28 @@ impl<R: Round> Context<R> {
             };
         }
 
+        // When x is tiny enough, only the sign of the term after the leading one matters for the
+        // rounding: eˣ = 1 + x + ..., and eˣ - 1 = x + x²/2 + ...
+        if minus_one {
+            let digits = x.digits().max(self.precision) + 2;
+            if x.log2_bounds().1 + 1. < -((digits + 1) as f32) * B.log2_bounds().1 {
+                return self.round_with_tail(x, Sign::Positive, digits);
+            }
+        } else {
+            let digits = self.precision + 2;
+            if x.log2_bounds().1 + 2. < -((digits + 1) as f32) * B.log2_bounds().1 {
+                return self.round_with_tail(&Repr::one(), x.sign(), digits);
+            }
+        }
+
+        // the approximation is not always correctly rounded, especially under the directed
+        // rounding modes, so it's evaluated with more digits until the rounding is decided
+        self.round_ziv(|context| context.exp_approx(x, minus_one))
+    }
+
+    /// Evaluate `eˣ` (or `eˣ - 1` if `minus_one` is true) for a nonzero finite x under this
+    /// context. The error of the result is within a few units in the last place.
+    fn exp_approx<const B: Word>(&self, x: &Repr<B>, minus_one: bool) -> FBig<R, B> {
         // A simple algorithm:
         // - let r = (x - s logB) / Bⁿ, where s = floor(x / logB), such that r < B⁻ⁿ.
         // - if the target precision is p digits, then there're only about p/m terms in Tyler series
@@ -274,11 +452,15 @@ impl<R: Round> Context<R> {
             } else {
                 work_precision = self.precision + series_guard_digits;
             }
-            let context = Context::<R>::new(work_precision);
+            let context = self.work_context(work_precision);
             (0, 0, FBig::new(context.repr_round_ref(x).value(), context))
         } else {
-            work_precision = self.precision + series_guard_digits + pow_guard_digits;
-            let context = Context::<R>::new(work_precision);
+            // the integer part s has about log_B(|x|) digits, which are cancelled in the
+            // reduction x - s logB, so the same number of extra digits is required
+            let reduction_guard_digits = (x.log2_est() / B.log2_est()).max(0.) as usize + 1;
+            work_precision =
+                self.precision + series_guard_digits + pow_guard_digits + reduction_guard_digits;
+            let context = self.work_context(work_precision);
             let x = FBig::new(context.repr_round_ref(x).value(), context);
             let logb = context.ln_base::<B>();
             let (s, r) = x.div_rem_euclid(logb);
@@ -302,27 +484,28 @@ impl<R: Round> Context<R> {
         loop {
             factorial *= k;
             pow *= &r;
-            // TODO: use &pow / &factorial < ulp as stop criteria?
 
-            let next = &sum + &pow / &factorial;
-            if next == sum {
+            // stop when the term is less than the last place of the sum, comparing the sums
+            // doesn't work for directed rounding, where a tiny term can still change the sum
+            let term = &pow / &factorial;
+            if term.repr.is_zero() || term.repr.top() < sum.repr.top() - work_precision as isize {
                 break;
             }
-            sum = next;
+            sum += term;
             k += 1;
         }
 
         if no_scaling {
-            sum.with_precision(self.precision)
+            self.round_fbig(sum).value()
         } else if minus_one {
             // add extra digits to compensate for the subtraction
-            Context::<R>::new(self.precision + self.precision / 8 + 1) // heuristic
+            self.work_context(self.precision + self.precision / 8 + 1) // heuristic
                 .powi(sum.repr(), Repr::<B>::BASE.pow(n))
                 .map(|v| (v << s) - FBig::ONE)
-                .and_then(|v| v.with_precision(self.precision))
+                .and_then(|v| self.round_fbig(v))
+                .value()
         } else {
-            self.powi(sum.repr(), Repr::<B>::BASE.pow(n))
-                .map(|v| v << s)
+            self.powi(sum.repr(), Repr::<B>::BASE.pow(n)).value() << s
         }
     }
 }
@@ -89,6 +89,34 @@ use dashu_int::{DoubleWord, IBig};
 /// two methods.
 ///
 /// The infinities are converted as it is, and the subnormals are converted using its actual values.
+/// The converted numbers are not in the IEEE mode, so the negative zero is converted to zero. In the
+/// IEEE mode, the negative zero can be created by negating [FBig::ZERO].
+///
+/// # IEEE mode
+///
+/// By default, the infinities are only sentinels and the sign of zero is not tracked. To follow the
+/// IEEE 754 semantics for the special values (e.g. `inf - inf` and `0 / 0` produce NaN instead of
+/// panicking), enable the IEEE mode with [with_ieee()][FBig::with_ieee]. See [Context] for details.
+///
+/// Regardless of the mode, the comparison between [FBig] instances is a total order, where the NaN
+/// is equal to itself and greater than any other values, and the two zeros are equal.
+///
+/// # Comparison with other types
+///
+/// [FBig] can be compared with [UBig][dashu_int::UBig], [IBig][dashu_int::IBig], the primitive
+/// integers, [f32] and [f64] directly. These comparisons are exact, neither side is rounded. The NaN
+/// is ordered in the same way as the comparison between [FBig] instances: it's equal to the NaN
+/// of [f32] and [f64], and greater than any other values.
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// # use dashu_float::DBig;
+/// let a = DBig::from_str_native("0.1")?;
+/// assert!(a < 1);
+/// assert!(a != 0.1f64); // 0.1f64 is slightly larger than 0.1
+/// assert!(a < 0.1f64);
+/// # Ok::<(), ParseError>(())
+/// ```
 ///
 pub struct FBig<RoundingMode: Round = mode::Zero, const BASE: Word = 2> {
     pub(crate) repr: Repr<BASE>,
@@ -124,7 +152,7 @@ impl<R: Round, const B: Word> FBig<R, B> {
     #[inline]
     pub fn from_repr(repr: Repr<B>, context: Context<R>) -> Self {
         debug_assert!(
-            repr.is_infinite() || !context.is_limited() || repr.digits() <= context.precision
+            !repr.is_finite() || !context.is_limited() || repr.digits() <= context.precision
         );
         Self { repr, context }
     }
@@ -152,6 +180,12 @@ impl<R: Round, const B: Word> FBig<R, B> {
     /// To test if the float number is infinite, use `self.repr().infinite()`.
     pub const NEG_INFINITY: Self = Self::new(Repr::neg_infinity(), Context::new(0));
 
+    /// [FBig] instance representing the NaN (not a number)
+    ///
+    /// To test if the float number is NaN, use `self.repr().is_nan()`. Operations on the NaN
+    /// are only allowed in the [IEEE mode][FBig::with_ieee].
+    pub const NAN: Self = Self::new(Repr::nan(), Context::new(0));
+
     /// Get the maximum precision set for the float number.
     ///
     /// It's equivalent to `self.context().precision()`.
@@ -202,6 +236,32 @@ impl<R: Round, const B: Word> FBig<R, B> {
     pub const fn context(&self) -> Context<R> {
         self.context
     }
+
+    /// Enable or disable the IEEE mode for the operations on this number.
+    ///
+    /// See [the IEEE mode section][Context#ieee-mode] of [Context] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let zero = DBig::ZERO.with_ieee(true);
+    /// let neg_zero = -&zero;
+    /// assert!(neg_zero.repr().is_neg_zero());
+    /// assert_eq!(DBig::ONE / &neg_zero, DBig::NEG_INFINITY);
+    /// assert!((&zero / &neg_zero).repr().is_nan());
+    /// assert_eq!(DBig::INFINITY.with_ieee(true) - DBig::ONE, DBig::INFINITY);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn with_ieee(self, enabled: bool) -> Self {
+        Self {
+            repr: self.repr,
+            context: self.context.with_ieee(enabled),
+        }
+    }
+
     /// Get a reference to the underlying numeric representation
     #[inline]
     pub const fn repr(&self) -> &Repr<B> {
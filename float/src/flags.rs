@@ -0,0 +1,198 @@
+//! Sticky exception flags of the float operations.
+//!
+//! Similar to the flags in MPFR and the IEEE 754 standard, the exception flags are raised by the
+//! operations under a context with flag tracking enabled (see [Context::with_flags]), and they
+//! stay raised until explicitly cleared. Therefore, it's possible to check whether a whole
+//! sequence of operations is exact, or whether any overflow happened in it.
+//!
+//! The flags are stored per thread, and they are only available with the `std` feature. Without
+//! the `std` feature, the flags are never raised.
+//!
+//! # Examples
+//!
+//! ```
+//! use dashu_float::{flags::{self, Flags}, Context, DBig, round::mode::HalfAway};
+//!
+//! let context = Context::<HalfAway>::new(4).with_flags(true);
+//! let a = DBig::from_repr(DBig::from(3).into_repr(), context);
+//!
+//! flags::clear(Flags::ALL);
+//! let b = &a * &a + DBig::ONE; // 3 * 3 + 1 = 10
+//! assert!(flags::get().is_empty());
+//!
+//! let _ = b / &a; // 10 / 3 is inexact
+//! assert_eq!(flags::get(), Flags::INEXACT);
+//!
+//! flags::clear(Flags::INEXACT);
+//! assert!(!flags::test(Flags::INEXACT));
+//! ```
+
+use core::{
+    fmt::{self, Debug, Formatter},
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not},
+};
+
+use crate::{repr::Context, round::Round};
+
+/// A set of exception flags.
+///
+/// The flags can be combined with the bitwise operators (`|`, `&`, `!`).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Flags(u8);
+
+impl Flags {
+    /// No flag is raised
+    pub const EMPTY: Self = Self(0);
+    /// The result of an operation is rounded
+    pub const INEXACT: Self = Self(1);
+    /// The result of an operation is tiny (its magnitude is smaller than the smallest normal
+    /// number) and inexact. This flag is only raised under a bounded exponent range.
+    pub const UNDERFLOW: Self = Self(2);
+    /// The rounded result of an operation exceeds the largest finite number. This flag is only
+    /// raised under a bounded exponent range.
+    pub const OVERFLOW: Self = Self(4);
+    /// An operation has no meaningful result (such as `inf - inf` and `0 / 0`), and a NaN is
+    /// produced in the IEEE mode.
+    pub const INVALID: Self = Self(8);
+    /// An infinite result is produced from finite operands, such as dividing a nonzero number by zero.
+    pub const DIVIDE_BY_ZERO: Self = Self(16);
+    /// All the flags
+    pub const ALL: Self = Self(31);
+
+    /// Check whether no flag is set
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Check whether all the flags in `other` are set
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Check whether any of the flags in `other` is set
+    #[inline]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl BitOr for Flags {
+    type Output = Flags;
+    #[inline]
+    fn bitor(self, rhs: Flags) -> Flags {
+        Flags(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Flags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Flags) {
+        self.0 |= rhs.0
+    }
+}
+
+impl BitAnd for Flags {
+    type Output = Flags;
+    #[inline]
+    fn bitand(self, rhs: Flags) -> Flags {
+        Flags(self.0 & rhs.0)
+    }
+}
+
+impl BitAndAssign for Flags {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Flags) {
+        self.0 &= rhs.0
+    }
+}
+
+impl Not for Flags {
+    type Output = Flags;
+    #[inline]
+    fn not(self) -> Flags {
+        Flags(!self.0 & Self::ALL.0)
+    }
+}
+
+impl Debug for Flags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const NAMES: [(Flags, &str); 5] = [
+            (Flags::INEXACT, "INEXACT"),
+            (Flags::UNDERFLOW, "UNDERFLOW"),
+            (Flags::OVERFLOW, "OVERFLOW"),
+            (Flags::INVALID, "INVALID"),
+            (Flags::DIVIDE_BY_ZERO, "DIVIDE_BY_ZERO"),
+        ];
+
+        if self.is_empty() {
+            return f.write_str("EMPTY");
+        }
+        let mut first = true;
+        for (flag, name) in NAMES {
+            if self.contains(flag) {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_str(name)?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// The flags raised in the current thread
+    static FLAGS: core::cell::Cell<Flags> = const { core::cell::Cell::new(Flags::EMPTY) };
+}
+
+/// Get the flags raised in the current thread.
+#[cfg(feature = "std")]
+#[inline]
+pub fn get() -> Flags {
+    FLAGS.with(|flags| flags.get())
+}
+
+/// Check whether any of the given flags is raised in the current thread.
+#[cfg(feature = "std")]
+#[inline]
+pub fn test(flags: Flags) -> bool {
+    get().intersects(flags)
+}
+
+/// Clear the given flags in the current thread.
+#[cfg(feature = "std")]
+#[inline]
+pub fn clear(flags: Flags) {
+    FLAGS.with(|f| f.set(f.get() & !flags))
+}
+
+/// Get the flags raised in the current thread and clear all of them.
+#[cfg(feature = "std")]
+#[inline]
+pub fn take() -> Flags {
+    FLAGS.with(|flags| flags.replace(Flags::EMPTY))
+}
+
+/// Raise the given flags in the current thread.
+#[cfg(feature = "std")]
+#[inline]
+pub fn raise(flags: Flags) {
+    FLAGS.with(|f| f.set(f.get() | flags))
+}
+
+impl<R: Round> Context<R> {
+    /// Raise the flags if the flag tracking is enabled
+    #[inline]
+    pub(crate) fn raise_flags(&self, flags: Flags) {
+        #[cfg(feature = "std")]
+        if self.flags && !flags.is_empty() {
+            raise(flags);
+        }
+        #[cfg(not(feature = "std"))]
+        let _ = flags;
+    }
+}
@@ -0,0 +1,326 @@
+//! Fused multiply-add operations and the dot product accumulator
+
+use alloc::vec::Vec;
+
+use crate::{
+    error::{check_inf, check_inf_operands},
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{Round, Rounded},
+    utils::sticky_digits,
+};
+use dashu_base::Sign;
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Calculate `self * a + b` with only one rounding at the end, like the `fma` operation in IEEE 754.
+    ///
+    /// The precision of the output is the maximum of the precisions of the three operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// let b = DBig::from_str_native("6.789")?;
+    /// let c = DBig::from_str_native("-8.378")?;
+    /// // the product is not rounded before the addition
+    /// assert_eq!(a.mul_add(&b, &c), DBig::from_str_native("-0.000374")?);
+    /// assert_eq!(&a * &b + &c, DBig::ZERO);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the operands is infinite.
+    #[inline]
+    pub fn mul_add(&self, a: &Self, b: &Self) -> Self {
+        let context = Context::max(Context::max(self.context, a.context), b.context);
+        context.mul_add(&self.repr, &a.repr, &b.repr).value()
+    }
+
+    /// Calculate `self * a - b` with only one rounding at the end.
+    ///
+    /// The precision of the output is the maximum of the precisions of the three operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// let b = DBig::from_str_native("6.789")?;
+    /// let c = DBig::from_str_native("8.378")?;
+    /// assert_eq!(a.mul_sub(&b, &c), DBig::from_str_native("-0.000374")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the operands is infinite.
+    #[inline]
+    pub fn mul_sub(&self, a: &Self, b: &Self) -> Self {
+        let context = Context::max(Context::max(self.context, a.context), b.context);
+        context.mul_sub(&self.repr, &a.repr, &b.repr).value()
+    }
+}
+
+impl<R: Round> Context<R> {
+    /// Calculate `a * b + c` under this context, where the result is rounded only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// let b = DBig::from_str_native("6.789")?;
+    /// let c = DBig::from_str_native("1.0")?;
+    /// assert_eq!(
+    ///     context.mul_add(&a.repr(), &b.repr(), &c.repr()),
+    ///     Inexact(DBig::from_str_native("9.4")?, AddOne)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the operands is infinite.
+    pub fn mul_add<const B: Word>(
+        &self,
+        a: &Repr<B>,
+        b: &Repr<B>,
+        c: &Repr<B>,
+    ) -> Rounded<FBig<R, B>> {
+        check_inf_operands(a, b);
+        check_inf(c);
+        self.round_sum(exact_mul(a, b), c.clone())
+    }
+
+    /// Calculate `a * b - c` under this context, where the result is rounded only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// let b = DBig::from_str_native("6.789")?;
+    /// let c = DBig::from_str_native("-1.0")?;
+    /// assert_eq!(
+    ///     context.mul_sub(&a.repr(), &b.repr(), &c.repr()),
+    ///     Inexact(DBig::from_str_native("9.4")?, AddOne)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the operands is infinite.
+    pub fn mul_sub<const B: Word>(
+        &self,
+        a: &Repr<B>,
+        b: &Repr<B>,
+        c: &Repr<B>,
+    ) -> Rounded<FBig<R, B>> {
+        check_inf_operands(a, b);
+        check_inf(c);
+        self.round_sum(exact_mul(a, b), -c.clone())
+    }
+}
+
+impl<R: Round> Context<R> {
+    /// Round the sum of two numbers with only one rounding.
+    #[inline]
+    fn round_sum<const B: Word>(&self, x: Repr<B>, y: Repr<B>) -> Rounded<FBig<R, B>> {
+        let sum = self.sticky_sum(x, y);
+        self.repr_round(sum).map(|v| FBig::new(v, *self))
+    }
+
+    /// Calculate the sum of two numbers, which is exact or rounds to the same value
+    /// as the exact sum under this context.
+    fn sticky_sum<const B: Word>(&self, x: Repr<B>, y: Repr<B>) -> Repr<B> {
+        let (hi, mut lo) = if x.top() >= y.top() { (x, y) } else { (y, x) };
+
+        if self.is_limited() && !hi.is_zero() && !lo.is_zero() {
+            // If the smaller operand lies entirely below the last digit of the larger operand
+            // and the rounding position (with the sticky digits), it only decides the direction
+            // of the rounding. In this case it can be replaced by a unit with the same sign at
+            // the same position, so that the exact sum doesn't blow up when the exponents differ
+            // widely, and the replacement is still negligible for the stochastic rounding.
+            let guard_digits = sticky_digits::<B>() + 1;
+            let e = (hi.exponent - 1).min(hi.top() - (self.precision + guard_digits) as isize);
+            if lo.top() <= e {
+                lo = Repr::new(lo.significand.signum(), e);
+            }
+        }
+
+        Context::<R>::new(0).add(&hi, &lo).value().repr
+    }
+}
+
+/// Calculate the exact product of two finite numbers
+#[inline]
+fn exact_mul<const B: Word>(a: &Repr<B>, b: &Repr<B>) -> Repr<B> {
+    Repr::new(&a.significand * &b.significand, a.exponent + b.exponent)
+}
+
+/// An accumulator of products and numbers, which keeps the sum exact and
+/// rounds only once when the result is requested.
+///
+/// It can be used to evaluate dot products or polynomials without the errors
+/// accumulated from the intermediate roundings. The sum is stored as separate chunks
+/// when the exponents of the operands differ widely, so that adding numbers
+/// like `1e100000` and `1e-100000` doesn't require huge integers.
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// # use dashu_float::DBig;
+/// use dashu_base::Approximation::*;
+/// use dashu_float::{Context, DotAccumulator, round::{mode::HalfAway, Rounding::*}};
+///
+/// let a = [DBig::from_str_native("1.234")?, DBig::from_str_native("-5.678")?];
+/// let b = [DBig::from_str_native("9.876")?, DBig::from_str_native("2.146")?];
+///
+/// let mut acc = DotAccumulator::new(Context::<HalfAway>::new(4));
+/// for (x, y) in a.iter().zip(b.iter()) {
+///     acc.add_product(x.repr(), y.repr());
+/// }
+/// // 1.234 * 9.876 - 5.678 * 2.146 = 0.001996
+/// assert_eq!(acc.value(), Exact(DBig::from_str_native("0.001996")?));
+/// # Ok::<(), ParseError>(())
+/// ```
+#[derive(Clone)]
+pub struct DotAccumulator<R: Round, const B: Word> {
+    context: Context<R>,
+
+    /// Non-zero partial sums sorted by the exponent in ascending order. The digits of
+    /// different chunks are separated by at least [CHUNK_GAP] digits.
+    chunks: Vec<Repr<B>>,
+}
+
+/// Minimum number of zero digits between two chunks in a [DotAccumulator]
+const CHUNK_GAP: usize = 64;
+
+impl<R: Round, const B: Word> DotAccumulator<R, B> {
+    /// Create an empty accumulator, whose result will be rounded under the given context.
+    #[inline]
+    pub const fn new(context: Context<R>) -> Self {
+        Self {
+            context,
+            chunks: Vec::new(),
+        }
+    }
+
+    /// Get the context used to round the result
+    #[inline]
+    pub const fn context(&self) -> Context<R> {
+        self.context
+    }
+
+    /// Change the context used to round the result
+    #[inline]
+    pub fn with_context(self, context: Context<R>) -> Self {
+        Self { context, ..self }
+    }
+
+    /// Add a number to the accumulator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is infinite.
+    #[inline]
+    pub fn add(&mut self, x: &Repr<B>) {
+        check_inf(x);
+        self.accumulate(x, Sign::Positive);
+    }
+
+    /// Subtract a number from the accumulator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is infinite.
+    #[inline]
+    pub fn sub(&mut self, x: &Repr<B>) {
+        check_inf(x);
+        self.accumulate(x, Sign::Negative);
+    }
+
+    /// Add the product `a * b` to the accumulator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the operands is infinite.
+    #[inline]
+    pub fn add_product(&mut self, a: &Repr<B>, b: &Repr<B>) {
+        check_inf_operands(a, b);
+        self.accumulate(&exact_mul(a, b), Sign::Positive);
+    }
+
+    /// Subtract the product `a * b` from the accumulator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the operands is infinite.
+    #[inline]
+    pub fn sub_product(&mut self, a: &Repr<B>, b: &Repr<B>) {
+        check_inf_operands(a, b);
+        self.accumulate(&exact_mul(a, b), Sign::Negative);
+    }
+
+    /// Round the accumulated sum under the context of the accumulator.
+    pub fn value(&self) -> Rounded<FBig<R, B>> {
+        // sum up from the smallest chunk, each chunk is smaller than
+        // any digit of the chunks above it
+        let mut chunks = self.chunks.iter();
+        let sum = match chunks.next() {
+            Some(lowest) => chunks
+                .fold(lowest.clone(), |sum, chunk| self.context.sticky_sum(chunk.clone(), sum)),
+            None => Repr::zero(),
+        };
+        self.context
+            .repr_round(sum)
+            .map(|v| FBig::new(v, self.context))
+    }
+
+    fn accumulate(&mut self, x: &Repr<B>, sign: Sign) {
+        if x.is_zero() {
+            return;
+        }
+        let mut x = match sign {
+            Sign::Positive => x.clone(),
+            Sign::Negative => -x.clone(),
+        };
+
+        // merge all the chunks that are close to x
+        let exact = Context::<R>::new(0);
+        let gap = CHUNK_GAP as isize;
+        let mut i = 0;
+        while i < self.chunks.len() {
+            let chunk = &self.chunks[i];
+            if x.exponent <= chunk.top() + gap && chunk.exponent <= x.top() + gap {
+                x = exact.add(&self.chunks.remove(i), &x).value().repr;
+                if x.is_zero() {
+                    return;
+                }
+                // the range of x is changed, so check from the start again
+                i = 0;
+            } else {
+                i += 1;
+            }
+        }
+
+        let pos = self.chunks.partition_point(|c| c.exponent < x.exponent);
+        self.chunks.insert(pos, x);
+    }
+}
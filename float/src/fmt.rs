@@ -33,12 +33,16 @@ impl<'a, 'b> DebugStructHelper for fmt::DebugStruct<'a, 'b> {
 
 impl<const B: Word> fmt::Debug for Repr<B> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // shortcut for infinities
+        // shortcut for infinities, NaN and the negative zero
         if self.is_infinite() {
             return match self.sign() {
                 Sign::Positive => f.write_str("inf"),
                 Sign::Negative => f.write_str("-inf"),
             };
+        } else if self.is_nan() {
+            return f.write_str("NaN");
+        } else if self.is_neg_zero() {
+            return f.write_str("-0");
         }
 
         if f.alternate() {
@@ -52,13 +56,26 @@ impl<const B: Word> fmt::Debug for Repr<B> {
     }
 }
 
-impl<R: Round> fmt::Debug for Context<R> {
+/// The name of the rounding mode of a context, the actual mode is included for [mode::Dynamic].
+struct RoundingName<'a, R: Round>(&'a Context<R>);
+
+impl<R: Round> Display for RoundingName<'_, R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let rnd_name = core::any::type_name::<R>();
         let rnd_name = rnd_name
             .rfind("::")
             .map(|pos| &rnd_name[pos + 2..])
             .unwrap_or(rnd_name);
+        match self.0.mode {
+            Some(mode) => write!(f, "{}({:?})", rnd_name, mode),
+            None => f.write_str(rnd_name),
+        }
+    }
+}
+
+impl<R: Round> fmt::Debug for Context<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rnd_name = RoundingName(self);
         f.debug_struct("Context")
             .field("precision", &self.precision)
             .field("rounding", &format_args!("{}", rnd_name))
@@ -69,31 +86,38 @@ impl<R: Round> fmt::Debug for Context<R> {
 impl<const B: Word> Repr<B> {
     /// Print the float number with given rounding mode. The rounding may happen if the precision option
     /// of the formatter is set.
-    fn fmt_round<R: Round>(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        // shortcut for infinities
+    fn fmt_round<R: Round>(&self, context: &Context<R>, f: &mut Formatter<'_>) -> fmt::Result {
+        // shortcut for infinities and NaN
         if self.is_infinite() {
             return match self.sign() {
                 Sign::Positive => f.write_str("inf"),
                 Sign::Negative => f.write_str("-inf"),
             };
+        } else if self.is_nan() {
+            return f.write_str("NaN");
         }
 
         // first perform rounding before actual printing if necessary
-        let negative = self.significand.sign() == Sign::Negative;
+        let negative = self.sign() == Sign::Negative; // the negative zero is printed with sign
+        let exponent = if self.significand.is_zero() {
+            0
+        } else {
+            self.exponent
+        };
         let rounded_signif;
         let (signif, exp) = if let Some(prec) = f.precision() {
-            let diff = prec as isize + self.exponent;
+            let diff = prec as isize + exponent;
             if diff < 0 {
                 let shift = -diff as usize;
                 let (signif, rem) = split_digits_ref::<B>(&self.significand, shift);
-                let adjust = R::round_fract::<B>(&signif, rem, shift);
+                let adjust = context.round_fract::<B>(&signif, rem, shift);
                 rounded_signif = signif + adjust;
-                (&rounded_signif, self.exponent - diff)
+                (&rounded_signif, exponent - diff)
             } else {
-                (&self.significand, self.exponent)
+                (&self.significand, exponent)
             }
         } else {
-            (&self.significand, self.exponent)
+            (&self.significand, exponent)
         };
 
         // calculate padding if necessary
@@ -119,7 +143,7 @@ impl<const B: Word> Repr<B> {
             }
 
             let has_sign = (negative || f.sign_plus()) as usize;
-            let has_float_point = if exp > 0 {
+            let has_float_point = if exp >= 0 {
                 // if there's no fractional part, the result has the floating point
                 // only if the precision is set to be non-zero
                 f.precision().unwrap_or(0) > 0
@@ -264,25 +288,23 @@ impl<const B: Word> Repr<B> {
 impl<const B: Word> Display for Repr<B> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        self.fmt_round::<Zero>(f)
+        self.fmt_round(&Context::<Zero>::new(0), f)
     }
 }
 
 impl<R: Round, const B: Word> fmt::Debug for FBig<R, B> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // shortcut for infinities
+        // shortcut for infinities and NaN
         if self.repr.is_infinite() {
             return match self.repr.sign() {
                 Sign::Positive => f.write_str("inf"),
                 Sign::Negative => f.write_str("-inf"),
             };
+        } else if self.repr.is_nan() {
+            return f.write_str("NaN");
         }
 
-        let rnd_name = core::any::type_name::<R>();
-        let rnd_name = rnd_name
-            .rfind("::")
-            .map(|pos| &rnd_name[pos + 2..])
-            .unwrap_or(rnd_name);
+        let rnd_name = RoundingName(&self.context);
 
         if f.alternate() {
             f.debug_struct("FBig")
@@ -303,7 +325,7 @@ impl<R: Round, const B: Word> fmt::Debug for FBig<R, B> {
 impl<R: Round, const B: Word> Display for FBig<R, B> {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        self.repr.fmt_round::<R>(f)
+        self.repr.fmt_round(&self.context, f)
     }
 }
 
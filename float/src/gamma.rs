@@ -0,0 +1,611 @@
+//! Implementation of the gamma function and the related functions
+
+use alloc::vec::Vec;
+
+use crate::{
+    error::{check_inf, check_inf_operands, check_precision_limited, panic_out_of_domain},
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{Round, Rounded},
+    utils::{shl_digits, split_digits_ref, top_or_min},
+};
+use dashu_base::{Abs, Approximation::*, EstimatedLog2, Sign, UnsignedAbs};
+use dashu_int::IBig;
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Calculate the gamma function (`Γ(x)`) on the float number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.gamma(), DBig::from_str_native("0.9098")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is zero or a negative integer.
+    #[inline]
+    pub fn gamma(&self) -> Self {
+        self.context.gamma(&self.repr).value()
+    }
+
+    /// Calculate the logarithm of the absolute value of the gamma function (`log|Γ(x)|`)
+    /// on the float number, along with the sign of `Γ(x)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Sign;
+    ///
+    /// let a = DBig::from_str_native("-1.234")?;
+    /// assert_eq!(a.ln_gamma(), (DBig::from_str_native("1.428")?, Sign::Positive));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is zero or a negative integer.
+    #[inline]
+    pub fn ln_gamma(&self) -> (Self, Sign) {
+        let (value, sign) = self.context.ln_gamma(&self.repr);
+        (value.value(), sign)
+    }
+
+    /// Calculate the beta function (`B(x, y) = Γ(x)Γ(y)/Γ(x+y)`) on two float numbers.
+    ///
+    /// The precision of the output is the maximum of the precisions of the two inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// let b = DBig::from_str_native("5.678")?;
+    /// assert_eq!(a.beta(&b), DBig::from_str_native("0.1042")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or any of the numbers is zero or a negative integer.
+    #[inline]
+    pub fn beta(&self, other: &Self) -> Self {
+        let context = Context::max(self.context, other.context);
+        context.beta(&self.repr, &other.repr).value()
+    }
+
+    /// Calculate the digamma function (`ψ(x) = Γ'(x)/Γ(x)`) on the float number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.digamma(), DBig::from_str_native("-0.2468")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is zero or a negative integer.
+    #[inline]
+    pub fn digamma(&self) -> Self {
+        self.context.digamma(&self.repr).value()
+    }
+}
+
+/// Split the number into the nearest integer `n` and the remainder `x - n`, where |x - n| <= 1/2.
+pub(crate) fn split_nearest<const B: Word>(x: &Repr<B>) -> (IBig, Repr<B>) {
+    if x.exponent >= 0 {
+        return (shl_digits::<B>(&x.significand, x.exponent as usize), Repr::zero());
+    }
+
+    let shift = (-x.exponent) as usize;
+    let (trunc, fract) = split_digits_ref::<B>(&x.significand, shift);
+    let n = if fract.abs() * 2u8 > Repr::<B>::BASE.pow(shift) {
+        trunc + x.significand.signum()
+    } else {
+        trunc
+    };
+    let rem = &x.significand - shl_digits::<B>(&n, shift);
+    (n, Repr::new(rem, x.exponent))
+}
+
+/// Check if the remainder from [split_nearest] is ±1/2
+#[inline]
+fn is_half<const B: Word>(rem: &Repr<B>) -> bool {
+    let double = Repr::<B>::new(&rem.significand * 2u8, rem.exponent);
+    double.exponent == 0 && double.significand.abs() == IBig::ONE
+}
+
+/// Panics if the number split by [split_nearest] is a pole of the gamma function
+#[inline]
+fn check_gamma_pole<const B: Word>(n: &IBig, rem: &Repr<B>) {
+    if rem.is_zero() && n <= &IBig::ZERO {
+        panic_out_of_domain()
+    }
+}
+
+/// The sign of `Γ(x)` where x is split by [split_nearest].
+///
+/// When x < 0, the sign is negative iff floor(x) is odd.
+fn gamma_sign<const B: Word>(n: &IBig, rem: &Repr<B>) -> Sign {
+    let floor = if rem.sign() == Sign::Negative && !rem.is_zero() {
+        n - IBig::ONE
+    } else {
+        n.clone()
+    };
+    if floor < IBig::ZERO && floor.unsigned_abs().bit(0) {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    }
+}
+
+/// Calculate n!
+fn factorial(n: usize) -> IBig {
+    (2..=n).fold(IBig::ONE, |acc, i| acc * IBig::from(i))
+}
+
+/// Calculate the sum Σ num/d for d in `dens` as a fraction (numerator, denominator)
+pub(crate) fn rational_sum<I: Iterator<Item = usize>>(num: u8, dens: I) -> (IBig, IBig) {
+    dens.fold((IBig::ZERO, IBig::ONE), |(n, d), k| {
+        let k = IBig::from(k);
+        (n * &k + &d * num, d * k)
+    })
+}
+
+/// Calculate the tangent numbers T₁, T₂, ..., Tₙ, which are related to the Bernoulli numbers by
+/// B₂ₖ = (-1)ᵏ⁻¹ 2k Tₖ / (4ᵏ(4ᵏ-1)).
+///
+/// Reference: Brent and Harvey, Fast computation of Bernoulli, Tangent and Secant numbers.
+pub(crate) fn tangent_numbers(n: usize) -> Vec<IBig> {
+    let mut t = Vec::with_capacity(n);
+    if n == 0 {
+        return t;
+    }
+    t.push(IBig::ONE);
+    for k in 1..n {
+        let next = &t[k - 1] * IBig::from(k);
+        t.push(next);
+    }
+    for k in 1..n {
+        for j in k..n {
+            let next = &t[j - 1] * IBig::from(j - k) + &t[j] * IBig::from(j - k + 2);
+            t[j] = next;
+        }
+    }
+    t
+}
+
+impl<R: Round> Context<R> {
+    /// Maximum integer argument for which the exact factorials are used
+    #[inline]
+    fn factorial_limit<const B: Word>(&self) -> usize {
+        (self.precision as f32 * B.log2_est()) as usize * 4 + 64
+    }
+
+    /// Convert the integer to usize if it's positive and not larger than [Self::factorial_limit]
+    pub(crate) fn small_int<const B: Word>(&self, n: &IBig) -> Option<usize> {
+        match usize::try_from(n) {
+            Ok(n) if n > 0 && n <= self.factorial_limit::<B>() => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Evaluate a sum of terms which could cancel each other, given a closure that returns
+    /// the sum and the position of the most significant digit of the largest term.
+    ///
+    /// The working precision is increased until the error is less than a few units in the
+    /// last place of the result under this context (if `relative`), or less than a few
+    /// units of `B⁻ᵖ` otherwise.
+    pub(crate) fn eval_cancelling<const B: Word, F>(
+        &self,
+        relative: bool,
+        mut eval: F,
+    ) -> FBig<R, B>
+    where
+        F: FnMut(&Context<R>) -> (FBig<R, B>, isize),
+    {
+        // use a simple rule for guard bits, the same as powf
+        let guard_digits = 10 + self.precision.log2_est() as usize;
+        let mut precision = self.precision + guard_digits;
+        loop {
+            let (value, max_top) = eval(&self.work_context(precision));
+            let required = if !relative {
+                self.precision + guard_digits + max_top.max(0) as usize
+            } else if value.repr.is_zero() {
+                precision * 2
+            } else {
+                let cancelled = max_top - top_or_min(&value.repr);
+                self.precision + guard_digits + cancelled.max(0) as usize
+            };
+            if precision >= required {
+                return value;
+            }
+            precision = required;
+        }
+    }
+
+    /// Shift the input to be large enough for the asymptotic expansions, return the
+    /// shift amount `m` and `x + m` rounded under this context.
+    fn asymptotic_shift<const B: Word>(&self, x: &Repr<B>) -> (usize, FBig<R, B>) {
+        // the terms of the asymptotic series are bounded by about e^(-2πx), so x + m
+        // is required to be larger than about p·ln(2)/(2π) where p is the precision in bits
+        let threshold = (self.precision as f32 * B.log2_est()) as usize / 4 + 8;
+        let (n, _) = split_nearest(x);
+        let m = match usize::try_from(n) {
+            Ok(n) if n < threshold => threshold - n,
+            _ => 0,
+        };
+        (m, self.add(x, &Repr::new(m.into(), 0)).value())
+    }
+
+    /// Evaluate Σ cₖ·first·ratioᵏ⁻¹ for k >= 1 until the terms are smaller than `B^limit`,
+    /// where cₖ = B₂ₖ/(2k(2k-1)) if `ln_gamma` is true, and cₖ = B₂ₖ/(2k) otherwise.
+    fn bernoulli_series<const B: Word>(
+        &self,
+        first: FBig<R, B>,
+        ratio: &FBig<R, B>,
+        ln_gamma: bool,
+        limit: isize,
+    ) -> FBig<R, B> {
+        let count = (self.precision as f32 * B.log2_est()) as usize / 8 + 16;
+        let mut sum = FBig::new(Repr::zero(), *self);
+        let mut pow = first;
+        for (k, t) in (1..).zip(tangent_numbers(count)) {
+            // cₖ = (-1)ᵏ⁻¹ Tₖ / (4ᵏ(4ᵏ-1)), with an extra factor 1/(2k-1) for log-gamma
+            let four_k = IBig::ONE << (2 * k);
+            let mut den = (&four_k - IBig::ONE) * four_k;
+            if ln_gamma {
+                den *= IBig::from(2 * k - 1);
+            }
+            let term = self.div(&Repr::new(t, 0), &Repr::new(den, 0)).value() * &pow;
+            if top_or_min(&term.repr) < limit {
+                break;
+            }
+            sum = if k % 2 == 1 { sum + term } else { sum - term };
+            pow *= ratio;
+        }
+        sum
+    }
+
+    /// Evaluate log(Γ(x)) for x > 0, return the value and the position of its largest term.
+    fn ln_gamma_positive<const B: Word>(&self, x: &Repr<B>) -> (FBig<R, B>, isize) {
+        // log(Γ(x)) = log(Γ(x+m)) - log(x(x+1)...(x+m-1))
+        let (m, y) = self.asymptotic_shift(x);
+        let ln_prod = if m > 0 {
+            let mut prod = FBig::new(self.repr_round_ref(x).value(), *self);
+            for i in 1..m {
+                prod *= self.add(x, &Repr::new(i.into(), 0)).value();
+            }
+            self.ln(&prod.repr).value()
+        } else {
+            FBig::new(Repr::zero(), *self)
+        };
+
+        // Stirling's series: log(Γ(y)) ~ (y-1/2)log(y) - y + log(2π)/2 + Σ B₂ₖ/(2k(2k-1)y²ᵏ⁻¹)
+        let ln_y = self.ln(&y.repr).value();
+        let lead = (y.clone() * 2u8 - 1u8) * ln_y / 2u8;
+        let max_top = top_or_min(&lead.repr).max(top_or_min(&ln_prod.repr));
+        let ln_2pi = self.ln(&(self.pi::<B>().value() * 2u8).repr).value();
+        let lead = lead - &y + ln_2pi / 2u8;
+
+        let inv_y = FBig::ONE / y;
+        let inv_y2 = inv_y.square();
+        let limit = top_or_min(&lead.repr) - self.precision as isize - 1;
+        let series = self.bernoulli_series(inv_y, &inv_y2, true, limit);
+        (lead + series - ln_prod, max_top)
+    }
+
+    /// Evaluate log|Γ(x)|, return the value and the position of its largest term.
+    fn ln_gamma_terms<const B: Word>(&self, x: &Repr<B>) -> (FBig<R, B>, isize) {
+        if x.sign() == Sign::Positive {
+            return self.ln_gamma_positive(x);
+        }
+
+        // reflection formula: log|Γ(x)| = log(π) - log|sin(πx)| - log(Γ(1-x)),
+        // where sin(πx) = ±sin(π(x-n)) is evaluated with the reduced argument
+        let (_, rem) = split_nearest(x);
+        let one_minus_x = Context::<R>::new(0).sub(&Repr::one(), x).value();
+        let (ln_gamma, max_top) = self.ln_gamma_positive(&one_minus_x.repr);
+        let pi = self.pi::<B>().value();
+        let sin = self.sin(&(&pi * FBig::new(self.repr_round_ref(&rem).value(), *self)).repr);
+        let ln_sin = self.ln(&sin.value().abs().repr).value();
+        let ln_pi = self.ln(&pi.repr).value();
+
+        let max_top = max_top
+            .max(top_or_min(&ln_sin.repr))
+            .max(top_or_min(&ln_pi.repr));
+        (ln_pi - ln_sin - ln_gamma, max_top)
+    }
+
+    /// Evaluate ψ(x) for x > 0, return the value and the position of its largest term.
+    fn digamma_positive<const B: Word>(&self, x: &Repr<B>) -> (FBig<R, B>, isize) {
+        // ψ(x) = ψ(x+m) - Σ 1/(x+i) for i in 0..m
+        let (m, y) = self.asymptotic_shift(x);
+        let mut inv_sum = FBig::new(Repr::zero(), *self);
+        for i in 0..m {
+            inv_sum += FBig::ONE / self.add(x, &Repr::new(i.into(), 0)).value();
+        }
+
+        // asymptotic expansion: ψ(y) ~ log(y) - 1/(2y) - Σ B₂ₖ/(2k y²ᵏ)
+        let ln_y = self.ln(&y.repr).value();
+        let max_top = top_or_min(&ln_y.repr).max(top_or_min(&inv_sum.repr));
+        let inv_y = FBig::ONE / y;
+        let inv_y2 = inv_y.square();
+        let lead = ln_y - inv_y / 2u8;
+        let limit = top_or_min(&lead.repr) - self.precision as isize - 1;
+        let series = self.bernoulli_series(inv_y2.clone(), &inv_y2, false, limit);
+        (lead - series - inv_sum, max_top)
+    }
+
+    /// Evaluate ψ(x), return the value and the position of its largest term.
+    fn digamma_terms<const B: Word>(&self, x: &Repr<B>) -> (FBig<R, B>, isize) {
+        if x.sign() == Sign::Positive {
+            return self.digamma_positive(x);
+        }
+
+        // reflection formula: ψ(x) = ψ(1-x) - π·cot(πx), where cot(πx) = cot(π(x-n))
+        let (_, rem) = split_nearest(x);
+        let one_minus_x = Context::<R>::new(0).sub(&Repr::one(), x).value();
+        let (digamma, max_top) = self.digamma_positive(&one_minus_x.repr);
+        let pi = self.pi::<B>().value();
+        let arg = &pi * FBig::new(self.repr_round_ref(&rem).value(), *self);
+        let (sin, cos) = self.sin_cos(&arg.repr);
+        let pi_cot = pi * cos.value() / sin.value();
+
+        let max_top = max_top.max(top_or_min(&pi_cot.repr));
+        (digamma - pi_cot, max_top)
+    }
+
+    /// Calculate the gamma function (`Γ(x)`) on the float number under this context.
+    ///
+    /// The result is exact if the input is a positive integer and its factorial
+    /// can be represented under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("-1.234")?;
+    /// assert_eq!(context.gamma(&a.repr()), Inexact(DBig::from_str_native("4.2")?, AddOne));
+    /// let b = DBig::from_str_native("6")?;
+    /// assert_eq!(context.gamma(&b.repr()), Exact(DBig::from_str_native("120")?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is zero or a negative integer.
+    pub fn gamma<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        let (n, rem) = split_nearest(x);
+        check_gamma_pole(&n, &rem);
+
+        if rem.is_zero() {
+            // Γ(n) = (n-1)!
+            if let Some(n) = self.small_int::<B>(&n) {
+                let fact = Repr::new(factorial(n - 1), 0);
+                return self.repr_round(fact).map(|v| FBig::new(v, *self));
+            }
+        } else if is_half(&rem) {
+            // Γ(k+1/2) = (2k)!/(4ᵏk!)·√π and Γ(1/2-k) = (-4)ᵏk!/(2k)!·√π
+            let positive = rem.sign() == Sign::Positive;
+            let k = if positive { n.clone() } else { IBig::ONE - &n };
+            if let Some(k) = self.small_int::<B>(&(k + IBig::ONE)).map(|k| k - 1) {
+                let fact2k = factorial(2 * k);
+                let pow4k = factorial(k) << (2 * k);
+                let (num, den) = if positive {
+                    (fact2k, pow4k)
+                } else if k % 2 == 1 {
+                    (-pow4k, fact2k)
+                } else {
+                    (pow4k, fact2k)
+                };
+                let (num, den) = (Repr::new(num, 0), Repr::new(den, 0));
+                return self.round_ziv(|c| c.div(&num, &den).value() * c.pi().value().sqrt());
+            }
+        }
+
+        // Γ(x) = ±exp(log|Γ(x)|)
+        let sign = gamma_sign(&n, &rem);
+        self.round_ziv(|c| {
+            let ln_gamma = c.eval_cancelling(false, |c| c.ln_gamma_terms(x));
+            sign * c.exp(&ln_gamma.repr).value()
+        })
+    }
+
+    /// Calculate the logarithm of the absolute value of the gamma function (`log|Γ(x)|`)
+    /// on the float number under this context, along with the sign of `Γ(x)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::{Approximation::*, Sign};
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("-2.5")?;
+    /// assert_eq!(
+    ///     context.ln_gamma(&a.repr()),
+    ///     (Inexact(DBig::from_str_native("-0.056")?, NoOp), Sign::Negative)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is zero or a negative integer.
+    pub fn ln_gamma<const B: Word>(&self, x: &Repr<B>) -> (Rounded<FBig<R, B>>, Sign) {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        let (n, rem) = split_nearest(x);
+        check_gamma_pole(&n, &rem);
+        let sign = gamma_sign(&n, &rem);
+
+        if rem.is_zero() {
+            // log(Γ(n)) = log((n-1)!)
+            if let Some(n) = self.small_int::<B>(&n) {
+                let value = if n <= 2 {
+                    Exact(FBig::ZERO)
+                } else {
+                    self.ln(&Repr::new(factorial(n - 1), 0))
+                };
+                return (value, sign);
+            }
+        }
+
+        let value = self.round_ziv(|c| c.eval_cancelling(true, |c| c.ln_gamma_terms(x)));
+        (value, sign)
+    }
+
+    /// Calculate the beta function (`B(x, y) = Γ(x)Γ(y)/Γ(x+y)`) on two float numbers under this context.
+    ///
+    /// The result is exact if both inputs are positive integers and the result
+    /// can be represented under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("-1.234")?;
+    /// let b = DBig::from_str_native("0.5")?;
+    /// assert_eq!(context.beta(&a.repr(), &b.repr()), Inexact(DBig::from_str_native("-1.6")?, SubOne));
+    /// let c = DBig::from_str_native("2")?;
+    /// let d = DBig::from_str_native("3")?;
+    /// assert_eq!(context.beta(&c.repr(), &d.repr()), Inexact(DBig::from_str_native("0.083")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or any of the numbers is zero or a negative integer.
+    pub fn beta<const B: Word>(&self, x: &Repr<B>, y: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf_operands(x, y);
+        check_precision_limited(self.precision);
+        let (nx, rx) = split_nearest(x);
+        let (ny, ry) = split_nearest(y);
+        check_gamma_pole(&nx, &rx);
+        check_gamma_pole(&ny, &ry);
+
+        // B(x, y) = 0 if x + y is a pole of Γ
+        let sum = Context::<R>::new(0).add(x, y).value().repr;
+        let (ns, rs) = split_nearest(&sum);
+        if rs.is_zero() && ns <= IBig::ZERO {
+            return Exact(FBig::ZERO);
+        }
+
+        // B(1, y) = 1/y and B(x, 1) = 1/x
+        if x == &Repr::one() {
+            return self.repr_div(Repr::one(), y).map(|v| FBig::new(v, *self));
+        } else if y == &Repr::one() {
+            return self.repr_div(Repr::one(), x).map(|v| FBig::new(v, *self));
+        }
+
+        // B(m, n) = (m-1)!(n-1)!/(m+n-1)!
+        if rx.is_zero() && ry.is_zero() {
+            if let (Some(m), Some(n)) = (self.small_int::<B>(&nx), self.small_int::<B>(&ny)) {
+                let num = Repr::new(factorial(m - 1) * factorial(n - 1), 0);
+                let den = Repr::new(factorial(m + n - 1), 0);
+                return self.repr_div(num, &den).map(|v| FBig::new(v, *self));
+            }
+        }
+
+        // B(x, y) = ±exp(log|Γ(x)| + log|Γ(y)| - log|Γ(x+y)|)
+        let sign = gamma_sign(&nx, &rx) * gamma_sign(&ny, &ry) * gamma_sign(&ns, &rs);
+        self.round_ziv(|c| {
+            let ln_beta = c.eval_cancelling(false, |c| {
+                let (lx, tx) = c.ln_gamma_terms(x);
+                let (ly, ty) = c.ln_gamma_terms(y);
+                let (ls, ts) = c.ln_gamma_terms(&sum);
+                (lx + ly - ls, tx.max(ty).max(ts))
+            });
+            sign * c.exp(&ln_beta.repr).value()
+        })
+    }
+
+    /// Calculate the digamma function (`ψ(x) = Γ'(x)/Γ(x)`) on the float number under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("-1.234")?;
+    /// assert_eq!(context.digamma(&a.repr()), Inexact(DBig::from_str_native("4.0")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is zero or a negative integer.
+    pub fn digamma<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        let (n, rem) = split_nearest(x);
+        check_gamma_pole(&n, &rem);
+
+        if rem.is_zero() {
+            // ψ(n) = Σ 1/k - γ for k in 1..n
+            if let Some(n) = self.small_int::<B>(&n) {
+                let (num, den) = rational_sum(1, 1..n);
+                let (num, den) = (Repr::new(num, 0), Repr::new(den, 0));
+                return self.round_ziv(|c| {
+                    c.eval_cancelling(true, |c| {
+                        let harmonic = c.div(&num, &den).value();
+                        let gamma = c.euler_gamma().value();
+                        let max_top = top_or_min(&harmonic.repr).max(top_or_min(&gamma.repr));
+                        (harmonic - gamma, max_top)
+                    })
+                });
+            }
+        } else if is_half(&rem) {
+            // ψ(k+1/2) = ψ(1/2-k) = Σ 2/(2j-1) - γ - 2log(2) for j in 1..=k
+            let k = if rem.sign() == Sign::Positive {
+                n.clone()
+            } else {
+                IBig::ONE - &n
+            };
+            if let Some(k) = self.small_int::<B>(&(k + IBig::ONE)).map(|k| k - 1) {
+                let (num, den) = rational_sum(2, (1..=k).map(|j| 2 * j - 1));
+                let (num, den) = (Repr::new(num, 0), Repr::new(den, 0));
+                return self.round_ziv(|c| {
+                    c.eval_cancelling(true, |c| {
+                        let sum = c.div(&num, &den).value();
+                        let consts = c.euler_gamma().value() + c.ln2().value() * 2u8;
+                        let max_top = top_or_min(&sum.repr).max(top_or_min(&consts.repr));
+                        (sum - consts, max_top)
+                    })
+                });
+            }
+        }
+
+        self.round_ziv(|c| c.eval_cancelling(true, |c| c.digamma_terms(x)))
+    }
+}
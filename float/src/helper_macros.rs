@@ -1,11 +1,12 @@
-/// Implement `impl Op<A> for FBig` by converting A to FBig. This macro includes operations taking by references.
+/// Implement `impl Op<A> for FBig` by converting the integer A to FBig with the function `$conv`. This
+/// macro includes operations taking by references.
 macro_rules! impl_binop_with_primitive {
-    (impl $trait:ident<$target:ty>, $method:ident) => {
+    (impl $trait:ident<$target:ty>, $method:ident, $conv:ident) => {
         impl<R: Round, const B: Word> $trait<$target> for FBig<R, B> {
             type Output = FBig<R, B>;
             #[inline]
             fn $method(self, rhs: $target) -> Self::Output {
-                self.$method(FBig::<R, B>::from(rhs))
+                self.$method(FBig::<R, B>::$conv(rhs))
             }
         }
 
@@ -13,7 +14,7 @@ macro_rules! impl_binop_with_primitive {
             type Output = FBig<R, B>;
             #[inline]
             fn $method(self, rhs: $target) -> Self::Output {
-                self.$method(FBig::<R, B>::from(rhs))
+                self.$method(FBig::<R, B>::$conv(rhs))
             }
         }
 
@@ -21,7 +22,7 @@ macro_rules! impl_binop_with_primitive {
             type Output = FBig<R, B>;
             #[inline]
             fn $method(self, rhs: &$target) -> Self::Output {
-                self.$method(FBig::<R, B>::from(rhs.clone()))
+                self.$method(FBig::<R, B>::$conv(rhs.clone()))
             }
         }
 
@@ -29,22 +30,22 @@ macro_rules! impl_binop_with_primitive {
             type Output = FBig<R, B>;
             #[inline]
             fn $method(self, rhs: &$target) -> Self::Output {
-                self.$method(FBig::<R, B>::from(rhs.clone()))
+                self.$method(FBig::<R, B>::$conv(rhs.clone()))
             }
         }
     };
 }
 
-/// Implement `impl Op<A> for FBig` and `impl Op<FBig> for A` by converting A to FBig.
+/// Implement `impl Op<A> for FBig` and `impl Op<FBig> for A` by converting the integer A to FBig with `$conv`.
 macro_rules! impl_commutative_binop_with_primitive {
-    (impl $trait:ident<$target:ty>, $method:ident) => {
-        crate::helper_macros::impl_binop_with_primitive!(impl $trait<$target>, $method);
+    (impl $trait:ident<$target:ty>, $method:ident, $conv:ident) => {
+        crate::helper_macros::impl_binop_with_primitive!(impl $trait<$target>, $method, $conv);
 
         impl<R: Round, const B: Word> $trait<FBig<R, B>> for $target {
             type Output = FBig<R, B>;
             #[inline]
             fn $method(self, rhs: FBig<R, B>) -> Self::Output {
-                FBig::<R, B>::from(self).$method(rhs)
+                FBig::<R, B>::$conv(self).$method(rhs)
             }
         }
 
@@ -52,7 +53,7 @@ macro_rules! impl_commutative_binop_with_primitive {
             type Output = FBig<R, B>;
             #[inline]
             fn $method(self, rhs: FBig<R, B>) -> Self::Output {
-                FBig::<R, B>::from(self.clone()).$method(rhs)
+                FBig::<R, B>::$conv(self.clone()).$method(rhs)
             }
         }
 
@@ -60,7 +61,7 @@ macro_rules! impl_commutative_binop_with_primitive {
             type Output = FBig<R, B>;
             #[inline]
             fn $method(self, rhs: &FBig<R, B>) -> Self::Output {
-                FBig::<R, B>::from(self).$method(rhs)
+                FBig::<R, B>::$conv(self).$method(rhs)
             }
         }
 
@@ -68,26 +69,26 @@ macro_rules! impl_commutative_binop_with_primitive {
             type Output = FBig<R, B>;
             #[inline]
             fn $method(self, rhs: &FBig<R, B>) -> Self::Output {
-                FBig::<R, B>::from(self.clone()).$method(rhs)
+                FBig::<R, B>::$conv(self.clone()).$method(rhs)
             }
         }
     };
 }
 
-/// Implement `impl OpAssign<A> for FBig` by converting A to FBig. This macro
+/// Implement `impl OpAssign<A> for FBig` by converting the integer A to FBig with `$conv`. This macro
 /// includes operation with &A
 macro_rules! impl_binop_assign_with_primitive {
-    (impl $trait:ident<$target:ty>, $method:ident) => {
+    (impl $trait:ident<$target:ty>, $method:ident, $conv:ident) => {
         impl<R: Round, const B: Word> $trait<$target> for FBig<R, B> {
             #[inline]
             fn $method(&mut self, rhs: $target) {
-                self.$method(FBig::from(rhs))
+                self.$method(FBig::<R, B>::$conv(rhs))
             }
         }
         impl<R: Round, const B: Word> $trait<&$target> for FBig<R, B> {
             #[inline]
             fn $method(&mut self, rhs: &$target) {
-                self.$method(FBig::from(rhs.clone()))
+                self.$method(FBig::<R, B>::$conv(rhs.clone()))
             }
         }
     };
@@ -0,0 +1,419 @@
+//! Hyperbolic functions and their inverses
+//!
+//! The functions are evaluated with the exponential and logarithm functions under a higher
+//! precision, using the forms that don't suffer from cancellation near zero (and near one for
+//! the inverse cosine). The input is not rounded before the evaluation.
+
+use core::cmp::Ordering;
+
+use crate::{
+    cmp::repr_cmp,
+    error::{check_inf, check_precision_limited, panic_out_of_domain},
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{Round, Rounded},
+    utils::shl_digits,
+};
+use dashu_base::{Abs, Approximation::*, EstimatedLog2, Sign, UnsignedAbs};
+use dashu_int::IBig;
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Calculate the hyperbolic sine function (`sinh(x)`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.sinh(), DBig::from_str_native("1.572")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn sinh(&self) -> Self {
+        self.context.sinh(&self.repr).value()
+    }
+
+    /// Calculate the hyperbolic cosine function (`cosh(x)`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.cosh(), DBig::from_str_native("1.863")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn cosh(&self) -> Self {
+        self.context.cosh(&self.repr).value()
+    }
+
+    /// Calculate the hyperbolic tangent function (`tanh(x)`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.tanh(), DBig::from_str_native("0.8437")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn tanh(&self) -> Self {
+        self.context.tanh(&self.repr).value()
+    }
+
+    /// Calculate the inverse hyperbolic sine function (`asinh(x)`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.asinh(), DBig::from_str_native("1.038")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn asinh(&self) -> Self {
+        self.context.asinh(&self.repr).value()
+    }
+
+    /// Calculate the inverse hyperbolic cosine function (`acosh(x)`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.acosh(), DBig::from_str_native("0.6714")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is less than 1.
+    #[inline]
+    pub fn acosh(&self) -> Self {
+        self.context.acosh(&self.repr).value()
+    }
+
+    /// Calculate the inverse hyperbolic tangent function (`atanh(x)`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("0.1234")?;
+    /// assert_eq!(a.atanh(), DBig::from_str_native("0.12403")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is not in the range `(-1, 1)`.
+    #[inline]
+    pub fn atanh(&self) -> Self {
+        self.context.atanh(&self.repr).value()
+    }
+}
+
+impl<R: Round> Context<R> {
+    /// Check if `x` is so small that the second term in the Maclaurin series of the functions
+    /// (which is in the order of x³) doesn't affect the rounding except for its sign. In this
+    /// case, the number of digits to be used with [Self::round_with_tail] is returned.
+    fn tiny_digits<const B: Word>(&self, x: &Repr<B>) -> Option<usize> {
+        // the relative size of the second term is smaller than x²
+        let digits = x.digits().max(self.precision) + 2;
+        let log2_bound = -((digits + 1) as f32) * B.log2_bounds().1;
+        if x.log2_bounds().1 * 2. < log2_bound {
+            Some(digits)
+        } else {
+            None
+        }
+    }
+
+    /// Round `x + ε` under this context, where ε is an infinitesimal with the given sign.
+    ///
+    /// It's done by shifting `x` to have the given number of digits, and then add or subtract
+    /// one in the last place.
+    pub(crate) fn round_with_tail<const B: Word>(
+        &self,
+        x: &Repr<B>,
+        tail_sign: Sign,
+        digits: usize,
+    ) -> Rounded<FBig<R, B>> {
+        let shift = digits - x.digits();
+        let signif = shl_digits::<B>(&x.significand, shift) + tail_sign * IBig::ONE;
+        let repr = Repr::new(signif, x.exponent - shift as isize);
+        self.repr_round(repr).map(|v| FBig::new(v, *self))
+    }
+
+    /// Create a context with guard digits for the evaluation of the hyperbolic functions,
+    /// and associate the input with this context. The input is kept exact, so that it's not
+    /// rounded before the evaluation.
+    fn hyperbolic_work<const B: Word>(&self, x: &Repr<B>) -> (Context<R>, FBig<R, B>) {
+        // use a simple rule for guard bits, the same as powf
+        let guard_digits = 10 + self.precision.log2_est() as usize;
+        let work_context = self.work_context(self.precision + guard_digits);
+        let x = FBig::new(x.clone(), work_context);
+        (work_context, x)
+    }
+
+    /// Calculate the hyperbolic sine function (`sinh(x)`) on the floating point number under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.sinh(&a.repr()), Inexact(DBig::from_str_native("1.6")?, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn sinh<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if x.is_zero() {
+            return Exact(FBig::ZERO);
+        }
+        if let Some(digits) = self.tiny_digits(x) {
+            // sinh(x) = x + x³/6 + ...
+            return self.round_with_tail(x, x.sign(), digits);
+        }
+
+        // sinh(x) = (eˣ - e⁻ˣ)/2 = (u + u/(u+1))/2 where u = eˣ - 1,
+        // which doesn't suffer from cancellation when x is close to zero
+        let (work_context, x) = self.hyperbolic_work(x);
+        let sign = x.repr.sign();
+        let u = work_context.exp_m1(x.abs().repr()).value();
+        let sinh = (&u / (FBig::ONE + &u) + u) / 2u8;
+        self.round_fbig(sign * sinh)
+    }
+
+    /// Calculate the hyperbolic cosine function (`cosh(x)`) on the floating point number under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.cosh(&a.repr()), Inexact(DBig::from_str_native("1.9")?, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn cosh<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if x.is_zero() {
+            return Exact(FBig::ONE);
+        }
+        if let Some(digits) = self.tiny_digits(x) {
+            // cosh(x) = 1 + x²/2 + ...
+            return self.round_with_tail(&Repr::one(), Sign::Positive, digits);
+        }
+
+        // cosh(x) = (eˣ + e⁻ˣ)/2
+        let (work_context, x) = self.hyperbolic_work(x);
+        let exp = work_context.exp(x.abs().repr()).value();
+        let cosh = (FBig::ONE / &exp + exp) / 2u8;
+        self.round_fbig(cosh)
+    }
+
+    /// Calculate the hyperbolic tangent function (`tanh(x)`) on the floating point number under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.tanh(&a.repr()), Inexact(DBig::from_str_native("0.84")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn tanh<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if x.is_zero() {
+            return Exact(FBig::ZERO);
+        }
+        if let Some(digits) = self.tiny_digits(x) {
+            // tanh(x) = x - x³/3 + ...
+            return self.round_with_tail(x, -x.sign(), digits);
+        }
+
+        // tanh(x) = ±(1 - 2e⁻²ˣ + ...) when |x| is large enough, and the second term
+        // is negligible if 2e⁻²ˣ < B⁻⁽ᵖ⁺³⁾, which is implied by 2x > (p+3)log₂B + 1
+        let digits = self.precision + 2;
+        let threshold = ((digits + 1) as f32 * B.log2_bounds().1) as usize / 2 + 1;
+        let abs_x = Repr::<B>::new(x.significand.clone().unsigned_abs().into(), x.exponent);
+        if repr_cmp(&abs_x, &Repr::new(threshold.into(), 0), None) == Ordering::Greater {
+            let one = if x.sign() == Sign::Positive {
+                Repr::one()
+            } else {
+                Repr::neg_one()
+            };
+            return self.round_with_tail(&one, -x.sign(), digits);
+        }
+
+        // tanh(x) = (e²ˣ - 1)/(e²ˣ + 1) = u/(u+2) where u = e²ˣ - 1
+        let (work_context, x) = self.hyperbolic_work(x);
+        let sign = x.repr.sign();
+        let u = work_context.exp_m1((x.abs() * 2u8).repr()).value();
+        let tanh = &u / (u.clone() + 2u8);
+        self.round_fbig(sign * tanh)
+    }
+
+    /// Calculate the inverse hyperbolic sine function (`asinh(x)`) on the floating point number under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.asinh(&a.repr()), Inexact(DBig::from_str_native("1.0")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn asinh<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if x.is_zero() {
+            return Exact(FBig::ZERO);
+        }
+        if let Some(digits) = self.tiny_digits(x) {
+            // asinh(x) = x - x³/6 + ...
+            return self.round_with_tail(x, -x.sign(), digits);
+        }
+
+        // asinh(x) = log(x + √(x²+1)) = log1p(x + x²/(1 + √(x²+1))) for x > 0,
+        // the latter form doesn't suffer from cancellation when x is close to zero
+        let (work_context, x) = self.hyperbolic_work(x);
+        let sign = x.repr.sign();
+        let x = x.abs();
+        let x2 = x.square();
+        let hyp = (FBig::ONE + &x2).sqrt();
+        let arg = x + x2 / (FBig::ONE + hyp);
+        let asinh = work_context.ln_1p(arg.repr()).value();
+        self.round_fbig(sign * asinh)
+    }
+
+    /// Calculate the inverse hyperbolic cosine function (`acosh(x)`) on the floating point number under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.acosh(&a.repr()), Inexact(DBig::from_str_native("0.67")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is less than 1.
+    pub fn acosh<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        match repr_cmp(x, &Repr::one(), None) {
+            Ordering::Less => panic_out_of_domain(),
+            Ordering::Equal => return Exact(FBig::ZERO),
+            Ordering::Greater => {}
+        }
+
+        // acosh(x) = log(x + √(x²-1)) = log1p(t + √(2t + t²)) where t = x - 1,
+        // the latter form doesn't suffer from cancellation when x is close to one
+        let (work_context, x) = self.hyperbolic_work(x);
+        let t = x - FBig::ONE;
+        let root = ((&t + 2u8) * &t).sqrt();
+        let acosh = work_context.ln_1p((t + root).repr()).value();
+        self.round_fbig(acosh)
+    }
+
+    /// Calculate the inverse hyperbolic tangent function (`atanh(x)`) on the floating point number under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("0.1234")?;
+    /// assert_eq!(context.atanh(&a.repr()), Inexact(DBig::from_str_native("0.12")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not in the range `(-1, 1)`.
+    pub fn atanh<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if repr_cmp(x, &Repr::one(), None) != Ordering::Less
+            || repr_cmp(x, &Repr::neg_one(), None) != Ordering::Greater
+        {
+            panic_out_of_domain()
+        }
+        if x.is_zero() {
+            return Exact(FBig::ZERO);
+        }
+        if let Some(digits) = self.tiny_digits(x) {
+            // atanh(x) = x + x³/3 + ...
+            return self.round_with_tail(x, x.sign(), digits);
+        }
+
+        // atanh(x) = log((1+x)/(1-x))/2 = log1p(2x/(1-x))/2 for x > 0,
+        // the latter form doesn't suffer from cancellation when x is close to zero
+        let (work_context, x) = self.hyperbolic_work(x);
+        let sign = x.repr.sign();
+        let x = x.abs();
+        let arg = (&x * 2u8) / (FBig::ONE - x);
+        let atanh = work_context.ln_1p(arg.repr()).value() / 2u8;
+        self.round_fbig(sign * atanh)
+    }
+}
@@ -0,0 +1,190 @@
+//! Handling of the special values (NaN, infinities and signed zeros) under the IEEE mode.
+//!
+//! Each method in this module checks the operands of a basic arithmetic operation. If the
+//! result is determined by the special values, it's returned directly. Otherwise `None` is
+//! returned and the operation should be carried out as usual. When the IEEE mode is disabled,
+//! these methods only check that the operands are finite.
+
+use core::cmp::Ordering;
+
+use crate::{
+    error::{check_inf, check_inf_operands},
+    flags::Flags,
+    repr::{Context, Repr, Word},
+    round::{Round, Rounding},
+};
+use dashu_base::{Sign, UnsignedAbs};
+use dashu_int::IBig;
+
+#[inline]
+const fn signed_zero<const B: Word>(sign: Sign) -> Repr<B> {
+    match sign {
+        Sign::Positive => Repr::zero(),
+        Sign::Negative => Repr::neg_zero(),
+    }
+}
+
+#[inline]
+const fn signed_infinity<const B: Word>(sign: Sign) -> Repr<B> {
+    match sign {
+        Sign::Positive => Repr::infinity(),
+        Sign::Negative => Repr::neg_infinity(),
+    }
+}
+
+impl<R: Round> Context<R> {
+    /// Whether the rounding mode rounds toward -∞, in which case an exact zero sum is negative.
+    fn rounds_toward_neg_inf(&self) -> bool {
+        self.round_low_part(&IBig::ZERO, Sign::Negative, || Ordering::Less) == Rounding::SubOne
+            && self.round_low_part(&IBig::ZERO, Sign::Positive, || Ordering::Less) == Rounding::NoOp
+    }
+
+    /// Produce a NaN for an invalid operation
+    fn invalid<const B: Word>(&self) -> Repr<B> {
+        self.raise_flags(Flags::INVALID);
+        Repr::nan()
+    }
+
+    /// Handle the special values for `lhs + rhs_sign * rhs`.
+    pub(crate) fn ieee_add<const B: Word>(
+        &self,
+        lhs: &Repr<B>,
+        rhs: &Repr<B>,
+        rhs_sign: Sign,
+    ) -> Option<Repr<B>> {
+        if !self.ieee {
+            check_inf_operands(lhs, rhs);
+            return None;
+        }
+        if lhs.is_nan() || rhs.is_nan() {
+            return Some(Repr::nan());
+        }
+
+        let (lhs_sign, rhs_sign) = (lhs.sign(), rhs.sign() * rhs_sign);
+        match (lhs.is_infinite(), rhs.is_infinite()) {
+            // inf - inf is invalid
+            (true, true) if lhs_sign != rhs_sign => Some(self.invalid()),
+            (true, _) => Some(lhs.clone()),
+            (false, true) => Some(signed_infinity(rhs_sign)),
+            (false, false) => {
+                let cancelled = if lhs.is_zero() && rhs.is_zero() {
+                    // (+0) + (+0) = +0, (-0) + (-0) = -0
+                    if lhs_sign == rhs_sign {
+                        return Some(signed_zero(lhs_sign));
+                    }
+                    true
+                } else {
+                    lhs_sign != rhs_sign
+                        && lhs.exponent == rhs.exponent
+                        && (&lhs.significand).unsigned_abs() == (&rhs.significand).unsigned_abs()
+                };
+
+                // the sum of two operands with opposite signs is +0, except that it's -0
+                // when rounding toward -∞
+                if cancelled {
+                    let sign = if self.rounds_toward_neg_inf() {
+                        Sign::Negative
+                    } else {
+                        Sign::Positive
+                    };
+                    Some(signed_zero(sign))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Handle the special values for `lhs * rhs`.
+    pub(crate) fn ieee_mul<const B: Word>(&self, lhs: &Repr<B>, rhs: &Repr<B>) -> Option<Repr<B>> {
+        if !self.ieee {
+            check_inf_operands(lhs, rhs);
+            return None;
+        }
+        if lhs.is_nan() || rhs.is_nan() {
+            return Some(Repr::nan());
+        }
+
+        let sign = lhs.sign() * rhs.sign();
+        if lhs.is_infinite() || rhs.is_infinite() {
+            // inf * 0 is invalid
+            if lhs.is_zero() || rhs.is_zero() {
+                Some(self.invalid())
+            } else {
+                Some(signed_infinity(sign))
+            }
+        } else if lhs.is_zero() || rhs.is_zero() {
+            Some(signed_zero(sign))
+        } else {
+            None
+        }
+    }
+
+    /// Handle the special values for `lhs / rhs`.
+    pub(crate) fn ieee_div<const B: Word>(&self, lhs: &Repr<B>, rhs: &Repr<B>) -> Option<Repr<B>> {
+        if !self.ieee {
+            check_inf_operands(lhs, rhs);
+            return None;
+        }
+        if lhs.is_nan() || rhs.is_nan() {
+            return Some(Repr::nan());
+        }
+
+        let sign = lhs.sign() * rhs.sign();
+        match (lhs.is_infinite(), rhs.is_infinite()) {
+            // inf / inf is invalid
+            (true, true) => Some(self.invalid()),
+            (true, false) => Some(signed_infinity(sign)),
+            (false, true) => Some(signed_zero(sign)),
+            (false, false) => match (lhs.is_zero(), rhs.is_zero()) {
+                // 0 / 0 is invalid
+                (true, true) => Some(self.invalid()),
+                (false, true) => {
+                    self.raise_flags(Flags::DIVIDE_BY_ZERO);
+                    Some(signed_infinity(sign))
+                }
+                (true, false) => Some(signed_zero(sign)),
+                (false, false) => None,
+            },
+        }
+    }
+
+    /// Handle the special values for `sqrt(x)`.
+    pub(crate) fn ieee_sqrt<const B: Word>(&self, x: &Repr<B>) -> Option<Repr<B>> {
+        if !self.ieee {
+            check_inf(x);
+            return None;
+        }
+
+        if x.is_nan() || x.is_zero() || x == &Repr::infinity() {
+            // sqrt(-0) = -0
+            Some(x.clone())
+        } else if x.sign() == Sign::Negative {
+            Some(self.invalid())
+        } else {
+            None
+        }
+    }
+
+    /// Handle the special values for the `n`-th root of `x` (`n != 2`).
+    pub(crate) fn ieee_nth_root<const B: Word>(&self, x: &Repr<B>, n: usize) -> Option<Repr<B>> {
+        if !self.ieee {
+            check_inf(x);
+            return None;
+        }
+
+        let odd = n % 2 == 1;
+        if x.is_nan() {
+            Some(Repr::nan())
+        } else if x.sign() == Sign::Negative && !x.is_zero() && !odd {
+            Some(self.invalid())
+        } else if x.is_infinite() {
+            Some(x.clone())
+        } else if x.is_zero() {
+            // the odd roots keep the sign of zero, while the even roots of -0 are +0
+            Some(if odd { x.clone() } else { Repr::zero() })
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,564 @@
+//! Implementation of the interval type [Interval]
+
+use core::{
+    cmp::{max, min},
+    fmt::{self, Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::{
+    error::{check_inf, panic_divide_by_0, panic_out_of_domain},
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{
+        mode::{Down, HalfEven, Up},
+        Round,
+    },
+    utils::digit_len,
+};
+use dashu_base::Sign;
+use dashu_int::IBig;
+
+/// An arbitrary precision closed interval `[lower, upper]`, with the endpoints represented by [FBig].
+///
+/// The interval is used for rigorous error bounds (interval arithmetic): the result of every
+/// operation on the interval is guaranteed to contain all the possible results of the operation
+/// on the numbers in the operand intervals. This is done by rounding the lower endpoint toward
+/// -∞ and rounding the upper endpoint toward +∞ (that is, the outward rounding).
+///
+/// The precision used in the operations on [Interval] is the maximum precision of the endpoints
+/// of the operands, as with the [FBig] operations. The endpoints are always finite.
+///
+/// # Printing
+///
+/// The interval is printed with the certified digits only. That is, the printed number is the
+/// value rounded to the nearest with the most digits such that all the numbers in the interval
+/// round to the same value. If there isn't such a number, the interval is printed as `[lower, upper]`.
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// # use dashu_float::DBig;
+/// use dashu_float::Interval;
+///
+/// let a = Interval::from_point(DBig::from_str_native("2.0000000")?);
+/// let sqrt2 = a.sqrt(); // [1.4142135, 1.4142136]
+/// assert!(sqrt2.lower() < sqrt2.upper());
+/// assert_eq!(sqrt2.to_string(), "1.414214");
+///
+/// let b = Interval::new(DBig::from_str_native("3.140")?, DBig::from_str_native("3.142")?);
+/// let c = &b * &b; // [9.859, 9.873]
+/// assert_eq!(c.to_string(), "9.9");
+/// assert!(c.contains(&DBig::from_str_native("9.87")?));
+/// # Ok::<(), ParseError>(())
+/// ```
+pub struct Interval<const BASE: Word = 2> {
+    lower: FBig<Down, BASE>,
+    upper: FBig<Up, BASE>,
+}
+
+impl<const B: Word> Interval<B> {
+    /// [Interval] containing only 0, with unlimited precision
+    pub const ZERO: Self = Self {
+        lower: FBig::ZERO,
+        upper: FBig::ZERO,
+    };
+
+    /// [Interval] containing only 1, with unlimited precision
+    pub const ONE: Self = Self {
+        lower: FBig::ONE,
+        upper: FBig::ONE,
+    };
+
+    /// Create an interval from the lower and upper endpoints.
+    ///
+    /// The precision of the endpoints are preserved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any endpoint is infinite, or the lower endpoint is larger than the upper endpoint.
+    pub fn new<R1: Round, R2: Round>(lower: FBig<R1, B>, upper: FBig<R2, B>) -> Self {
+        check_inf(lower.repr());
+        check_inf(upper.repr());
+        assert!(lower <= upper, "the lower endpoint must not be larger than the upper endpoint");
+        Self {
+            lower: lower.with_rounding(),
+            upper: upper.with_rounding(),
+        }
+    }
+
+    /// Create an interval containing only a single number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is infinite.
+    #[inline]
+    pub fn from_point<R: Round>(value: FBig<R, B>) -> Self {
+        check_inf(value.repr());
+        let value = value.with_rounding::<Down>();
+        Self {
+            upper: value.clone().with_rounding(),
+            lower: value,
+        }
+    }
+
+    /// Create an interval `[center - radius, center + radius]` from a ball.
+    ///
+    /// The endpoints are rounded outward with the larger precision of the inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::Interval;
+    ///
+    /// let c = DBig::from_str_native("1.234")?;
+    /// let r = DBig::from_str_native("0.002")?;
+    /// let a = Interval::from_ball(&c, &r);
+    /// assert_eq!(a.lower(), &DBig::from_str_native("1.232")?);
+    /// assert_eq!(a.upper(), &DBig::from_str_native("1.236")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any input is infinite, or the radius is negative.
+    pub fn from_ball<R: Round>(center: &FBig<R, B>, radius: &FBig<R, B>) -> Self {
+        check_inf(center.repr());
+        check_inf(radius.repr());
+        if radius.repr().sign() == Sign::Negative && !radius.repr().is_zero() {
+            panic_out_of_domain()
+        }
+
+        let precision = center.precision().max(radius.precision());
+        Self {
+            lower: Context::<Down>::new(precision)
+                .sub(center.repr(), radius.repr())
+                .value(),
+            upper: Context::<Up>::new(precision)
+                .add(center.repr(), radius.repr())
+                .value(),
+        }
+    }
+
+    /// Get the lower endpoint of the interval
+    #[inline]
+    pub const fn lower(&self) -> &FBig<Down, B> {
+        &self.lower
+    }
+
+    /// Get the upper endpoint of the interval
+    #[inline]
+    pub const fn upper(&self) -> &FBig<Up, B> {
+        &self.upper
+    }
+
+    /// Convert the interval into the lower and upper endpoints
+    #[inline]
+    pub fn into_parts(self) -> (FBig<Down, B>, FBig<Up, B>) {
+        (self.lower, self.upper)
+    }
+
+    /// Get the maximum precision set for the endpoints of the interval.
+    #[inline]
+    pub const fn precision(&self) -> usize {
+        let (lp, up) = (self.lower.context.precision, self.upper.context.precision);
+        if lp > up {
+            lp
+        } else {
+            up
+        }
+    }
+
+    /// Apply a new precision limit to the interval.
+    ///
+    /// The endpoints are rounded outward if the new precision is lower.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::Interval;
+    ///
+    /// let a = Interval::new(DBig::from_str_native("1.234")?, DBig::from_str_native("1.235")?);
+    /// let b = a.with_precision(2);
+    /// assert_eq!(b.lower(), &DBig::from_str_native("1.2")?);
+    /// assert_eq!(b.upper(), &DBig::from_str_native("1.3")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn with_precision(self, precision: usize) -> Self {
+        Self {
+            lower: self.lower.with_precision(precision).value(),
+            upper: self.upper.with_precision(precision).value(),
+        }
+    }
+
+    /// Convert the interval to another base, the endpoints are rounded outward.
+    ///
+    /// See [FBig::with_base] for how the precision is determined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::{FBig, Interval};
+    ///
+    /// // the binary interval containing 0.1
+    /// let a = Interval::from_point(FBig::<dashu_float::round::mode::Zero>::try_from(0.1).unwrap());
+    /// let b = a.with_base::<10>();
+    /// assert!(b.lower() < b.upper());
+    /// assert_eq!(b.to_string(), "0.10000000000000");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn with_base<const NEW_B: Word>(self) -> Interval<NEW_B> {
+        Interval {
+            lower: self.lower.with_base::<NEW_B>().value(),
+            upper: self.upper.with_base::<NEW_B>().value(),
+        }
+    }
+
+    /// Check whether the interval contains only a single number.
+    #[inline]
+    pub fn is_point(&self) -> bool {
+        self.lower.repr == self.upper.repr
+    }
+
+    /// Get an upper bound of the width of the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::Interval;
+    ///
+    /// let a = Interval::new(DBig::from_str_native("1.234")?, DBig::from_str_native("1.25")?);
+    /// assert_eq!(a.width(), DBig::from_str_native("0.016")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn width(&self) -> FBig<Up, B> {
+        Context::<Up>::new(self.precision())
+            .sub(self.upper.repr(), self.lower.repr())
+            .value()
+    }
+
+    /// Check whether the number is in the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::Interval;
+    ///
+    /// let a = Interval::new(DBig::from_str_native("1.2")?, DBig::from_str_native("1.3")?);
+    /// assert!(a.contains(&DBig::from_str_native("1.25")?));
+    /// assert!(a.contains(&DBig::from_str_native("1.3")?));
+    /// assert!(!a.contains(&DBig::from_str_native("1.35")?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn contains<R: Round>(&self, value: &FBig<R, B>) -> bool {
+        &self.lower <= value && value <= &self.upper
+    }
+
+    /// Check whether the other interval is a subset of this interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::Interval;
+    ///
+    /// let a = Interval::new(DBig::from_str_native("1.2")?, DBig::from_str_native("1.3")?);
+    /// let b = Interval::new(DBig::from_str_native("1.25")?, DBig::from_str_native("1.3")?);
+    /// assert!(a.encloses(&b));
+    /// assert!(!b.encloses(&a));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn encloses(&self, other: &Self) -> bool {
+        self.lower <= other.lower && other.upper <= self.upper
+    }
+
+    /// Check whether the two intervals have common numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::Interval;
+    ///
+    /// let a = Interval::new(DBig::from_str_native("1.2")?, DBig::from_str_native("1.3")?);
+    /// let b = Interval::new(DBig::from_str_native("1.3")?, DBig::from_str_native("1.4")?);
+    /// let c = Interval::new(DBig::from_str_native("1.35")?, DBig::from_str_native("1.4")?);
+    /// assert!(a.overlaps(&b));
+    /// assert!(!a.overlaps(&c));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.lower <= other.upper && other.lower <= self.upper
+    }
+
+    /// Calculate the square root of the interval.
+    ///
+    /// The negative part of the interval is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the interval is entirely negative.
+    pub fn sqrt(&self) -> Self {
+        if self.upper.repr().sign() == Sign::Negative {
+            panic_out_of_domain()
+        }
+        let precision = self.precision();
+        let lower = if self.lower.repr().sign() == Sign::Negative {
+            FBig::new(Repr::zero(), Context::new(precision))
+        } else {
+            Context::<Down>::new(precision)
+                .sqrt(self.lower.repr())
+                .value()
+        };
+        let upper = Context::<Up>::new(precision)
+            .sqrt(self.upper.repr())
+            .value();
+        Self { lower, upper }
+    }
+
+    /// Calculate the exponential function on the interval.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn exp(&self) -> Self {
+        let precision = self.precision();
+        Self {
+            lower: Context::<Down>::new(precision)
+                .exp(self.lower.repr())
+                .value(),
+            upper: Context::<Up>::new(precision).exp(self.upper.repr()).value(),
+        }
+    }
+
+    /// Calculate the natural logarithm on the interval.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the interval contains non-positive numbers.
+    pub fn ln(&self) -> Self {
+        if self.lower.repr().sign() == Sign::Negative || self.lower.repr().is_zero() {
+            panic_out_of_domain()
+        }
+        let precision = self.precision();
+        Self {
+            lower: Context::<Down>::new(precision)
+                .ln(self.lower.repr())
+                .value(),
+            upper: Context::<Up>::new(precision).ln(self.upper.repr()).value(),
+        }
+    }
+
+    /// Get the contexts used for rounding the lower and upper endpoints in a binary operation
+    #[inline]
+    fn contexts(&self, rhs: &Self) -> (Context<Down>, Context<Up>) {
+        let precision = self.precision().max(rhs.precision());
+        (Context::new(precision), Context::new(precision))
+    }
+
+    fn add_ref(&self, rhs: &Self) -> Self {
+        let (down, up) = self.contexts(rhs);
+        Self {
+            lower: down.add(self.lower.repr(), rhs.lower.repr()).value(),
+            upper: up.add(self.upper.repr(), rhs.upper.repr()).value(),
+        }
+    }
+
+    fn sub_ref(&self, rhs: &Self) -> Self {
+        let (down, up) = self.contexts(rhs);
+        Self {
+            lower: down.sub(self.lower.repr(), rhs.upper.repr()).value(),
+            upper: up.sub(self.upper.repr(), rhs.lower.repr()).value(),
+        }
+    }
+
+    fn mul_ref(&self, rhs: &Self) -> Self {
+        let (down, up) = self.contexts(rhs);
+        let pairs = [
+            (self.lower.repr(), rhs.lower.repr()),
+            (self.lower.repr(), rhs.upper.repr()),
+            (self.upper.repr(), rhs.lower.repr()),
+            (self.upper.repr(), rhs.upper.repr()),
+        ];
+        let lower = pairs
+            .iter()
+            .map(|(a, b)| down.mul(a, b).value())
+            .reduce(min);
+        let upper = pairs.iter().map(|(a, b)| up.mul(a, b).value()).reduce(max);
+        Self {
+            lower: lower.unwrap(),
+            upper: upper.unwrap(),
+        }
+    }
+
+    fn div_ref(&self, rhs: &Self) -> Self {
+        if rhs.contains(&FBig::<Down, B>::ZERO) {
+            panic_divide_by_0()
+        }
+
+        let (down, up) = self.contexts(rhs);
+        let pairs = [
+            (self.lower.repr(), rhs.lower.repr()),
+            (self.lower.repr(), rhs.upper.repr()),
+            (self.upper.repr(), rhs.lower.repr()),
+            (self.upper.repr(), rhs.upper.repr()),
+        ];
+        let lower = pairs
+            .iter()
+            .map(|(a, b)| down.div(a, b).value())
+            .reduce(min);
+        let upper = pairs.iter().map(|(a, b)| up.div(a, b).value()).reduce(max);
+        Self {
+            lower: lower.unwrap(),
+            upper: upper.unwrap(),
+        }
+    }
+}
+
+macro_rules! impl_interval_binop {
+    (impl $trait:ident, $method:ident, $op:ident) => {
+        impl<'l, 'r, const B: Word> $trait<&'r Interval<B>> for &'l Interval<B> {
+            type Output = Interval<B>;
+            #[inline]
+            fn $method(self, rhs: &Interval<B>) -> Self::Output {
+                self.$op(rhs)
+            }
+        }
+
+        impl<'r, const B: Word> $trait<&'r Interval<B>> for Interval<B> {
+            type Output = Interval<B>;
+            #[inline]
+            fn $method(self, rhs: &Interval<B>) -> Self::Output {
+                self.$op(rhs)
+            }
+        }
+
+        impl<'l, const B: Word> $trait<Interval<B>> for &'l Interval<B> {
+            type Output = Interval<B>;
+            #[inline]
+            fn $method(self, rhs: Interval<B>) -> Self::Output {
+                self.$op(&rhs)
+            }
+        }
+
+        impl<const B: Word> $trait<Interval<B>> for Interval<B> {
+            type Output = Interval<B>;
+            #[inline]
+            fn $method(self, rhs: Interval<B>) -> Self::Output {
+                self.$op(&rhs)
+            }
+        }
+    };
+}
+impl_interval_binop!(impl Add, add, add_ref);
+impl_interval_binop!(impl Sub, sub, sub_ref);
+impl_interval_binop!(impl Mul, mul, mul_ref);
+impl_interval_binop!(impl Div, div, div_ref);
+
+impl<const B: Word> Neg for Interval<B> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            lower: (-self.upper).with_rounding(),
+            upper: (-self.lower).with_rounding(),
+        }
+    }
+}
+
+impl<const B: Word> Neg for &Interval<B> {
+    type Output = Interval<B>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.clone().neg()
+    }
+}
+
+impl<const B: Word> Clone for Interval<B> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            lower: self.lower.clone(),
+            upper: self.upper.clone(),
+        }
+    }
+}
+
+impl<const B: Word> Default for Interval<B> {
+    /// Default value: [0, 0].
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const B: Word> PartialEq for Interval<B> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.lower == other.lower && self.upper == other.upper
+    }
+}
+impl<const B: Word> Eq for Interval<B> {}
+
+impl<R: Round, const B: Word> From<FBig<R, B>> for Interval<B> {
+    #[inline]
+    fn from(value: FBig<R, B>) -> Self {
+        Self::from_point(value)
+    }
+}
+
+impl<const B: Word> From<IBig> for Interval<B> {
+    #[inline]
+    fn from(value: IBig) -> Self {
+        Self::from_point(FBig::<Down, B>::from(value))
+    }
+}
+
+impl<const B: Word> Display for Interval<B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_point() {
+            return Display::fmt(&self.lower.repr, f);
+        }
+
+        // find the most digits such that both endpoints round to the same number
+        let max_digits = digit_len::<B>(&self.lower.repr.significand)
+            .max(digit_len::<B>(&self.upper.repr.significand));
+        for digits in (1..=max_digits).rev() {
+            let context = Context::<HalfEven>::new(digits);
+            let lower = context.repr_round_ref(&self.lower.repr).value();
+            let upper = context.repr_round_ref(&self.upper.repr).value();
+            if lower == upper {
+                // print the trailing zeros in the certified digits as well
+                let magnitude = digit_len::<B>(&lower.significand) as isize + lower.exponent;
+                let frac_digits = (digits as isize - magnitude).max(0) as usize;
+                return write!(f, "{:.*}", frac_digits, lower);
+            }
+        }
+        write!(f, "[{}, {}]", self.lower.repr, self.upper.repr)
+    }
+}
+
+impl<const B: Word> fmt::Debug for Interval<B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Interval")
+            .field("lower", &self.lower)
+            .field("upper", &self.upper)
+            .finish()
+    }
+}
@@ -1,12 +1,18 @@
-//! Implementation of core::iter traits
+//! Implementation of core::iter traits and the exact summation
 
-use crate::{fbig::FBig, repr::Word, round::Round};
+use crate::{
+    fbig::FBig,
+    fma::DotAccumulator,
+    repr::{Context, Repr, Word},
+    round::{Round, Rounded},
+};
 use core::{
+    borrow::Borrow,
     iter::{Product, Sum},
     ops::{Add, Mul},
 };
 
-// XXX: implement precise summation of multiple floats
+// The summation here rounds at each step, use FBig::sum_exact or Context::sum for a correctly rounded sum
 impl<T, R: Round, const B: Word> Sum<T> for FBig<R, B>
 where
     Self: Add<T, Output = Self>,
@@ -24,3 +30,148 @@ where
         iter.fold(FBig::ONE, FBig::mul)
     }
 }
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Sum up the floating point numbers exactly, and round the result only once.
+    ///
+    /// Different from the [Sum] implementation, which rounds the partial sum at every step,
+    /// the result of this function is correctly rounded. The precision of the output is the
+    /// maximum of the precisions of the inputs. It's also available as an iterator adapter
+    /// through the [SumExact] trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1e10")?;
+    /// let b = DBig::from_str_native("1")?;
+    /// let c = DBig::from_str_native("-1e10")?;
+    /// assert_eq!(DBig::sum_exact([&a, &b, &c]), DBig::ONE);
+    /// assert_eq!([&a, &b, &c].into_iter().sum::<DBig>(), DBig::ZERO);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the numbers is infinite.
+    pub fn sum_exact<T: Borrow<Self>, I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut context = Context::new(0);
+        let mut acc = DotAccumulator::new(context);
+        for x in iter {
+            let x = x.borrow();
+            context = Context::max(context, x.context);
+            acc.add(&x.repr);
+        }
+        acc.with_context(context).value().value()
+    }
+}
+
+/// An extension trait for the iterators of floating point numbers, which provides the
+/// correctly rounded summation [FBig::sum_exact] as an iterator adapter.
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// # use dashu_float::DBig;
+/// use dashu_float::SumExact;
+///
+/// let a = DBig::from_str_native("1e10")?;
+/// let b = DBig::from_str_native("1")?;
+/// let c = DBig::from_str_native("-1e10")?;
+/// let values = [a, b, c];
+/// assert_eq!(values.iter().sum_exact(), DBig::ONE);
+/// assert_eq!(values.into_iter().sum_exact(), DBig::ONE);
+/// # Ok::<(), ParseError>(())
+/// ```
+pub trait SumExact<R: Round, const B: Word>: Iterator {
+    /// Sum up the numbers exactly, and round the result only once.
+    /// See [FBig::sum_exact] for details.
+    fn sum_exact(self) -> FBig<R, B>;
+}
+
+impl<R: Round, const B: Word, I: Iterator> SumExact<R, B> for I
+where
+    I::Item: Borrow<FBig<R, B>>,
+{
+    #[inline]
+    fn sum_exact(self) -> FBig<R, B> {
+        FBig::sum_exact(self)
+    }
+}
+
+impl<R: Round> Context<R> {
+    /// Sum up the floating point numbers exactly, and round the result only once under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1e100")?;
+    /// let b = DBig::from_str_native("1.234")?;
+    /// let c = DBig::from_str_native("-1e100")?;
+    /// assert_eq!(
+    ///     context.sum([a.repr(), b.repr(), c.repr()]),
+    ///     Inexact(DBig::from_str_native("1.2")?, NoOp)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the numbers is infinite.
+    pub fn sum<'a, const B: Word, I>(&self, iter: I) -> Rounded<FBig<R, B>>
+    where
+        I: IntoIterator<Item = &'a Repr<B>>,
+    {
+        let mut acc = DotAccumulator::new(*self);
+        for x in iter {
+            acc.add(x);
+        }
+        acc.value()
+    }
+
+    /// Calculate the dot product of two sequences of floating point numbers exactly,
+    /// and round the result only once under this context.
+    ///
+    /// If the two sequences have different lengths, the extra elements in the longer one are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = [DBig::from_str_native("1.234")?, DBig::from_str_native("-5.678")?];
+    /// let b = [DBig::from_str_native("9.876")?, DBig::from_str_native("2.146")?];
+    /// assert_eq!(
+    ///     context.dot(a.iter().map(|x| x.repr()), b.iter().map(|x| x.repr())),
+    ///     Inexact(DBig::from_str_native("0.0020")?, AddOne)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the numbers is infinite.
+    pub fn dot<'a, 'b, const B: Word, I, J>(&self, lhs: I, rhs: J) -> Rounded<FBig<R, B>>
+    where
+        I: IntoIterator<Item = &'a Repr<B>>,
+        J: IntoIterator<Item = &'b Repr<B>>,
+    {
+        let mut acc = DotAccumulator::new(*self);
+        for (a, b) in lhs.into_iter().zip(rhs) {
+            acc.add_product(a, b);
+        }
+        acc.value()
+    }
+}
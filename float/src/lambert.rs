@@ -0,0 +1,217 @@
+//! Implementation of the Lambert W function
+
+use core::cmp::Ordering;
+
+use crate::{
+    cmp::repr_cmp,
+    error::{check_inf, check_precision_limited, panic_out_of_domain},
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{Round, Rounded},
+    utils::top_or_min,
+};
+use dashu_base::{Approximation::*, EstimatedLog2, Sign};
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Calculate the principal branch of the Lambert W function (`W₀(x)`) on the float number,
+    /// which is the solution of `w·eʷ = x` with `w >= -1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.lambert_w0(), DBig::from_str_native("0.6465")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is less than `-1/e`.
+    #[inline]
+    pub fn lambert_w0(&self) -> Self {
+        self.context.lambert_w0(&self.repr).value()
+    }
+
+    /// Calculate the lower branch of the Lambert W function (`W₋₁(x)`) on the float number,
+    /// which is the solution of `w·eʷ = x` with `w <= -1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("-0.1234")?;
+    /// assert_eq!(a.lambert_wm1(), DBig::from_str_native("-3.2802")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not in the range `[-1/e, 0)`.
+    #[inline]
+    pub fn lambert_wm1(&self) -> Self {
+        self.context.lambert_wm1(&self.repr).value()
+    }
+}
+
+impl<R: Round> Context<R> {
+    /// Check if `x >= -1/e`, and return the number of extra digits required to evaluate
+    /// the Lambert W function, which is ill-conditioned near the branch point `-1/e`.
+    ///
+    /// The offset `e·x + 1` is evaluated with the precision increased until its sign is decided.
+    fn lambert_domain<const B: Word>(&self, x: &Repr<B>) -> usize {
+        if x.sign() == Sign::Positive {
+            return 0;
+        }
+
+        let mut precision = self.precision + 4;
+        loop {
+            let context = self.work_context(precision);
+            let x = FBig::new(context.repr_round_ref(x).value(), context);
+            let offset = context.e::<B>().value() * x + FBig::ONE;
+
+            // the error of the offset is within a few units of B^(1-precision)
+            let offset_top = top_or_min(&offset.repr);
+            if offset_top > 4 - precision as isize {
+                if offset.repr.sign() == Sign::Negative {
+                    panic_out_of_domain()
+                }
+                return if offset_top < 0 {
+                    -offset_top as usize
+                } else {
+                    0
+                };
+            }
+            precision *= 2;
+        }
+    }
+
+    /// Make an initial guess of `W₀(x)` or `W₋₁(x)` (when `lower` is true) for the Halley iteration
+    fn lambert_initial<const B: Word>(&self, x: &FBig<R, B>, lower: bool) -> FBig<R, B> {
+        let offset = self.e::<B>().value() * x + FBig::ONE;
+        let double_offset = &offset * 2u8;
+        if x.repr.sign() == Sign::Negative
+            && repr_cmp(&double_offset.repr, &Repr::one(), None) == Ordering::Less
+        {
+            // near the branch point, W(x) = -1 + p - p²/3 + 11p³/72 - ..., where p = ±√(2(ex + 1))
+            let p = double_offset.sqrt();
+            let p = if lower { -p } else { p };
+            let p2 = p.square();
+            let p3 = &p2 * &p;
+            return &p - FBig::ONE - p2 / 3u8 + p3 * 11u8 / 72u8;
+        }
+
+        let three = Repr::new(3.into(), 0);
+        if !lower && repr_cmp(&x.repr, &three, None) == Ordering::Less {
+            // W₀(x) ≈ log(1+x) for small x
+            x.ln_1p()
+        } else {
+            // W(x) = L₁ - L₂ + L₂/L₁ + ..., where L₁ = log(±x) and L₂ = log(±L₁),
+            // for W₀(x) at x -> ∞ and W₋₁(x) at x -> 0⁻
+            let l1 = if lower { (-x).ln() } else { x.ln() };
+            let l2 = if lower { (-&l1).ln() } else { l1.ln() };
+            &l1 - &l2 + l2 / l1
+        }
+    }
+
+    /// Evaluate `W₀(x)` or `W₋₁(x)` (when `lower` is true) by the Halley iteration
+    /// `w' = w - f/(eʷ(w+1) - (w+2)f/(2w+2))` where `f = w·eʷ - x`.
+    fn lambert_halley<const B: Word>(
+        &self,
+        x: &Repr<B>,
+        lower: bool,
+        extra_digits: usize,
+    ) -> FBig<R, B> {
+        // use a simple rule for guard bits, the same as powf
+        let guard_digits = 10 + self.precision.log2_est() as usize;
+        let work_context = self.work_context(self.precision + extra_digits + guard_digits);
+        let x = FBig::new(work_context.repr_round_ref(x).value(), work_context);
+
+        let mut w = work_context.lambert_initial(&x, lower);
+        loop {
+            let exp_w = w.exp();
+            let f = &w * &exp_w - &x;
+            let w1 = &w + FBig::ONE;
+            let den = exp_w * &w1 - (&w + 2u8) * &f / (w1 * 2u8);
+            let delta = f / den;
+            w -= delta.clone();
+            if delta.repr.is_zero()
+                || top_or_min(&delta.repr) < top_or_min(&w.repr) - self.precision as isize
+            {
+                return w;
+            }
+        }
+    }
+
+    /// Calculate the principal branch of the Lambert W function (`W₀(x)`) on the float number
+    /// under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("-0.36")?;
+    /// assert_eq!(context.lambert_w0(&a.repr()), Inexact(DBig::from_str_native("-0.81")?, SubOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is less than `-1/e`.
+    pub fn lambert_w0<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if x.is_zero() {
+            return Exact(FBig::ZERO);
+        }
+        let extra_digits = self.lambert_domain(x);
+
+        // W₀(x) = x - x² + 3x³/2 - ..., where the terms except the first one don't affect
+        // the rounding except for its sign if |x| < B^-(p+2)
+        let digits = self.precision + 2;
+        if x.log2_bounds().1 < -(digits as f32) * B.log2_bounds().1 {
+            return self.round_with_tail(x, Sign::Negative, digits);
+        }
+
+        self.round_ziv(|c| c.lambert_halley(x, false, extra_digits))
+    }
+
+    /// Calculate the lower branch of the Lambert W function (`W₋₁(x)`) on the float number
+    /// under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("-0.36")?;
+    /// assert_eq!(context.lambert_wm1(&a.repr()), Inexact(DBig::from_str_native("-1.2")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not in the range `[-1/e, 0)`.
+    pub fn lambert_wm1<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if x.sign() == Sign::Positive {
+            // including the case x = 0, where W₋₁(x) -> -∞
+            panic_out_of_domain()
+        }
+        let extra_digits = self.lambert_domain(x);
+
+        self.round_ziv(|c| c.lambert_halley(x, true, extra_digits))
+    }
+}
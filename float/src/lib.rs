@@ -45,6 +45,7 @@
 //! assert_eq!(b.precision(), 4); // 4 decimal digits
 //!
 //! assert!(b > c); // comparison is limited in the same base
+//! assert!(c > 2 && c < 3.0); // or with integers and primitive floats
 //! assert!(a.to_decimal().value() < d);
 //! assert_eq!(c.to_string(), "2.71828");
 //!
@@ -62,30 +63,50 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 mod add;
+mod agm;
 mod cmp;
+mod complex;
+mod consts;
 mod convert;
 mod div;
+mod erf;
 mod error;
 mod exp;
 mod fbig;
+pub mod flags;
+mod fma;
 mod fmt;
+mod gamma;
 mod helper_macros;
+mod hyperbolic;
+mod ieee;
+mod interval;
 mod iter;
+mod lambert;
 mod log;
 mod mul;
 mod parse;
+mod radix;
+mod range;
 mod repr;
 mod root;
 pub mod round;
 mod shift;
 mod sign;
+mod trig;
 mod utils;
+mod zeta;
 
+pub use complex::CBig;
 pub use fbig::FBig;
+pub use fma::DotAccumulator;
+pub use interval::Interval;
+pub use iter::SumExact;
+pub use radix::DynFBig;
 pub use repr::{Context, Repr};
 
 /// Multi-precision float number with decimal exponent and [HalfAway][round::mode::HalfAway] rounding mode
 pub type DBig = FBig<round::mode::HalfAway, 10>;
-
-// TODO: allow operations with inf, but only panic when the result is nan (inf - inf and inf / inf)
@@ -2,12 +2,16 @@ use dashu_base::{Approximation::*, EstimatedLog2, Sign};
 use dashu_int::IBig;
 
 use crate::{
-    error::{check_inf, check_precision_limited},
+    error::{check_inf, check_precision_limited, panic_out_of_domain},
     fbig::FBig,
     repr::{Context, Repr, Word},
-    round::{Round, Rounded},
+    round::{mode, Round, Rounded},
 };
 
+/// The precision (in bits) above which the logarithm could be evaluated by the
+/// arithmetic-geometric mean instead of the Maclaurin series
+const LN_AGM_THRESHOLD: usize = 1024;
+
 impl<const B: Word> EstimatedLog2 for Repr<B> {
     // currently a Word has at most 64 bits, so log2() < f32::MAX
     fn log2_bounds(&self) -> (f32, f32) {
@@ -82,93 +86,94 @@ impl<R: Round, const B: Word> FBig<R, B> {
     pub fn ln_1p(&self) -> Self {
         self.context.ln_1p(&self.repr).value()
     }
-}
 
-impl<R: Round> Context<R> {
-    /// Calculate log(2)
+    /// Calculate the binary logarithm function (`log₂(x)`) on the float number.
     ///
-    /// The precision of the output will be larger than self.precision
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.log2(), DBig::from_str_native("0.3033")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is not positive.
     #[inline]
-    fn ln2<const B: Word>(&self) -> FBig<R, B> {
-        // log(2) = 4L(6) + 2L(99)
-        // see formula (24) from Gourdon, Xavier, and Pascal Sebah.
-        // "The Logarithmic Constant: Log 2." (2004)
-        4 * self.iacoth(6.into()) + 2 * self.iacoth(99.into())
+    pub fn log2(&self) -> Self {
+        self.context.log2(&self.repr).value()
     }
 
-    /// Calculate log(2)
+    /// Calculate the common logarithm function (`log₁₀(x)`) on the float number.
     ///
-    /// The precision of the output will be larger than self.precision
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.log10(), DBig::from_str_native("0.09132")?);
+    ///
+    /// let b = DBig::from_str_native("1000")?;
+    /// assert_eq!(b.log10(), DBig::from_str_native("3")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is not positive.
     #[inline]
-    fn ln10<const B: Word>(&self) -> FBig<R, B> {
-        // log(10) = log(2) + log(5) = 3log(2) + 2L(9)
-        3 * self.ln2() + 2 * self.iacoth(9.into())
+    pub fn log10(&self) -> Self {
+        self.context.log10(&self.repr).value()
     }
 
+    /// Calculate the logarithm function (`log_b(x)`) with an arbitrary base on the float number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// let b = DBig::from_str_native("5.678")?;
+    /// assert_eq!(a.log(&b), DBig::from_str_native("0.1211")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number or the base is not positive, or the base is one.
+    #[inline]
+    pub fn log(&self, base: &Self) -> Self {
+        let context = Context::max(self.context, base.context);
+        context.log(&self.repr, &base.repr).value()
+    }
+}
+
+impl<R: Round> Context<R> {
     /// Calculate log(B), for internal use only
     ///
     /// The precision of the output will be larger than self.precision
     #[inline]
     pub(crate) fn ln_base<const B: Word>(&self) -> FBig<R, B> {
+        // keep the same amount of guard digits as the series used by the logarithm
+        let guard_digits = (self.precision.log2_est() / B.log2_est()) as usize;
+        let context = self.work_context(self.precision + guard_digits + 2);
         match B {
-            2 => self.ln2(),
-            10 => self.ln10(),
-            i if i.is_power_of_two() => self.ln2() * i.trailing_zeros(),
+            2 => context.ln2().value(),
+            10 => context.ln10().value(),
+            i if i.is_power_of_two() => context.ln2().value() * i.trailing_zeros(),
             _ => self.ln(&Repr::new(Repr::<B>::BASE, 0)).value(),
         }
     }
 
-    /// Calculate L(n) = acoth(n) = atanh(1/n) = 1/2 log((n+1)/(n-1))
-    ///
-    /// This method is intended to be used in logarithm calculation,
-    /// so the precision of the output will be larger than desired precision.
-    fn iacoth<const B: Word>(&self, n: IBig) -> FBig<R, B> {
-        /*
-         * use Maclaurin series:
-         *       1    1     n+1             1
-         * atanh(—) = — log(———) =  Σ  ———————————
-         *       n    2     n-1    i≥0 n²ⁱ⁺¹(2i+1)
-         *
-         * Therefore to achieve precision B^p, the series should be stopped at
-         *    n²ⁱ⁺¹(2i+1) / n = B^p
-         * => 2i*ln(n) + ln(2i+1) = p ln(B)
-         * ~> 2i*ln(n) = p ln(B)
-         * => 2i = p/log_B(n)
-         *
-         * There will be i summations when calculating the series, to prevent
-         * loss of significant, we needs about log_B(i) guard digits.
-         *    log_B(i)
-         * <= log_B(p/2log_B(n))
-         *  = log_B(p/2) - log_B(log_B(n))
-         * <= log_B(p/2)
-         */
-
-        // extras digits are added to ensure precise result
-        // TODO: test if we can use log_B(p/2log_B(n)) directly
-        let guard_digits = (self.precision.log2_est() / B.log2_est()) as usize;
-        let work_context = Self::new(self.precision + guard_digits + 2);
-
-        let n = work_context.convert_int(n).value();
-        let inv = FBig::ONE / n;
-        let inv2 = inv.square();
-        let mut sum = inv.clone();
-        let mut pow = inv;
-
-        let mut k: usize = 3;
-        loop {
-            pow *= &inv2;
-            let next = &sum + &pow / work_context.convert_int::<B>(k.into()).value();
-
-            if next == sum {
-                return sum;
-            }
-            sum = next;
-            k += 2;
-        }
-    }
-
     /// Calculate the natural logarithm function (`log(x)`) on the float number under this context.
     ///
+    /// The result is correctly rounded.
+    ///
     /// # Examples
     ///
     /// ```
@@ -189,6 +194,8 @@ impl<R: Round> Context<R> {
 
     /// Calculate the natural logarithm function (`log(x+1)`) on the float number under this context.
     ///
+    /// The result is correctly rounded.
+    ///
     /// # Examples
     ///
     /// ```
@@ -207,6 +214,109 @@ impl<R: Round> Context<R> {
         self.ln_internal(x, true)
     }
 
+    /// Calculate the binary logarithm function (`log₂(x)`) on the float number under this context.
+    ///
+    /// The result is correctly rounded, and it's exact if the input is a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.log2(&a.repr()), Inexact(DBig::from_str_native("0.30")?, NoOp));
+    /// let b = DBig::from_str_native("0.125")?;
+    /// assert_eq!(context.log2(&b.repr()), Exact(DBig::from_str_native("-3")?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not positive.
+    pub fn log2<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_log_domain(x);
+        if let Some((p, q)) = exact_log(x, &Repr::new(2.into(), 0)) {
+            return self.convert_fraction(p.into(), q.into());
+        }
+        self.round_ziv(|context| context.ln(x).value() / context.ln2().value())
+    }
+
+    /// Calculate the common logarithm function (`log₁₀(x)`) on the float number under this context.
+    ///
+    /// The result is correctly rounded, and it's exact if the input is a power of ten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.log10(&a.repr()), Inexact(DBig::from_str_native("0.091")?, NoOp));
+    /// let b = DBig::from_str_native("1e-20")?;
+    /// assert_eq!(context.log10(&b.repr()), Exact(DBig::from_str_native("-20")?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not positive.
+    pub fn log10<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_log_domain(x);
+        if let Some((p, q)) = exact_log(x, &Repr::new(10.into(), 0)) {
+            return self.convert_fraction(p.into(), q.into());
+        }
+        self.round_ziv(|context| context.ln(x).value() / context.ln10().value())
+    }
+
+    /// Calculate the logarithm function (`log_b(x)`) with an arbitrary base on the float number
+    /// under this context.
+    ///
+    /// The result is correctly rounded, and it's exact if the input is a power of the base with
+    /// a rational exponent that is representable in base `B`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// let b = DBig::from_str_native("5.678")?;
+    /// assert_eq!(context.log(&a.repr(), &b.repr()), Inexact(DBig::from_str_native("0.12")?, NoOp));
+    ///
+    /// let c = DBig::from_str_native("0.125")?;
+    /// let d = DBig::from_str_native("0.5")?;
+    /// assert_eq!(context.log(&c.repr(), &d.repr()), Exact(DBig::from_str_native("3")?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number or the base is not positive,
+    /// or the base is one.
+    pub fn log<const B: Word>(&self, x: &Repr<B>, base: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_log_domain(x);
+        check_log_domain(base);
+        if base.is_one() {
+            panic_out_of_domain()
+        }
+        if let Some((p, q)) = exact_log(x, base) {
+            return self.convert_fraction(p.into(), q.into());
+        }
+        self.round_ziv(|context| context.ln(x).value() / context.ln(base).value())
+    }
+
     fn ln_internal<const B: Word>(&self, x: &Repr<B>, one_plus: bool) -> Rounded<FBig<R, B>> {
         check_inf(x);
         check_precision_limited(self.precision);
@@ -215,12 +325,38 @@ impl<R: Round> Context<R> {
             return Exact(FBig::ZERO);
         }
 
+        // when x is close to one, log(x) = log(1 + (x-1)), where the subtraction is exact,
+        // so that the digits of x are not lost when it's rounded to the working precision
+        if !one_plus && x.log2_bounds().0 > -1. && x.log2_bounds().1 < 1. {
+            let x_m1 = Context::<R>::new(0).sub(x, &Repr::one()).value();
+            if x_m1.log2_est() < -B.log2_est() {
+                return self.ln_internal(&x_m1.repr, true);
+            }
+        }
+
+        // log(1+x) = x - x²/2 + ..., when x is tiny enough, only the sign of the second
+        // term matters for the rounding
+        if one_plus {
+            let digits = x.digits().max(self.precision) + 2;
+            if x.log2_bounds().1 + 1. < -((digits + 1) as f32) * B.log2_bounds().1 {
+                return self.round_with_tail(x, Sign::Negative, digits);
+            }
+        }
+
+        // the approximation is not always correctly rounded, especially under the directed
+        // rounding modes, so it's evaluated with more digits until the rounding is decided
+        self.round_ziv(|context| context.ln_approx(x, one_plus))
+    }
+
+    /// Evaluate `log(x)` (or `log(1+x)` if `one_plus` is true) under this context, where the
+    /// result is not zero. The error of the result is within a few units in the last place.
+    fn ln_approx<const B: Word>(&self, x: &Repr<B>, one_plus: bool) -> FBig<R, B> {
         // A simple algorithm:
         // - let log(x) = log(x/2^s) + slog2 where s = floor(log2(x))
         // - such that x*2^s is close to but larger than 1 (and x*2^s < 2)
         let guard_digits = (self.precision.log2_est() / B.log2_est()) as usize + 2;
         let mut work_precision = self.precision + guard_digits + one_plus as usize;
-        let context = Context::<R>::new(work_precision);
+        let context = self.work_context(work_precision);
         let x = FBig::new(context.repr_round_ref(x).value(), context);
 
         // When one_plus is true and |x| < 1/B, the input is fed into the Maclaurin without scaling
@@ -251,16 +387,35 @@ impl<R: Round> Context<R> {
             work_precision += self.precision;
             x_scaled.context.precision = work_precision;
         };
-        let work_context = Context::new(work_precision);
+        let work_context = self.work_context(work_precision);
+        let ln_scaled = if !no_scaling && work_context.ln_use_agm(&x_scaled) {
+            work_context.ln_agm(&x_scaled)
+        } else {
+            work_context.ln_series(x_scaled, no_scaling)
+        };
 
+        // compose the logarithm of the original number
+        let result: FBig<R, B> = if no_scaling {
+            ln_scaled
+        } else {
+            // ln2 is evaluated with extra digits, so that the sum is not prematurely rounded
+            let ln2_context = self.work_context(work_precision + guard_digits);
+            ln_scaled + s * ln2_context.ln2().value()
+        };
+        self.round_fbig(result).value()
+    }
+
+    /// Evaluate `log(x)` for `1 <= x < 2` (or `log(1+x)` for `|x| < 1/B` if `one_plus` is true)
+    /// by the Maclaurin series under this context.
+    fn ln_series<const B: Word>(&self, x: FBig<R, B>, one_plus: bool) -> FBig<R, B> {
         // after the number is scaled to nearly one, use Maclaurin series on log(x) = 2atanh(z):
         // let z = (x-1)/(x+1) < 1, log(x) = 2atanh(z) = 2Σ(z²ⁱ⁺¹/(2i+1)) for i = 1,3,5,...
-        // similar to iacoth, the required iterations stop at i = -p/2log_B(z), and we need log_B(i) guard bits
-        let z = if no_scaling {
-            let d = &x_scaled + (FBig::ONE + FBig::ONE);
-            x_scaled / d
+        // similar to the series of acoth, the required iterations stop at i = -p/2log_B(z), and we need log_B(i) guard bits
+        let z = if one_plus {
+            let d = &x + (FBig::ONE + FBig::ONE);
+            x / d
         } else {
-            (&x_scaled - FBig::ONE) / (x_scaled + FBig::ONE)
+            (&x - FBig::ONE) / (x + FBig::ONE)
         };
         let z2 = z.square();
         let mut pow = z.clone();
@@ -269,23 +424,161 @@ impl<R: Round> Context<R> {
         let mut k: usize = 3;
         loop {
             pow *= &z2;
-            let next = &sum + &pow / work_context.convert_int::<B>(k.into()).value();
 
-            if next == sum {
+            // stop when the term is less than the last place of the sum (see exp_internal)
+            let term = &pow / self.convert_int::<B>(k.into()).value();
+            if term.repr.is_zero() || term.repr.top() < sum.repr.top() - self.precision as isize {
                 break;
             }
-            sum = next;
+            sum += term;
             k += 2;
         }
+        2 * sum
+    }
 
-        // compose the logarithm of the original number
-        let result: FBig<R, B> = if no_scaling {
-            2 * sum
+    /// Check whether the AGM is faster than the series to evaluate `log(x)` for `1 <= x < 2`
+    /// under this context.
+    fn ln_use_agm<const B: Word>(&self, x: &FBig<R, B>) -> bool {
+        let bits = (self.precision as f32 * B.log2_est()) as usize;
+        if bits < LN_AGM_THRESHOLD {
+            return false;
+        }
+
+        // the series takes about p/(-2·log₂(z)) iterations where z = (x-1)/(x+1), while
+        // the AGM takes about 2·log₂(p) iterations, each of which is more expensive
+        let x_m1 = x - FBig::ONE;
+        if x_m1.repr.is_zero() {
+            return false;
+        }
+        let log2_z = x_m1.log2_est() - 1.;
+        bits as f32 > -log2_z * 8. * bits.log2_est()
+    }
+
+    /// Evaluate `log(x)` for `1 < x < 2` with the arithmetic-geometric mean under this context.
+    ///
+    /// It's based on the approximation `log(s) ≈ π/(2·agm(1, 4/s))` whose error is about
+    /// `log(s)/s²`, where `s = x·2ᵐ` is large enough.
+    fn ln_agm<const B: Word>(&self, x: &FBig<R, B>) -> FBig<R, B> {
+        // log(x) is obtained by log(s) - m·log(2), which cancels about log₂(m) - log₂(log(x))
+        // bits, where log(x) > (x-1)/2. The AGM iterations also accumulate some errors.
+        let bits = (self.precision as f32 * B.log2_est()) as usize;
+        let x_m1 = x - FBig::ONE;
+        let cancelled = bits.log2_est() * 2. + 2. - x_m1.log2_bounds().0;
+        let guard_digits = (cancelled / B.log2_est()) as usize + 2;
+        let work_context = self.work_context(self.precision + guard_digits);
+
+        let m = (work_context.precision as f32 * B.log2_bounds().1) as usize / 2 + 2;
+        let x = FBig::new(work_context.repr_round_ref(&x.repr).value(), work_context);
+        let s = if B == 2 {
+            x << m as isize
         } else {
-            2 * sum + s * work_context.ln2()
+            x * (IBig::ONE << m)
         };
-        result.with_precision(self.precision)
+
+        let four = FBig::new(Repr::new(4.into(), 0), work_context);
+        let agm = work_context.agm_iterate(FBig::ONE, four / s, |_| {});
+        let ln_s = work_context.pi::<B>().value() / (agm * 2u8);
+        let ln = ln_s - work_context.ln2::<B>().value() * m;
+        self.round_fbig(ln).value()
+    }
+}
+
+/// Check that the input of a logarithm is finite and positive
+fn check_log_domain<const B: Word>(x: &Repr<B>) {
+    check_inf(x);
+    if x.is_zero() || x.sign() == Sign::Negative {
+        panic_out_of_domain()
+    }
+}
+
+/// The largest denominator of the exponent `k` that is tried by [exact_log]
+const EXACT_LOG_MAX_DENOMINATOR: usize = 1 << 12;
+
+/// Find the fraction `k = p/q` such that `x = baseᵏ` exactly, where both `x` and `base` are positive.
+fn exact_log<const B: Word>(x: &Repr<B>, base: &Repr<B>) -> Option<(isize, usize)> {
+    if x.is_one() {
+        return Some((0, 1));
+    }
+    if base.is_one() {
+        return None;
     }
+
+    // k = log(x)/log(base) lies in the interval given by the bounds of both logarithms
+    let (x_lb, x_ub) = x.log2_bounds();
+    let (b_lb, b_ub) = base.log2_bounds();
+    if (x_lb <= 0. && x_ub >= 0.) || (b_lb <= 0. && b_ub >= 0.) {
+        return None;
+    }
+    let negative = (x_lb < 0.) != (b_lb < 0.);
+    let (x_lb, x_ub) = (x_lb.abs().min(x_ub.abs()), x_lb.abs().max(x_ub.abs()));
+    let (b_lb, b_ub) = (b_lb.abs().min(b_ub.abs()), b_lb.abs().max(b_ub.abs()));
+    // the interval is widened a little to cover the rounding errors of f32
+    let lo = (x_lb / b_ub) as f64 * (1. - 1e-6);
+    let hi = (x_ub / b_lb) as f64 * (1. + 1e-6);
+
+    // find the first convergent of the continued fraction of |k| that lies in the interval
+    let mut k = (lo + hi) / 2.;
+    let mut a = k as usize;
+    let (mut p0, mut q0, mut p1, mut q1) = (1usize, 0usize, a, 1usize);
+    while (p1 as f64) < lo * q1 as f64 || (p1 as f64) > hi * q1 as f64 {
+        let fract = k - a as f64;
+        if fract <= 0. {
+            return None;
+        }
+        k = 1. / fract;
+        a = k as usize;
+        let p2 = a.checked_mul(p1)?.checked_add(p0)?;
+        let q2 = a.checked_mul(q1)?.checked_add(q0)?;
+        if q2 > EXACT_LOG_MAX_DENOMINATOR {
+            return None;
+        }
+        (p0, q0, p1, q1) = (p1, q1, p2, q2);
+    }
+    let p: isize = p1.try_into().ok()?;
+    let (p, q) = (if negative { -p } else { p }, q1);
+    if p == 0 {
+        return None;
+    }
+
+    // verify x^q = base^p with exact arithmetic, if the powers are not too large
+    let x_bits = x.significand.log2_est() as usize;
+    let b_bits = base.significand.log2_est() as usize;
+    let max_bits = (x_bits + b_bits + 1) * 64 + EXACT_LOG_MAX_DENOMINATOR;
+    if q.saturating_mul(x_bits) > max_bits || p.unsigned_abs().saturating_mul(b_bits) > max_bits {
+        return None;
+    }
+    let exact = Context::<mode::Zero>::new(0);
+    let x_pow = exact.powi(x, q.into()).value();
+    let b_pow = exact.powi(base, p.unsigned_abs().into()).value();
+    let verified = if p > 0 {
+        x_pow.repr == b_pow.repr
+    } else {
+        exact.mul(&x_pow.repr, &b_pow.repr).value().repr.is_one()
+    };
+    verified.then(|| (p, q))
+}
+
+/// Get the exact representation of `bᵏ` in base `B`, or [None] if it's not representable
+/// with at most `max_digits` digits.
+pub(crate) fn exact_pow<const B: Word>(b: Word, k: isize, max_digits: usize) -> Option<Repr<B>> {
+    if b == B {
+        return Some(Repr::new(IBig::ONE, k));
+    }
+
+    // bᵏ has about k·log_B(b) digits, and b⁻ᵏ = (B/b)ᵏ / Bᵏ has about k·log_B(B/b) digits
+    let base = if k >= 0 {
+        b
+    } else if B % b == 0 {
+        B / b
+    } else {
+        return None;
+    };
+    let digits = k.unsigned_abs() as f32 * base.log2_est() / B.log2_est();
+    if digits > max_digits as f32 + 1. {
+        return None;
+    }
+    let pow = IBig::from(base).pow(k.unsigned_abs());
+    Some(Repr::new(pow, k.min(0)))
 }
 
 #[cfg(test)]
@@ -293,55 +586,28 @@ mod tests {
     use super::*;
     use crate::round::mode;
 
-    #[test]
-    fn test_iacoth() {
-        let context = Context::<mode::Zero>::new(10);
-        let binary_6 = context.iacoth::<2>(6.into()).with_precision(10).value();
-        assert_eq!(binary_6.repr.significand, 689);
-        let decimal_6 = context.iacoth::<10>(6.into()).with_precision(10).value();
-        assert_eq!(decimal_6.repr.significand, 1682361183);
-
-        let context = Context::<mode::Zero>::new(40);
-        let decimal_6 = context.iacoth::<10>(6.into()).with_precision(40).value();
-        assert_eq!(
-            decimal_6.repr.significand,
-            IBig::from_str_radix("1682361183106064652522967051084960450557", 10).unwrap()
-        );
-
-        let context = Context::<mode::Zero>::new(201);
-        let binary_6 = context.iacoth::<2>(6.into()).with_precision(201).value();
-        assert_eq!(
-            binary_6.repr.significand,
-            IBig::from_str_radix(
-                "2162760151454160450909229890833066944953539957685348083415205",
-                10
-            )
-            .unwrap()
-        );
-    }
-
     #[test]
     fn test_ln2_ln10() {
         let context = Context::<mode::Zero>::new(45);
-        let decimal_ln2 = context.ln2::<10>().with_precision(45).value();
+        let decimal_ln2 = context.ln2::<10>().value();
         assert_eq!(
             decimal_ln2.repr.significand,
             IBig::from_str_radix("693147180559945309417232121458176568075500134", 10).unwrap()
         );
-        let decimal_ln10 = context.ln10::<10>().with_precision(45).value();
+        let decimal_ln10 = context.ln10::<10>().value();
         assert_eq!(
             decimal_ln10.repr.significand,
             IBig::from_str_radix("230258509299404568401799145468436420760110148", 10).unwrap()
         );
 
         let context = Context::<mode::Zero>::new(180);
-        let binary_ln2 = context.ln2::<2>().with_precision(180).value();
+        let binary_ln2 = context.ln2::<2>().value();
         assert_eq!(
             binary_ln2.repr.significand,
             IBig::from_str_radix("1062244963371879310175186301324412638028404515790072203", 10)
                 .unwrap()
         );
-        let binary_ln10 = context.ln10::<2>().with_precision(180).value();
+        let binary_ln10 = context.ln10::<2>().value();
         assert_eq!(
             binary_ln10.repr.significand,
             IBig::from_str_radix("882175346869410758689845931257775553286341791676474847", 10)
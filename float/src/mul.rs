@@ -1,22 +1,25 @@
 use dashu_int::{IBig, UBig};
 
 use crate::{
-    error::{check_inf, check_inf_operands},
+    cmp::repr_cmp,
     fbig::FBig,
     helper_macros,
     repr::{Context, Repr, Word},
-    round::{Round, Rounded},
+    round::{Mode, Round, Rounded},
+    utils::split_digits_ref,
 };
 use core::ops::{Mul, MulAssign};
+use dashu_base::Approximation::{Exact, Inexact};
 
 impl<'l, 'r, R: Round, const B: Word> Mul<&'r FBig<R, B>> for &'l FBig<R, B> {
     type Output = FBig<R, B>;
 
     #[inline]
     fn mul(self, rhs: &FBig<R, B>) -> Self::Output {
-        check_inf_operands(&self.repr, &rhs.repr);
-
         let context = Context::max(self.context, rhs.context);
+        if let Some(special) = context.ieee_mul(&self.repr, &rhs.repr) {
+            return FBig::new(special, context);
+        }
         let repr = Repr::new(
             &self.repr.significand * &rhs.repr.significand,
             self.repr.exponent + rhs.repr.exponent,
@@ -30,9 +33,10 @@ impl<'r, R: Round, const B: Word> Mul<&'r FBig<R, B>> for FBig<R, B> {
 
     #[inline]
     fn mul(self, rhs: &FBig<R, B>) -> Self::Output {
-        check_inf_operands(&self.repr, &rhs.repr);
-
         let context = Context::max(self.context, rhs.context);
+        if let Some(special) = context.ieee_mul(&self.repr, &rhs.repr) {
+            return FBig::new(special, context);
+        }
         let repr = Repr::new(
             self.repr.significand * &rhs.repr.significand,
             self.repr.exponent + rhs.repr.exponent,
@@ -46,9 +50,10 @@ impl<'l, R: Round, const B: Word> Mul<FBig<R, B>> for &'l FBig<R, B> {
 
     #[inline]
     fn mul(self, rhs: FBig<R, B>) -> Self::Output {
-        check_inf_operands(&self.repr, &rhs.repr);
-
         let context = Context::max(self.context, rhs.context);
+        if let Some(special) = context.ieee_mul(&self.repr, &rhs.repr) {
+            return FBig::new(special, context);
+        }
         let repr = Repr::new(
             &self.repr.significand * rhs.repr.significand,
             self.repr.exponent + rhs.repr.exponent,
@@ -62,9 +67,10 @@ impl<R: Round, const B: Word> Mul<FBig<R, B>> for FBig<R, B> {
 
     #[inline]
     fn mul(self, rhs: FBig<R, B>) -> Self::Output {
-        check_inf_operands(&self.repr, &rhs.repr);
-
         let context = Context::max(self.context, rhs.context);
+        if let Some(special) = context.ieee_mul(&self.repr, &rhs.repr) {
+            return FBig::new(special, context);
+        }
         let repr = Repr::new(
             self.repr.significand * rhs.repr.significand,
             self.repr.exponent + rhs.repr.exponent,
@@ -77,8 +83,8 @@ helper_macros::impl_binop_assign_by_taking!(impl MulAssign<Self>, mul_assign, mu
 
 macro_rules! impl_add_sub_primitive_with_fbig {
     ($($t:ty)*) => {$(
-        helper_macros::impl_commutative_binop_with_primitive!(impl Mul<$t>, mul);
-        helper_macros::impl_binop_assign_with_primitive!(impl MulAssign<$t>, mul_assign);
+        helper_macros::impl_commutative_binop_with_primitive!(impl Mul<$t>, mul, from);
+        helper_macros::impl_binop_assign_with_primitive!(impl MulAssign<$t>, mul_assign, from);
     )*};
 }
 impl_add_sub_primitive_with_fbig!(u8 u16 u32 u64 u128 usize UBig i8 i16 i32 i64 i128 isize IBig);
@@ -111,36 +117,16 @@ impl<R: Round> Context<R> {
     /// # Ok::<(), ParseError>(())
     /// ```
     pub fn mul<const B: Word>(&self, lhs: &Repr<B>, rhs: &Repr<B>) -> Rounded<FBig<R, B>> {
-        check_inf_operands(lhs, rhs);
-
-        // at most double the precision is required to get a correct result
-        // shrink the input operands if necessary
-        let max_precision = if self.is_limited() {
-            self.precision * 2
-        } else {
-            usize::MAX
-        };
+        if let Some(special) = self.ieee_mul(lhs, rhs) {
+            return Exact(FBig::new(special, *self));
+        }
 
-        let lhs_shrink;
-        let lhs_repr = if lhs.digits() > max_precision {
-            lhs_shrink = Context::<R>::new(max_precision).repr_round_ref(lhs).value();
-            &lhs_shrink
-        } else {
-            lhs
-        };
-
-        let rhs_shrink;
-        let rhs_repr = if rhs.digits() > max_precision {
-            rhs_shrink = Context::<R>::new(max_precision).repr_round_ref(rhs).value();
-            &rhs_shrink
-        } else {
-            rhs
-        };
+        // shrink the input operands if they are much longer than the precision
+        if let Some(rounded) = self.repr_mul_truncated(lhs, rhs) {
+            return rounded.map(|v| FBig::new(v, *self));
+        }
 
-        let repr = Repr::new(
-            &lhs_repr.significand * &rhs_repr.significand,
-            lhs_repr.exponent + rhs_repr.exponent,
-        );
+        let repr = Repr::new(&lhs.significand * &rhs.significand, lhs.exponent + rhs.exponent);
         self.repr_round(repr).map(|v| FBig::new(v, *self))
     }
 
@@ -160,24 +146,75 @@ impl<R: Round> Context<R> {
     /// # Ok::<(), ParseError>(())
     /// ```
     pub fn square<const B: Word>(&self, f: &Repr<B>) -> Rounded<FBig<R, B>> {
-        check_inf(f);
+        if let Some(special) = self.ieee_mul(f, f) {
+            return Exact(FBig::new(special, *self));
+        }
 
-        // shrink the input operands if necessary
-        let max_precision = if self.is_limited() {
-            self.precision * 2
-        } else {
-            usize::MAX
-        };
+        // shrink the input operand if it's much longer than the precision
+        if let Some(rounded) = self.repr_mul_truncated(f, f) {
+            return rounded.map(|v| FBig::new(v, *self));
+        }
 
-        let f_shrink;
-        let f_repr = if f.digits() > max_precision {
-            f_shrink = Context::<R>::new(max_precision).repr_round_ref(f).value();
-            &f_shrink
-        } else {
-            f
-        };
-
-        let repr = Repr::new(f_repr.significand.square(), 2 * f_repr.exponent);
+        let repr = Repr::new(f.significand.square(), 2 * f.exponent);
         self.repr_round(repr).map(|v| FBig::new(v, *self))
     }
+
+    /// Multiply the operands truncated to `2 * precision + 2` digits, which is much faster when an
+    /// operand has a lot more digits than that.
+    ///
+    /// The truncated operands only give a lower and an upper bound of the product (rounding
+    /// the operands directly could lead to a double rounding), so the result is returned only if
+    /// both bounds are rounded to the same value, and the value is not between the bounds.
+    /// Otherwise [None] is returned and the full product should be used.
+    fn repr_mul_truncated<const B: Word>(
+        &self,
+        lhs: &Repr<B>,
+        rhs: &Repr<B>,
+    ) -> Option<Rounded<Repr<B>>> {
+        use Mode::*;
+
+        // the bounds are only comparable under the deterministic rounding modes, and the
+        // tininess of the result is not decided by the bounds when the exponent is bounded
+        let deterministic = matches!(
+            self.rounding_mode(),
+            Some(Zero | Away | Up | Down | HalfEven | HalfAway | ToOdd)
+        );
+        if !self.is_limited() || self.is_bounded() || !deterministic {
+            return None;
+        }
+
+        let max_digits = 2 * self.precision + 2;
+        let (ldigits, rdigits) = (lhs.digits(), rhs.digits());
+        if ldigits <= max_digits && rdigits <= max_digits {
+            return None;
+        }
+
+        // truncate the operand toward zero, and return the one unit in the last place of
+        // the truncated operand (zero if it's not truncated) along with it
+        let truncate = |repr: &Repr<B>, digits: usize| {
+            if digits > max_digits {
+                let shift = digits - max_digits;
+                let (signif, _) = split_digits_ref::<B>(&repr.significand, shift);
+                let ulp = signif.signum();
+                (signif, repr.exponent + shift as isize, ulp)
+            } else {
+                (repr.significand.clone(), repr.exponent, IBig::ZERO)
+            }
+        };
+        let (lsignif, lexp, lulp) = truncate(lhs, ldigits);
+        let (rsignif, rexp, rulp) = truncate(rhs, rdigits);
+        let upper = Repr::new((&lsignif + lulp) * (&rsignif + rulp), lexp + rexp);
+        let lower = Repr::new(lsignif * rsignif, lexp + rexp);
+
+        // the bounds are rounded without raising the flags, since they are not the result
+        let probe = self.with_flags(false);
+        match (probe.repr_round_ref(&lower), probe.repr_round_ref(&upper)) {
+            (Inexact(lo, _), Inexact(hi, _))
+                if lo == hi && repr_cmp(&lo, &lower, None) == repr_cmp(&lo, &upper, None) =>
+            {
+                Some(self.repr_round(lower))
+            }
+            _ => None,
+        }
+    }
 }
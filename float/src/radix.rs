@@ -0,0 +1,372 @@
+//! Implementation of the float number with the base decided at runtime, [DynFBig]
+
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::{
+    error::panic_unsupported_base,
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{
+        mode::{HalfAway, ToOdd},
+        Round, Rounded,
+    },
+};
+use dashu_base::EstimatedLog2;
+use dashu_int::error::ParseError;
+
+/// An arbitrary precision float number whose base is decided at runtime.
+///
+/// The base of [FBig] is fixed by its const generic parameter, so numbers with different bases
+/// have different types. [DynFBig] stores a [FBig] with one of the bases in [DynFBig::BASES],
+/// so that the numbers in different bases can be stored together, or the base can be chosen
+/// from the user input.
+///
+/// The precision of a [DynFBig] is counted in digits of its own base. Changing the base (with
+/// [with_radix()][DynFBig::with_radix], or with the binary operations) is done by
+/// [FBig::with_base], so the precision is adjusted and the result is rounded in the same way.
+///
+/// # Binary operations
+///
+/// If the operands of a binary operation have different bases, the right operand is first
+/// converted to the base of the left operand, and then the operation is done in that base.
+/// The precision of the right operand is converted with enough digits to keep it. The value is
+/// converted exactly if possible (e.g. from base 2 to base 10), otherwise it's converted with
+/// extra digits, so that the result is rounded only once in practice.
+///
+/// # Panics
+///
+/// The binary operations panic if both operands have unlimited precision, and the right operand
+/// cannot be converted to the base of the left operand losslessly.
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// use dashu_float::DynFBig;
+///
+/// let a = DynFBig::<dashu_float::round::mode::HalfEven>::from_str_with_base("0.1", 10)?;
+/// let b = DynFBig::from_str_with_base("0.1", 2)?; // 0.5
+/// assert_eq!(a.base(), 10);
+/// assert_eq!(b.base(), 2);
+///
+/// let c = &a + &b;
+/// assert_eq!(c.base(), 10);
+/// assert_eq!(c.to_string(), "0.6");
+///
+/// // the base can be changed at runtime as well
+/// let d = b.with_radix(16).value();
+/// assert_eq!(d.to_string(), "0.8");
+/// # Ok::<(), ParseError>(())
+/// ```
+pub struct DynFBig<R: Round = HalfAway>(Inner<R>);
+
+enum Inner<R: Round> {
+    Base2(FBig<R, 2>),
+    Base8(FBig<R, 8>),
+    Base10(FBig<R, 10>),
+    Base16(FBig<R, 16>),
+}
+
+/// Evaluate the expression with `$x` bound to the [FBig] stored in the number
+macro_rules! with_fbig {
+    ($value:expr, $x:ident => $body:expr) => {
+        match $value {
+            Inner::Base2($x) => $body,
+            Inner::Base8($x) => $body,
+            Inner::Base10($x) => $body,
+            Inner::Base16($x) => $body,
+        }
+    };
+}
+
+/// Evaluate the expression with the const `$B` bound to the given base at runtime
+macro_rules! with_base {
+    ($base:expr, const $B:ident => $body:expr) => {
+        match $base {
+            2 => {
+                const $B: Word = 2;
+                $body
+            }
+            8 => {
+                const $B: Word = 8;
+                $body
+            }
+            10 => {
+                const $B: Word = 10;
+                $body
+            }
+            16 => {
+                const $B: Word = 16;
+                $body
+            }
+            _ => panic_unsupported_base(),
+        }
+    };
+}
+
+impl<R: Round> DynFBig<R> {
+    /// The bases supported by [DynFBig]
+    pub const BASES: [Word; 4] = [2, 8, 10, 16];
+
+    /// Parse a number in the given base, with the same format as [FBig::from_str_native].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::DynFBig;
+    ///
+    /// let a: DynFBig = DynFBig::from_str_with_base("1.2a", 16)?;
+    /// assert_eq!(a.base(), 16);
+    /// assert_eq!(a.precision(), 3);
+    /// assert_eq!(
+    ///     DynFBig::<dashu_float::round::mode::Zero>::from_str_with_base("1.2", 7),
+    ///     Err(ParseError::UnsupportedRadix)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_str_with_base(src: &str, base: Word) -> Result<Self, ParseError> {
+        if !Self::BASES.contains(&base) {
+            return Err(ParseError::UnsupportedRadix);
+        }
+        with_base!(base, const B => FBig::<R, B>::from_str_native(src).map(Self::from))
+    }
+
+    /// Get the base of the number
+    #[inline]
+    pub const fn base(&self) -> Word {
+        match self.0 {
+            Inner::Base2(_) => 2,
+            Inner::Base8(_) => 8,
+            Inner::Base10(_) => 10,
+            Inner::Base16(_) => 16,
+        }
+    }
+
+    /// Get the maximum precision set for the number (in digits of its base).
+    #[inline]
+    pub fn precision(&self) -> usize {
+        with_fbig!(&self.0, x => x.precision())
+    }
+
+    /// Get the context associated with the number
+    #[inline]
+    pub fn context(&self) -> Context<R> {
+        with_fbig!(&self.0, x => x.context())
+    }
+
+    /// Convert the number to a [FBig] with the given base.
+    ///
+    /// See [FBig::with_base] for how the precision is adjusted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number has unlimited precision and the conversion cannot be performed
+    /// losslessly.
+    #[inline]
+    #[allow(non_upper_case_globals)]
+    pub fn with_base<const NewB: Word>(self) -> Rounded<FBig<R, NewB>> {
+        with_fbig!(self.0, x => x.with_base::<NewB>())
+    }
+
+    /// Convert the number to a [FBig] with the given base and precision (under the new base).
+    ///
+    /// See [FBig::with_base_and_precision] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number has unlimited precision and the conversion cannot be performed
+    /// losslessly.
+    #[inline]
+    #[allow(non_upper_case_globals)]
+    pub fn with_base_and_precision<const NewB: Word>(
+        self,
+        precision: usize,
+    ) -> Rounded<FBig<R, NewB>> {
+        with_fbig!(self.0, x => x.with_base_and_precision::<NewB>(precision))
+    }
+
+    /// Change the base of the number at runtime.
+    ///
+    /// See [FBig::with_base] for how the precision is adjusted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{DynFBig, round::Rounding::*};
+    ///
+    /// let a: DynFBig = DynFBig::from_str_with_base("0.0011", 2)?;
+    /// assert_eq!(a.clone().with_radix(16), Exact(DynFBig::from_str_with_base("0.3", 16)?));
+    /// let b = a.with_radix(10);
+    /// assert_eq!(b, Inexact(DynFBig::from_str_with_base("0.2", 10)?, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base is not in [DynFBig::BASES], or the number has unlimited precision
+    /// and the conversion cannot be performed losslessly.
+    #[inline]
+    pub fn with_radix(self, base: Word) -> Rounded<Self> {
+        with_base!(base, const B => self.with_base::<B>().map(Self::from))
+    }
+
+    /// Change the base of the number at runtime, with the given precision (under the new base).
+    ///
+    /// See [FBig::with_base_and_precision] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base is not in [DynFBig::BASES], or the number has unlimited precision
+    /// and the conversion cannot be performed losslessly.
+    #[inline]
+    pub fn with_radix_and_precision(self, base: Word, precision: usize) -> Rounded<Self> {
+        with_base!(base, const B => self.with_base_and_precision::<B>(precision).map(Self::from))
+    }
+
+    /// Convert the number to the base `B` as the right operand of a binary operation, where
+    /// `context` is the context of the left operand. It returns the converted number and the
+    /// context of the operation.
+    ///
+    /// Different from [with_base()][Self::with_base], the precision in the new base is rounded
+    /// up, so that the operand is not limited to fewer digits than it has. The number itself is
+    /// converted exactly if it's representable with `2p + 2` digits in the base `B`, where `p`
+    /// is the precision of the operation. Otherwise it's rounded to odd with `2p + 2` digits,
+    /// so that the rounding of the conversion is sticky for the rounding of the result.
+    fn into_operand<const B: Word>(self, context: Context<R>) -> (Repr<B>, Context<R>) {
+        let (base, precision) = (self.base(), self.precision());
+        if base == B {
+            let operand = self.with_base::<B>().value();
+            return (operand.repr, Context::max(context, operand.context));
+        }
+
+        let precision = if precision == 0 {
+            0
+        } else {
+            let bits = precision as f32 * base.log2_bounds().1;
+            (bits / B.log2_bounds().0) as usize + 1
+        };
+        let context = Context::max(context, self.context().with_precision(precision));
+        if context.precision == 0 {
+            // both operands have unlimited precision
+            return (self.with_base::<B>().value().repr, context);
+        }
+
+        let digits = 2 * context.precision + 2;
+        let operand = with_fbig!(self.0, x => {
+            x.with_rounding::<ToOdd>().with_base_and_precision::<B>(digits)
+        });
+        (operand.value().repr, context)
+    }
+}
+
+macro_rules! impl_from_fbig {
+    ($($base:literal => $variant:ident),*) => {$(
+        impl<R: Round> From<FBig<R, $base>> for DynFBig<R> {
+            #[inline]
+            fn from(value: FBig<R, $base>) -> Self {
+                Self(Inner::$variant(value))
+            }
+        }
+    )*};
+}
+impl_from_fbig!(2 => Base2, 8 => Base8, 10 => Base10, 16 => Base16);
+
+macro_rules! impl_dyn_binop {
+    (impl $trait:ident, $method:ident) => {
+        impl<R: Round> $trait<DynFBig<R>> for DynFBig<R> {
+            type Output = DynFBig<R>;
+            #[inline]
+            fn $method(self, rhs: DynFBig<R>) -> Self::Output {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<'r, R: Round> $trait<&'r DynFBig<R>> for DynFBig<R> {
+            type Output = DynFBig<R>;
+            #[inline]
+            fn $method(self, rhs: &DynFBig<R>) -> Self::Output {
+                (&self).$method(rhs.clone())
+            }
+        }
+
+        impl<'l, R: Round> $trait<DynFBig<R>> for &'l DynFBig<R> {
+            type Output = DynFBig<R>;
+            fn $method(self, rhs: DynFBig<R>) -> Self::Output {
+                with_fbig!(&self.0, x => {
+                    let (y, context) = rhs.into_operand(x.context());
+                    context.$method(x.repr(), &y).value().into()
+                })
+            }
+        }
+
+        impl<'l, 'r, R: Round> $trait<&'r DynFBig<R>> for &'l DynFBig<R> {
+            type Output = DynFBig<R>;
+            #[inline]
+            fn $method(self, rhs: &DynFBig<R>) -> Self::Output {
+                self.$method(rhs.clone())
+            }
+        }
+    };
+}
+impl_dyn_binop!(impl Add, add);
+impl_dyn_binop!(impl Sub, sub);
+impl_dyn_binop!(impl Mul, mul);
+impl_dyn_binop!(impl Div, div);
+
+impl<R: Round> Neg for DynFBig<R> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        with_fbig!(self.0, x => Self::from(-x))
+    }
+}
+
+impl<R: Round> Neg for &DynFBig<R> {
+    type Output = DynFBig<R>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.clone().neg()
+    }
+}
+
+impl<R: Round> Clone for DynFBig<R> {
+    #[inline]
+    fn clone(&self) -> Self {
+        with_fbig!(&self.0, x => Self::from(x.clone()))
+    }
+}
+
+impl<R: Round> PartialEq for DynFBig<R> {
+    /// Two numbers are equal only if they have the same base and the same value. Numbers with
+    /// different bases are never equal, they should be converted to the same base to be compared.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Inner::Base2(x), Inner::Base2(y)) => x == y,
+            (Inner::Base8(x), Inner::Base8(y)) => x == y,
+            (Inner::Base10(x), Inner::Base10(y)) => x == y,
+            (Inner::Base16(x), Inner::Base16(y)) => x == y,
+            _ => false,
+        }
+    }
+}
+
+impl<R: Round> Display for DynFBig<R> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        with_fbig!(&self.0, x => Display::fmt(x, f))
+    }
+}
+
+impl<R: Round> fmt::Debug for DynFBig<R> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        with_fbig!(&self.0, x => fmt::Debug::fmt(x, f))
+    }
+}
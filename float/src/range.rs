@@ -0,0 +1,104 @@
+//! Handling of the bounded exponent range (overflow and underflow).
+//!
+//! The rounding primitives ([Context::repr_round], etc.) use [Context::min_exponent] to decide
+//! the position of rounding for the tiny results, and then [Context::repr_check_range] to
+//! handle the overflow and the underflow to zero of the rounded result. The exception flags
+//! of the rounding are also raised in [Context::repr_check_range].
+
+use core::cmp::Ordering;
+
+use crate::{
+    flags::Flags,
+    repr::{Context, Repr, Word},
+    round::{Round, Rounded, Rounding},
+};
+use dashu_base::{Approximation::*, Sign};
+use dashu_int::IBig;
+
+impl<R: Round> Context<R> {
+    /// Whether an overflowing result with the given sign is rounded to the infinity (instead of
+    /// the largest finite number). This is the case for the modes rounding to the nearest and
+    /// the directed modes rounding away from zero.
+    fn overflows_to_infinity(&self, sign: Sign) -> bool {
+        // the significand of the largest finite number is odd (for an even base), so the
+        // integer in the test is an odd number with the same sign as the result
+        let integer = match sign {
+            Sign::Positive => IBig::ONE,
+            Sign::Negative => IBig::NEG_ONE,
+        };
+        self.round_low_part(&integer, sign, || Ordering::Greater) != Rounding::NoOp
+    }
+
+    /// Check whether the exponent range is bounded and the precision is limited
+    #[inline]
+    pub(crate) const fn is_bounded(&self) -> bool {
+        self.precision != 0 && (self.emin != isize::MIN || self.emax != isize::MAX)
+    }
+
+    /// Get the minimum exponent of the last digit for a number whose leading digit has the
+    /// exponent `lead`. Returns [None] if the number is normal or the range is not bounded.
+    #[inline]
+    pub(crate) fn min_exponent(&self, lead: isize) -> Option<isize> {
+        if !self.is_bounded() || lead >= self.emin {
+            None
+        } else if self.subnormal {
+            Some(self.emin - (self.precision as isize - 1))
+        } else {
+            Some(self.emin)
+        }
+    }
+
+    /// Handle the overflow of a rounded result, fix the sign of the zero if the result
+    /// underflows, and raise the exception flags. `sign` is the sign of the exact result.
+    pub(crate) fn repr_check_range<const B: Word>(
+        &self,
+        value: Rounded<Repr<B>>,
+        sign: Sign,
+    ) -> Rounded<Repr<B>> {
+        let mut flags = Flags::EMPTY;
+        let value = if self.is_bounded() {
+            self.repr_limit(value, sign, &mut flags)
+        } else {
+            value
+        };
+        if let Inexact(..) = value {
+            flags |= Flags::INEXACT;
+        }
+        self.raise_flags(flags);
+        value
+    }
+
+    /// Limit the rounded result in the exponent range, and collect the range related flags.
+    fn repr_limit<const B: Word>(
+        &self,
+        value: Rounded<Repr<B>>,
+        sign: Sign,
+        flags: &mut Flags,
+    ) -> Rounded<Repr<B>> {
+        let repr = value.value_ref();
+        if repr.is_zero() || repr.top() - 1 < self.emin {
+            if let Inexact(..) = value {
+                *flags |= Flags::UNDERFLOW;
+            }
+            if repr.is_zero() && self.ieee && sign == Sign::Negative {
+                return value.map(|_| Repr::neg_zero());
+            }
+            return value;
+        }
+        if repr.top() - 1 <= self.emax {
+            return value;
+        }
+
+        *flags |= Flags::OVERFLOW;
+        if self.overflows_to_infinity(sign) {
+            match sign {
+                Sign::Positive => Inexact(Repr::infinity(), Rounding::AddOne),
+                Sign::Negative => Inexact(Repr::neg_infinity(), Rounding::SubOne),
+            }
+        } else {
+            let max = Repr::<B>::BASE.pow(self.precision) - IBig::ONE;
+            let exponent = self.emax - (self.precision as isize - 1);
+            Inexact(Repr::new(sign * max, exponent), Rounding::NoOp)
+        }
+    }
+}
@@ -1,9 +1,9 @@
 use crate::{
-    error::panic_operate_with_inf,
-    round::{Round, Rounded},
+    error::{panic_different_rounding, panic_operate_with_inf},
+    round::{mode::Dynamic, Mode, Round, Rounded},
     utils::{base_as_ibig, digit_len, split_digits, split_digits_ref},
 };
-use core::marker::PhantomData;
+use core::{cmp::Ordering, marker::PhantomData};
 use dashu_base::{Approximation::*, EstimatedLog2, Sign};
 pub use dashu_int::Word;
 use dashu_int::{IBig, UBig};
@@ -26,12 +26,20 @@ use dashu_int::{IBig, UBig};
 /// Any other operations on the infinity will lead to panic. If an operation result is too large
 /// or too small, the operation will **panic** instead of returning an infinity.
 ///
-#[derive(PartialEq, Eq)]
+/// # NaN and negative zero
+///
+/// The NaN and the negative zero can also be represented, but they are only produced by the
+/// operations under a context with the [IEEE mode][Context::with_ieee] enabled. Outside of the
+/// IEEE mode, the negative zero behaves the same as the zero, and operations on the NaN will
+/// lead to panic.
+///
 pub struct Repr<const BASE: Word> {
     /// The significand of the floating point number. If the significand is zero, then the number is:
     /// - Zero, if exponent = 0
-    /// - Positive infinity, if exponent > 0
-    /// - Negative infinity, if exponent < 0
+    /// - Positive infinity, if exponent = 1
+    /// - Negative infinity, if exponent = -1
+    /// - NaN, if exponent = 2
+    /// - Negative zero, if exponent = -2
     pub(crate) significand: IBig,
 
     /// The exponent of the floating point number.
@@ -67,11 +75,68 @@ pub struct Repr<const BASE: Word> {
 ///
 /// For binary operations, the two oprands must have the same rounding mode.
 ///
+/// The rounding mode can also be decided at runtime with the [Dynamic] mode, where the actual
+/// rounding mode is stored in the context (see [with_rounding_mode()][Context::with_rounding_mode]).
+///
+/// # IEEE Mode
+///
+/// By default, operations that have no meaningful numeric result (such as `inf - inf` or `0 / 0`)
+/// will panic, and the sign of zero is not tracked. If the IEEE mode is enabled
+/// (see [with_ieee()][Context::with_ieee]), these operations follow the IEEE 754 semantics instead:
+/// invalid operations produce a NaN, dividing a nonzero number by zero produces an infinity,
+/// and the sign of zero is preserved through the basic arithmetic operations
+/// (`+`, `-`, `*`, `/`, and the roots). Besides, the result of addition and subtraction
+/// is always correctly rounded to the precision, while by default it could keep one more digit
+/// when cancellation happens.
+///
+/// For binary operations, the IEEE mode is enabled if it's enabled for any of the operands.
+///
+/// # Exponent Range
+///
+/// By default, the exponent of a float number is unbounded. A context can carry a bounded exponent
+/// range (see [with_exponent_range()][Context::with_exponent_range]) to emulate the fixed-size
+/// formats such as the IEEE 754 binary32 or decimal64. The range `emin..=emax` limits the exponent
+/// of the leading digit, that is a number `x` is normal if `B^emin <= |x| < B^(emax+1)`. Whenever
+/// a result is rounded under a context with bounded exponent range (and limited precision):
+/// - If the leading exponent of the rounded result is larger than `emax`, the result overflows.
+///   It's rounded to the infinity or the largest finite number based on the rounding mode and the sign.
+/// - If the leading exponent is smaller than `emin` and the subnormal numbers are enabled (which is
+///   the default, see [with_subnormals()][Context::with_subnormals]), the result is rounded to
+///   a multiple of `B^(emin - precision + 1)`, so that it can lose precision gradually.
+/// - If the leading exponent is smaller than `emin` and the subnormal numbers are disabled,
+///   the result is rounded to a multiple of `B^emin`, that is either zero or `±B^emin`.
+///
+/// The result underflowing to zero is negative in the IEEE mode if the exact result is negative.
+/// Similar to the IEEE mode, the result of addition and subtraction is always correctly rounded
+/// to the precision under a bounded exponent range.
+///
+/// For binary operations, the intersection of the exponent ranges of the operands is used, and the
+/// subnormal numbers are enabled only if they are enabled for both operands.
+///
+/// # Exception Flags
+///
+/// If the flag tracking is enabled (see [with_flags()][Context::with_flags]), the operations
+/// under the context will raise the sticky exception flags (such as the inexact and overflow flags).
+/// See [the flags module][crate::flags] for details. For binary operations, the flags are
+/// tracked if the tracking is enabled for any of the operands.
+///
 #[derive(Clone, Copy)]
 pub struct Context<RoundingMode: Round> {
     /// The precision of the floating point number.
     /// If set to zero, then the precision is unlimited.
     pub(crate) precision: usize,
+    /// Whether the IEEE 754 semantics is enabled for the special values.
+    pub(crate) ieee: bool,
+    /// The minimum exponent of the leading digit for the normal numbers.
+    pub(crate) emin: isize,
+    /// The maximum exponent of the leading digit for the finite numbers.
+    pub(crate) emax: isize,
+    /// Whether the gradual underflow (subnormal numbers) is enabled.
+    pub(crate) subnormal: bool,
+    /// Whether the exception flags are raised by the operations.
+    pub(crate) flags: bool,
+    /// The rounding mode decided at runtime. It's only set for the [Dynamic] rounding mode.
+    pub(crate) mode: Option<Mode>,
     _marker: PhantomData<RoundingMode>,
 }
 
@@ -120,18 +185,64 @@ impl<const B: Word> Repr<B> {
         }
     }
 
-    /// Determine if the [Repr] represents zero
+    /// Create a [Repr] instance representing the negative zero
+    #[inline]
+    pub const fn neg_zero() -> Self {
+        Self {
+            significand: IBig::ZERO,
+            exponent: -2,
+        }
+    }
+    /// Create a [Repr] instance representing the NaN (not a number)
+    #[inline]
+    pub const fn nan() -> Self {
+        Self {
+            significand: IBig::ZERO,
+            exponent: 2,
+        }
+    }
+
+    /// Determine if the [Repr] represents zero (either positive or negative)
     ///
     /// # Examples
     ///
     /// ```
     /// # use dashu_float::Repr;
     /// assert!(Repr::<2>::zero().is_zero());
+    /// assert!(Repr::<2>::neg_zero().is_zero());
     /// assert!(!Repr::<10>::one().is_zero());
     /// ```
     #[inline]
     pub const fn is_zero(&self) -> bool {
-        self.significand.is_zero() && self.exponent == 0
+        self.significand.is_zero() && (self.exponent == 0 || self.exponent == -2)
+    }
+
+    /// Determine if the [Repr] represents the negative zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_float::Repr;
+    /// assert!(Repr::<2>::neg_zero().is_neg_zero());
+    /// assert!(!Repr::<2>::zero().is_neg_zero());
+    /// ```
+    #[inline]
+    pub const fn is_neg_zero(&self) -> bool {
+        self.significand.is_zero() && self.exponent == -2
+    }
+
+    /// Determine if the [Repr] represents the NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_float::Repr;
+    /// assert!(Repr::<2>::nan().is_nan());
+    /// assert!(!Repr::<2>::infinity().is_nan());
+    /// ```
+    #[inline]
+    pub const fn is_nan(&self) -> bool {
+        self.significand.is_zero() && self.exponent == 2
     }
 
     /// Determine if the [Repr] represents one
@@ -160,10 +271,10 @@ impl<const B: Word> Repr<B> {
     /// ```
     #[inline]
     pub const fn is_infinite(&self) -> bool {
-        self.significand.is_zero() && self.exponent != 0
+        self.significand.is_zero() && (self.exponent == 1 || self.exponent == -1)
     }
 
-    /// Determine if the [Repr] represents a finite number
+    /// Determine if the [Repr] represents a finite number (neither infinite nor NaN)
     ///
     /// # Examples
     ///
@@ -172,13 +283,14 @@ impl<const B: Word> Repr<B> {
     /// assert!(Repr::<2>::zero().is_finite());
     /// assert!(Repr::<10>::one().is_finite());
     /// assert!(!Repr::<16>::infinity().is_finite());
+    /// assert!(!Repr::<16>::nan().is_finite());
     /// ```
     #[inline]
     pub const fn is_finite(&self) -> bool {
-        !self.is_infinite()
+        !self.significand.is_zero() || self.exponent == 0 || self.exponent == -2
     }
 
-    /// Get the sign of the number
+    /// Get the sign of the number. The NaN has a positive sign.
     ///
     /// # Examples
     ///
@@ -186,6 +298,7 @@ impl<const B: Word> Repr<B> {
     /// # use dashu_base::Sign;
     /// # use dashu_float::Repr;
     /// assert_eq!(Repr::<2>::zero().sign(), Sign::Positive);
+    /// assert_eq!(Repr::<2>::neg_zero().sign(), Sign::Negative);
     /// assert_eq!(Repr::<2>::neg_one().sign(), Sign::Negative);
     /// assert_eq!(Repr::<10>::neg_infinity().sign(), Sign::Negative);
     /// ```
@@ -251,7 +364,7 @@ impl<const B: Word> Repr<B> {
     /// ```
     #[inline]
     pub fn digits(&self) -> usize {
-        if self.is_infinite() {
+        if !self.is_finite() {
             panic_operate_with_inf();
         }
 
@@ -272,7 +385,7 @@ impl<const B: Word> Repr<B> {
     /// ```
     #[inline]
     pub fn digits_ub(&self) -> usize {
-        if self.is_infinite() {
+        if !self.is_finite() {
             panic_operate_with_inf();
         } else if self.significand.is_zero() {
             return 0;
@@ -299,7 +412,7 @@ impl<const B: Word> Repr<B> {
     /// ```
     #[inline]
     pub fn digits_lb(&self) -> usize {
-        if self.is_infinite() {
+        if !self.is_finite() {
             panic_operate_with_inf();
         } else if self.significand.is_zero() {
             return 0;
@@ -313,6 +426,12 @@ impl<const B: Word> Repr<B> {
         log as usize
     }
 
+    /// The position of the most significant digit (exclusive), that is `exponent + digits`.
+    #[inline]
+    pub(crate) fn top(&self) -> isize {
+        self.exponent + self.digits() as isize
+    }
+
     /// Create a [Repr] from the significand and exponent. This
     /// constructor will normalize the representation.
     ///
@@ -392,11 +511,282 @@ impl<R: Round> Context<R> {
     pub const fn new(precision: usize) -> Self {
         Self {
             precision,
+            ieee: false,
+            emin: isize::MIN,
+            emax: isize::MAX,
+            subnormal: true,
+            flags: false,
+            mode: None,
             _marker: PhantomData,
         }
     }
 
+    /// Enable or disable the IEEE mode for the special values (NaN, infinities and signed zeros).
+    ///
+    /// See [the IEEE mode section][Context#ieee-mode] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::{Context, Repr, round::mode::HalfEven};
+    ///
+    /// let context = Context::<HalfEven>::new(10).with_ieee(true);
+    /// assert!(context.is_ieee());
+    ///
+    /// let inf = Repr::<10>::infinity();
+    /// assert!(context.sub(&inf, &inf).value().repr().is_nan());
+    /// let neg_zero = context.mul(&Repr::neg_one(), &Repr::zero()).value();
+    /// assert!(neg_zero.repr().is_neg_zero());
+    /// assert_eq!(context.div(&Repr::one(), neg_zero.repr()).value(), DBig::NEG_INFINITY);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub const fn with_ieee(self, enabled: bool) -> Self {
+        Self {
+            ieee: enabled,
+            ..self
+        }
+    }
+
+    /// Check whether the IEEE mode is enabled
+    #[inline]
+    pub const fn is_ieee(&self) -> bool {
+        self.ieee
+    }
+
+    /// Set the exponent range of the leading digit, where `emin` is the minimum exponent of the
+    /// normal numbers and `emax` is the maximum exponent of the finite numbers.
+    ///
+    /// See [the exponent range section][Context#exponent-range] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, FBig, Repr, round::{mode::HalfEven, Rounding::*}};
+    /// type FBin = FBig<HalfEven, 2>;
+    ///
+    /// // emulate the IEEE 754 binary16 format
+    /// let context = Context::<HalfEven>::new(11).with_exponent_range(-14, 15);
+    /// assert_eq!(context.exponent_range(), (-14, 15));
+    ///
+    /// let max = Repr::<2>::new(2047.into(), 5); // 65504
+    /// assert_eq!(context.add(&max, &Repr::new(1.into(), 3)).value(), FBin::from(65504));
+    /// assert_eq!(context.add(&max, &Repr::new(1.into(), 4)), Inexact(FBin::INFINITY, AddOne));
+    ///
+    /// // the smallest subnormal number is 2^-24
+    /// let tiny = Repr::<2>::new(1.into(), -24);
+    /// let x = context.mul(&tiny, &Repr::new(3.into(), -1)).value();
+    /// assert_eq!(x.repr(), &Repr::new(1.into(), -23));
+    /// assert_eq!(context.mul(&tiny, &Repr::new(1.into(), -1)), Inexact(FBin::ZERO, NoOp));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `emin > emax`.
+    #[inline]
+    pub const fn with_exponent_range(self, emin: isize, emax: isize) -> Self {
+        assert!(emin <= emax);
+        Self { emin, emax, ..self }
+    }
+
+    /// Enable or disable the subnormal numbers (gradual underflow) when the exponent range is bounded.
+    ///
+    /// See [the exponent range section][Context#exponent-range] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, FBig, Repr, round::{mode::Up, Rounding::*}};
+    ///
+    /// let context = Context::<Up>::new(11).with_exponent_range(-14, 15).with_subnormals(false);
+    /// assert!(!context.has_subnormals());
+    ///
+    /// // results below 2^-14 are rounded to either zero or 2^-14
+    /// let tiny = Repr::<2>::new(1.into(), -20);
+    /// let min_normal = Repr::<2>::new(1.into(), -14);
+    /// assert_eq!(
+    ///     context.mul(&tiny, &tiny),
+    ///     Inexact(FBig::from_repr(min_normal, context), AddOne)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn with_subnormals(self, enabled: bool) -> Self {
+        Self {
+            subnormal: enabled,
+            ..self
+        }
+    }
+
+    /// Get the exponent range `(emin, emax)` of the leading digit. It's `(isize::MIN, isize::MAX)`
+    /// if the range is not bounded.
+    #[inline]
+    pub const fn exponent_range(&self) -> (isize, isize) {
+        (self.emin, self.emax)
+    }
+
+    /// Check whether the subnormal numbers are enabled
+    #[inline]
+    pub const fn has_subnormals(&self) -> bool {
+        self.subnormal
+    }
+
+    /// Enable or disable the tracking of the exception flags.
+    ///
+    /// See [the flags module][crate::flags] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_float::{flags::{self, Flags}, Context, Repr, round::mode::HalfEven};
+    ///
+    /// let context = Context::<HalfEven>::new(2);
+    /// let tracked = context.with_flags(true);
+    /// assert!(tracked.tracks_flags());
+    ///
+    /// flags::clear(Flags::ALL);
+    /// let _ = context.div(&Repr::<10>::one(), &Repr::new(3.into(), 0));
+    /// assert!(!flags::test(Flags::INEXACT));
+    /// let _ = tracked.div(&Repr::<10>::one(), &Repr::new(3.into(), 0));
+    /// assert!(flags::test(Flags::INEXACT));
+    /// ```
+    #[inline]
+    pub const fn with_flags(self, enabled: bool) -> Self {
+        Self {
+            flags: enabled,
+            ..self
+        }
+    }
+
+    /// Check whether the tracking of the exception flags is enabled
+    #[inline]
+    pub const fn tracks_flags(&self) -> bool {
+        self.flags
+    }
+}
+
+impl Context<Dynamic> {
+    /// Set the rounding mode of a context with the [Dynamic] rounding mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, DBig, Repr, round::{Mode, mode::Dynamic, Rounding::*}};
+    ///
+    /// let context = Context::<Dynamic>::new(2);
+    /// let third = |mode: Mode| {
+    ///     let result = context.with_rounding_mode(mode).div(&Repr::<10>::one(), &Repr::new(3.into(), 0));
+    ///     result.map(|v| v.with_rounding::<Dynamic>().into_repr())
+    /// };
+    /// assert_eq!(third(Mode::Up), Inexact(Repr::new(34.into(), -2), AddOne));
+    /// assert_eq!(third(Mode::HalfEven), Inexact(Repr::new(33.into(), -2), NoOp));
+    /// ```
+    #[inline]
+    pub const fn with_rounding_mode(self, mode: Mode) -> Self {
+        Self {
+            mode: Some(mode),
+            ..self
+        }
+    }
+}
+
+impl<R: Round> Context<R> {
+    /// Create a context with the same settings except for the precision
+    #[inline]
+    pub(crate) const fn with_precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+
+    /// Get the rounding mode of the context as a value.
+    ///
+    /// For the [Dynamic] rounding mode, it's the mode set in the context ([Mode::Zero] if it's
+    /// not set). It returns [None] for the custom rounding modes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_float::{Context, round::{Mode, mode::{Dynamic, HalfEven}}};
+    ///
+    /// assert_eq!(Context::<HalfEven>::new(2).rounding_mode(), Some(Mode::HalfEven));
+    /// assert_eq!(Context::<Dynamic>::new(2).rounding_mode(), Some(Mode::Zero));
+    /// ```
+    #[inline]
+    pub const fn rounding_mode(&self) -> Option<Mode> {
+        match self.mode {
+            Some(mode) => Some(mode),
+            None if R::DYNAMIC => Some(Mode::Zero),
+            None => R::MODE,
+        }
+    }
+
+    /// Create a context with the same settings except for the rounding mode.
+    ///
+    /// When converting to the [Dynamic] rounding mode, the current rounding mode is
+    /// stored in the new context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_float::{Context, round::{Mode, mode::{Dynamic, HalfEven, Up}}};
+    ///
+    /// let context = Context::<HalfEven>::new(2).with_rounding::<Dynamic>();
+    /// assert_eq!(context.rounding_mode(), Some(Mode::HalfEven));
+    /// let context = context.with_rounding_mode(Mode::Up).with_rounding::<Up>();
+    /// assert_eq!(context.rounding_mode(), Some(Mode::Up));
+    /// ```
+    #[inline]
+    pub const fn with_rounding<NewR: Round>(self) -> Context<NewR> {
+        let mode = if !NewR::DYNAMIC {
+            None
+        } else if self.mode.is_some() {
+            self.mode
+        } else {
+            R::MODE
+        };
+        Context {
+            precision: self.precision,
+            ieee: self.ieee,
+            emin: self.emin,
+            emax: self.emax,
+            subnormal: self.subnormal,
+            flags: self.flags,
+            mode,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Create a context for the intermediate computations with the given precision. Only the
+    /// rounding mode is inherited, the other settings are the defaults.
+    #[inline]
+    pub(crate) const fn work_context(&self, precision: usize) -> Self {
+        Context {
+            mode: self.mode,
+            ..Context::new(precision)
+        }
+    }
+
+    /// Create a context for the intermediate computations with the given precision and the
+    /// opposite rounding direction
+    #[inline]
+    pub(crate) const fn reversed_work_context(&self, precision: usize) -> Context<R::Reverse> {
+        let mode = match self.mode {
+            Some(mode) => Some(mode.reverse()),
+            None => None,
+        };
+        Context {
+            mode,
+            ..Context::new(precision)
+        }
+    }
+
     /// Create a float operation context with the higher precision from the two context inputs.
+    /// The IEEE mode and the flag tracking are enabled if they are enabled in any of the inputs,
+    /// and the exponent range is the intersection of the two ranges. For the [Dynamic] rounding
+    /// mode, the rounding mode is taken from the input where it's set.
     ///
     /// # Examples
     ///
@@ -415,6 +805,24 @@ impl<R: Round> Context<R> {
             } else {
                 rhs.precision
             },
+            ieee: lhs.ieee || rhs.ieee,
+            emin: if lhs.emin > rhs.emin {
+                lhs.emin
+            } else {
+                rhs.emin
+            },
+            emax: if lhs.emax < rhs.emax {
+                lhs.emax
+            } else {
+                rhs.emax
+            },
+            subnormal: lhs.subnormal && rhs.subnormal,
+            flags: lhs.flags || rhs.flags,
+            mode: match (lhs.mode, rhs.mode) {
+                (Some(l), Some(r)) if l as u8 != r as u8 => panic_different_rounding(),
+                (Some(mode), _) | (None, Some(mode)) => Some(mode),
+                (None, None) => None,
+            },
             _marker: PhantomData,
         }
     }
@@ -439,14 +847,26 @@ impl<R: Round> Context<R> {
         }
 
         let digits = repr.digits();
-        if digits > self.precision {
-            let shift = digits - self.precision;
+        let mut shift = digits.saturating_sub(self.precision);
+        if !repr.is_zero() {
+            if let Some(min_exp) = self.min_exponent(repr.top() - 1) {
+                shift = shift.max((min_exp - repr.exponent).max(0) as usize);
+            }
+        }
+
+        let sign = repr.sign();
+        let rounded = if shift > digits {
+            // the number is less than half of the unit in the last place
+            let adjust = self.round_low_part(&IBig::ZERO, sign, || Ordering::Less);
+            Inexact(Repr::new(IBig::ZERO + adjust, repr.exponent + shift as isize), adjust)
+        } else if shift > 0 {
             let (signif_hi, signif_lo) = split_digits::<B>(repr.significand, shift);
-            let adjust = R::round_fract::<B>(&signif_hi, signif_lo, shift);
+            let adjust = self.round_fract::<B>(&signif_hi, signif_lo, shift);
             Inexact(Repr::new(signif_hi + adjust, repr.exponent + shift as isize), adjust)
         } else {
             Exact(repr)
-        }
+        };
+        self.repr_check_range(rounded, sign)
     }
 
     /// Round the repr to the desired precision
@@ -457,13 +877,25 @@ impl<R: Round> Context<R> {
         }
 
         let digits = repr.digits();
-        if digits > self.precision {
-            let shift = digits - self.precision;
+        let mut shift = digits.saturating_sub(self.precision);
+        if !repr.is_zero() {
+            if let Some(min_exp) = self.min_exponent(repr.top() - 1) {
+                shift = shift.max((min_exp - repr.exponent).max(0) as usize);
+            }
+        }
+
+        let sign = repr.sign();
+        let rounded = if shift > digits {
+            // the number is less than half of the unit in the last place
+            let adjust = self.round_low_part(&IBig::ZERO, sign, || Ordering::Less);
+            Inexact(Repr::new(IBig::ZERO + adjust, repr.exponent + shift as isize), adjust)
+        } else if shift > 0 {
             let (signif_hi, signif_lo) = split_digits_ref::<B>(&repr.significand, shift);
-            let adjust = R::round_fract::<B>(&signif_hi, signif_lo, shift);
+            let adjust = self.round_fract::<B>(&signif_hi, signif_lo, shift);
             Inexact(Repr::new(signif_hi + adjust, repr.exponent + shift as isize), adjust)
         } else {
             Exact(repr.clone())
-        }
+        };
+        self.repr_check_range(rounded, sign)
     }
 }
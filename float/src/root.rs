@@ -1,8 +1,10 @@
-use dashu_base::{Approximation, Sign};
+use core::cmp::Ordering;
+
+use dashu_base::{Abs, Approximation, Root, Sign};
 use dashu_int::IBig;
 
 use crate::{
-    error::{check_inf, check_precision_limited},
+    error::{check_precision_limited, panic_out_of_domain, panic_root_zeroth},
     fbig::FBig,
     repr::{Context, Repr, Word},
     round::{Round, Rounded},
@@ -25,6 +27,57 @@ impl<R: Round, const B: Word> FBig<R, B> {
     pub fn sqrt(&self) -> Self {
         self.context.sqrt(self.repr()).value()
     }
+
+    /// Calculate the cubic root of the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("-1.23")?;
+    /// assert_eq!(a.cbrt(), DBig::from_str_native("-1.07")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn cbrt(&self) -> Self {
+        self.context.cbrt(self.repr()).value()
+    }
+
+    /// Calculate the n-th root of the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.23")?;
+    /// assert_eq!(a.nth_root(4), DBig::from_str_native("1.05")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or the number is negative while `n` is even (the result is NaN
+    /// in the IEEE mode).
+    #[inline]
+    pub fn nth_root(&self, n: usize) -> Self {
+        self.context.nth_root(self.repr(), n).value()
+    }
+}
+
+impl<R: Round, const B: Word> Root for FBig<R, B> {
+    type Output = Self;
+
+    #[inline]
+    fn sqrt(&self) -> Self {
+        FBig::sqrt(self)
+    }
+
+    #[inline]
+    fn cbrt(&self) -> Self {
+        FBig::cbrt(self)
+    }
 }
 
 impl<R: Round> Context<R> {
@@ -48,12 +101,24 @@ impl<R: Round> Context<R> {
     ///
     /// Panics if the precision is unlimited.
     pub fn sqrt<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
-        check_inf(x);
+        if let Some(special) = self.ieee_sqrt(x) {
+            return Approximation::Exact(FBig::new(special, *self));
+        }
         check_precision_limited(self.precision);
+        if x.is_zero() {
+            return Approximation::Exact(FBig::new(x.clone(), *self));
+        }
 
-        // adjust the signifcand so that the exponent is even
-        let digits = x.digits() as isize;
-        let shift = self.precision as isize * 2 - (digits & 1) + (x.exponent & 1) - digits;
+        // the exponent of the leading digit of the root is exactly half of the input's (rounded down),
+        // so the exponent of the last digit of the result can be decided before the evaluation
+        let lead = (x.top() - 1).div_euclid(2);
+        let mut exp = lead - (self.precision as isize - 1);
+        if let Some(min_exp) = self.min_exponent(lead) {
+            exp = exp.max(min_exp);
+        }
+
+        // adjust the signifcand so that the exponent is 2 * exp
+        let shift = x.exponent - 2 * exp;
         let (signif, low, low_digits) = if shift > 0 {
             (shl_digits::<B>(&x.significand, shift as usize), IBig::ZERO, 0)
         } else {
@@ -64,20 +129,136 @@ impl<R: Round> Context<R> {
 
         let (root, rem) = signif.sqrt_rem();
         let root = Sign::Positive * root;
-        let exp = (x.exponent - shift) / 2;
 
-        let res = if rem.is_zero() {
+        let res = if rem.is_zero() && low.is_zero() {
             Approximation::Exact(root)
         } else {
-            let adjust = R::round_low_part(&root, Sign::Positive, || {
+            let adjust = self.round_low_part(&root, Sign::Positive, || {
                 (Sign::Positive * rem)
                     .cmp(&root)
                     .then_with(|| (low * 4u8).cmp(&Repr::<B>::BASE.pow(low_digits)))
             });
             Approximation::Inexact(root + adjust, adjust)
         };
-        res.map(|signif| Repr::new(signif, exp))
-            .and_then(|v| self.repr_round(v))
+        let res = res.map(|signif| Repr::new(signif, exp));
+        self.repr_check_range(res, Sign::Positive)
+            .map(|v| FBig::new(v, *self))
+    }
+}
+
+impl<R: Round> Context<R> {
+    /// Calculate the cubic root of the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("-3.375")?;
+    /// assert_eq!(context.cbrt(&a.repr()), Exact(DBig::from_str_native("-1.5")?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn cbrt<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        self.nth_root(x, 3)
+    }
+
+    /// Calculate the n-th root of the floating point number.
+    ///
+    /// The result is exact if the input is an n-th power of a number
+    /// representable under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.23")?;
+    /// assert_eq!(context.nth_root(&a.repr(), 5), Inexact(DBig::from_str_native("1.0")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, if `n` is zero, or if the number
+    /// is negative while `n` is even (the result is NaN in the IEEE mode).
+    pub fn nth_root<const B: Word>(&self, x: &Repr<B>, n: usize) -> Rounded<FBig<R, B>> {
+        match n {
+            0 => panic_root_zeroth(),
+            2 => return self.sqrt(x),
+            _ => {}
+        }
+        if let Some(special) = self.ieee_nth_root(x, n) {
+            return Approximation::Exact(FBig::new(special, *self));
+        }
+        check_precision_limited(self.precision);
+        if n == 1 {
+            return self.repr_round_ref(x).map(|v| FBig::new(v, *self));
+        }
+        let sign = x.sign();
+        if sign == Sign::Negative && n % 2 == 0 {
+            panic_out_of_domain()
+        }
+        if x.is_zero() {
+            return Approximation::Exact(FBig::new(Repr::zero(), *self));
+        }
+
+        // the exponent of the leading digit of the root is exactly 1/n of the input's (rounded down),
+        // so the exponent of the last digit of the result can be decided before the evaluation
+        let n_isize = n as isize;
+        let lead = (x.top() - 1).div_euclid(n_isize);
+        let mut exp = lead - (self.precision as isize - 1);
+        if let Some(min_exp) = self.min_exponent(lead) {
+            exp = exp.max(min_exp);
+        }
+
+        // adjust the signifcand so that the exponent is n * exp
+        let shift = x.exponent - n_isize * exp;
+        let (signif, low, low_digits) = if shift > 0 {
+            (shl_digits::<B>(&x.significand, shift as usize), IBig::ZERO, 0)
+        } else {
+            let shift = (-shift) as usize;
+            let (hi, lo) = split_digits_ref::<B>(&x.significand, shift);
+            (hi, lo, shift)
+        };
+        let (signif, low) = (signif.abs(), low.abs());
+
+        let root = signif.nth_root(n);
+        let res = if low.is_zero() && root.pow(n) == signif {
+            Approximation::Exact(sign * root)
+        } else {
+            // compare the fractional part of the root with 1/2, that is to compare
+            // (signif + low / B^low_digits) * 2^n with (2 * root + 1)^n
+            let half = (root.clone() * 2u8 + 1u8).pow(n);
+            let scaled = signif << n;
+            let half_test = || {
+                if low.is_zero() || scaled >= half {
+                    scaled.cmp(&half)
+                } else if scaled.clone() + (IBig::ONE << n) <= half {
+                    Ordering::Less
+                } else {
+                    let base_pow = Repr::<B>::BASE.pow(low_digits);
+                    (scaled * &base_pow + (low << n)).cmp(&(half * base_pow))
+                }
+            };
+            let root = sign * root;
+            let adjust = self.round_low_part(&root, sign, half_test);
+            Approximation::Inexact(root + adjust, adjust)
+        };
+        let res = res.map(|signif| Repr::new(signif, exp));
+        self.repr_check_range(res, sign)
             .map(|v| FBig::new(v, *self))
     }
 }
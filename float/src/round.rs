@@ -5,6 +5,8 @@ use core::ops::{Add, AddAssign};
 use dashu_base::{Approximation, EstimatedLog2, Sign, UnsignedAbs};
 use dashu_int::{IBig, UBig, Word};
 
+use crate::repr::Context;
+
 /// Built-in rounding modes of the floating numbers.
 ///
 /// # Rounding Error
@@ -26,9 +28,16 @@ use dashu_int::{IBig, UBig, Word};
 /// | HalfEven | AddOne   | `[-1/2 ulp, 0)`                  |
 /// | HalfEven | NoOp     | `[-1/2 ulp, 1/2 ulp]`            |
 /// | HalfEven | SubOne   | `(0, 1/2 ulp]`                   |
+/// | ToOdd    | AddOne   | `(-1 ulp, 0)`                    |
+/// | ToOdd    | NoOp     | `(-1 ulp 0)` or `(0, 1 ulp)`*    |
+/// | ToOdd    | SubOne   | `(0, 1 ulp)`                     |
 ///
 /// *: Dependends on the sign of the result
 ///
+/// The [Stochastic][mode::Stochastic] mode rounds to one of the two neighbors randomly, so its
+/// error is in the range `(-1 ulp, 1 ulp)`, and the sign of the error can be told in the same
+/// way as the directed rounding modes.
+///
 pub mod mode {
     /// Round toward 0 (default mode for binary float)
     #[derive(Clone, Copy)]
@@ -53,6 +62,113 @@ pub mod mode {
     /// Round to the nearest value, ties away from zero
     #[derive(Clone, Copy)]
     pub struct HalfAway;
+
+    /// Round to the neighbor with an odd last digit when the result is inexact.
+    ///
+    /// This mode (also known as sticky rounding) keeps the information of whether a result is
+    /// exact in the last digit. If a result is calculated with this mode under a precision that
+    /// is at least two digits higher than the target precision, then rounding it again to the
+    /// target precision with any other mode produces the same result as rounding the exact
+    /// value directly, that is, there is no double-rounding error. (The base of the number
+    /// has to be even for this property.)
+    #[derive(Clone, Copy)]
+    pub struct ToOdd;
+
+    /// Round to one of the two neighbors randomly, with the probabilities proportional to the
+    /// distances to the opposite neighbors.
+    ///
+    /// The expectation of the rounded result is the exact value, so the rounding errors
+    /// don't accumulate in a sequence of operations. The random numbers are drawn from a
+    /// generator local to the current thread, which can be seeded by [Stochastic::seed] or
+    /// replaced by [Stochastic::set_rng]. Without seeding, the generator starts from a fixed
+    /// seed in each thread.
+    ///
+    /// The additions, subtractions, multiplications, divisions and conversions are rounded with
+    /// exact probabilities (up to an error of `2^-64`). The other operations only determine
+    /// whether the discarded part is less than, equal to or greater than a half unit, in which
+    /// case the result is rounded to the nearest with random ties.
+    ///
+    /// This mode is only available with the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_float::{round::mode::Stochastic, Context, Repr};
+    ///
+    /// Stochastic::seed(42);
+    /// let context = Context::<Stochastic>::new(1);
+    /// let x = Repr::<10>::new(13.into(), -1); // 1.3
+    ///
+    /// // 1.3 is rounded to 1 with the probability 70% and to 2 with the probability 30%
+    /// let sum: usize = (0..1000)
+    ///     .map(|_| context.mul(&x, &Repr::one()).value().to_int().value())
+    ///     .map(|v| usize::try_from(v).unwrap())
+    ///     .sum();
+    /// assert!(sum > 1250 && sum < 1350);
+    /// ```
+    #[cfg(feature = "std")]
+    #[derive(Clone, Copy)]
+    pub struct Stochastic;
+
+    /// Rounding mode decided at runtime.
+    ///
+    /// The actual rounding mode ([Mode][crate::round::Mode]) is stored in the context, and it
+    /// can be set by [Context::with_rounding_mode][crate::Context::with_rounding_mode], or
+    /// inherited when a number or a context with a built-in rounding mode is converted by
+    /// `with_rounding::<Dynamic>()`. If the rounding mode is not set, the numbers are rounded
+    /// toward zero (the same as [Zero]).
+    ///
+    /// For binary operations, the rounding mode is taken from the operand where it's set. If it's
+    /// set for both operands, then it must be the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::{round::{mode::{Dynamic, HalfEven}, Mode}, Context, DBig, FBig};
+    ///
+    /// // the rounding mode can be read from a configuration
+    /// let mode = Mode::Up;
+    /// let context = Context::<Dynamic>::new(4).with_rounding_mode(mode);
+    /// let a = FBig::<Dynamic, 10>::from_repr(DBig::from_str_native("1.234")?.into_repr(), context);
+    /// let b = FBig::<Dynamic, 10>::from(3);
+    /// assert_eq!((a / b).to_string(), "0.4114");
+    ///
+    /// // conversion between the static and the dynamic rounding modes
+    /// let c = DBig::from_str_native("1.234")?.with_rounding::<Dynamic>();
+    /// assert_eq!(c.context().rounding_mode(), Some(Mode::HalfAway));
+    /// let d = c.with_rounding::<HalfEven>();
+    /// assert_eq!(d.context().rounding_mode(), Some(Mode::HalfEven));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[derive(Clone, Copy)]
+    pub struct Dynamic;
+}
+
+/// The built-in rounding modes as a value, which is used by the [Dynamic][mode::Dynamic]
+/// rounding mode to select the rounding behavior at runtime.
+///
+/// Each variant has the same behavior as the rounding mode type with the same name in
+/// [the `mode` module][mode].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// See [mode::Zero]
+    Zero,
+    /// See [mode::Away]
+    Away,
+    /// See [mode::Up]
+    Up,
+    /// See [mode::Down]
+    Down,
+    /// See [mode::HalfEven]
+    HalfEven,
+    /// See [mode::HalfAway]
+    HalfAway,
+    /// See [mode::ToOdd]
+    ToOdd,
+    /// See [mode::Stochastic]
+    #[cfg(feature = "std")]
+    Stochastic,
 }
 
 /// The adjustment of a rounding operation
@@ -81,6 +197,14 @@ pub trait Round: Copy {
     /// The rounding operation that rounds to an opposite direction
     type Reverse: Round;
 
+    /// The rounding mode as a value, which is used when converting a number or a context to the
+    /// [Dynamic][mode::Dynamic] rounding mode. It's [None] for the custom rounding modes.
+    const MODE: Option<Mode> = None;
+
+    /// Whether the rounding mode is decided at runtime by the context. It's only true for the
+    /// [Dynamic][mode::Dynamic] rounding mode.
+    const DYNAMIC: bool = false;
+
     /// Calculate the rounding of the number (integer + rem), assuming rem != 0 and |rem| < 1.
     /// `low_half_test` should tell |rem|.cmp(0.5)
     fn round_low_part<F: FnOnce() -> Ordering>(
@@ -142,6 +266,7 @@ pub trait Round: Copy {
 
 impl Round for mode::Zero {
     type Reverse = mode::Away;
+    const MODE: Option<Mode> = Some(Mode::Zero);
 
     #[inline]
     fn round_low_part<F: FnOnce() -> Ordering>(
@@ -162,6 +287,7 @@ impl Round for mode::Zero {
 
 impl Round for mode::Away {
     type Reverse = mode::Zero;
+    const MODE: Option<Mode> = Some(Mode::Away);
 
     #[inline]
     fn round_low_part<F: FnOnce() -> Ordering>(
@@ -188,6 +314,7 @@ impl Round for mode::Away {
 
 impl Round for mode::Down {
     type Reverse = mode::Up;
+    const MODE: Option<Mode> = Some(Mode::Down);
 
     #[inline]
     fn round_low_part<F: FnOnce() -> Ordering>(
@@ -206,6 +333,7 @@ impl Round for mode::Down {
 
 impl Round for mode::Up {
     type Reverse = mode::Down;
+    const MODE: Option<Mode> = Some(Mode::Up);
 
     #[inline]
     fn round_low_part<F: FnOnce() -> Ordering>(
@@ -224,6 +352,7 @@ impl Round for mode::Up {
 
 impl Round for mode::HalfAway {
     type Reverse = Self;
+    const MODE: Option<Mode> = Some(Mode::HalfAway);
 
     #[inline]
     fn round_low_part<F: FnOnce() -> Ordering>(
@@ -259,6 +388,7 @@ impl Round for mode::HalfAway {
 
 impl Round for mode::HalfEven {
     type Reverse = Self;
+    const MODE: Option<Mode> = Some(Mode::HalfEven);
 
     #[inline]
     fn round_low_part<F: FnOnce() -> Ordering>(
@@ -293,6 +423,248 @@ impl Round for mode::HalfEven {
     }
 }
 
+impl Round for mode::ToOdd {
+    type Reverse = Self;
+    const MODE: Option<Mode> = Some(Mode::ToOdd);
+
+    #[inline]
+    fn round_low_part<F: FnOnce() -> Ordering>(
+        integer: &IBig,
+        low_sign: Sign,
+        _low_half_test: F,
+    ) -> Rounding {
+        // the result is the odd one of the two integers next to the exact value
+        if integer & 1 == 1 {
+            Rounding::NoOp
+        } else {
+            match low_sign {
+                Sign::Positive => Rounding::AddOne,
+                Sign::Negative => Rounding::SubOne,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod stochastic {
+    use super::{mode::Stochastic, Mode, Round, Rounding};
+    use alloc::boxed::Box;
+    use core::cell::RefCell;
+    use core::cmp::Ordering;
+    use dashu_base::{Sign, UnsignedAbs};
+    use dashu_int::{IBig, UBig, Word};
+
+    /// The source of the random numbers used by the stochastic rounding
+    enum Generator {
+        /// The built-in SplitMix64 generator with its state
+        SplitMix(u64),
+        /// A generator provided by the user
+        Custom(Box<dyn FnMut() -> u64>),
+    }
+
+    std::thread_local! {
+        static GENERATOR: RefCell<Generator> = const { RefCell::new(Generator::SplitMix(0)) };
+    }
+
+    /// Draw a random number uniformly distributed in `[0, 2^64)`
+    fn random() -> u64 {
+        GENERATOR.with(|g| match &mut *g.borrow_mut() {
+            Generator::SplitMix(state) => {
+                *state = state.wrapping_add(0x9e3779b97f4a7c15);
+                let mut z = *state;
+                z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+                z ^ (z >> 31)
+            }
+            Generator::Custom(f) => f(),
+        })
+    }
+
+    /// Round toward the direction `sign` with the probability `num / den`, assuming
+    /// `0 < num < den`.
+    fn round_random(sign: Sign, num: UBig, den: UBig) -> Rounding {
+        // round if r < num / den, where r is a random number uniformly distributed in [0, 1)
+        let r = UBig::from(random());
+        if r * den < num << 64 {
+            match sign {
+                Sign::Positive => Rounding::AddOne,
+                Sign::Negative => Rounding::SubOne,
+            }
+        } else {
+            Rounding::NoOp
+        }
+    }
+
+    impl Stochastic {
+        /// Reset the random generator of the current thread to the built-in one with the
+        /// given seed.
+        ///
+        /// The built-in generator is a SplitMix64 generator, which is fast but not
+        /// cryptographically secure.
+        pub fn seed(seed: u64) {
+            GENERATOR.with(|g| *g.borrow_mut() = Generator::SplitMix(seed));
+        }
+
+        /// Replace the random generator of the current thread with the given one.
+        ///
+        /// The generator should return random numbers uniformly distributed in the whole range
+        /// of [u64], and it must not perform any operation with the stochastic rounding itself.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use dashu_float::round::mode::Stochastic;
+        ///
+        /// // a linear congruential generator for demonstration
+        /// let mut state = 1u64;
+        /// Stochastic::set_rng(move || {
+        ///     state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ///     state
+        /// });
+        /// ```
+        pub fn set_rng<G: FnMut() -> u64 + 'static>(rng: G) {
+            GENERATOR.with(|g| *g.borrow_mut() = Generator::Custom(Box::new(rng)));
+        }
+    }
+
+    impl Round for Stochastic {
+        type Reverse = Self;
+        const MODE: Option<Mode> = Some(Mode::Stochastic);
+
+        #[inline]
+        fn round_low_part<F: FnOnce() -> Ordering>(
+            _integer: &IBig,
+            low_sign: Sign,
+            low_half_test: F,
+        ) -> Rounding {
+            // only the comparison with 1/2 is known, so the result is rounded to the nearest,
+            // and a tie is broken randomly
+            let round = match low_half_test() {
+                Ordering::Less => false,
+                Ordering::Equal => random() & 1 == 1,
+                Ordering::Greater => true,
+            };
+            match (round, low_sign) {
+                (false, _) => Rounding::NoOp,
+                (true, Sign::Positive) => Rounding::AddOne,
+                (true, Sign::Negative) => Rounding::SubOne,
+            }
+        }
+
+        fn round_fract<const B: Word>(_integer: &IBig, fract: IBig, precision: usize) -> Rounding {
+            if fract.is_zero() {
+                return Rounding::NoOp;
+            }
+            let (fsign, fmag) = fract.into_parts();
+            round_random(fsign, fmag, UBig::from_word(B).pow(precision))
+        }
+
+        fn round_ratio(_integer: &IBig, num: IBig, den: &IBig) -> Rounding {
+            assert!(!den.is_zero());
+            if num.is_zero() {
+                return Rounding::NoOp;
+            }
+            let (nsign, nmag) = num.into_parts();
+            round_random(nsign * den.sign(), nmag, den.unsigned_abs())
+        }
+    }
+}
+
+impl Round for mode::Dynamic {
+    type Reverse = Self;
+    const DYNAMIC: bool = true;
+
+    // these functions are only called when the rounding mode is not set in the context,
+    // see `Context::round_low_part`, etc.
+
+    #[inline]
+    fn round_low_part<F: FnOnce() -> Ordering>(
+        integer: &IBig,
+        low_sign: Sign,
+        low_half_test: F,
+    ) -> Rounding {
+        mode::Zero::round_low_part(integer, low_sign, low_half_test)
+    }
+}
+
+/// Call the function of the rounding mode type corresponding to the [Mode] value
+macro_rules! dispatch_mode {
+    ($mode:expr, $func:ident $(::<$b:ident>)? ($($arg:expr),*)) => {
+        match $mode {
+            Mode::Zero => mode::Zero::$func$(::<$b>)?($($arg),*),
+            Mode::Away => mode::Away::$func$(::<$b>)?($($arg),*),
+            Mode::Up => mode::Up::$func$(::<$b>)?($($arg),*),
+            Mode::Down => mode::Down::$func$(::<$b>)?($($arg),*),
+            Mode::HalfEven => mode::HalfEven::$func$(::<$b>)?($($arg),*),
+            Mode::HalfAway => mode::HalfAway::$func$(::<$b>)?($($arg),*),
+            Mode::ToOdd => mode::ToOdd::$func$(::<$b>)?($($arg),*),
+            #[cfg(feature = "std")]
+            Mode::Stochastic => mode::Stochastic::$func$(::<$b>)?($($arg),*),
+        }
+    };
+}
+
+impl Mode {
+    /// Get the rounding mode that rounds to an opposite direction
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_float::round::Mode;
+    /// assert_eq!(Mode::Up.reverse(), Mode::Down);
+    /// assert_eq!(Mode::HalfEven.reverse(), Mode::HalfEven);
+    /// ```
+    pub const fn reverse(self) -> Self {
+        match self {
+            Mode::Zero => Mode::Away,
+            Mode::Away => Mode::Zero,
+            Mode::Up => Mode::Down,
+            Mode::Down => Mode::Up,
+            other => other,
+        }
+    }
+}
+
+impl<R: Round> Context<R> {
+    // The rounding functions dispatched with the rounding mode decided at runtime. The rounding
+    // mode is only set in the context for the dynamic rounding mode, so the static functions
+    // of R are called otherwise.
+
+    #[inline]
+    pub(crate) fn round_low_part<F: FnOnce() -> Ordering>(
+        &self,
+        integer: &IBig,
+        low_sign: Sign,
+        low_half_test: F,
+    ) -> Rounding {
+        match self.mode {
+            Some(mode) => dispatch_mode!(mode, round_low_part(integer, low_sign, low_half_test)),
+            None => R::round_low_part(integer, low_sign, low_half_test),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn round_fract<const B: Word>(
+        &self,
+        integer: &IBig,
+        fract: IBig,
+        precision: usize,
+    ) -> Rounding {
+        match self.mode {
+            Some(mode) => dispatch_mode!(mode, round_fract::<B>(integer, fract, precision)),
+            None => R::round_fract::<B>(integer, fract, precision),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn round_ratio(&self, integer: &IBig, num: IBig, den: &IBig) -> Rounding {
+        match self.mode {
+            Some(mode) => dispatch_mode!(mode, round_ratio(integer, num, den)),
+            None => R::round_ratio(integer, num, den),
+        }
+    }
+}
+
 impl Add<Rounding> for IBig {
     type Output = IBig;
 
@@ -336,9 +708,9 @@ mod tests {
     fn test_from_fract() {
         #[rustfmt::skip]
         fn test_all_rounding<const B: Word, const D: usize>(
-            input: &(i32, i32, Rounding, Rounding, Rounding, Rounding, Rounding, Rounding),
+            input: &(i32, i32, Rounding, Rounding, Rounding, Rounding, Rounding, Rounding, Rounding),
         ) {
-            let (value, fract, rnd_zero, rnd_away, rnd_up, rnd_down, rnd_halfeven, rnd_halfaway) = *input;
+            let (value, fract, rnd_zero, rnd_away, rnd_up, rnd_down, rnd_halfeven, rnd_halfaway, rnd_odd) = *input;
             let (value, fract) = (IBig::from(value), IBig::from(fract));
             assert_eq!(Zero::round_fract::<B>(&value, fract.clone(), D), rnd_zero);
             assert_eq!(Away::round_fract::<B>(&value, fract.clone(), D), rnd_away);
@@ -346,34 +718,35 @@ mod tests {
             assert_eq!(Down::round_fract::<B>(&value, fract.clone(), D), rnd_down);
             assert_eq!(HalfEven::round_fract::<B>(&value, fract.clone(), D), rnd_halfeven);
             assert_eq!(HalfAway::round_fract::<B>(&value, fract.clone(), D), rnd_halfaway);
+            assert_eq!(ToOdd::round_fract::<B>(&value, fract.clone(), D), rnd_odd);
         }
 
         // cases for radix = 2, 2 digit fraction
         #[rustfmt::skip]
         let binary_cases = [
             // (integer value, fraction part, roundings...)
-            // Mode: Zero  , Away  , Up    , Down  , HEven,  HAway
-            ( 0,  3, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne),
-            ( 0,  2, NoOp  , AddOne, AddOne, NoOp  , NoOp  , AddOne),
-            ( 0,  1, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  ),
-            ( 0,  0, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 0, -1, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  ),
-            ( 0, -2, NoOp  , SubOne, NoOp  , SubOne, NoOp  , SubOne),
-            ( 0, -3, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne),
-            ( 1,  3, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne),
-            ( 1,  2, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne),
-            ( 1,  1, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  ),
-            ( 1,  0, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 1, -1, SubOne, NoOp  , NoOp  , SubOne, NoOp  , NoOp  ),
-            ( 1, -2, SubOne, NoOp  , NoOp  , SubOne, SubOne, NoOp  ),
-            ( 1, -3, SubOne, NoOp  , NoOp  , SubOne, SubOne, SubOne),
-            (-1,  3, AddOne, NoOp  , AddOne, NoOp  , AddOne, AddOne),
-            (-1,  2, AddOne, NoOp  , AddOne, NoOp  , AddOne, NoOp  ),
-            (-1,  1, AddOne, NoOp  , AddOne, NoOp  , NoOp  , NoOp  ),
-            (-1,  0, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            (-1, -1, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  ),
-            (-1, -2, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne),
-            (-1, -3, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne),
+            // Mode: Zero  , Away  , Up    , Down  , HEven,  HAway , ToOdd
+            ( 0,  3, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne, AddOne),
+            ( 0,  2, NoOp  , AddOne, AddOne, NoOp  , NoOp  , AddOne, AddOne),
+            ( 0,  1, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  , AddOne),
+            ( 0,  0, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 0, -1, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  , SubOne),
+            ( 0, -2, NoOp  , SubOne, NoOp  , SubOne, NoOp  , SubOne, SubOne),
+            ( 0, -3, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne, SubOne),
+            ( 1,  3, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            ( 1,  2, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            ( 1,  1, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 1,  0, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 1, -1, SubOne, NoOp  , NoOp  , SubOne, NoOp  , NoOp  , NoOp  ),
+            ( 1, -2, SubOne, NoOp  , NoOp  , SubOne, SubOne, NoOp  , NoOp  ),
+            ( 1, -3, SubOne, NoOp  , NoOp  , SubOne, SubOne, SubOne, NoOp  ),
+            (-1,  3, AddOne, NoOp  , AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            (-1,  2, AddOne, NoOp  , AddOne, NoOp  , AddOne, NoOp  , NoOp  ),
+            (-1,  1, AddOne, NoOp  , AddOne, NoOp  , NoOp  , NoOp  , NoOp  ),
+            (-1,  0, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            (-1, -1, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  , NoOp  ),
+            (-1, -2, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne, NoOp  ),
+            (-1, -3, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne, NoOp  ),
         ];
         binary_cases.iter().for_each(test_all_rounding::<2, 2>);
 
@@ -381,22 +754,22 @@ mod tests {
         #[rustfmt::skip]
         let tenary_cases = [
             // (integer value, fraction part, roundings...)
-            // Mode: Zero,   Away  , Up    , Down  , HEven , HAway
-            ( 0,  2, NoOp,   AddOne, AddOne, NoOp  , AddOne, AddOne),
-            ( 0,  1, NoOp,   AddOne, AddOne, NoOp  , NoOp  , NoOp  ),
-            ( 0,  0, NoOp,   NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 0, -1, NoOp,   SubOne, NoOp  , SubOne, NoOp  , NoOp  ),
-            ( 0, -2, NoOp,   SubOne, NoOp  , SubOne, SubOne, SubOne),
-            ( 1,  2, NoOp,   AddOne, AddOne, NoOp  , AddOne, AddOne),
-            ( 1,  1, NoOp,   AddOne, AddOne, NoOp  , NoOp  , NoOp  ),
-            ( 1,  0, NoOp,   NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 1, -1, SubOne, NoOp  , NoOp  , SubOne, NoOp  , NoOp  ),
-            ( 1, -2, SubOne, NoOp  , NoOp  , SubOne, SubOne, SubOne),
-            (-1,  2, AddOne, NoOp  , AddOne, NoOp  , AddOne, AddOne),
-            (-1,  1, AddOne, NoOp  , AddOne, NoOp  , NoOp  , NoOp  ),
-            (-1,  0, NoOp,   NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            (-1, -1, NoOp,   SubOne, NoOp  , SubOne, NoOp  , NoOp  ),
-            (-1, -2, NoOp,   SubOne, NoOp  , SubOne, SubOne, SubOne),
+            // Mode: Zero,   Away  , Up    , Down  , HEven , HAway , ToOdd
+            ( 0,  2, NoOp,   AddOne, AddOne, NoOp  , AddOne, AddOne, AddOne),
+            ( 0,  1, NoOp,   AddOne, AddOne, NoOp  , NoOp  , NoOp  , AddOne),
+            ( 0,  0, NoOp,   NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 0, -1, NoOp,   SubOne, NoOp  , SubOne, NoOp  , NoOp  , SubOne),
+            ( 0, -2, NoOp,   SubOne, NoOp  , SubOne, SubOne, SubOne, SubOne),
+            ( 1,  2, NoOp,   AddOne, AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            ( 1,  1, NoOp,   AddOne, AddOne, NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 1,  0, NoOp,   NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 1, -1, SubOne, NoOp  , NoOp  , SubOne, NoOp  , NoOp  , NoOp  ),
+            ( 1, -2, SubOne, NoOp  , NoOp  , SubOne, SubOne, SubOne, NoOp  ),
+            (-1,  2, AddOne, NoOp  , AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            (-1,  1, AddOne, NoOp  , AddOne, NoOp  , NoOp  , NoOp  , NoOp  ),
+            (-1,  0, NoOp,   NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            (-1, -1, NoOp,   SubOne, NoOp  , SubOne, NoOp  , NoOp  , NoOp  ),
+            (-1, -2, NoOp,   SubOne, NoOp  , SubOne, SubOne, SubOne, NoOp  ),
         ];
         tenary_cases.iter().for_each(test_all_rounding::<3, 1>);
 
@@ -404,28 +777,28 @@ mod tests {
         #[rustfmt::skip]
         let decimal_cases = [
             // (integer value, fraction part, roundings...)
-            // Mode: Zero  , Away  , Up    , Down  , HEven , HAway
-            ( 0,  7, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne),
-            ( 0,  5, NoOp  , AddOne, AddOne, NoOp  , NoOp  , AddOne),
-            ( 0,  2, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  ),
-            ( 0,  0, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 0, -2, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  ),
-            ( 0, -5, NoOp  , SubOne, NoOp  , SubOne, NoOp  , SubOne),
-            ( 0, -7, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne),
-            ( 1,  7, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne),
-            ( 1,  5, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne),
-            ( 1,  2, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  ),
-            ( 1,  0, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 1, -2, SubOne, NoOp  , NoOp  , SubOne, NoOp  , NoOp  ),
-            ( 1, -5, SubOne, NoOp  , NoOp  , SubOne, SubOne, NoOp  ),
-            ( 1, -7, SubOne, NoOp  , NoOp  , SubOne, SubOne, SubOne),
-            (-1,  7, AddOne, NoOp  , AddOne, NoOp  , AddOne, AddOne),
-            (-1,  5, AddOne, NoOp  , AddOne, NoOp  , AddOne, NoOp  ),
-            (-1,  2, AddOne, NoOp  , AddOne, NoOp  , NoOp  , NoOp  ),
-            (-1,  0, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            (-1, -2, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  ),
-            (-1, -5, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne),
-            (-1, -7, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne),
+            // Mode: Zero  , Away  , Up    , Down  , HEven , HAway , ToOdd
+            ( 0,  7, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne, AddOne),
+            ( 0,  5, NoOp  , AddOne, AddOne, NoOp  , NoOp  , AddOne, AddOne),
+            ( 0,  2, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  , AddOne),
+            ( 0,  0, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 0, -2, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  , SubOne),
+            ( 0, -5, NoOp  , SubOne, NoOp  , SubOne, NoOp  , SubOne, SubOne),
+            ( 0, -7, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne, SubOne),
+            ( 1,  7, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            ( 1,  5, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            ( 1,  2, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 1,  0, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 1, -2, SubOne, NoOp  , NoOp  , SubOne, NoOp  , NoOp  , NoOp  ),
+            ( 1, -5, SubOne, NoOp  , NoOp  , SubOne, SubOne, NoOp  , NoOp  ),
+            ( 1, -7, SubOne, NoOp  , NoOp  , SubOne, SubOne, SubOne, NoOp  ),
+            (-1,  7, AddOne, NoOp  , AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            (-1,  5, AddOne, NoOp  , AddOne, NoOp  , AddOne, NoOp  , NoOp  ),
+            (-1,  2, AddOne, NoOp  , AddOne, NoOp  , NoOp  , NoOp  , NoOp  ),
+            (-1,  0, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            (-1, -2, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  , NoOp  ),
+            (-1, -5, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne, NoOp  ),
+            (-1, -7, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne, NoOp  ),
         ];
         decimal_cases.iter().for_each(test_all_rounding::<10, 1>);
     }
@@ -434,9 +807,9 @@ mod tests {
     fn test_from_ratio() {
         #[rustfmt::skip]
         fn test_all_rounding(
-            input: &(i32, i32, i32, Rounding, Rounding, Rounding, Rounding, Rounding, Rounding),
+            input: &(i32, i32, i32, Rounding, Rounding, Rounding, Rounding, Rounding, Rounding, Rounding),
         ) {
-            let (value, num, den, rnd_zero, rnd_away, rnd_up, rnd_down, rnd_halfeven, rnd_halfaway) = *input;
+            let (value, num, den, rnd_zero, rnd_away, rnd_up, rnd_down, rnd_halfeven, rnd_halfaway, rnd_odd) = *input;
             let (value, num, den) = (IBig::from(value), IBig::from(num), IBig::from(den));
             assert_eq!(Zero::round_ratio(&value, num.clone(), &den), rnd_zero);
             assert_eq!(Away::round_ratio(&value, num.clone(), &den), rnd_away);
@@ -444,62 +817,63 @@ mod tests {
             assert_eq!(Down::round_ratio(&value, num.clone(), &den), rnd_down);
             assert_eq!(HalfEven::round_ratio(&value, num.clone(), &den), rnd_halfeven);
             assert_eq!(HalfAway::round_ratio(&value, num.clone(), &den), rnd_halfaway);
+            assert_eq!(ToOdd::round_ratio(&value, num.clone(), &den), rnd_odd);
         }
 
         // cases for radix = 2, 2 digit fraction
         #[rustfmt::skip]
         let test_cases = [
             // (integer value, mumerator, denominator, roundings...)
-            // Mode:     Zero  , Away  , Up    , Down  , HEven , HAway
-            ( 0,  0,  2, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 0,  1,  2, NoOp  , AddOne, AddOne, NoOp  , NoOp  , AddOne),
-            ( 0, -1,  2, NoOp  , SubOne, NoOp  , SubOne, NoOp  , SubOne),
-            ( 0,  0, -2, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 0,  1, -2, NoOp  , SubOne, NoOp  , SubOne, NoOp  , SubOne),
-            ( 0, -1, -2, NoOp  , AddOne, AddOne, NoOp  , NoOp  , AddOne),
-            ( 1,  0,  2, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 1,  1,  2, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne),
-            ( 1, -1,  2, SubOne, NoOp  , NoOp  , SubOne, SubOne, NoOp  ),
-            ( 1,  0, -2, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 1,  1, -2, SubOne, NoOp  , NoOp  , SubOne, SubOne, NoOp  ),
-            ( 1, -1, -2, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne),
-            (-1,  0,  2, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            (-1,  1,  2, AddOne, NoOp  , AddOne, NoOp  , AddOne, NoOp  ),
-            (-1, -1,  2, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne),
-            (-1,  0, -2, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            (-1,  1, -2, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne),
-            (-1, -1, -2, AddOne, NoOp  , AddOne, NoOp  , AddOne, NoOp  ),
-
-            ( 0, -2,  3, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne),
-            ( 0, -1,  3, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  ),
-            ( 0,  0,  3, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 0,  1,  3, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  ),
-            ( 0,  2,  3, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne),
-            ( 0, -2, -3, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne),
-            ( 0, -1, -3, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  ),
-            ( 0,  0, -3, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 0,  1, -3, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  ),
-            ( 0,  2, -3, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne),
-            ( 1, -2,  3, SubOne, NoOp  , NoOp  , SubOne, SubOne, SubOne),
-            ( 1, -1,  3, SubOne, NoOp  , NoOp  , SubOne, NoOp  , NoOp  ),
-            ( 1,  0,  3, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 1,  1,  3, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  ),
-            ( 1,  2,  3, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne),
-            ( 1, -2, -3, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne),
-            ( 1, -1, -3, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  ),
-            ( 1,  0, -3, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            ( 1,  1, -3, SubOne, NoOp  , NoOp  , SubOne, NoOp  , NoOp  ),
-            ( 1,  2, -3, SubOne, NoOp  , NoOp  , SubOne, SubOne, SubOne),
-            (-1, -2,  3, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne),
-            (-1, -1,  3, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  ),
-            (-1,  0,  3, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            (-1,  1,  3, AddOne, NoOp  , AddOne, NoOp  , NoOp  , NoOp  ),
-            (-1,  2,  3, AddOne, NoOp  , AddOne, NoOp  , AddOne, AddOne),
-            (-1, -2, -3, AddOne, NoOp  , AddOne, NoOp  , AddOne, AddOne),
-            (-1, -1, -3, AddOne, NoOp  , AddOne, NoOp  , NoOp  , NoOp  ),
-            (-1,  0, -3, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
-            (-1,  1, -3, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  ),
-            (-1,  2, -3, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne),
+            // Mode:     Zero  , Away  , Up    , Down  , HEven , HAway , ToOdd
+            ( 0,  0,  2, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 0,  1,  2, NoOp  , AddOne, AddOne, NoOp  , NoOp  , AddOne, AddOne),
+            ( 0, -1,  2, NoOp  , SubOne, NoOp  , SubOne, NoOp  , SubOne, SubOne),
+            ( 0,  0, -2, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 0,  1, -2, NoOp  , SubOne, NoOp  , SubOne, NoOp  , SubOne, SubOne),
+            ( 0, -1, -2, NoOp  , AddOne, AddOne, NoOp  , NoOp  , AddOne, AddOne),
+            ( 1,  0,  2, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 1,  1,  2, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            ( 1, -1,  2, SubOne, NoOp  , NoOp  , SubOne, SubOne, NoOp  , NoOp  ),
+            ( 1,  0, -2, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 1,  1, -2, SubOne, NoOp  , NoOp  , SubOne, SubOne, NoOp  , NoOp  ),
+            ( 1, -1, -2, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            (-1,  0,  2, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            (-1,  1,  2, AddOne, NoOp  , AddOne, NoOp  , AddOne, NoOp  , NoOp  ),
+            (-1, -1,  2, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne, NoOp  ),
+            (-1,  0, -2, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            (-1,  1, -2, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne, NoOp  ),
+            (-1, -1, -2, AddOne, NoOp  , AddOne, NoOp  , AddOne, NoOp  , NoOp  ),
+
+            ( 0, -2,  3, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne, SubOne),
+            ( 0, -1,  3, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  , SubOne),
+            ( 0,  0,  3, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 0,  1,  3, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  , AddOne),
+            ( 0,  2,  3, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne, AddOne),
+            ( 0, -2, -3, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne, AddOne),
+            ( 0, -1, -3, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  , AddOne),
+            ( 0,  0, -3, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 0,  1, -3, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  , SubOne),
+            ( 0,  2, -3, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne, SubOne),
+            ( 1, -2,  3, SubOne, NoOp  , NoOp  , SubOne, SubOne, SubOne, NoOp  ),
+            ( 1, -1,  3, SubOne, NoOp  , NoOp  , SubOne, NoOp  , NoOp  , NoOp  ),
+            ( 1,  0,  3, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 1,  1,  3, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 1,  2,  3, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            ( 1, -2, -3, NoOp  , AddOne, AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            ( 1, -1, -3, NoOp  , AddOne, AddOne, NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 1,  0, -3, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            ( 1,  1, -3, SubOne, NoOp  , NoOp  , SubOne, NoOp  , NoOp  , NoOp  ),
+            ( 1,  2, -3, SubOne, NoOp  , NoOp  , SubOne, SubOne, SubOne, NoOp  ),
+            (-1, -2,  3, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne, NoOp  ),
+            (-1, -1,  3, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  , NoOp  ),
+            (-1,  0,  3, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            (-1,  1,  3, AddOne, NoOp  , AddOne, NoOp  , NoOp  , NoOp  , NoOp  ),
+            (-1,  2,  3, AddOne, NoOp  , AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            (-1, -2, -3, AddOne, NoOp  , AddOne, NoOp  , AddOne, AddOne, NoOp  ),
+            (-1, -1, -3, AddOne, NoOp  , AddOne, NoOp  , NoOp  , NoOp  , NoOp  ),
+            (-1,  0, -3, NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  , NoOp  ),
+            (-1,  1, -3, NoOp  , SubOne, NoOp  , SubOne, NoOp  , NoOp  , NoOp  ),
+            (-1,  2, -3, NoOp  , SubOne, NoOp  , SubOne, SubOne, SubOne, NoOp  ),
         ];
         test_cases.iter().for_each(test_all_rounding);
     }
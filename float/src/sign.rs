@@ -9,7 +9,11 @@ use dashu_int::IBig;
 
 impl<R: Round, const B: Word> FBig<R, B> {
     pub const fn signum(&self) -> Self {
-        let significand = if self.repr.significand.is_zero() && self.repr.exponent != 0 {
+        if self.repr.is_nan() {
+            return Self::new(Repr::nan(), Context::new(1));
+        }
+
+        let significand = if self.repr.is_infinite() {
             if self.repr.exponent > 0 {
                 IBig::ONE
             } else {
@@ -30,7 +34,11 @@ impl<const B: Word> Neg for Repr<B> {
     type Output = Self;
     #[inline]
     fn neg(mut self) -> Self::Output {
-        self.significand = -self.significand;
+        if self.is_infinite() {
+            self.exponent = -self.exponent;
+        } else {
+            self.significand = -self.significand;
+        }
         self
     }
 }
@@ -39,7 +47,16 @@ impl<R: Round, const B: Word> Neg for FBig<R, B> {
     type Output = Self;
     #[inline]
     fn neg(mut self) -> Self::Output {
-        self.repr.significand = -self.repr.significand;
+        // the sign of zero is only flipped in the IEEE mode
+        if self.context.ieee && self.repr.is_zero() {
+            self.repr = if self.repr.is_neg_zero() {
+                Repr::zero()
+            } else {
+                Repr::neg_zero()
+            };
+        } else {
+            self.repr = -self.repr;
+        }
         self
     }
 }
@@ -55,7 +72,13 @@ impl<R: Round, const B: Word> Neg for &FBig<R, B> {
 impl<R: Round, const B: Word> Abs for FBig<R, B> {
     type Output = Self;
     fn abs(mut self) -> Self::Output {
-        self.repr.significand = self.repr.significand.abs();
+        if self.repr.is_infinite() {
+            self.repr = Repr::infinity();
+        } else if self.repr.is_neg_zero() {
+            self.repr = Repr::zero();
+        } else {
+            self.repr.significand = self.repr.significand.abs();
+        }
         self
     }
 }
@@ -0,0 +1,808 @@
+//! Trigonometric functions and their inverses
+//!
+//! The functions are evaluated on fixed point integers, in a similar way to the constants. The
+//! argument is reduced modulo π/2 with a value of π that has as many extra bits as the integer
+//! part of the argument, so that the reduction is accurate even for huge arguments. The rounding
+//! of the result is verified with an error bound, and the evaluation is repeated with a higher
+//! precision when the rounding can't be decided.
+
+use core::cmp::Ordering;
+
+use crate::{
+    cmp::repr_cmp,
+    consts::Constant,
+    error::{check_inf, check_precision_limited, panic_out_of_domain},
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{Round, Rounded},
+    utils::shl_digits,
+};
+use dashu_base::{Abs, Approximation::*, BitTest, DivEuclid, EstimatedLog2, Sign, UnsignedAbs};
+use dashu_int::IBig;
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Calculate the sine function (`sin(x)`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.sin(), DBig::from_str_native("0.9438")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn sin(&self) -> Self {
+        self.context.sin(&self.repr).value()
+    }
+
+    /// Calculate the cosine function (`cos(x)`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.cos(), DBig::from_str_native("0.3305")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn cos(&self) -> Self {
+        self.context.cos(&self.repr).value()
+    }
+
+    /// Calculate the tangent function (`tan(x)`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.tan(), DBig::from_str_native("2.856")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn tan(&self) -> Self {
+        self.context.tan(&self.repr).value()
+    }
+
+    /// Calculate the sine and the cosine of the floating point number at the same time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// let (sin, cos) = a.sin_cos();
+    /// assert_eq!(sin, DBig::from_str_native("0.9438")?);
+    /// assert_eq!(cos, DBig::from_str_native("0.3305")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn sin_cos(&self) -> (Self, Self) {
+        let (sin, cos) = self.context.sin_cos(&self.repr);
+        (sin.value(), cos.value())
+    }
+
+    /// Calculate the arcsine function (`asin(x)`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("0.1234")?;
+    /// assert_eq!(a.asin(), DBig::from_str_native("0.12372")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is not in the range `[-1, 1]`.
+    #[inline]
+    pub fn asin(&self) -> Self {
+        self.context.asin(&self.repr).value()
+    }
+
+    /// Calculate the arccosine function (`acos(x)`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("0.1234")?;
+    /// assert_eq!(a.acos(), DBig::from_str_native("1.4471")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is not in the range `[-1, 1]`.
+    #[inline]
+    pub fn acos(&self) -> Self {
+        self.context.acos(&self.repr).value()
+    }
+
+    /// Calculate the arctangent function (`atan(x)`) on the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.atan(), DBig::from_str_native("0.8898")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn atan(&self) -> Self {
+        self.context.atan(&self.repr).value()
+    }
+
+    /// Calculate the four quadrant arctangent of `self` (`y`) and `x`.
+    ///
+    /// The result is the angle of the point `(x, y)` in the range `[-π, π]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let y = DBig::from_str_native("1.234")?;
+    /// let x = DBig::from_str_native("-5.678")?;
+    /// assert_eq!(y.atan2(&x), DBig::from_str_native("2.928")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn atan2(&self, x: &Self) -> Self {
+        let context = Context::max(self.context, x.context);
+        context.atan2(&self.repr, &x.repr).value()
+    }
+}
+
+/// Convert the number to a fixed point integer `N ≈ x * 2^bits`, with `|x * 2^bits - N| < 1`
+pub(crate) fn repr_to_fixed<const B: Word>(x: &Repr<B>, bits: usize) -> IBig {
+    if x.exponent >= 0 {
+        shl_digits::<B>(&x.significand, x.exponent as usize) << bits
+    } else {
+        (&x.significand << bits) / Repr::<B>::BASE.pow(x.exponent.unsigned_abs())
+    }
+}
+
+/// Reduce the nonnegative fixed point number `x` modulo π/2.
+///
+/// Returns `(r, q)` such that `x ≈ r + qπ/2` where `|r| ≤ π/4` (roughly), `q` is the
+/// quadrant (modulo 4), and the error of `r` is less than 4 units.
+fn reduce_half_pi(x: &IBig, bits: usize) -> (IBig, usize) {
+    // π is evaluated with extra bits for the integer part of the argument, to prevent
+    // the error from being amplified by the multiplication
+    let int_bits = (x >> bits).unsigned_abs().bit_len() + 1;
+    let pi = Constant::Pi.fixed(bits + int_bits);
+
+    // k = round(x / (π/2)) = floor((4x + π) / 2π)
+    let k = ((x << (int_bits + 2)) + &pi) / (&pi << 1);
+    let r = x - ((&k * pi) >> (int_bits + 1));
+    (r, (k % 4u8) as usize)
+}
+
+/// Evaluate sin(r) and cos(r) for the fixed point number `|r| < 1` using the Maclaurin series.
+///
+/// Returns the results with their common error bound (in units of `2^-bits`).
+fn sin_cos_fixed(r: &IBig, bits: usize) -> (IBig, IBig, IBig) {
+    let r2 = (r * r) >> bits;
+
+    // sin(r) = Σ (-1)ⁱ r²ⁱ⁺¹/(2i+1)!
+    let mut term = r.clone();
+    let mut sin = r.clone();
+    let mut k = 1usize;
+    while !term.is_zero() {
+        term = -((term * &r2) >> bits) / IBig::from(2 * k * (2 * k + 1));
+        sin += &term;
+        k += 1;
+    }
+    let sin_terms = k;
+
+    // cos(r) = Σ (-1)ⁱ r²ⁱ/(2i)!
+    let mut term = IBig::ONE << bits;
+    let mut cos = term.clone();
+    let mut k = 1usize;
+    while !term.is_zero() {
+        term = -((term * &r2) >> bits) / IBig::from((2 * k - 1) * (2 * k));
+        cos += &term;
+        k += 1;
+    }
+
+    // the error of r is less than 4 units and both functions are 1-Lipschitz, each term in the
+    // series adds at most 2 units of error from the truncations.
+    let err = IBig::from(2 * sin_terms.max(k) + 8);
+    (sin, cos, err)
+}
+
+/// Divide the fixed point interval `[a_lo, a_hi]` by the interval `[b_lo, b_hi]`, return
+/// [None] if the divisor interval contains zero.
+fn div_fixed_bounds(
+    (a_lo, a_hi): (IBig, IBig),
+    (b_lo, b_hi): (IBig, IBig),
+    bits: usize,
+) -> Option<(IBig, IBig)> {
+    if b_lo <= IBig::ZERO && b_hi >= IBig::ZERO {
+        return None;
+    }
+
+    // make the divisor positive
+    let ((a_lo, a_hi), (b_lo, b_hi)) = if b_lo.sign() == Sign::Negative {
+        ((-a_hi, -a_lo), (-b_hi, -b_lo))
+    } else {
+        ((a_lo, a_hi), (b_lo, b_hi))
+    };
+
+    // the quotient is increasing with a, and decreasing with b when a is positive
+    let lo_den = if a_lo.sign() == Sign::Negative {
+        &b_lo
+    } else {
+        &b_hi
+    };
+    let hi_den = if a_hi.sign() == Sign::Negative {
+        &b_hi
+    } else {
+        &b_lo
+    };
+    let lo = (a_lo << bits).div_euclid(lo_den);
+    let hi = (a_hi << bits).div_euclid(hi_den) + IBig::ONE;
+    Some((lo, hi))
+}
+
+/// The sine and cosine of the reduced argument, represented as fixed point intervals
+struct SinCosBounds {
+    sin: (IBig, IBig),
+    cos: (IBig, IBig),
+}
+
+impl SinCosBounds {
+    /// Evaluate the intervals containing sin(x) and cos(x) in fixed point
+    fn new<const B: Word>(x: &Repr<B>, bits: usize) -> Self {
+        let sign = x.sign();
+        let x = repr_to_fixed(x, bits);
+        let (r, q) = reduce_half_pi(&x.abs(), bits);
+        let (sin, cos, err) = sin_cos_fixed(&r, bits);
+
+        let sin_r = (&sin - &err, sin + &err);
+        let cos_r = (&cos - &err, cos + err);
+        let neg = |(lo, hi): (IBig, IBig)| (-hi, -lo);
+        let (sin, cos) = match q {
+            0 => (sin_r, cos_r),
+            1 => (cos_r, neg(sin_r)),
+            2 => (neg(sin_r), neg(cos_r)),
+            _ => (neg(cos_r), sin_r),
+        };
+
+        // sin(-x) = -sin(x), cos(-x) = cos(x)
+        let sin = if sign == Sign::Negative {
+            neg(sin)
+        } else {
+            sin
+        };
+        Self { sin, cos }
+    }
+}
+
+/// Evaluate atan(t) for the fixed point number `|t| ≤ 1`, with an error less than 2 units.
+fn atan_fixed(t: &IBig, bits: usize) -> IBig {
+    // the argument is reduced with atan(t) = 2atan(t / (1 + √(1+t²))), so that the series
+    // converges faster. The error is amplified by the doublings, so extra bits are used.
+    let halvings = (1 << (bits.bit_len() / 2)) / 2; // about √bits / 2
+    let guard_bits = halvings + bits.bit_len() + 4;
+    let work_bits = bits + guard_bits;
+    let one = IBig::ONE << work_bits;
+
+    let mut t = t << guard_bits;
+    for _ in 0..halvings {
+        let hyp: IBig = (&t * &t + (&one << work_bits)).sqrt().into();
+        t = (t << work_bits) / (hyp + &one);
+    }
+
+    // atan(t) = Σ (-1)ⁱ t²ⁱ⁺¹/(2i+1)
+    let t2 = (&t * &t) >> work_bits;
+    let mut pow = t.clone();
+    let mut sum = t;
+    let mut k = 1usize;
+    loop {
+        pow = -((pow * &t2) >> work_bits);
+        let term = &pow / IBig::from(2 * k + 1);
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+        k += 1;
+    }
+    (sum << halvings) >> guard_bits
+}
+
+/// Evaluate an interval containing atan(t) for all `t` in the fixed point interval `[lo, hi]`.
+fn atan_fixed_bounds((lo, hi): (IBig, IBig), bits: usize) -> (IBig, IBig) {
+    let one = IBig::ONE << bits;
+    let half_pi = Constant::Pi.fixed(bits) >> 1;
+    let atan = |t: IBig| {
+        if t.clone().abs() <= one {
+            atan_fixed(&t, bits)
+        } else {
+            // atan(t) = ±π/2 - atan(1/t)
+            let inv = (IBig::ONE << (2 * bits)) / &t;
+            t.sign() * half_pi.clone() - atan_fixed(&inv, bits)
+        }
+    };
+
+    // the error of π/2 is less than 2 units, the error of the reciprocal is less than 1 unit
+    let err = 5u8;
+    (atan(lo) - err, atan(hi) + err)
+}
+
+/// Evaluate an interval containing asin(x) in fixed point, where `|x| < 1`.
+///
+/// Returns [None] if the precision is not enough to separate `|x|` from 1.
+fn asin_fixed_bounds<const B: Word>(x: &Repr<B>, bits: usize) -> Option<(IBig, IBig)> {
+    // asin(x) = atan(x / √(1-x²))
+    let t = repr_to_fixed(x, bits).abs();
+    let one = IBig::ONE << bits;
+    let (t_lo, t_hi) = (&t - IBig::ONE, t + IBig::ONE);
+    if t_hi >= one {
+        return None;
+    }
+    let t_lo = t_lo.max(IBig::ZERO);
+
+    let one2 = IBig::ONE << (2 * bits);
+    let s_lo: IBig = (&one2 - &t_hi * &t_hi).sqrt().into();
+    let s_hi: IBig = IBig::from((one2 - &t_lo * &t_lo).sqrt()) + IBig::ONE;
+    if s_lo.is_zero() {
+        return None;
+    }
+    let u_lo = (t_lo << bits) / s_hi;
+    let u_hi = (t_hi << bits) / s_lo + IBig::ONE;
+
+    let (lo, hi) = atan_fixed_bounds((u_lo, u_hi), bits);
+    Some(match x.sign() {
+        Sign::Positive => (lo, hi),
+        Sign::Negative => (-hi, -lo),
+    })
+}
+
+/// Convert the ratio `y / x` to a fixed point interval
+fn ratio_to_fixed_bounds<const B: Word>(y: &Repr<B>, x: &Repr<B>, bits: usize) -> (IBig, IBig) {
+    let exp = y.exponent - x.exponent;
+    let (num, den) = if exp >= 0 {
+        (shl_digits::<B>(&y.significand, exp as usize) << bits, x.significand.clone())
+    } else {
+        (&y.significand << bits, shl_digits::<B>(&x.significand, exp.unsigned_abs()))
+    };
+    let (num, den) = match den.sign() {
+        Sign::Positive => (num, den),
+        Sign::Negative => (-num, -den),
+    };
+    let lo = num.div_euclid(den);
+    let hi = &lo + IBig::ONE;
+    (lo, hi)
+}
+
+impl<R: Round> Context<R> {
+    /// The initial number of bits for the fixed point evaluation of trigonometric functions
+    fn trig_fixed_bits<const B: Word>(&self, x: &Repr<B>) -> usize {
+        // more bits are required for small arguments, because the result is also small
+        let small_bits = (-x.log2_bounds().0).max(0.) as usize;
+        self.fixed_bits::<B>() + small_bits
+    }
+
+    /// Calculate the sine function (`sin(x)`) on the floating point number under this context.
+    ///
+    /// The result is correctly rounded, even for huge arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.sin(&a.repr()), Inexact(DBig::from_str_native("0.94")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn sin<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if x.is_zero() {
+            return Exact(FBig::ZERO);
+        }
+
+        let mut bits = self.trig_fixed_bits(x);
+        loop {
+            let (lo, hi) = SinCosBounds::new(x, bits).sin;
+            if let Some(v) = self.round_fixed_bounds(lo, hi, bits) {
+                return v;
+            }
+            bits += bits / 2;
+        }
+    }
+
+    /// Calculate the cosine function (`cos(x)`) on the floating point number under this context.
+    ///
+    /// The result is correctly rounded, even for huge arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.cos(&a.repr()), Inexact(DBig::from_str_native("0.33")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn cos<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if x.is_zero() {
+            return Exact(FBig::ONE);
+        }
+
+        let mut bits = self.fixed_bits::<B>();
+        loop {
+            let (lo, hi) = SinCosBounds::new(x, bits).cos;
+            if let Some(v) = self.round_fixed_bounds(lo, hi, bits) {
+                return v;
+            }
+            bits += bits / 2;
+        }
+    }
+
+    /// Calculate the tangent function (`tan(x)`) on the floating point number under this context.
+    ///
+    /// The result is correctly rounded, even for huge arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.tan(&a.repr()), Inexact(DBig::from_str_native("2.9")?, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn tan<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if x.is_zero() {
+            return Exact(FBig::ZERO);
+        }
+
+        let mut bits = self.trig_fixed_bits(x);
+        loop {
+            let SinCosBounds { sin, cos } = SinCosBounds::new(x, bits);
+            if let Some((lo, hi)) = div_fixed_bounds(sin, cos, bits) {
+                if let Some(v) = self.round_fixed_bounds(lo, hi, bits) {
+                    return v;
+                }
+            }
+            bits += bits / 2;
+        }
+    }
+
+    /// Calculate the sine and the cosine of the floating point number under this context.
+    ///
+    /// The argument reduction is shared by both functions, so it's faster than calling
+    /// [sin][Self::sin] and [cos][Self::cos] separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.sin_cos(&a.repr()), (
+    ///     Inexact(DBig::from_str_native("0.94")?, NoOp),
+    ///     Inexact(DBig::from_str_native("0.33")?, NoOp),
+    /// ));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn sin_cos<const B: Word>(
+        &self,
+        x: &Repr<B>,
+    ) -> (Rounded<FBig<R, B>>, Rounded<FBig<R, B>>) {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        if x.is_zero() {
+            return (Exact(FBig::ZERO), Exact(FBig::ONE));
+        }
+
+        let mut bits = self.trig_fixed_bits(x);
+        let (mut sin, mut cos) = (None, None);
+        loop {
+            let bounds = SinCosBounds::new(x, bits);
+            if sin.is_none() {
+                let (lo, hi) = bounds.sin;
+                sin = self.round_fixed_bounds(lo, hi, bits);
+            }
+            if cos.is_none() {
+                let (lo, hi) = bounds.cos;
+                cos = self.round_fixed_bounds(lo, hi, bits);
+            }
+            match (sin, cos) {
+                (Some(s), Some(c)) => return (s, c),
+                (s, c) => (sin, cos) = (s, c),
+            }
+            bits += bits / 2;
+        }
+    }
+
+    /// Calculate `num * π / 2^log_den` correctly rounded under this context.
+    fn pi_multiple<const B: Word>(&self, num: i8, log_den: usize) -> Rounded<FBig<R, B>> {
+        let mut bits = self.fixed_bits::<B>();
+        loop {
+            let n = Constant::Pi.fixed(bits) * num;
+            let err = 2 * num.unsigned_abs();
+            if let Some(v) = self.round_fixed_bounds(&n - err, n + err, bits + log_den) {
+                return v;
+            }
+            bits += bits / 2;
+        }
+    }
+
+    /// Calculate the arcsine function (`asin(x)`) on the floating point number under this context.
+    ///
+    /// The result is correctly rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("0.1234")?;
+    /// assert_eq!(context.asin(&a.repr()), Inexact(DBig::from_str_native("0.12")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not in the range `[-1, 1]`.
+    pub fn asin<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_unit_domain(x);
+        check_precision_limited(self.precision);
+
+        if x.is_zero() {
+            return Exact(FBig::ZERO);
+        } else if x.is_one() {
+            return self.pi_multiple(1, 1);
+        } else if *x == Repr::neg_one() {
+            return self.pi_multiple(-1, 1);
+        }
+
+        let mut bits = self.trig_fixed_bits(x);
+        loop {
+            if let Some((lo, hi)) = asin_fixed_bounds(x, bits) {
+                if let Some(v) = self.round_fixed_bounds(lo, hi, bits) {
+                    return v;
+                }
+            }
+            bits += bits / 2;
+        }
+    }
+
+    /// Calculate the arccosine function (`acos(x)`) on the floating point number under this context.
+    ///
+    /// The result is correctly rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("0.1234")?;
+    /// assert_eq!(context.acos(&a.repr()), Inexact(DBig::from_str_native("1.4")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not in the range `[-1, 1]`.
+    pub fn acos<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_unit_domain(x);
+        check_precision_limited(self.precision);
+
+        if x.is_zero() {
+            return self.pi_multiple(1, 1);
+        } else if x.is_one() {
+            return Exact(FBig::ZERO);
+        } else if *x == Repr::neg_one() {
+            return self.pi_multiple(1, 0);
+        }
+
+        let mut bits = self.fixed_bits::<B>();
+        loop {
+            // acos(x) = π/2 - asin(x)
+            if let Some((lo, hi)) = asin_fixed_bounds(x, bits) {
+                let half_pi = Constant::Pi.fixed(bits) >> 1;
+                let (lo, hi) = (&half_pi - hi - 2u8, half_pi - lo + 2u8);
+                if let Some(v) = self.round_fixed_bounds(lo, hi, bits) {
+                    return v;
+                }
+            }
+            bits += bits / 2;
+        }
+    }
+
+    /// Calculate the arctangent function (`atan(x)`) on the floating point number under this context.
+    ///
+    /// The result is correctly rounded. The arctangent of the infinities are ±π/2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.atan(&a.repr()), Inexact(DBig::from_str_native("0.89")?, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn atan<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_precision_limited(self.precision);
+
+        if x.is_infinite() {
+            return self.pi_multiple(x.sign() * 1i8, 1);
+        } else if x.is_zero() {
+            return Exact(FBig::ZERO);
+        }
+
+        let mut bits = self.trig_fixed_bits(x);
+        loop {
+            let t = repr_to_fixed(x, bits);
+            let (lo, hi) = atan_fixed_bounds((&t - IBig::ONE, t + IBig::ONE), bits);
+            if let Some(v) = self.round_fixed_bounds(lo, hi, bits) {
+                return v;
+            }
+            bits += bits / 2;
+        }
+    }
+
+    /// Calculate the four quadrant arctangent of `y` and `x` under this context.
+    ///
+    /// The result is the angle of the point `(x, y)` in the range `[-π, π]`, correctly rounded.
+    /// The special cases are handled as follows:
+    /// * `atan2(0, 0) = 0`, `atan2(0, x) = π` for `x < 0`
+    /// * `atan2(±∞, x) = ±π/2` for finite `x`
+    /// * `atan2(y, +∞) = 0`, `atan2(y, -∞) = ±π` for finite `y`
+    /// * `atan2(±∞, +∞) = ±π/4`, `atan2(±∞, -∞) = ±3π/4`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let y = DBig::from_str_native("1.234")?;
+    /// let x = DBig::from_str_native("-5.678")?;
+    /// assert_eq!(context.atan2(&y.repr(), &x.repr()), Inexact(DBig::from_str_native("2.9")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn atan2<const B: Word>(&self, y: &Repr<B>, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_precision_limited(self.precision);
+
+        // the sign of y is considered positive when y is zero
+        let ysign = y.sign() * 1i8;
+        match (y.is_infinite(), x.is_infinite()) {
+            (true, true) => {
+                return match x.sign() {
+                    Sign::Positive => self.pi_multiple(ysign, 2),
+                    Sign::Negative => self.pi_multiple(3 * ysign, 2),
+                }
+            }
+            (true, false) => return self.pi_multiple(ysign, 1),
+            (false, true) => {
+                return match x.sign() {
+                    Sign::Positive => Exact(FBig::ZERO),
+                    Sign::Negative => self.pi_multiple(ysign, 0),
+                }
+            }
+            (false, false) => {}
+        }
+        if x.is_zero() {
+            return match y.is_zero() {
+                true => Exact(FBig::ZERO),
+                false => self.pi_multiple(ysign, 1),
+            };
+        } else if y.is_zero() {
+            return match x.sign() {
+                Sign::Positive => Exact(FBig::ZERO),
+                Sign::Negative => self.pi_multiple(1, 0),
+            };
+        }
+
+        // more bits are required when the result is small
+        let small_bits = (x.log2_bounds().0 - y.log2_bounds().1).max(0.) as usize;
+        let mut bits = self.fixed_bits::<B>() + small_bits;
+        loop {
+            let (lo, hi) = atan_fixed_bounds(ratio_to_fixed_bounds(y, x, bits), bits);
+
+            // adjust the result by ±π when x < 0
+            let (lo, hi) = match x.sign() {
+                Sign::Positive => (lo, hi),
+                Sign::Negative => {
+                    let pi = Constant::Pi.fixed(bits);
+                    match ysign {
+                        1 => (lo + &pi - 2u8, hi + pi + 2u8),
+                        _ => (lo - &pi - 2u8, hi - pi + 2u8),
+                    }
+                }
+            };
+            if let Some(v) = self.round_fixed_bounds(lo, hi, bits) {
+                return v;
+            }
+            bits += bits / 2;
+        }
+    }
+}
+
+/// Panics if the number is not in the range [-1, 1]
+fn check_unit_domain<const B: Word>(x: &Repr<B>) {
+    if x.is_infinite()
+        || repr_cmp(x, &Repr::one(), None) == Ordering::Greater
+        || repr_cmp(x, &Repr::neg_one(), None) == Ordering::Less
+    {
+        panic_out_of_domain()
+    }
+}
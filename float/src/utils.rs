@@ -1,3 +1,4 @@
+use crate::repr::Repr;
 use dashu_base::{DivRem, Sign};
 use dashu_int::{DoubleWord, IBig, UBig, Word};
 
@@ -90,6 +91,10 @@ fn split_bits_ref(value: &IBig, n: usize) -> (IBig, IBig) {
 
     let (sign, words) = value.as_sign_words();
     let n_words = n / Word::BITS as usize;
+    if n_words >= words.len() {
+        // all the bits are in the low part
+        return (IBig::ZERO, value.clone());
+    }
 
     let mut hi = UBig::from_words(&words[n_words..]);
     hi >>= n % Word::BITS as usize;
@@ -142,6 +147,26 @@ pub fn split_digits<const B: Word>(value: IBig, pos: usize) -> (IBig, IBig) {
     }
 }
 
+/// The position of the most significant digit (exclusive) of the number, that is
+/// [Repr::top], except that zero is treated as the lowest (`isize::MIN`) instead of `0`.
+#[inline]
+pub fn top_or_min<const B: Word>(repr: &Repr<B>) -> isize {
+    if repr.is_zero() {
+        isize::MIN
+    } else {
+        repr.top()
+    }
+}
+
+/// The number of digits that covers 64 bits. When the smaller operand of an addition is
+/// replaced by its sign, it's kept below this number of digits after the rounding position, so
+/// that the replacement is negligible even for the stochastic rounding.
+#[inline]
+pub const fn sticky_digits<const B: Word>() -> usize {
+    let bits = (Word::BITS - 1 - B.leading_zeros()) as usize; // floor(log2(B))
+    (64 + bits - 1) / bits
+}
+
 /// If n is a power of base, then return the exponent,
 /// otherwise return 0.
 pub const fn ilog_exact(n: Word, base: Word) -> u32 {
@@ -0,0 +1,492 @@
+//! Implementation of the Riemann zeta function and the polylogarithm
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::{
+    cmp::repr_cmp,
+    error::{check_inf, check_precision_limited, panic_out_of_domain},
+    fbig::FBig,
+    gamma::{rational_sum, split_nearest, tangent_numbers},
+    repr::{Context, Repr, Word},
+    round::{Round, Rounded},
+    utils::{shl_digits, top_or_min},
+};
+use dashu_base::{Abs, Approximation::*, EstimatedLog2, RemEuclid, Sign, UnsignedAbs};
+use dashu_int::IBig;
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Calculate the Riemann zeta function (`ζ(s)`) on the float number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.zeta(), DBig::from_str_native("4.867")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is 1.
+    #[inline]
+    pub fn zeta(&self) -> Self {
+        self.context.zeta(&self.repr).value()
+    }
+
+    /// Calculate the polylogarithm of integer order (`Liₙ(x)`) on the float number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("0.1234")?;
+    /// assert_eq!(a.polylog(2), DBig::from_str_native("0.12743")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not in the domain of
+    /// the function, that is when `x > 1` and `n >= 1`, or when `x = 1` and `n <= 1`.
+    #[inline]
+    pub fn polylog(&self, n: isize) -> Self {
+        self.context.polylog(n, &self.repr).value()
+    }
+}
+
+/// Compare the absolute value of the number with 1/2
+fn abs_cmp_half<const B: Word>(x: &Repr<B>) -> Ordering {
+    let double = Repr::<B>::new(x.significand.clone().abs() * 2u8, x.exponent);
+    repr_cmp(&double, &Repr::one(), None)
+}
+
+/// Calculate the Eulerian numbers A(m, 0), A(m, 1), ..., A(m, m-1) (with A(0, 0) = 1)
+fn eulerian_numbers(m: usize) -> Vec<IBig> {
+    let mut a = Vec::with_capacity(m.max(1));
+    a.push(IBig::ONE);
+    for j in 2..=m {
+        // A(j, i) = (i+1)A(j-1, i) + (j-i)A(j-1, i-1)
+        a.push(IBig::ZERO);
+        for i in (1..j).rev() {
+            a[i] = &a[i] * IBig::from(i + 1) + &a[i - 1] * IBig::from(j - i);
+        }
+    }
+    a
+}
+
+impl<R: Round> Context<R> {
+    /// Calculate `(k+1)⁻ˢ` under this context
+    fn inv_pow<const B: Word>(&self, k: usize, s: &Repr<B>, s_int: Option<usize>) -> FBig<R, B> {
+        if let Some(s) = s_int {
+            let pow = Repr::new(IBig::from(k + 1).pow(s), 0);
+            self.div(&Repr::one(), &pow).value()
+        } else {
+            let s = FBig::new(self.repr_round_ref(s).value(), *self);
+            let ln = self.ln(&Repr::new(IBig::from(k + 1), 0)).value();
+            self.exp(&(-s * ln).repr).value()
+        }
+    }
+
+    /// Evaluate the Dirichlet eta function `η(s) = Σ (-1)ᵏ/(k+1)ˢ` for `s > 0`.
+    ///
+    /// Reference: Cohen, Rodriguez Villegas and Zagier, Convergence acceleration of alternating series.
+    fn eta<const B: Word>(&self, s: &Repr<B>) -> FBig<R, B> {
+        // the error is less than 2/(3+√8)ⁿ, and η(s) > 1/2 for s > 0
+        let bits = (self.precision as f32 * B.log2_bounds().1) as usize + 2;
+        let n = (bits as f32 / 2.54) as usize + 1;
+
+        // use exact powers when the exponent is a small integer
+        let s_int = if s.exponent >= 0 {
+            usize::try_from(shl_digits::<B>(&s.significand, s.exponent as usize))
+                .ok()
+                .filter(|&s| s <= bits)
+        } else {
+            None
+        };
+
+        // d = ((3+√8)ⁿ + (3-√8)ⁿ)/2, which satisfies dₙ₊₁ = 6dₙ - dₙ₋₁
+        let (mut d, mut prev) = (IBig::from(3u8), IBig::ONE);
+        for _ in 1..n {
+            let next = &d * 6u8 - prev;
+            prev = d;
+            d = next;
+        }
+
+        let mut b = -IBig::ONE;
+        let mut c = -d.clone();
+        let mut sum = FBig::new(Repr::zero(), *self);
+        for k in 0..n {
+            c = &b - c;
+            sum += self.inv_pow(k, s, s_int) * &c;
+            b = b * IBig::from(k + n) * (IBig::from(k) - IBig::from(n)) * 2u8
+                / IBig::from((2 * k + 1) * (k + 1));
+        }
+        sum / d
+    }
+
+    /// Evaluate `ζ(2k)` given the tangent number `Tₖ`, using `ζ(2k) = kTₖπ²ᵏ/((2k)!(4ᵏ-1))`
+    fn zeta_even<const B: Word>(&self, k: usize, tangent: &IBig) -> FBig<R, B> {
+        let num = Repr::new(tangent * IBig::from(k), 0);
+        let fact = (2..=2 * k).fold(IBig::ONE, |acc, i| acc * IBig::from(i));
+        let den = Repr::new(fact * ((IBig::ONE << (2 * k)) - IBig::ONE), 0);
+        let pi2k = self.pi::<B>().value().powi((2 * k).into());
+        self.div(&num, &den).value() * pi2k
+    }
+
+    /// Evaluate `ζ(s)` for `s > 0` and `s != 1`, using `ζ(s) = η(s)/(1 - 2¹⁻ˢ)`
+    fn zeta_positive<const B: Word>(&self, s: &Repr<B>) -> FBig<R, B> {
+        // 1 - 2¹⁻ˢ = -(exp((1-s)log2) - 1) doesn't suffer from cancellation when s is close to 1
+        let t = Context::<R>::new(0).sub(&Repr::one(), s).value();
+        let arg = FBig::new(self.repr_round(t.repr).value(), *self) * self.ln2::<B>().value();
+        let den = -self.exp_m1(&arg.repr).value();
+        self.eta(s) / den
+    }
+
+    /// Evaluate `ζ(s)` for `s != 1` which is not a trivial zero
+    fn zeta_terms<const B: Word>(&self, s: &Repr<B>) -> FBig<R, B> {
+        if s.sign() == Sign::Positive {
+            return self.zeta_positive(s);
+        }
+
+        // functional equation: ζ(s) = 2ˢπˢ⁻¹sin(πs/2)Γ(1-s)ζ(1-s), where sin(πs/2) is
+        // evaluated with the reduced argument s - n, the nearest integer n to s
+        let one_minus_s = Context::<R>::new(0).sub(&Repr::one(), s).value().repr;
+        let gamma = self.gamma(&one_minus_s).value();
+        let zeta = self.zeta_positive(&one_minus_s);
+
+        let (n, rem) = split_nearest(s);
+        let pi = self.pi::<B>().value();
+        let arg = FBig::new(self.repr_round(rem).value(), *self) * &pi / 2u8;
+        let (sin, cos) = self.sin_cos(&arg.repr);
+        let (sin, cos) = (sin.value(), cos.value());
+        let sin = match u8::try_from(n.rem_euclid(IBig::from(4u8))).unwrap() {
+            0 => sin,
+            1 => cos,
+            2 => -sin,
+            _ => -cos,
+        };
+
+        let s = FBig::new(self.repr_round_ref(s).value(), *self);
+        let ln_2pi = self.ln(&(&pi * 2u8).repr).value();
+        let scale = self.exp(&(s * ln_2pi).repr).value() / pi;
+        scale * sin * gamma * zeta
+    }
+
+    /// Evaluate `ζ(n)` for integer `n >= 2`, where `tangents` contains the tangent numbers
+    /// up to at least `T_{n/2}`
+    fn zeta_integer<const B: Word>(&self, n: usize, tangents: &[IBig]) -> FBig<R, B> {
+        if n % 2 == 0 {
+            self.zeta_even(n / 2, &tangents[n / 2 - 1])
+        } else {
+            self.zeta_positive(&Repr::new(n.into(), 0))
+        }
+    }
+
+    /// Evaluate `Liₙ(x)` for `|x| <= 1/2` (or large n) by the series `Σ xᵏ/kⁿ`
+    fn polylog_series<const B: Word>(&self, n: usize, x: &Repr<B>) -> FBig<R, B> {
+        let x = FBig::new(self.repr_round_ref(x).value(), *self);
+        let mut pow = x.clone();
+        let mut sum = x.clone();
+        for k in 2usize.. {
+            pow *= &x;
+            let term = &pow / IBig::from(k).pow(n);
+            if term.repr.is_zero() || term.repr.top() < sum.repr.top() - self.precision as isize {
+                break;
+            }
+            sum += term;
+        }
+        sum
+    }
+
+    /// Evaluate `Liₙ(x)` for `1/2 < x < 1`, return the value and the position of its largest term.
+    fn polylog_near_one<const B: Word>(&self, n: usize, x: &Repr<B>) -> (FBig<R, B>, isize) {
+        // Liₙ(eᵘ) = Σ ζ(n-k)μᵏ/k! + μⁿ⁻¹/(n-1)!·(Hₙ₋₁ - log(-μ)) where the term k = n-1 is
+        // excluded from the sum, and the terms decrease as fast as (μ/2π)ᵏ
+        let mu = self.ln(x).value();
+        let bits = (self.precision as f32 * B.log2_bounds().1) as usize;
+        let tangents = tangent_numbers((bits / 6 + 4).max(n / 2));
+        let limit = -(self.precision as isize) - 1;
+
+        let mut sum = FBig::new(Repr::zero(), *self);
+        let mut max_top = isize::MIN;
+        let mut pow = FBig::new(Repr::one(), *self); // μᵏ/k!
+        for k in 0usize.. {
+            if k > 0 {
+                pow *= &mu;
+                pow /= k;
+            }
+
+            let term = if k + 1 < n {
+                &pow * self.zeta_integer(n - k, &tangents)
+            } else if k + 1 == n {
+                let (num, den) = rational_sum(1, 1..n);
+                let harmonic = self.div(&Repr::new(num, 0), &Repr::new(den, 0)).value();
+                &pow * (harmonic - self.ln(&(-&mu).repr).value())
+            } else if k == n {
+                // ζ(0) = -1/2
+                -&pow / 2u8
+            } else if (k - n) % 2 == 0 {
+                // ζ(-2i) = 0
+                continue;
+            } else {
+                // ζ(1-2i) = (-1)ⁱTᵢ/(4ⁱ(4ⁱ-1))
+                let i = (k - n + 1) / 2;
+                if i > tangents.len() {
+                    break;
+                }
+                let four_i = IBig::ONE << (2 * i);
+                let den = Repr::new((&four_i - IBig::ONE) * four_i, 0);
+                let zeta = self
+                    .div(&Repr::new(tangents[i - 1].clone(), 0), &den)
+                    .value();
+                let term = &pow * zeta;
+                if i % 2 == 1 {
+                    -term
+                } else {
+                    term
+                }
+            };
+
+            if k > n && top_or_min(&term.repr) < limit {
+                break;
+            }
+            max_top = max_top.max(top_or_min(&term.repr));
+            sum += term;
+        }
+        (sum, max_top)
+    }
+
+    /// Evaluate `Liₙ(x)` for `x < -1`, return the value and the position of its largest term.
+    fn polylog_inversion<const B: Word>(&self, n: usize, x: &Repr<B>) -> (FBig<R, B>, isize) {
+        // Liₙ(-y) = -(-1)ⁿLiₙ(-1/y) - logⁿ(y)/n! + 2Σ logⁿ⁻²ᵏ(y)/(n-2k)!·Li₂ₖ(-1) for k in 1..=n/2,
+        // where Li₂ₖ(-1) = (2¹⁻²ᵏ - 1)ζ(2k)
+        let y = -x.clone();
+        let inv = self.repr_div(Repr::neg_one(), &y).value();
+        let (inner, inner_top) = self.polylog_terms(n, &inv);
+        let inner = if n % 2 == 0 { -inner } else { inner };
+
+        // the powers of log(y) divided by the factorials
+        let ln_y = self.ln(&y).value();
+        let mut ln_pows = Vec::with_capacity(n + 1);
+        ln_pows.push(FBig::new(Repr::one(), *self));
+        for j in 1..=n {
+            let next = &ln_pows[j - 1] * &ln_y / j;
+            ln_pows.push(next);
+        }
+
+        let mut sum = -ln_pows[n].clone();
+        let mut max_top = top_or_min(&sum.repr).max(inner_top);
+        for (k, t) in (1..=n / 2).zip(tangent_numbers(n / 2)) {
+            let pow2 = self.div(&Repr::one(), &Repr::new(IBig::ONE << (2 * k - 1), 0));
+            let li = (pow2.value() - 1u8) * self.zeta_even(k, &t);
+            let term = &ln_pows[n - 2 * k] * li * 2u8;
+            max_top = max_top.max(top_or_min(&term.repr));
+            sum += term;
+        }
+        (sum + inner, max_top)
+    }
+
+    /// Evaluate `Liₙ(x)` for `n >= 2` and `x <= 1`, return the value and the position of its largest term.
+    fn polylog_terms<const B: Word>(&self, n: usize, x: &Repr<B>) -> (FBig<R, B>, isize) {
+        if x == &Repr::one() {
+            let zeta = self.zeta_integer(n, &tangent_numbers(n / 2));
+            let zeta_top = top_or_min(&zeta.repr);
+            return (zeta, zeta_top);
+        }
+
+        // the series converges fast enough when |x| <= 1/2, or when 2⁻ⁿ is small enough
+        let bits = (self.precision as f32 * B.log2_bounds().1) as usize;
+        if abs_cmp_half(x) != Ordering::Greater || 2 * n >= bits {
+            return (self.polylog_series(n, x), top_or_min(x));
+        }
+        if x.sign() == Sign::Positive {
+            return self.polylog_near_one(n, x);
+        }
+        if repr_cmp(x, &Repr::neg_one(), None) == Ordering::Less {
+            return self.polylog_inversion(n, x);
+        }
+
+        // duplication formula: Liₙ(-y) = 2¹⁻ⁿLiₙ(y²) - Liₙ(y)
+        let y = -x.clone();
+        let y2 = Context::<R>::new(0).mul(&y, &y).value().repr;
+        let (li_y2, top_y2) = self.polylog_terms(n, &y2);
+        let (li_y, top_y) = self.polylog_terms(n, &y);
+        let pow2 = self.div(&Repr::one(), &Repr::new(IBig::ONE << (n - 1), 0));
+        let li_y2 = li_y2 * pow2.value();
+        let max_top = top_or_min(&li_y2.repr)
+            .max(top_y2 - (n - 1) as isize)
+            .max(top_y);
+        (li_y2 - li_y, max_top)
+    }
+
+    /// Evaluate `Li₋ₘ(x) = x·Aₘ(x)/(1-x)ᵐ⁺¹` as a rational function, where `Aₘ` is the Eulerian polynomial
+    fn polylog_rational<const B: Word>(&self, m: usize, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        // x = a/b
+        let (a, b) = if x.exponent >= 0 {
+            (shl_digits::<B>(&x.significand, x.exponent as usize), IBig::ONE)
+        } else {
+            let b = Repr::<B>::BASE.pow((-x.exponent) as usize);
+            (x.significand.clone(), b)
+        };
+
+        // b^d·Aₘ(a/b) where d = deg(Aₘ), evaluated by the Horner's method
+        let euler = eulerian_numbers(m);
+        let d = euler.len() - 1;
+        let mut poly = euler[d].clone();
+        let mut b_pow = b.clone();
+        for coeff in euler[..d].iter().rev() {
+            poly = poly * &a + coeff * &b_pow;
+            b_pow *= &b;
+        }
+
+        let num = a.clone() * poly * b.pow(m - d);
+        let den = (b - a).pow(m + 1);
+        self.repr_div(Repr::new(num, 0), &Repr::new(den, 0))
+            .map(|v| FBig::new(v, *self))
+    }
+
+    /// Calculate the Riemann zeta function (`ζ(s)`) on the float number under this context.
+    ///
+    /// The result is exact if the input is a non-positive integer and the result can
+    /// be represented under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("-1.234")?;
+    /// assert_eq!(context.zeta(&a.repr()), Inexact(DBig::from_str_native("-0.051")?, SubOne));
+    /// let b = DBig::from_str_native("-4")?;
+    /// assert_eq!(context.zeta(&b.repr()), Exact(DBig::from_str_native("0")?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is 1.
+    pub fn zeta<const B: Word>(&self, s: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(s);
+        check_precision_limited(self.precision);
+        if s.is_zero() {
+            // ζ(0) = -1/2
+            return self
+                .repr_div(Repr::neg_one(), &Repr::new(2.into(), 0))
+                .map(|v| FBig::new(v, *self));
+        }
+        if s == &Repr::one() {
+            panic_out_of_domain()
+        }
+
+        // ζ(s) = 1 + 2⁻ˢ + 3⁻ˢ + ..., where the sum of the terms except the first one is
+        // less than 2¹⁻ˢ, and it doesn't affect the rounding except for its sign if s - 1 > (p+2)log₂B
+        let digits = self.precision + 2;
+        let bound = digits * (B.log2_bounds().1 as usize + 1) + 1;
+        if repr_cmp(s, &Repr::new(bound.into(), 0), None) == Ordering::Greater {
+            return self.round_with_tail(&Repr::one(), Sign::Positive, digits);
+        }
+
+        let (n, rem) = split_nearest(s);
+        if rem.is_zero() {
+            let odd = (&n).unsigned_abs().bit(0);
+            if n < IBig::ZERO && !odd {
+                // trivial zeros: ζ(-2k) = 0
+                return Exact(FBig::ZERO);
+            } else if n < IBig::ZERO {
+                // ζ(1-2k) = (-1)ᵏTₖ/(4ᵏ(4ᵏ-1))
+                if let Some(k) = self.small_int::<B>(&((IBig::ONE - n) / 2u8)) {
+                    let tangent = tangent_numbers(k).pop().unwrap();
+                    let tangent = if k % 2 == 1 { -tangent } else { tangent };
+                    let four_k = IBig::ONE << (2 * k);
+                    let den = Repr::new((&four_k - IBig::ONE) * four_k, 0);
+                    return self
+                        .repr_div(Repr::new(tangent, 0), &den)
+                        .map(|v| FBig::new(v, *self));
+                }
+            } else if !odd {
+                // ζ(2k) = kTₖπ²ᵏ/((2k)!(4ᵏ-1))
+                if let Some(k) = self.small_int::<B>(&(n / 2u8)) {
+                    let tangent = tangent_numbers(k).pop().unwrap();
+                    return self.round_ziv(|c| c.zeta_even(k, &tangent));
+                }
+            }
+        }
+
+        self.round_ziv(|c| {
+            c.eval_cancelling(true, |c| {
+                let zeta = c.zeta_terms(s);
+                let zeta_top = top_or_min(&zeta.repr);
+                (zeta, zeta_top)
+            })
+        })
+    }
+
+    /// Calculate the polylogarithm of integer order (`Liₙ(x)`) on the float number under this context.
+    ///
+    /// For `n <= 0`, the function is a rational function of `x`, and the result is exact if
+    /// it can be represented under this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("-1.234")?;
+    /// assert_eq!(context.polylog(3, &a.repr()), Inexact(DBig::from_str_native("-1.1")?, SubOne));
+    /// let b = DBig::from_str_native("0.5")?;
+    /// assert_eq!(context.polylog(-1, &b.repr()), Exact(DBig::from_str_native("2")?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or the number is not in the domain of
+    /// the function, that is when `x > 1` and `n >= 1`, or when `x = 1` and `n <= 1`.
+    pub fn polylog<const B: Word>(&self, n: isize, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+        let x_cmp_one = repr_cmp(x, &Repr::one(), None);
+        if x_cmp_one == Ordering::Equal && n <= 1 || x_cmp_one == Ordering::Greater && n >= 1 {
+            panic_out_of_domain()
+        }
+        if x.is_zero() {
+            return Exact(FBig::ZERO);
+        }
+        if n <= 0 {
+            return self.polylog_rational(n.unsigned_abs(), x);
+        }
+        if x_cmp_one == Ordering::Equal {
+            return self.zeta(&Repr::new(n.into(), 0));
+        }
+
+        // Liₙ(x) = x + x²/2ⁿ + ..., where the sum of the terms except the first one is positive
+        // and less than 2|x|²/2ⁿ when |x| <= 1 (|x| <= 1/2 if n = 1), so it doesn't affect the
+        // rounding except for its sign if 2|x|/2ⁿ < B^-(p+2)
+        let digits = self.precision + 2;
+        let log2_tail = x.log2_bounds().1 + 1. - n as f32;
+        if log2_tail < -(digits as f32) * B.log2_bounds().1
+            && repr_cmp(x, &Repr::neg_one(), None) != Ordering::Less
+        {
+            return self.round_with_tail(x, Sign::Positive, digits);
+        }
+
+        if n == 1 {
+            // Li₁(x) = -log(1-x)
+            let neg_x = -x.clone();
+            return self.round_ziv(|c| -c.ln_1p(&neg_x).value());
+        }
+        self.round_ziv(|c| c.eval_cancelling(true, |c| c.polylog_terms(n as usize, x)))
+    }
+}
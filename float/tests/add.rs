@@ -2,8 +2,15 @@ use core::{
     fmt::Debug,
     ops::{Add, AddAssign, Sub, SubAssign},
 };
-use dashu_base::Approximation::*;
-use dashu_float::{round::Rounding::*, Context};
+use dashu_base::{Abs, Approximation::*};
+use dashu_float::{
+    round::{
+        mode::{Down, Up},
+        Rounding::*,
+    },
+    Context, FBig, Repr,
+};
+use dashu_int::{IBig, UBig};
 
 mod helper_macros;
 
@@ -267,6 +274,26 @@ fn test_sub_decimal() {
     }
 }
 
+#[test]
+fn test_sub_from_zero() {
+    // the subtrahend is negated before the rounding
+    let context = Context::<Up>::new(2);
+    let diff = context.sub(dbig!(0).repr(), dbig!(1.23).repr());
+    assert_eq!(diff.map(|v| v.with_rounding()), Inexact(dbig!(-1.2), NoOp));
+    let context = Context::<Down>::new(2);
+    let diff = context.sub(dbig!(0).repr(), dbig!(1.23).repr());
+    assert_eq!(diff.map(|v| v.with_rounding()), Inexact(dbig!(-1.3), SubOne));
+}
+
+#[test]
+fn test_neg_infinity() {
+    assert_eq!(-dashu_float::DBig::INFINITY, dashu_float::DBig::NEG_INFINITY);
+    assert_eq!(-dashu_float::DBig::NEG_INFINITY, dashu_float::DBig::INFINITY);
+    assert_eq!(-dashu_float::Repr::<2>::infinity(), dashu_float::Repr::<2>::neg_infinity());
+    assert_eq!(dashu_float::DBig::NEG_INFINITY.abs(), dashu_float::DBig::INFINITY);
+    assert_eq!(dashu_float::DBig::INFINITY.abs(), dashu_float::DBig::INFINITY);
+}
+
 #[test]
 #[should_panic]
 fn test_add_by_inf() {
@@ -303,3 +330,63 @@ fn test_add_sub_unlimited_precision() {
     test_add(&a, &dbig!(1e-4), &a);
     test_add(&a, &b, &dbig!(100000001e-4));
 }
+
+#[test]
+fn test_add_tiny() {
+    // the rounding of a sum with a tiny operand depends on the precision instead of the digits
+    let context = Context::<Up>::new(4);
+    let a = Repr::<2>::new(14.into(), 0);
+    let tiny = Repr::<2>::new(1.into(), -100);
+    let b = Repr::new(15.into(), 0);
+    assert_eq!(context.add(&a, &tiny), Inexact(FBig::from_repr(b.clone(), context), AddOne));
+    assert_eq!(context.add(&tiny, &a), Inexact(FBig::from_repr(b, context), AddOne));
+
+    let context = Context::<Down>::new(3);
+    let a = Repr::<10>::new(12.into(), 0);
+    let tiny = Repr::<10>::new(1.into(), -50);
+    assert_eq!(context.sub(&a, &tiny).value().repr(), &Repr::new(1199.into(), -2));
+    assert_eq!(context.sub(&tiny, &a).value().repr(), &Repr::new((-12).into(), 0));
+}
+
+#[test]
+fn test_sub_cancellation() {
+    // the result is exact even if the cancellation continues into the low part
+    let one = Repr::<2>::one();
+    let a = Repr::<2>::new((IBig::ONE << 300) + 1, -300);
+    let b = Repr::<2>::new(-(IBig::ONE << 300) + 1, -300);
+    let tiny = FBig::from_repr(Repr::new(1.into(), -300), Context::new(20));
+    assert_eq!(Context::<Down>::new(20).sub(&a, &one), Exact(tiny.clone()));
+    assert_eq!(Context::<Down>::new(20).add(&b, &one), Exact(tiny));
+
+    let one = Repr::<10>::one();
+    let pow = IBig::from(10).pow(100);
+    let a = Repr::<10>::new(&pow + 1, -100);
+    let b = Repr::<10>::new(IBig::ONE - &pow, -100);
+    for precision in [10, 23, 37, 60] {
+        let context = Context::<Up>::new(precision);
+        let tiny = FBig::from_repr(Repr::new(1.into(), -100), context);
+        assert_eq!(context.sub(&a, &one), Exact(tiny.clone()));
+        assert_eq!(context.add(&b, &one), Exact(tiny.clone()));
+        assert_eq!(context.sub(&one, &a), Exact(-tiny));
+    }
+}
+
+#[test]
+fn test_add_sub_int() {
+    // the integer operands are not rounded before the operation
+    let one = dbig!(1);
+    let sum = &one + 1000u32;
+    assert_eq!(sum, dbig!(1001));
+    assert_eq!(sum.precision(), 4);
+    assert_eq!(1000u32 + &one, dbig!(1001));
+    assert_eq!(one.clone() - IBig::from(-1000), dbig!(1001));
+    assert_eq!(UBig::from(1000u16) - one.clone(), dbig!(999));
+    assert_eq!(fbig!(1) + 1024i64, fbig!(0x401));
+    assert_eq!(-1024i16 - fbig!(1), fbig!(-0x401));
+
+    let mut a = dbig!(1.500);
+    a += 100u8;
+    assert_eq!(a, dbig!(101.5));
+    a -= 1000i32;
+    assert_eq!(a, dbig!(-898.5));
+}
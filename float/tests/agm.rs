@@ -0,0 +1,158 @@
+use core::str::FromStr;
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DBig,
+};
+
+mod helper_macros;
+
+type HalfAway = Context<mode::HalfAway>;
+
+#[test]
+fn test_agm_binary() {
+    assert_eq!(fbig!(0x1).agm(&fbig!(0)), fbig!(0));
+    assert_eq!(fbig!(0x3p-2).agm(&fbig!(0x3p-2)), fbig!(0x3p-2));
+
+    let inexact_cases = [
+        (fbig!(0x1), fbig!(0x2), fbig!(0xbp-3)),
+        (fbig!(0xcp-4), fbig!(0x1234p-4), fbig!(0xf909p-10)),
+    ];
+    for (a, b, agm) in &inexact_cases {
+        assert_eq!(a.agm(b), *agm);
+        assert_eq!(b.agm(a), *agm);
+        let context = Context::max(a.context(), b.context());
+        if let Inexact(v, _) = context.agm(a.repr(), b.repr()) {
+            assert_eq!(v, *agm);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_agm_decimal() {
+    let context = HalfAway::new(3);
+    assert_eq!(context.agm(dbig!(0).repr(), dbig!(2).repr()), Exact(dbig!(0)));
+    assert_eq!(context.agm(dbig!(1.5).repr(), dbig!(1.5).repr()), Exact(dbig!(1.5)));
+
+    // test cases: a, b, precision, agm(a, b), rounding
+    let inexact_cases = [
+        (dbig!(1), dbig!(2), 3, dbig!(146e-2), AddOne),
+        (dbig!(1), dbig!(1e-100), 4, dbig!(6781e-6), NoOp),
+        (dbig!(1e100), dbig!(1), 4, dbig!(6781e94), NoOp),
+        (dbig!(0.5), dbig!(0.5000001), 7, dbig!(5e-1), NoOp),
+        (dbig!(123.456), dbig!(0.001), 5, dbig!(14792e-3), NoOp),
+        (dbig!(2), dbig!(3), 20, dbig!(24746804362363044626e-19), NoOp),
+    ];
+    for (a, b, precision, agm, rnd) in &inexact_cases {
+        let context = HalfAway::new(*precision);
+        assert_eq!(context.agm(a.repr(), b.repr()), Inexact(agm.clone(), *rnd));
+    }
+
+    // directed rounding
+    let context = Context::<mode::Up>::new(5);
+    let agm = context.agm(dbig!(1).repr(), dbig!(2).repr());
+    assert_eq!(agm.map(|v| v.with_rounding()), Inexact(dbig!(14568e-4), AddOne));
+    let context = Context::<mode::Down>::new(5);
+    let agm = context.agm(dbig!(1).repr(), dbig!(2).repr());
+    assert_eq!(agm.map(|v| v.with_rounding()), Inexact(dbig!(14567e-4), NoOp));
+
+    // high precision, the reciprocal of Gauss's constant
+    let context = HalfAway::new(100);
+    let sqrt2 = context.sqrt(dbig!(2).repr()).value();
+    let gauss = DBig::from_str("1.198140234735592207439922492280323878227212663215651558263674952946405214143915670835885556489793389").unwrap();
+    assert_eq!(context.agm(dbig!(1).repr(), sqrt2.repr()), Inexact(gauss, NoOp));
+}
+
+#[test]
+#[should_panic]
+fn test_agm_negative() {
+    let _ = dbig!(1).agm(&dbig!(-1));
+}
+
+#[test]
+fn test_elliptic_binary() {
+    assert_eq!(fbig!(0x1).elliptic_e(), fbig!(0x1));
+    assert_eq!(fbig!(-0x1).elliptic_e(), fbig!(0x1));
+
+    // test cases: k, K(k), E(k)
+    let inexact_cases = [
+        (fbig!(0x1p-1), fbig!(0xdp-3), fbig!(0xbp-3)),
+        (fbig!(-0xcp-4), fbig!(0xfp-3), fbig!(0x5p-2)),
+        (fbig!(0xffp-8), fbig!(0x3dp-4), fbig!(0x81p-7)),
+        (fbig!(0x1p-50), fbig!(0x3p-1), fbig!(0x3p-1)),
+    ];
+    for (k, elliptic_k, elliptic_e) in &inexact_cases {
+        assert_eq!(k.elliptic_k(), *elliptic_k);
+        assert_eq!(k.elliptic_e(), *elliptic_e);
+        if let Inexact(v, _) = k.context().elliptic_k(k.repr()) {
+            assert_eq!(v, *elliptic_k);
+        } else {
+            panic!("the result should be inexact!")
+        }
+        if let Inexact(v, _) = k.context().elliptic_e(k.repr()) {
+            assert_eq!(v, *elliptic_e);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_elliptic_decimal() {
+    // test cases: k, precision, K(k), rounding of K, E(k), rounding of E
+    let inexact_cases = [
+        (dbig!(0), 3, dbig!(157e-2), NoOp, dbig!(157e-2), NoOp),
+        (dbig!(0.5), 3, dbig!(169e-2), AddOne, dbig!(147e-2), AddOne),
+        (dbig!(-0.5), 3, dbig!(169e-2), AddOne, dbig!(147e-2), AddOne),
+        (dbig!(0.99), 4, dbig!(3357e-3), AddOne, dbig!(1028e-3), NoOp),
+        (dbig!(0.999999), 6, dbig!(794748e-5), AddOne, dbig!(100001e-5), AddOne),
+        (dbig!(1e-20), 5, dbig!(15708e-4), AddOne, dbig!(15708e-4), AddOne),
+        (dbig!(0.1), 2, dbig!(16e-1), AddOne, dbig!(16e-1), AddOne),
+        (
+            dbig!(0.9999999999999999),
+            16,
+            dbig!(1946040151479228e-14),
+            NoOp,
+            dbig!(1000000000000002e-15),
+            AddOne,
+        ),
+    ];
+    for (k, precision, elliptic_k, rnd_k, elliptic_e, rnd_e) in &inexact_cases {
+        let context = HalfAway::new(*precision);
+        assert_eq!(context.elliptic_k(k.repr()), Inexact(elliptic_k.clone(), *rnd_k));
+        assert_eq!(context.elliptic_e(k.repr()), Inexact(elliptic_e.clone(), *rnd_e));
+    }
+
+    // directed rounding
+    let context = Context::<mode::Up>::new(5);
+    let elliptic_k = context.elliptic_k(dbig!(0.75).repr());
+    assert_eq!(elliptic_k.map(|v| v.with_rounding()), Inexact(dbig!(1911e-3), AddOne));
+    let elliptic_e = context.elliptic_e(dbig!(0.75).repr());
+    assert_eq!(elliptic_e.map(|v| v.with_rounding()), Inexact(dbig!(13185e-4), AddOne));
+    let context = Context::<mode::Down>::new(5);
+    let elliptic_k = context.elliptic_k(dbig!(0.75).repr());
+    assert_eq!(elliptic_k.map(|v| v.with_rounding()), Inexact(dbig!(19109e-4), NoOp));
+    let elliptic_e = context.elliptic_e(dbig!(0.75).repr());
+    assert_eq!(elliptic_e.map(|v| v.with_rounding()), Inexact(dbig!(13184e-4), NoOp));
+
+    // high precision
+    let context = HalfAway::new(100);
+    let elliptic_k = DBig::from_str("1.68575035481259604287120365779907698950080089414108904411994829789343370288234676040645097393661257").unwrap();
+    assert_eq!(context.elliptic_k(dbig!(0.5).repr()), Inexact(elliptic_k, NoOp));
+    let elliptic_e = DBig::from_str("1.467462209339427155459795266990916136025361752327231960500790636490824227271290635654038530733504602").unwrap();
+    assert_eq!(context.elliptic_e(dbig!(0.5).repr()), Inexact(elliptic_e, NoOp));
+}
+
+#[test]
+#[should_panic]
+fn test_elliptic_k_out_of_domain() {
+    let _ = dbig!(1).elliptic_k();
+}
+
+#[test]
+#[should_panic]
+fn test_elliptic_e_out_of_domain() {
+    let _ = dbig!(-1.01).elliptic_e();
+}
@@ -1,6 +1,7 @@
 use core::cmp::Ordering;
 
 use dashu_float::DBig;
+use dashu_int::{IBig, UBig};
 type FBig = dashu_float::FBig;
 
 mod helper_macros;
@@ -132,3 +133,92 @@ fn test_cmp_decimal() {
     assert!(dbig!(1234e4) < dbig!(12345678));
     assert!(dbig!(-1234e-4) > dbig!(-12345678e-8));
 }
+
+#[test]
+fn test_cmp_int() {
+    assert_eq!(fbig!(0x1000), IBig::from(0x1000));
+    assert_eq!(IBig::from(0x1000), fbig!(0x1p12));
+    assert_eq!(dbig!(-0e1), 0u8);
+    assert_eq!(dbig!(12e3), UBig::from(12000u32));
+    assert_ne!(dbig!(12e-3), 0i32);
+    assert_ne!(dbig!(-12e3), UBig::from(12000u32));
+
+    assert!(dbig!(1.5) > 1i32);
+    assert!(dbig!(1.5) < 2u64);
+    assert!(dbig!(-1.5) < -1isize);
+    assert!(-2i128 < dbig!(-1.5));
+    assert!(dbig!(1e100) > u128::MAX);
+    assert!(dbig!(-1e-100) < 0u8);
+    assert!(dbig!(-1e-100) > -1i8);
+    assert!(fbig!(0x1p200) > u128::MAX);
+    assert!(fbig!(-0x1p200) < i128::MIN);
+    assert!(DBig::INFINITY > IBig::from(10).pow(1000));
+    assert!(DBig::NEG_INFINITY < -1);
+
+    // the precision doesn't affect the comparison
+    let x = DBig::from_str_native("1.0000000000000000000001").unwrap();
+    let x = x.with_precision(2).value();
+    assert_eq!(x, 1);
+    let y = DBig::from_str_native("1.0000000000000000000001").unwrap();
+    assert!(y > 1u8);
+    assert!(y != IBig::ONE);
+
+    // NaN is greater than any integer, as in the comparison between FBig instances
+    let nan = DBig::NAN;
+    assert_eq!(nan.partial_cmp(&0), Some(Ordering::Greater));
+    assert!(nan != 0);
+    assert!(nan > IBig::from(10).pow(1000));
+    assert!(u8::MAX < nan);
+}
+
+#[test]
+fn test_cmp_float() {
+    assert_eq!(fbig!(0x1p-1), 0.5f32);
+    assert_eq!(0.5f64, dbig!(0.5));
+    assert_eq!(dbig!(0), -0.0f64);
+    assert_eq!(dbig!(-1e2), -100f32);
+    assert_eq!(
+        DBig::from_str_native("0.1000000000000000055511151231257827021181583404541015625").unwrap(),
+        0.1f64
+    );
+
+    // 0.1 is not exactly representable by f64
+    assert_ne!(dbig!(0.1), 0.1f64);
+    assert!(dbig!(0.1) < 0.1f64);
+    assert!(dbig!(0.1) < 0.1f32); // 0.1f32 ≈ 0.10000000149
+    assert!(dbig!(-0.1) > -0.1f32);
+
+    // extreme values
+    assert!(dbig!(1e39) > f32::MAX);
+    assert!(dbig!(1e38) < f32::MAX);
+    assert!(dbig!(1e-46) < f32::from_bits(1));
+    assert!(dbig!(1e-45) < f32::from_bits(1));
+    assert!(dbig!(2e-45) > f32::from_bits(1));
+    assert!(dbig!(1e400) < f64::INFINITY);
+    assert!(dbig!(-1e400) > f64::NEG_INFINITY);
+    assert!(dbig!(1e-400) > 0f64);
+    assert!(dbig!(1e-400) < f64::from_bits(1));
+    assert_eq!(DBig::INFINITY, f64::INFINITY);
+    assert!(DBig::INFINITY > f64::MAX);
+    assert!(FBig::NEG_INFINITY < f32::MIN);
+
+    // the exact comparison with a base coprime to 2
+    type FBase3 = dashu_float::FBig<dashu_float::round::mode::Zero, 3>;
+    let third = FBase3::from_parts(1.into(), -1);
+    assert!(third > 0.333f64);
+    assert!(third < 0.334f64);
+    assert!(third != 1. / 3.);
+    assert_eq!(FBase3::from_parts(3.into(), 2), 27f32);
+    assert_eq!(FBase3::from_parts(IBig::ONE, 30), 3f64.powi(30));
+    // 3^100 is rounded in f64
+    assert_ne!(FBase3::from_parts(IBig::ONE, 100), 3f64.powi(100));
+
+    // NaN is equal to itself and greater than any other values, as in the comparison between
+    // FBig instances
+    assert_eq!(dbig!(1).partial_cmp(&f64::NAN), Some(Ordering::Less));
+    assert_eq!(f32::NAN.partial_cmp(&dbig!(1)), Some(Ordering::Greater));
+    assert_eq!(DBig::NAN.partial_cmp(&f64::INFINITY), Some(Ordering::Greater));
+    assert!(DBig::NAN.eq(&f64::NAN));
+    assert!(!dbig!(1).eq(&f64::NAN));
+    assert_eq!(DBig::INFINITY.partial_cmp(&f32::NAN), Some(Ordering::Less));
+}
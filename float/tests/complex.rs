@@ -0,0 +1,254 @@
+use core::str::FromStr;
+use dashu_float::{round::mode, CBig, DBig};
+use dashu_int::IBig;
+
+mod helper_macros;
+
+type DCBig = CBig<mode::HalfAway, 10>;
+
+fn cbig(s: &str) -> DCBig {
+    DCBig::from_str(s).unwrap()
+}
+
+#[test]
+fn test_parse_and_print() {
+    let cases = [
+        ("1.2+3.4i", dbig!(1.2), dbig!(3.4)),
+        ("-1.2-3.4i", dbig!(-1.2), dbig!(-3.4)),
+        ("1.2e3-4.5e-6i", dbig!(1.2e3), dbig!(-4.5e-6)),
+        ("-1e-3+1e-3i", dbig!(-1e-3), dbig!(1e-3)),
+        ("5", dbig!(5), dbig!(0)),
+        ("-5.6i", dbig!(0), dbig!(-5.6)),
+        ("i", dbig!(0), dbig!(1)),
+        ("-i", dbig!(0), dbig!(-1)),
+        ("2+i", dbig!(2), dbig!(1)),
+        ("2-i", dbig!(2), dbig!(-1)),
+    ];
+    for (s, re, im) in &cases {
+        let z = cbig(s);
+        assert_eq!(z.re(), re);
+        assert_eq!(z.im(), im);
+    }
+
+    // the precision of each part is kept
+    let z = cbig("1.20-3.4i");
+    assert_eq!(z.re().precision(), 3);
+    assert_eq!(z.im().precision(), 2);
+    assert_eq!(z.precision(), 3);
+
+    let z = CBig::<mode::Zero, 2>::from_str("0x1.8p-1-0x3i").unwrap();
+    assert_eq!(z.re(), &fbig!(0x3p-2));
+    assert_eq!(z.im(), &fbig!(-0x3));
+    let z = CBig::<mode::Zero, 2>::from_str("-0x1.ep1+0x1bi").unwrap();
+    assert_eq!(z.re(), &fbig!(-0xfp-2));
+    assert_eq!(z.im(), &fbig!(0x1b));
+
+    for s in ["", "+", "1+2", "1+2j", "1.2.3+4i", "1+2ii"] {
+        assert!(DCBig::from_str(s).is_err());
+    }
+
+    assert_eq!(format!("{}", cbig("1.2+3.4i")), "1.2+3.4i");
+    assert_eq!(format!("{}", cbig("-1.2-3.4i")), "-1.2-3.4i");
+    assert_eq!(format!("{}", cbig("5")), "5+0i");
+    assert_eq!(format!("{}", cbig("-i")), "0-1i");
+    assert_eq!(format!("{:.2}", cbig("1.2+3.4i")), "1.20+3.40i");
+}
+
+#[test]
+fn test_arithmetic() {
+    let x = cbig("1.234-5.678i");
+    let y = cbig("9.876+2.146i");
+    assert_eq!(&x + &y, cbig("11.11-3.532i"));
+    assert_eq!(&x - &y, cbig("-8.642-7.824i"));
+    assert_eq!(&x * &y, cbig("24.37-53.43i"));
+    assert_eq!(&x / &y, cbig("1.954e-5-0.5749i"));
+    assert_eq!(-&x, cbig("-1.234+5.678i"));
+    assert_eq!(x.conj(), cbig("1.234+5.678i"));
+
+    // each part is rounded only once
+    let x3 = x.clone().with_precision(3);
+    assert_eq!(&x3 * &y.clone().with_precision(3), cbig("24.4-53.5i"));
+    assert_eq!(x3.precision(), 3);
+    assert_eq!(cbig("1.234+5.678i") * cbig("5.678+1.234i"), cbig("33.76i"));
+    assert_eq!(
+        (cbig("1.234-5.678i") * cbig("9.876+2.146i")).with_precision(3),
+        cbig("24.4-53.4i")
+    );
+    assert_eq!((&x / &y).with_precision(3), cbig("1.95e-5-0.575i"));
+
+    // division by real or imaginary numbers
+    assert_eq!(&x / cbig("2"), cbig("0.617-2.839i"));
+    assert_eq!(&x / cbig("2i"), cbig("-2.839-0.617i"));
+
+    // exact operations
+    assert_eq!(cbig("1.0+2.0i") * cbig("3.0-4.0i"), cbig("11+2i"));
+    assert_eq!(cbig("11+2.0i") / cbig("3.0-4.0i"), cbig("1+2i"));
+    assert_eq!(DCBig::I * DCBig::I, -DCBig::ONE);
+
+    // assignment
+    let mut z = x.clone();
+    z += &y;
+    z -= &y;
+    assert_eq!(z, x);
+    z *= y.clone();
+    z /= y;
+    assert_eq!(z, cbig("1.234-5.678i"));
+}
+
+#[test]
+fn test_arithmetic_binary() {
+    let x = CBig::from_parts(fbig!(0x1234p-8), fbig!(-0x5678p-12));
+    assert_eq!(x.precision(), 16);
+    assert_eq!(&x * &x, CBig::from_parts(fbig!(0x4b89p-6), fbig!(-0x313p-2)));
+    let y = CBig::from_parts(fbig!(0x3), fbig!(0x1));
+    assert_eq!(&x / &y, CBig::from_parts(fbig!(0x275dp-11), fbig!(-0xdc43p-14)));
+}
+
+#[test]
+fn test_ops_with_real() {
+    let x = cbig("1.234-5.678i");
+    let r = dbig!(2.000);
+    assert_eq!(&x + &r, cbig("3.234-5.678i"));
+    assert_eq!(&r - &x, cbig("0.766+5.678i"));
+    assert_eq!(&x * &r, cbig("2.468-11.36i"));
+    assert_eq!(&x / &r, cbig("0.617-2.839i"));
+    assert_eq!(&r / &cbig("1+1i"), cbig("1-1i"));
+
+    let n = IBig::from(-3);
+    assert_eq!(&x + &n, cbig("-1.766-5.678i"));
+    assert_eq!(&n - &x, cbig("-4.234+5.678i"));
+    assert_eq!(&x * &n, cbig("-3.702+17.03i"));
+    assert_eq!(cbig("3+6i") / n.clone(), cbig("-1-2i"));
+    assert_eq!(n.clone() / cbig("1+1i").with_precision(4), cbig("-1.5+1.5i"));
+
+    let mut z = x.clone();
+    z *= DBig::from_str("-1").unwrap();
+    z += IBig::from(2);
+    assert_eq!(z, cbig("0.766+5.678i"));
+    assert_eq!(DCBig::from(n), cbig("-3"));
+    assert_eq!(DCBig::from(r), cbig("2"));
+}
+
+#[test]
+fn test_abs_arg() {
+    let x = cbig("1.234-5.678i");
+    assert_eq!(x.abs(), dbig!(5.811));
+    assert_eq!(x.arg(), dbig!(-1.357));
+    assert_eq!(cbig("3+4i").with_precision(3).abs(), dbig!(5));
+    assert_eq!(cbig("-1.2").abs(), dbig!(1.2));
+    assert_eq!(cbig("-1.2i").abs(), dbig!(1.2));
+    assert_eq!(cbig("-1.000").arg(), dbig!(3.142));
+    assert_eq!(cbig("-1.000i").arg(), dbig!(-1.571));
+    assert_eq!(cbig("0").with_precision(5).arg(), dbig!(0));
+
+    let x = CBig::from_parts(fbig!(0x1234p-8), fbig!(-0x5678p-12));
+    assert_eq!(x.abs(), fbig!(0x12fdp-8));
+}
+
+#[test]
+fn test_sqrt() {
+    // exact cases
+    assert_eq!(cbig("-5+12i").sqrt(), cbig("2+3i"));
+    assert_eq!(cbig("-5-12i").sqrt(), cbig("2-3i"));
+    assert_eq!(cbig("2.0i").sqrt(), cbig("1+1i"));
+    assert_eq!(cbig("-4.0").sqrt(), cbig("2i"));
+    assert_eq!(cbig("4.0").sqrt(), cbig("2"));
+    assert_eq!(cbig("0.75+1i").with_precision(3).sqrt(), cbig("1+0.5i"));
+
+    // inexact cases
+    assert_eq!(cbig("1.234-5.678i").sqrt(), cbig("1.877-1.513i"));
+    assert_eq!(cbig("-1.234+5.678i").sqrt(), cbig("1.513+1.877i"));
+    assert_eq!(cbig("-2.0000-0.1i").sqrt(), cbig("0.035344-1.4147i"));
+
+    let x = CBig::from_parts(fbig!(0x1234p-8), fbig!(-0x5678p-12));
+    assert_eq!(x.sqrt(), CBig::from_parts(fbig!(0x44ffp-12), fbig!(-0xa069p-16)));
+
+    // directed rounding
+    let z = cbig("-1.234+5.678i");
+    let z_up = CBig::from_parts(
+        z.re().clone().with_rounding::<mode::Up>(),
+        z.im().clone().with_rounding(),
+    );
+    assert_eq!(z_up.sqrt(), cbig("1.513+1.877i"));
+    let z_down = CBig::from_parts(
+        z.re().clone().with_rounding::<mode::Down>(),
+        z.im().clone().with_rounding(),
+    );
+    assert_eq!(z_down.sqrt(), cbig("1.512+1.876i"));
+}
+
+#[test]
+fn test_exp_ln() {
+    assert_eq!(cbig("1.234-5.678i").exp(), cbig("2.825+1.954i"));
+    assert_eq!(cbig("1.0000i").exp(), cbig("0.5403+0.84147i"));
+    assert_eq!(cbig("-2.00+3.14i").exp(), cbig("-0.135+0.000216i"));
+    assert_eq!(cbig("1.000").exp(), cbig("2.718"));
+
+    assert_eq!(cbig("1.234-5.678i").ln(), cbig("1.760-1.357i"));
+    assert_eq!(cbig("0.6+0.8i").with_precision(3).ln(), cbig("0.927i"));
+    assert_eq!(cbig("-1.0000").ln(), cbig("3.1416i"));
+    assert_eq!(cbig("-2.000i").ln(), cbig("0.6931-1.571i"));
+    assert_eq!(cbig("0.001+0.99999i").with_precision(5).ln(), cbig("-9.5e-6+1.5698i"));
+
+    let x = CBig::from_parts(fbig!(0x1234p-8), fbig!(-0x5678p-12));
+    assert_eq!(x.exp(), CBig::from_parts(fbig!(0xc3cbp10), fbig!(0xec4fp10)));
+    assert_eq!(x.ln(), CBig::from_parts(fbig!(0xbc67p-14), fbig!(-0x93c3p-17)));
+
+    // directed rounding
+    let z = cbig("1.234-5.678i");
+    let z_up = CBig::from_parts(
+        z.re().clone().with_rounding::<mode::Up>(),
+        z.im().clone().with_rounding(),
+    );
+    assert_eq!(z_up.exp(), cbig("2.825+1.955i"));
+    assert_eq!(z_up.ln(), cbig("1.760-1.356i"));
+    let z_down = CBig::from_parts(
+        z.re().clone().with_rounding::<mode::Down>(),
+        z.im().clone().with_rounding(),
+    );
+    assert_eq!(z_down.exp(), cbig("2.824+1.954i"));
+    assert_eq!(z_down.ln(), cbig("1.759-1.357i"));
+}
+
+#[test]
+fn test_pow() {
+    let x = cbig("1.234-5.678i");
+    assert_eq!(x.powi(IBig::ZERO), DCBig::ONE);
+    assert_eq!(x.powi(IBig::ONE), x);
+    assert_eq!(x.powi(10.into()), cbig("2.365e7-3.695e7i"));
+    assert_eq!(x.powi((-3).into()), cbig("-0.003052-0.004082i"));
+    assert_eq!(cbig("1.0+1.0i").powi(8.into()), cbig("16"));
+    assert_eq!(DCBig::I.powi(4.into()), DCBig::ONE);
+
+    let y = cbig("9.876+2.146i");
+    assert_eq!(x.pow(&y), cbig("-6.358e8+1.28e8i"));
+    assert_eq!(x.pow(&cbig("3.0")), x.powi(3.into()));
+    assert_eq!(cbig("0").pow(&cbig("1.5+2i")), DCBig::ZERO);
+    let i = cbig("1.000i");
+    assert_eq!(i.pow(&i), cbig("0.2079"));
+}
+
+#[test]
+#[should_panic]
+fn test_ln_zero() {
+    let _ = cbig("0.0").with_precision(3).ln();
+}
+
+#[test]
+#[should_panic]
+fn test_pow_zero_base() {
+    let _ = cbig("0.0").pow(&cbig("-1.5+2i"));
+}
+
+#[test]
+fn test_high_precision() {
+    let x = DCBig::from_parts(
+        DBig::from_str("1.00000000000000000000000000000000000000000000000000").unwrap(),
+        DBig::from_str("2").unwrap(),
+    );
+    let ln = DCBig::from_parts(
+        DBig::from_str("0.804718956217050187300379666613093819762800677134259").unwrap(),
+        DBig::from_str("1.10714871779409050301706546017853704007004764540143").unwrap(),
+    );
+    assert_eq!(x.ln(), ln);
+}
@@ -0,0 +1,131 @@
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context,
+};
+
+mod helper_macros;
+
+type FBig = dashu_float::FBig;
+
+#[test]
+fn test_pi() {
+    let context = Context::<mode::HalfAway>::new(50);
+    assert_eq!(
+        context.pi::<10>(),
+        Inexact(dbig!(31415926535897932384626433832795028841971693993751e-49), NoOp)
+    );
+    let context = Context::<mode::HalfAway>::new(52);
+    assert_eq!(
+        context.pi::<10>(),
+        Inexact(dbig!(3141592653589793238462643383279502884197169399375106e-51), AddOne)
+    );
+
+    let context = Context::<mode::Zero>::new(100);
+    assert_eq!(context.pi::<2>(), Inexact(fbig!(0xc90fdaa22168c234c4c6628b8p-98), NoOp));
+    let context = Context::<mode::Up>::new(100);
+    assert_eq!(
+        context.pi::<2>(),
+        Inexact(fbig!(0xc90fdaa22168c234c4c6628b9p-98).with_rounding(), AddOne)
+    );
+
+    // larger precisions reuse the cached value
+    let context = Context::<mode::Zero>::new(1000);
+    let pi_1000 = context.pi::<10>().value();
+    let context = Context::<mode::Zero>::new(20);
+    assert_eq!(context.pi::<10>().value(), pi_1000.with_precision(20).value());
+}
+
+#[test]
+fn test_e() {
+    let context = Context::<mode::HalfAway>::new(50);
+    assert_eq!(
+        context.e::<10>(),
+        Inexact(dbig!(27182818284590452353602874713526624977572470937000e-49), AddOne)
+    );
+    let context = Context::<mode::Zero>::new(100);
+    assert_eq!(context.e::<2>(), Inexact(fbig!(0xadf85458a2bb4a9aafdc56202p-98), NoOp));
+}
+
+#[test]
+fn test_logarithms() {
+    let context = Context::<mode::HalfAway>::new(50);
+    assert_eq!(
+        context.ln2::<10>(),
+        Inexact(dbig!(69314718055994530941723212145817656807550013436026e-50), AddOne)
+    );
+    assert_eq!(
+        context.ln10::<10>(),
+        Inexact(dbig!(23025850929940456840179914546843642076011014886288e-49), AddOne)
+    );
+
+    let context = Context::<mode::Zero>::new(100);
+    assert_eq!(context.ln2::<2>(), Inexact(fbig!(0xb17217f7d1cf79abc9e3b3980p-100), NoOp));
+}
+
+#[test]
+fn test_sqrt2() {
+    let context = Context::<mode::HalfAway>::new(50);
+    assert_eq!(
+        context.sqrt2::<10>(),
+        Inexact(dbig!(14142135623730950488016887242096980785696718753769e-49), NoOp)
+    );
+    let context = Context::<mode::Zero>::new(100);
+    assert_eq!(context.sqrt2::<2>(), Inexact(fbig!(0xb504f333f9de6484597d89b37p-99), NoOp));
+}
+
+#[test]
+fn test_euler_gamma() {
+    let context = Context::<mode::HalfAway>::new(50);
+    assert_eq!(
+        context.euler_gamma::<10>(),
+        Inexact(dbig!(57721566490153286060651209008240243104215933593992e-50), NoOp)
+    );
+    let context = Context::<mode::HalfAway>::new(200);
+    let gamma = context.euler_gamma::<10>().value();
+    assert_eq!(
+        gamma.with_precision(100).value(),
+        dbig!(5772156649015328606065120900824024310421593359399235988057672348848677267776646709369470632917467495e-100)
+    );
+}
+
+#[test]
+fn test_catalan() {
+    let context = Context::<mode::HalfAway>::new(50);
+    assert_eq!(
+        context.catalan::<10>(),
+        Inexact(dbig!(91596559417721901505460351493238411077414937428167e-50), NoOp)
+    );
+    let context = Context::<mode::HalfAway>::new(200);
+    let catalan = context.catalan::<10>().value();
+    assert_eq!(
+        catalan.with_precision(100).value(),
+        dbig!(9159655941772190150546035149323841107741493742816721342664981196217630197762547694793565129261151062e-100)
+    );
+}
+
+#[test]
+fn test_other_bases() {
+    let context = Context::<mode::Zero>::new(25);
+    let pi16 = context.pi::<16>().value();
+    assert_eq!(pi16.repr().significand(), &ibig!(0x3243f6a8885a308d313198a2e));
+    assert_eq!(pi16.repr().exponent(), -24);
+
+    let pi3 = context.pi::<3>().value();
+    assert_eq!(pi3.repr().significand(), &ibig!(295759518988));
+    assert_eq!(pi3.repr().exponent(), -23);
+}
+
+#[test]
+#[should_panic]
+fn test_const_unlimited_precision() {
+    let _ = Context::<mode::Zero>::new(0).pi::<2>();
+}
+
+#[test]
+fn test_const_in_log_exp() {
+    // the cached constants are shared with the logarithm and exponential functions
+    let x = FBig::from(2).with_precision(200).value();
+    let context = Context::<mode::Zero>::new(200);
+    assert_eq!(x.ln(), context.ln2::<2>().value());
+}
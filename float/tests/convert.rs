@@ -5,7 +5,7 @@ use dashu_float::{
         mode::{HalfAway, Zero},
         Rounding::*,
     },
-    DBig, FBig,
+    Context, DBig, FBig,
 };
 
 mod helper_macros;
@@ -207,8 +207,9 @@ fn test_from_f32() {
     assert_eq!(FBin::try_from(12.34f32).unwrap(), fbig!(0x315c29p-18));
     assert_eq!(FBin::try_from(-12.34f32).unwrap(), fbig!(-0x315c29p-18));
     assert_eq!(1e-40_f32.to_bits(), 0x000116c2); // subnormal
-    assert_eq!(FBin::try_from(1e-40_f32).unwrap(), fbig!(0x116c2p-126));
-    assert_eq!(FBin::try_from(-1e-40_f32).unwrap(), fbig!(-0x116c2p-126));
+    assert_eq!(FBin::try_from(1e-40_f32).unwrap(), fbig!(0x116c2p-149));
+    assert_eq!(FBin::try_from(-1e-40_f32).unwrap(), fbig!(-0x116c2p-149));
+    assert_eq!(FBin::try_from(f32::from_bits(1)).unwrap(), fbig!(0x1p-149));
     assert_eq!(FBin::try_from(f32::INFINITY).unwrap(), FBin::INFINITY);
     assert_eq!(FBin::try_from(f32::NEG_INFINITY).unwrap(), FBin::NEG_INFINITY);
     assert!(FBin::try_from(f32::NAN).is_err());
@@ -226,8 +227,9 @@ fn test_from_f64() {
     assert_eq!(FBin::try_from(12.34f64).unwrap(), fbig!(0xc570a3d70a3d7p-48));
     assert_eq!(FBin::try_from(-12.34f64).unwrap(), fbig!(-0xc570a3d70a3d7p-48));
     assert_eq!(1e-308_f64.to_bits(), 0x000730d67819e8d2); // subnormal
-    assert_eq!(FBin::try_from(1e-308_f64).unwrap(), fbig!(0x730d67819e8d2p-1022));
-    assert_eq!(FBin::try_from(-1e-308_f64).unwrap(), fbig!(-0x730d67819e8d2p-1022));
+    assert_eq!(FBin::try_from(1e-308_f64).unwrap(), fbig!(0x730d67819e8d2p-1074));
+    assert_eq!(FBin::try_from(-1e-308_f64).unwrap(), fbig!(-0x730d67819e8d2p-1074));
+    assert_eq!(FBin::try_from(f64::from_bits(1)).unwrap(), fbig!(0x1p-1074));
     assert_eq!(FBin::try_from(f64::INFINITY).unwrap(), FBin::INFINITY);
     assert_eq!(FBin::try_from(f64::NEG_INFINITY).unwrap(), FBin::NEG_INFINITY);
     assert!(FBin::try_from(f64::NAN).is_err());
@@ -281,6 +283,14 @@ fn test_to_f32() {
     assert_eq!(FBig::<Zero, 2>::NEG_INFINITY.to_f32(), Inexact(f32::NEG_INFINITY, NoOp));
     assert_eq!((fbig!(0x1) << 200).to_f32(), Inexact(f32::INFINITY, AddOne));
     assert_eq!((fbig!(-0x1) << 200).to_f32(), Inexact(f32::NEG_INFINITY, SubOne));
+    // slightly larger than f32::MAX
+    assert_eq!((fbig!(0x3fffffd) << 102).to_f32(), Inexact(f32::MAX, NoOp));
+
+    // subnormal numbers
+    assert_eq!(fbig!(0x1p-149).to_f32(), Exact(f32::from_bits(1)));
+    assert_eq!(fbig!(0x7fffffp-149).to_f32(), Exact(f32::from_bits(0x7fffff)));
+    assert_eq!(fbig!(0x3p-151).to_f32(), Inexact(f32::from_bits(1), AddOne));
+    assert_eq!(fbig!(-0x1p-151).to_f32(), Inexact(-0.0, NoOp));
 }
 
 #[test]
@@ -308,4 +318,64 @@ fn test_to_f64() {
     assert_eq!(FBig::<Zero, 2>::NEG_INFINITY.to_f64(), Inexact(f64::NEG_INFINITY, NoOp));
     assert_eq!((fbig!(0x1) << 2000).to_f64(), Inexact(f64::INFINITY, AddOne));
     assert_eq!((fbig!(-0x1) << 2000).to_f64(), Inexact(f64::NEG_INFINITY, SubOne));
+
+    // subnormal numbers
+    assert_eq!(fbig!(0x1p-1074).to_f64(), Exact(f64::from_bits(1)));
+    assert_eq!(fbig!(0x123p-1078).to_f64(), Inexact(f64::from_bits(0x12), NoOp));
+    assert_eq!(fbig!(-0x1p-1080).to_f64(), Inexact(-0.0, NoOp));
+}
+
+#[test]
+fn test_convert_fraction() {
+    let context = Context::<HalfAway>::new(3);
+    let test_cases = [
+        (ibig!(0), ubig!(5), Exact(dbig!(0))),
+        (ibig!(5), ubig!(2), Exact(dbig!(2.5))),
+        (ibig!(1), ubig!(8), Exact(dbig!(0.125))),
+        (ibig!(-1), ubig!(1600), Exact(dbig!(-0.000625))),
+        (ibig!(1), ubig!(3), Inexact(dbig!(0.333), NoOp)),
+        (ibig!(-2), ubig!(3), Inexact(dbig!(-0.667), SubOne)),
+        (ibig!(1235), ubig!(10), Inexact(dbig!(124), AddOne)),
+        (ibig!(-1235), ubig!(10), Inexact(dbig!(-124), SubOne)),
+        (ibig!(12345), ubig!(1), Inexact(dbig!(1.23e4), NoOp)),
+        (ibig!(123456789), ubig!(7), Inexact(dbig!(1.76e7), NoOp)),
+        (ibig!(1000000000000000000000000000001), ubig!(3), Inexact(dbig!(3.33e29), NoOp)),
+    ];
+    for (num, den, result) in test_cases {
+        assert_eq!(context.convert_fraction::<10>(num, den), result);
+    }
+
+    let context = Context::<Zero>::new(8);
+    assert_eq!(
+        context.convert_fraction::<2>(ibig!(1), ubig!(3)),
+        Inexact(fbig!(0xaap-9), NoOp)
+    );
+    assert_eq!(
+        context.convert_fraction::<2>(ibig!(-1000), ubig!(3)),
+        Inexact(fbig!(-0x53p2), NoOp)
+    );
+
+    // the numerator has many more digits than the precision, there should be no double rounding
+    let context = Context::<Zero>::new(4);
+    assert_eq!(
+        context.convert_fraction::<2>(ibig!(-0x47ffff), ubig!(3)),
+        Inexact(fbig!(-0xbp17), NoOp)
+    );
+    let context = Context::<Zero>::new(3);
+    assert_eq!(
+        context.convert_fraction::<10>(ibig!(14999999), ubig!(5)),
+        Inexact(dbig!(299e4).with_rounding(), NoOp)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_convert_fraction_by_0() {
+    let _ = Context::<Zero>::new(8).convert_fraction::<2>(ibig!(1), ubig!(0));
+}
+
+#[test]
+#[should_panic]
+fn test_convert_fraction_unlimited_precision() {
+    let _ = Context::<Zero>::new(0).convert_fraction::<2>(ibig!(1), ubig!(3));
 }
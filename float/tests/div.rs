@@ -3,7 +3,11 @@ use core::{
     ops::{Div, DivAssign},
 };
 use dashu_base::{Approximation::*, DivEuclid, DivRemEuclid, RemEuclid};
-use dashu_float::{round::Rounding::*, Context};
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context,
+};
+use dashu_int::{IBig, UBig};
 
 mod helper_macros;
 
@@ -123,6 +127,78 @@ fn test_div_decimal() {
     }
 }
 
+#[test]
+fn test_div_exact_quotient_with_low_precision() {
+    // the quotient is exact but it has more digits than the precision
+    let context = Context::<mode::HalfAway>::new(1);
+    assert_eq!(context.div(dbig!(99).repr(), dbig!(9).repr()), Inexact(dbig!(1e1), NoOp));
+    assert_eq!(context.div(dbig!(-99).repr(), dbig!(9).repr()), Inexact(dbig!(-1e1), NoOp));
+    assert_eq!(context.div(dbig!(15).repr(), dbig!(1).repr()), Inexact(dbig!(2e1), AddOne));
+    assert_eq!(context.div(dbig!(-15).repr(), dbig!(1).repr()), Inexact(dbig!(-2e1), SubOne));
+    assert_eq!(context.div(dbig!(9).repr(), dbig!(3).repr()), Exact(dbig!(3)));
+
+    let context = Context::<mode::Zero>::new(2);
+    assert_eq!(context.div(fbig!(0x7).repr(), fbig!(0x1).repr()), Inexact(fbig!(0x3p1), NoOp));
+    assert_eq!(context.div(fbig!(0x15).repr(), fbig!(0x3).repr()), Inexact(fbig!(0x3p1), NoOp));
+}
+
+#[test]
+fn test_div_with_low_precision() {
+    // the divisor has more digits than the precision
+    let context = Context::<mode::HalfAway>::new(1);
+    assert_eq!(context.div(dbig!(9).repr(), dbig!(12).repr()), Inexact(dbig!(8e-1), AddOne));
+    assert_eq!(context.div(dbig!(-9).repr(), dbig!(12).repr()), Inexact(dbig!(-8e-1), SubOne));
+    assert_eq!(context.div(dbig!(3).repr(), dbig!(16).repr()), Inexact(dbig!(2e-1), AddOne));
+    assert_eq!(context.div(dbig!(1).repr(), dbig!(16).repr()), Inexact(dbig!(6e-2), NoOp));
+
+    let context = Context::<mode::Zero>::new(2);
+    assert_eq!(
+        context.div(fbig!(0x7).repr(), fbig!(0x8).repr()),
+        Inexact(fbig!(0x3p-2), NoOp)
+    );
+}
+
+#[test]
+fn test_div_long_dividend() {
+    // the dividend has more digits than the precision, there should be no double rounding
+    let context = Context::<mode::HalfAway>::new(1);
+    assert_eq!(context.div(dbig!(2499).repr(), dbig!(1).repr()), Inexact(dbig!(2e3), NoOp));
+    assert_eq!(context.div(dbig!(-2499).repr(), dbig!(3).repr()), Inexact(dbig!(-8e2), NoOp));
+    let context = Context::<mode::Down>::new(2);
+    assert_eq!(
+        context.div(dbig!(-1000001).repr(), dbig!(-1).repr()),
+        Inexact(dbig!(10e5).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        context.div(dbig!(1000001).repr(), dbig!(-1).repr()),
+        Inexact(dbig!(-11e5).with_rounding(), SubOne)
+    );
+    let context = Context::<mode::Up>::new(3);
+    assert_eq!(
+        context.div(dbig!(1000000001).repr(), dbig!(7).repr()),
+        Inexact(dbig!(143e6).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        context.div(dbig!(7000000000).repr(), dbig!(7).repr()),
+        Exact(dbig!(1e9).with_rounding())
+    );
+
+    let context = Context::<mode::Zero>::new(4);
+    assert_eq!(
+        context.div(fbig!(0x10001).repr(), fbig!(0x1).repr()),
+        Inexact(fbig!(0x8p13), NoOp)
+    );
+    assert_eq!(
+        context.div(fbig!(-0x47ffff).repr(), fbig!(0x3).repr()),
+        Inexact(fbig!(-0xbp17), NoOp)
+    );
+    let context = Context::<mode::Up>::new(4);
+    assert_eq!(
+        context.div(fbig!(0x10001).repr(), fbig!(0x1).repr()),
+        Inexact(fbig!(0x9p13).with_rounding(), AddOne)
+    );
+}
+
 #[test]
 #[should_panic]
 fn test_div_by_inf() {
@@ -200,3 +276,22 @@ fn test_div_rem_euclid_decimal() {
         assert_eq!(remainder, *r);
     }
 }
+
+#[test]
+fn test_div_int() {
+    let one = dbig!(1);
+    let third = dbig!(1.00) / 300u32;
+    assert_eq!(third, dbig!(333e-5));
+    assert_eq!(third.precision(), 3);
+    // the trailing zeros of the integer don't increase the precision
+    assert_eq!(&one / 300u32, dbig!(3e-3));
+    assert_eq!(300u32 / &one, dbig!(300));
+    assert_eq!(dbig!(1.000) / IBig::from(-8), dbig!(-0.125));
+    assert_eq!(UBig::from(10u8) / dbig!(4.0), dbig!(2.5));
+    assert_eq!(fbig!(0x3) / 4u64, fbig!(0x3p-2));
+    assert_eq!(-1i32 / fbig!(0x4), fbig!(-0x1p-2));
+
+    let mut a = dbig!(1.000);
+    a /= 8isize;
+    assert_eq!(a, dbig!(0.125));
+}
@@ -0,0 +1,195 @@
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{
+        mode::{Away, Down, Dynamic, HalfAway, HalfEven, ToOdd, Up, Zero},
+        Mode, Round,
+        Rounding::*,
+    },
+    Context, DBig, FBig, Repr,
+};
+
+mod helper_macros;
+
+type FBin<R> = FBig<R, 2>;
+
+fn bin(significand: i64, exponent: isize) -> Repr<2> {
+    Repr::new(significand.into(), exponent)
+}
+
+fn dec(significand: i64, exponent: isize) -> Repr<10> {
+    Repr::new(significand.into(), exponent)
+}
+
+/// Pseudo-random numbers from a linear congruential generator
+fn lcg(seed: &mut u64) -> i64 {
+    *seed = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    (*seed >> 40) as i64 - (1 << 23)
+}
+
+/// Check that the results under the dynamic rounding mode are the same as the static one
+fn check_mode<R: Round>(mode: Mode) {
+    let stat = Context::<R>::new(10);
+    let dynamic = Context::<Dynamic>::new(10).with_rounding_mode(mode);
+    assert_eq!(dynamic.rounding_mode(), Some(mode));
+    assert_eq!(stat.rounding_mode(), Some(mode));
+
+    let mut seed = 1;
+    for _ in 0..50 {
+        let a = bin(lcg(&mut seed), (lcg(&mut seed) % 20) as isize);
+        let b = bin(lcg(&mut seed), (lcg(&mut seed) % 20) as isize);
+        assert_eq!(
+            stat.add(&a, &b).map(|v| v.into_repr()),
+            dynamic.add(&a, &b).map(|v| v.into_repr())
+        );
+        assert_eq!(
+            stat.sub(&a, &b).map(|v| v.into_repr()),
+            dynamic.sub(&a, &b).map(|v| v.into_repr())
+        );
+        assert_eq!(
+            stat.mul(&a, &b).map(|v| v.into_repr()),
+            dynamic.mul(&a, &b).map(|v| v.into_repr())
+        );
+        assert_eq!(
+            stat.div(&a, &b).map(|v| v.into_repr()),
+            dynamic.div(&a, &b).map(|v| v.into_repr())
+        );
+        let abs_a = bin(lcg(&mut seed).abs(), (lcg(&mut seed) % 20) as isize);
+        assert_eq!(
+            stat.sqrt(&abs_a).map(|v| v.into_repr()),
+            dynamic.sqrt(&abs_a).map(|v| v.into_repr())
+        );
+
+        // conversions and formatting
+        let x = FBin::from_repr(a.clone(), Context::<R>::new(30));
+        let y = FBin::from_repr(a, Context::<Dynamic>::new(30).with_rounding_mode(mode));
+        assert_eq!(x.to_int(), y.to_int());
+        assert_eq!(format!("{:.3}", x), format!("{:.3}", y));
+        assert_eq!(
+            x.clone().with_precision(5).map(|v| v.into_repr()),
+            y.clone().with_precision(5).map(|v| v.into_repr())
+        );
+    }
+
+    // transcendental functions
+    let x = FBin::from_repr(bin(-5, -2), stat);
+    let y = FBin::from_repr(bin(-5, -2), dynamic);
+    assert_eq!(x.exp().repr(), y.exp().repr());
+    assert_eq!(x.powi((-3).into()).repr(), y.powi((-3).into()).repr());
+    assert_eq!((-&x).ln().repr(), (-&y).ln().repr());
+
+    // operators
+    let x2 = FBin::from_repr(bin(3, -5), stat);
+    let y2 = FBin::from_repr(bin(3, -5), dynamic);
+    assert_eq!((&x / &x2).repr(), (&y / &y2).repr());
+    assert_eq!((&x * &x2 - &x2).repr(), (&y * &y2 - &y2).repr());
+}
+
+#[test]
+fn test_modes() {
+    check_mode::<Zero>(Mode::Zero);
+    check_mode::<Away>(Mode::Away);
+    check_mode::<Up>(Mode::Up);
+    check_mode::<Down>(Mode::Down);
+    check_mode::<HalfEven>(Mode::HalfEven);
+    check_mode::<HalfAway>(Mode::HalfAway);
+    check_mode::<ToOdd>(Mode::ToOdd);
+}
+
+#[test]
+fn test_reverse() {
+    assert_eq!(Mode::Up.reverse(), Mode::Down);
+    assert_eq!(Mode::Down.reverse(), Mode::Up);
+    assert_eq!(Mode::HalfEven.reverse(), Mode::HalfEven);
+    assert_eq!(Mode::Zero.reverse(), Mode::Away);
+}
+
+#[test]
+fn test_special_values() {
+    // overflow under a bounded exponent range
+    let max = bin((1 << 24) - 1, 104);
+    let ulp = bin(1, 104);
+    let context = Context::<Dynamic>::new(24)
+        .with_exponent_range(-126, 127)
+        .with_ieee(true);
+    let add = |mode| context.with_rounding_mode(mode).add(&max, &ulp);
+    assert_eq!(add(Mode::Up), Inexact(FBin::INFINITY, AddOne));
+    assert_eq!(add(Mode::Down).value().repr(), &max);
+    assert_eq!(add(Mode::Zero).value().repr(), &max);
+
+    // the sign of an exact zero sum
+    let sub = |mode| context.with_rounding_mode(mode).sub(&max, &max).value();
+    assert!(sub(Mode::Down).repr().is_neg_zero());
+    assert!(!sub(Mode::Up).repr().is_neg_zero());
+    assert!(!sub(Mode::HalfEven).repr().is_neg_zero());
+}
+
+#[test]
+fn test_conversion() {
+    // the rounding mode is kept when converting to the dynamic mode
+    let context = Context::<HalfEven>::new(3).with_rounding::<Dynamic>();
+    assert_eq!(context.rounding_mode(), Some(Mode::HalfEven));
+    assert_eq!(context.precision(), 3);
+    assert_eq!(context.with_rounding::<Up>().rounding_mode(), Some(Mode::Up));
+    assert_eq!(context.with_rounding::<Dynamic>().rounding_mode(), Some(Mode::HalfEven));
+
+    let x = DBig::from_repr(dec(12345, -4), Context::new(5));
+    let y = x.clone().with_rounding::<Dynamic>();
+    assert_eq!(y.context().rounding_mode(), Some(Mode::HalfAway));
+    assert_eq!(y.clone().with_rounding::<HalfAway>(), x);
+    let z = y.with_rounding_mode(Mode::Up);
+    assert_eq!(z.context().rounding_mode(), Some(Mode::Up));
+    assert_eq!(
+        z.clone().with_precision(2),
+        Inexact(DBig::from_repr(dec(13, -1), Context::new(2)).with_rounding(), AddOne)
+    );
+
+    // an unset rounding mode behaves like rounding toward zero
+    let context = Context::<Dynamic>::new(2);
+    assert_eq!(context.rounding_mode(), Some(Mode::Zero));
+    assert_eq!(context.div(&dec(2, 0), &dec(3, 0)).value().repr(), &dec(66, -2));
+    assert_eq!(
+        Context::<Up>::new(2)
+            .with_rounding::<Zero>()
+            .rounding_mode(),
+        Some(Mode::Zero)
+    );
+}
+
+#[test]
+fn test_max() {
+    let unset = Context::<Dynamic>::new(3);
+    let up = Context::<Dynamic>::new(2).with_rounding_mode(Mode::Up);
+    assert_eq!(Context::max(unset, up).rounding_mode(), Some(Mode::Up));
+    assert_eq!(Context::max(up, unset).rounding_mode(), Some(Mode::Up));
+    assert_eq!(Context::max(up, up).rounding_mode(), Some(Mode::Up));
+    assert_eq!(Context::max(unset, unset).rounding_mode(), Some(Mode::Zero));
+
+    // constants have no rounding mode set
+    let a = FBig::<Dynamic, 10>::from_repr(dec(3, 0), up);
+    let third = FBig::ONE / a;
+    assert_eq!(third.context().rounding_mode(), Some(Mode::Up));
+    assert_eq!(third.repr(), &dec(34, -2));
+}
+
+#[test]
+#[should_panic]
+fn test_different_modes() {
+    let up = Context::<Dynamic>::new(2).with_rounding_mode(Mode::Up);
+    let down = Context::<Dynamic>::new(2).with_rounding_mode(Mode::Down);
+    let _ = Context::max(up, down);
+}
+
+#[test]
+fn test_debug() {
+    let context = Context::<Dynamic>::new(2);
+    assert_eq!(format!("{:?}", context), "Context { precision: 2, rounding: Dynamic }");
+    let context = context.with_rounding_mode(Mode::HalfEven);
+    assert_eq!(
+        format!("{:?}", context),
+        "Context { precision: 2, rounding: Dynamic(HalfEven) }"
+    );
+    let x = FBig::<Dynamic, 10>::from_repr(dec(12, -1), context);
+    assert_eq!(format!("{:?}", x), "12 * 10 ^ -1 (prec: 2, rnd: Dynamic(HalfEven))");
+}
@@ -0,0 +1,232 @@
+use core::str::FromStr;
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DBig,
+};
+
+mod helper_macros;
+
+#[test]
+fn test_erf_binary() {
+    assert_eq!(fbig!(0).erf(), fbig!(0));
+
+    let inexact_cases = [
+        (fbig!(0x1p-1), fbig!(0x1p-1)),
+        (fbig!(0x3p-2), fbig!(0xbp-4)),
+        (fbig!(-0x3p-2), fbig!(-0xbp-4)),
+        (fbig!(0x1234p-12), fbig!(0x7239p-15)),
+        (fbig!(-0x1234p-12), fbig!(-0x7239p-15)),
+        (fbig!(0x1p-200), fbig!(0x9p-203)),
+        (fbig!(0x4), fbig!(0xfp-4)),
+        (fbig!(-0x4), fbig!(-0xfp-4)),
+        (fbig!(0x6), fbig!(0xfp-4)),
+        (fbig!(0x1234), fbig!(0xffffp-16)),
+    ];
+    for (x, erf) in &inexact_cases {
+        assert_eq!(x.erf(), *erf);
+        if let Inexact(v, _) = x.context().erf(x.repr()) {
+            assert_eq!(v, *erf);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_erf_decimal() {
+    assert_eq!(dbig!(0).erf(), dbig!(0));
+
+    let inexact_cases = [
+        (dbig!(1e-1), dbig!(1e-1), NoOp),
+        (dbig!(1234e-3), dbig!(919e-3), NoOp),
+        (dbig!(-1234e-3), dbig!(-919e-3), NoOp),
+        (dbig!(1e-50), dbig!(1e-50), NoOp),
+        (dbig!(5), dbig!(1), AddOne),
+        (dbig!(-5), dbig!(-1), SubOne),
+        (dbig!(12345e-3), dbig!(1), AddOne),
+        (dbig!(-12345e-3), dbig!(-1), SubOne),
+    ];
+    for (x, erf, rnd) in &inexact_cases {
+        assert_eq!(x.erf(), *erf);
+        assert_eq!(x.context().erf(x.repr()), Inexact(erf.clone(), *rnd));
+    }
+
+    // directed rounding
+    let context = Context::<mode::Up>::new(5);
+    assert_eq!(
+        context
+            .erf(dbig!(1234e-3).repr())
+            .map(|v| v.with_rounding()),
+        Inexact(dbig!(91904e-5), AddOne)
+    );
+    assert_eq!(
+        context
+            .erf(dbig!(-12345e-3).repr())
+            .map(|v| v.with_rounding()),
+        Inexact(dbig!(-99999e-5), NoOp)
+    );
+    let context = Context::<mode::Down>::new(5);
+    assert_eq!(
+        context
+            .erf(dbig!(12345e-3).repr())
+            .map(|v| v.with_rounding()),
+        Inexact(dbig!(99999e-5), NoOp)
+    );
+    assert_eq!(
+        context
+            .erf(dbig!(-1234e-3).repr())
+            .map(|v| v.with_rounding()),
+        Inexact(dbig!(-91904e-5), SubOne)
+    );
+}
+
+#[test]
+fn test_erfc_binary() {
+    assert_eq!(fbig!(0).erfc(), fbig!(1));
+
+    let inexact_cases = [
+        (fbig!(0x1p-1), fbig!(0xfp-5)),
+        (fbig!(0x3p-2), fbig!(0x9p-5)),
+        (fbig!(-0x3p-2), fbig!(0xdp-3)),
+        (fbig!(0x1234p-12), fbig!(0x371bp-17)),
+        (fbig!(-0x1234p-12), fbig!(0xf239p-15)),
+        (fbig!(0x1p-200), fbig!(0xfp-4)),
+        (fbig!(0x4), fbig!(0x1p-26)),
+        (fbig!(-0x4), fbig!(0xfp-3)),
+        (fbig!(0x6), fbig!(0x3p-57)),
+        (fbig!(0x1234), fbig!(0x178fp-31329014)),
+    ];
+    for (x, erfc) in &inexact_cases {
+        assert_eq!(x.erfc(), *erfc);
+        if let Inexact(v, _) = x.context().erfc(x.repr()) {
+            assert_eq!(v, *erfc);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_erfc_decimal() {
+    assert_eq!(dbig!(0).erfc(), dbig!(1));
+
+    let inexact_cases = [
+        (dbig!(1e-1), dbig!(9e-1), AddOne),
+        (dbig!(1234e-3), dbig!(8096e-5), NoOp),
+        (dbig!(-1234e-3), dbig!(1919e-3), NoOp),
+        (dbig!(1e-50), dbig!(1), AddOne),
+        (dbig!(5), dbig!(2e-12), AddOne),
+        (dbig!(-5), dbig!(2), AddOne),
+        (dbig!(12345e-3), dbig!(2968e-71), NoOp),
+        (dbig!(-12345e-3), dbig!(2), AddOne),
+    ];
+    for (x, erfc, rnd) in &inexact_cases {
+        assert_eq!(x.erfc(), *erfc);
+        assert_eq!(x.context().erfc(x.repr()), Inexact(erfc.clone(), *rnd));
+    }
+
+    // directed rounding
+    let context = Context::<mode::Up>::new(5);
+    assert_eq!(
+        context
+            .erfc(dbig!(12345e-3).repr())
+            .map(|v| v.with_rounding()),
+        Inexact(dbig!(29681e-72), AddOne)
+    );
+    assert_eq!(
+        context
+            .erfc(dbig!(-1234e-3).repr())
+            .map(|v| v.with_rounding()),
+        Inexact(dbig!(19191e-4), AddOne)
+    );
+    let context = Context::<mode::Down>::new(5);
+    assert_eq!(
+        context.erfc(dbig!(1e-50).repr()).map(|v| v.with_rounding()),
+        Inexact(dbig!(99999e-5), NoOp)
+    );
+    assert_eq!(
+        context
+            .erfc(dbig!(-12345e-3).repr())
+            .map(|v| v.with_rounding()),
+        Inexact(dbig!(19999e-4), NoOp)
+    );
+
+    // high precision
+    let context = Context::<mode::HalfAway>::new(100);
+    let erfc30 = DBig::from_str("2.564656203756111600033397277501447146548889722778617054122599586184238694779197350757455924600231926e-393").unwrap();
+    assert_eq!(context.erfc(dbig!(30).repr()), Inexact(erfc30, AddOne));
+    let erfc3 = DBig::from_str("1.999977909503001414558627223870417679620152292912600750342761045157057543316379867732183745349184684").unwrap();
+    assert_eq!(context.erfc(dbig!(-3).repr()), Inexact(erfc3, AddOne));
+}
+
+#[test]
+fn test_erfinv_binary() {
+    assert_eq!(fbig!(0).erfinv(), fbig!(0));
+
+    let inexact_cases = [
+        (fbig!(0x1p-1), fbig!(0xfp-5)),
+        (fbig!(0x3p-2), fbig!(0xdp-4)),
+        (fbig!(0xfffp-12), fbig!(0x53p-5)),
+        (fbig!(-0xfffffffp-28), fbig!(-0x42b47fbp-24)),
+        (fbig!(0x1p-200), fbig!(0x7p-203)),
+    ];
+    for (x, erfinv) in &inexact_cases {
+        assert_eq!(x.erfinv(), *erfinv);
+        if let Inexact(v, _) = x.context().erfinv(x.repr()) {
+            assert_eq!(v, *erfinv);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_erfinv_decimal() {
+    assert_eq!(dbig!(0).erfinv(), dbig!(0));
+
+    let inexact_cases = [
+        (dbig!(5e-1), dbig!(5e-1), AddOne),
+        (dbig!(999e-3), dbig!(233e-2), AddOne),
+        (dbig!(-999999e-6), dbig!(-345891e-5), NoOp),
+        (dbig!(1e-50), dbig!(9e-51), AddOne),
+        (dbig!(-1234e-4), dbig!(-1098e-4), NoOp),
+        (dbig!(99999999999999999999e-20), dbig!(66015806223551425615e-19), NoOp),
+    ];
+    for (x, erfinv, rnd) in &inexact_cases {
+        assert_eq!(x.erfinv(), *erfinv);
+        assert_eq!(x.context().erfinv(x.repr()), Inexact(erfinv.clone(), *rnd));
+    }
+
+    // directed rounding
+    let context = Context::<mode::Up>::new(20);
+    let x = dbig!(99999999999999999999e-20);
+    assert_eq!(
+        context.erfinv(x.repr()).map(|v| v.with_rounding()),
+        Inexact(dbig!(66015806223551425616e-19), AddOne)
+    );
+    let context = Context::<mode::Down>::new(6);
+    assert_eq!(
+        context
+            .erfinv(dbig!(-999999e-6).repr())
+            .map(|v| v.with_rounding()),
+        Inexact(dbig!(-345892e-5), SubOne)
+    );
+
+    // high precision
+    let context = Context::<mode::HalfAway>::new(100);
+    let erfinv = DBig::from_str("0.4769362762044698733814183536431305598089697490594706447038826959193834477746467334886959158699890099").unwrap();
+    assert_eq!(context.erfinv(dbig!(5e-1).repr()), Inexact(erfinv, NoOp));
+}
+
+#[test]
+#[should_panic]
+fn test_erfinv_out_of_domain() {
+    let _ = dbig!(1).erfinv();
+}
+
+#[test]
+#[should_panic]
+fn test_erfinv_out_of_domain_negative() {
+    let _ = dbig!(-1001e-3).erfinv();
+}
@@ -1,5 +1,9 @@
-use dashu_base::Approximation::*;
-use dashu_float::{round::Rounding::*, DBig};
+use dashu_base::{Abs, Approximation::*};
+use dashu_float::{
+    round::{mode, Round, Rounding::*},
+    Context, DBig, FBig, Repr,
+};
+use dashu_int::{IBig, Word};
 
 mod helper_macros;
 
@@ -210,6 +214,64 @@ fn test_exp_decimal() {
     }
 }
 
+#[test]
+fn test_exp_directed_rounding() {
+    let x = dbig!(1.5);
+    let exp = Context::<mode::Up>::new(10).exp(x.repr());
+    assert_eq!(exp.map(|v| v.with_rounding()), Inexact(dbig!(4481689071e-9), AddOne));
+    let exp = Context::<mode::Away>::new(10).exp((-x.clone()).repr());
+    assert_eq!(exp.map(|v| v.with_rounding()), Inexact(dbig!(2231301602e-10), AddOne));
+    let exp = Context::<mode::Down>::new(10).exp(x.repr());
+    assert_eq!(exp.map(|v| v.with_rounding()), Inexact(dbig!(448168907e-8), NoOp));
+
+    let exp_m1 = Context::<mode::Up>::new(10).exp_m1(x.repr());
+    assert_eq!(exp_m1.map(|v| v.with_rounding()), Inexact(dbig!(3481689071e-9), AddOne));
+    let exp_m1 = Context::<mode::Away>::new(10).exp_m1((-x.clone()).repr());
+    assert_eq!(exp_m1.map(|v| v.with_rounding()), Inexact(dbig!(-7768698399e-10), SubOne));
+    let pow = Context::<mode::Up>::new(10).powf(x.repr(), dbig!(0.5).repr());
+    assert_eq!(pow.map(|v| v.with_rounding()), Inexact(dbig!(1224744872e-9), AddOne));
+
+    let exp = Context::<mode::Up>::new(20).exp(fbig!(0x3).repr());
+    assert_eq!(exp.map(|v| v.with_rounding()), Inexact(fbig!(0xa0af3p-15), AddOne));
+    let exp = Context::<mode::Away>::new(20).exp(fbig!(-0x3).repr());
+    assert_eq!(exp.map(|v| v.with_rounding()), Inexact(fbig!(0xcbed9p-24), AddOne));
+
+    // the results are correctly rounded even if the precision is low
+    let x = fbig!(0x197p-1);
+    let exp = Context::<mode::Up>::new(2).exp(x.repr());
+    assert_eq!(exp.map(|v| v.with_rounding()), Inexact(fbig!(0x1p294), AddOne));
+    let exp = Context::<mode::Down>::new(2).exp(x.repr());
+    assert_eq!(exp.map(|v| v.with_rounding()), Inexact(fbig!(0x3p292), NoOp));
+    let exp = Context::<mode::Up>::new(7).exp(fbig!(0xfp-1).repr());
+    assert_eq!(exp.map(|v| v.with_rounding()), Inexact(fbig!(0x39p5), AddOne));
+
+    // only the sign of the tiny term matters
+    let exp = Context::<mode::Down>::new(10).exp(dbig!(-1e-40).repr());
+    assert_eq!(exp.map(|v| v.with_rounding()), Inexact(dbig!(9999999999e-10), NoOp));
+    let exp_m1 = Context::<mode::Up>::new(10).exp_m1(dbig!(1e-40).repr());
+    assert_eq!(exp_m1.map(|v| v.with_rounding()), Inexact(dbig!(1000000001e-49), AddOne));
+}
+
+#[test]
+fn test_exp_huge_argument() {
+    // the digits of the integer part are kept in the argument reduction
+    let cases = [
+        (943660903i64, 1, 61),
+        (-943660903, 1, 61),
+        (123456789123, 3, 100),
+        (987654321987654321, -2, 64),
+        (3, 40, 53),
+        (-7, 38, 30),
+    ];
+    for (significand, exponent, precision) in cases {
+        let x = Repr::<2>::new(IBig::from(significand), exponent);
+        let exp = Context::<mode::HalfEven>::new(precision).exp(&x).value();
+        let reference = Context::<mode::HalfEven>::new(precision + 64).exp(&x).value();
+        let error = (exp.clone() - reference).with_precision(0).value();
+        assert!(error.abs() <= exp.ulp(), "exp({:?}) = {:?}", x, exp);
+    }
+}
+
 #[test]
 #[should_panic]
 fn test_exp_unlimited_precision() {
@@ -430,3 +492,181 @@ fn test_pow_unlimited_precision() {
 fn test_pow_inf() {
     let _ = DBig::INFINITY.powf(&dbig!(2));
 }
+
+#[test]
+fn test_exp2_binary() {
+    let exact_cases = [
+        (fbig!(0), fbig!(1)),
+        (fbig!(0x10), fbig!(0x1p16)),
+        (fbig!(-0x100), fbig!(0x1p-256)),
+    ];
+    for (x, pow) in &exact_cases {
+        assert_eq!(x.exp2(), *pow);
+        assert_eq!(x.context().exp2(x.repr()), Exact(pow.clone()));
+    }
+
+    let inexact_cases = [
+        (fbig!(0x1p-1), fbig!(0xbp-3)),
+        (fbig!(-0x3p-2), fbig!(0x9p-4)),
+        (fbig!(0x1234p-12), fbig!(0x8cd1p-14)),
+        (fbig!(-0x1234p-12), fbig!(0x7459p-16)),
+        (fbig!(0x1p-200), fbig!(0x1)),
+        (fbig!(0x1234p-4), fbig!(0x9837p276)),
+        (fbig!(-0x1234p-4), fbig!(0x35d1p-305)),
+    ];
+    for (x, pow) in &inexact_cases {
+        assert_eq!(x.exp2(), *pow);
+        if let Inexact(v, e) = x.context().exp2(x.repr()) {
+            assert_eq!(v, *pow);
+            assert_eq!(e, NoOp);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_exp2_decimal() {
+    let exact_cases = [
+        (dbig!(0), dbig!(1)),
+        (dbig!(0010), dbig!(1024)),
+        (dbig!(-0002), dbig!(25e-2)),
+        (dbig!(-3), dbig!(1e-1)),
+    ];
+    for (x, pow) in &exact_cases[..3] {
+        assert_eq!(x.exp2(), *pow);
+        assert_eq!(x.context().exp2(x.repr()), Exact(pow.clone()));
+    }
+    // the power is exact, but it's rounded to the precision
+    let (x, pow) = &exact_cases[3];
+    assert_eq!(x.context().exp2(x.repr()), Inexact(pow.clone(), NoOp));
+
+    let inexact_cases = [
+        (dbig!(1e-1), dbig!(1), NoOp),
+        (dbig!(-1e-1), dbig!(9e-1), NoOp),
+        (dbig!(1234e-3), dbig!(2352e-3), NoOp),
+        (dbig!(-1234e-3), dbig!(4251e-4), NoOp),
+        (dbig!(1e-50), dbig!(1), NoOp),
+        (dbig!(5e-1), dbig!(1), NoOp),
+        (dbig!(12345e-1), dbig!(41834e367), NoOp),
+        (dbig!(-12345e-1), dbig!(23904e-376), AddOne),
+        (dbig!(100), dbig!(127e28), AddOne),
+        (dbig!(-100), dbig!(789e-33), AddOne),
+    ];
+    for (x, pow, rnd) in &inexact_cases {
+        assert_eq!(x.exp2(), *pow);
+        if let Inexact(v, e) = x.context().exp2(x.repr()) {
+            assert_eq!(v, *pow);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_exp10_binary() {
+    let exact_cases = [(fbig!(0), fbig!(1)), (fbig!(0x0003), fbig!(0x3e8))];
+    for (x, pow) in &exact_cases {
+        assert_eq!(x.exp10(), *pow);
+        assert_eq!(x.context().exp10(x.repr()), Exact(pow.clone()));
+    }
+
+    let inexact_cases = [
+        (fbig!(-0x1), fbig!(0xcp-7)),
+        (fbig!(0x1p-1), fbig!(0x3)),
+        (fbig!(-0x3p-2), fbig!(0xbp-6)),
+        (fbig!(0x1234p-12), fbig!(0xdbb1p-12)),
+        (fbig!(-0x1234p-12), fbig!(0x9527p-19)),
+        (fbig!(0x1p-200), fbig!(0x1)),
+        (fbig!(0x1234p-4), fbig!(0xb679p952)),
+        (fbig!(-0x1234p-4), fbig!(0xb393p-983)),
+    ];
+    for (x, pow) in &inexact_cases {
+        assert_eq!(x.exp10(), *pow);
+        if let Inexact(v, e) = x.context().exp10(x.repr()) {
+            assert_eq!(v, *pow);
+            assert_eq!(e, NoOp);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_exp10_decimal() {
+    let exact_cases = [
+        (dbig!(0), dbig!(1)),
+        (dbig!(3), dbig!(1e3)),
+        (dbig!(100), dbig!(1e100)),
+        (dbig!(-100), dbig!(1e-100)),
+    ];
+    for (x, pow) in &exact_cases {
+        assert_eq!(x.exp10(), *pow);
+        assert_eq!(x.context().exp10(x.repr()), Exact(pow.clone()));
+    }
+
+    let inexact_cases = [
+        (dbig!(1e-1), dbig!(1), NoOp),
+        (dbig!(-1e-1), dbig!(8e-1), AddOne),
+        (dbig!(1234e-3), dbig!(1714e-2), AddOne),
+        (dbig!(-1234e-3), dbig!(5834e-5), NoOp),
+        (dbig!(1e-50), dbig!(1), NoOp),
+        (dbig!(5e-1), dbig!(3), NoOp),
+        (dbig!(12345e-1), dbig!(31623e1230), AddOne),
+        (dbig!(-12345e-1), dbig!(31623e-1239), AddOne),
+    ];
+    for (x, pow, rnd) in &inexact_cases {
+        assert_eq!(x.exp10(), *pow);
+        if let Inexact(v, e) = x.context().exp10(x.repr()) {
+            assert_eq!(v, *pow);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+/// Check that `Bⁿ⁺¹ᐟ²` under the context is the same as `√B·Bⁿ`, where `√B` is correctly rounded
+fn check_exp_base_half<R: Round, const B: Word>(n: i64, precision: usize) {
+    let context = Context::<R>::new(precision);
+    let x = Repr::<B>::new(IBig::from(2 * n + 1) * (B / 2), -1);
+    let pow = match B {
+        2 => context.exp2(&x),
+        _ => context.exp10(&x),
+    };
+    let sqrt = context.sqrt(&Repr::<B>::new(B.into(), 0)).map(|v| {
+        let v = v.into_repr();
+        let shifted = Repr::new(v.significand().clone(), v.exponent() + n as isize);
+        FBig::from_repr(shifted, context)
+    });
+    assert_eq!(pow, sqrt, "n = {}, precision = {}", n, precision);
+}
+
+#[test]
+fn test_exp_int_base_large_argument() {
+    // the integer part of the argument only shifts the exponent
+    let context = Context::<mode::HalfEven>::new(24);
+    let x = Repr::<2>::new(3774643613u32.into(), -1);
+    let pow = Repr::new(11863283.into(), 1887321783);
+    assert_eq!(context.exp2(&x), Inexact(FBig::from_repr(pow, context), NoOp));
+
+    for n in [1, -1, 1000, -1000, 943660903, -943660903, 1887321806] {
+        for precision in [1, 2, 10, 24, 53, 100] {
+            check_exp_base_half::<mode::Zero, 2>(n, precision);
+            check_exp_base_half::<mode::Up, 2>(n, precision);
+            check_exp_base_half::<mode::HalfEven, 2>(n, precision);
+            check_exp_base_half::<mode::HalfAway, 10>(n, precision);
+            check_exp_base_half::<mode::Down, 10>(n, precision);
+        }
+    }
+
+    // the long power of an integer argument is approximated instead of being expanded
+    let context = Context::<mode::HalfAway>::new(9);
+    let x = Repr::<10>::new(100000000.into(), 0);
+    let pow = Repr::new(368466594.into(), 30102991);
+    assert_eq!(context.exp2(&x), Inexact(FBig::from_repr(pow, context), AddOne));
+    let x = Repr::<10>::new((-100000000).into(), 0);
+    let pow = Repr::new(271395024.into(), -30103008);
+    assert_eq!(context.exp2(&x), Inexact(FBig::from_repr(pow, context), AddOne));
+}
@@ -0,0 +1,127 @@
+use dashu_float::{
+    flags::{self, Flags},
+    round::mode::{HalfAway, HalfEven},
+    Context, FBig, Repr,
+};
+
+mod helper_macros;
+
+fn bin(significand: i64, exponent: isize) -> Repr<2> {
+    Repr::new(significand.into(), exponent)
+}
+
+// The flags are stored per thread, so all the checks are put in one test to prevent
+// interference from the other tests.
+#[test]
+fn test_flags() {
+    flags::clear(Flags::ALL);
+    assert!(flags::get().is_empty());
+
+    // exact operations raise no flag
+    let ctx = Context::<HalfAway>::new(10).with_flags(true);
+    let _ = ctx.mul(&Repr::<10>::new(3.into(), 0), &Repr::new(3.into(), 0));
+    let _ = ctx.add(&Repr::<10>::one(), &Repr::new(5.into(), -1));
+    let _ = ctx.sqrt(&Repr::<10>::new(4.into(), 0));
+    assert_eq!(flags::get(), Flags::EMPTY);
+
+    // inexact results
+    let _ = ctx.div(&Repr::<10>::one(), &Repr::new(3.into(), 0));
+    assert_eq!(flags::get(), Flags::INEXACT);
+    assert_eq!(flags::take(), Flags::INEXACT);
+    let _ = ctx.exp(&Repr::<10>::one());
+    assert!(flags::test(Flags::INEXACT));
+    flags::clear(Flags::INEXACT);
+    assert!(!flags::test(Flags::INEXACT));
+
+    // no flag is raised when the tracking is disabled
+    let _ = ctx
+        .with_flags(false)
+        .div(&Repr::<10>::one(), &Repr::new(3.into(), 0));
+    assert!(flags::get().is_empty());
+
+    // overflow and underflow under the binary32 format
+    let ctx = Context::<HalfEven>::new(24)
+        .with_exponent_range(-126, 127)
+        .with_ieee(true)
+        .with_flags(true);
+    let max = bin((1 << 24) - 1, 104);
+    let tiny = bin(1, -149);
+    let _ = ctx.mul(&max, &max);
+    assert_eq!(flags::take(), Flags::OVERFLOW | Flags::INEXACT);
+    let _ = ctx.mul(&tiny, &bin(3, -2));
+    assert_eq!(flags::take(), Flags::UNDERFLOW | Flags::INEXACT);
+    let _ = ctx.mul(&tiny, &tiny);
+    assert_eq!(flags::take(), Flags::UNDERFLOW | Flags::INEXACT);
+    // exact subnormal results don't underflow
+    let _ = ctx.sub(&bin(1, -126), &tiny);
+    assert!(flags::take().is_empty());
+
+    // the transcendental functions overflow and underflow under the binary16 format
+    let half = Context::<HalfEven>::new(11)
+        .with_exponent_range(-14, 15)
+        .with_flags(true);
+    let _ = half.exp(&bin(12, 0));
+    assert_eq!(flags::take(), Flags::OVERFLOW | Flags::INEXACT);
+    let _ = half.exp(&bin(11628855, -20)); // e^x ≈ 65521.94
+    assert_eq!(flags::take(), Flags::OVERFLOW | Flags::INEXACT);
+    let _ = half.exp_m1(&bin(12, 0));
+    assert_eq!(flags::take(), Flags::OVERFLOW | Flags::INEXACT);
+    let _ = half.exp(&bin(-20, 0));
+    assert_eq!(flags::take(), Flags::UNDERFLOW | Flags::INEXACT);
+    // the flags are not raised when deciding the rounding: 2^x ≈ 65519.9973 is close to the
+    // overflow threshold 65520, but the result is finite
+    let x = bin(268429545, -24);
+    assert_eq!(half.exp2(&x).value().repr(), &bin(2047, 5));
+    assert_eq!(flags::take(), Flags::INEXACT);
+
+    // invalid operations and division by zero
+    let inf = Repr::<2>::infinity();
+    let _ = ctx.sub(&inf, &inf);
+    assert_eq!(flags::take(), Flags::INVALID);
+    let _ = ctx.mul(&inf, &Repr::zero());
+    let _ = ctx.div(&Repr::<2>::zero(), &Repr::zero());
+    let _ = ctx.sqrt(&bin(-1, 0));
+    assert_eq!(flags::take(), Flags::INVALID);
+    let _ = ctx.div(&bin(-1, 0), &Repr::zero());
+    assert_eq!(flags::take(), Flags::DIVIDE_BY_ZERO);
+    let _ = ctx.div(&inf, &bin(3, 0));
+    assert!(flags::take().is_empty());
+
+    // the flags accumulate until cleared
+    let x = FBig::<HalfEven, 2>::from_repr(bin(1, 0), ctx);
+    let y = FBig::from_repr(bin(3, 0), ctx);
+    let _ = &x / &y;
+    let _ = &x / FBig::ZERO;
+    assert_eq!(flags::get(), Flags::INEXACT | Flags::DIVIDE_BY_ZERO);
+    flags::clear(Flags::DIVIDE_BY_ZERO);
+    assert_eq!(flags::take(), Flags::INEXACT);
+    assert!(flags::get().is_empty());
+}
+
+#[test]
+fn test_flags_ops() {
+    let flags = Flags::INEXACT | Flags::OVERFLOW;
+    assert!(flags.contains(Flags::INEXACT));
+    assert!(!flags.contains(Flags::INEXACT | Flags::INVALID));
+    assert!(flags.intersects(Flags::INEXACT | Flags::INVALID));
+    assert!(!flags.intersects(Flags::UNDERFLOW));
+    assert_eq!(flags & Flags::OVERFLOW, Flags::OVERFLOW);
+    assert_eq!(!flags, Flags::UNDERFLOW | Flags::INVALID | Flags::DIVIDE_BY_ZERO);
+    assert_eq!(!Flags::ALL, Flags::EMPTY);
+
+    assert_eq!(format!("{:?}", Flags::EMPTY), "EMPTY");
+    assert_eq!(format!("{:?}", flags), "INEXACT | OVERFLOW");
+    assert_eq!(
+        format!("{:?}", Flags::ALL),
+        "INEXACT | UNDERFLOW | OVERFLOW | INVALID | DIVIDE_BY_ZERO"
+    );
+}
+
+#[test]
+fn test_context() {
+    let ctx = Context::<HalfAway>::new(10);
+    assert!(!ctx.tracks_flags());
+    assert!(ctx.with_flags(true).tracks_flags());
+    assert!(Context::max(ctx, ctx.with_flags(true)).tracks_flags());
+    assert!(Context::max(ctx.with_flags(true), ctx).tracks_flags());
+}
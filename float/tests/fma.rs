@@ -0,0 +1,149 @@
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DotAccumulator,
+};
+
+mod helper_macros;
+
+#[test]
+fn test_mul_add_binary() {
+    // test cases: a, b, c, a * b + c
+    let exact_cases = [
+        (fbig!(0x3), fbig!(0x3), fbig!(-0x9), fbig!(0)),
+        (fbig!(0xf), fbig!(0xf), fbig!(-0x1), fbig!(0xep4)),
+        (fbig!(-0x5p-4), fbig!(0x3p4), fbig!(0x1), fbig!(-0xe)),
+    ];
+    for (a, b, c, d) in &exact_cases {
+        assert_eq!(a.mul_add(b, c), *d);
+        assert_eq!(a.context().mul_add(a.repr(), b.repr(), c.repr()), Exact(d.clone()));
+    }
+
+    let inexact_cases = [
+        (fbig!(0x3), fbig!(0x3), fbig!(0x1p-20), fbig!(0x9)),
+        (fbig!(0xf), fbig!(0xf), fbig!(0x1), fbig!(0xep4)),
+        (fbig!(0x3), fbig!(0x5), fbig!(-0x1p-20), fbig!(0xe)),
+    ];
+    for (a, b, c, d) in &inexact_cases {
+        assert_eq!(a.mul_add(b, c), *d);
+        if let Inexact(v, e) = a.context().mul_add(a.repr(), b.repr(), c.repr()) {
+            assert_eq!(v, *d);
+            assert_eq!(e, NoOp);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_mul_add_decimal() {
+    // test cases: a, b, c, a * b + c
+    let exact_cases = [
+        (dbig!(3), dbig!(3), dbig!(-9), dbig!(0)),
+        (dbig!(1234e-3), dbig!(6789e-3), dbig!(-8378e-3), dbig!(-374e-6)),
+        (dbig!(-25e-1), dbig!(4), dbig!(10), dbig!(0)),
+    ];
+    for (a, b, c, d) in &exact_cases {
+        assert_eq!(a.mul_add(b, c), *d);
+        assert_eq!(a.context().mul_add(a.repr(), b.repr(), c.repr()), Exact(d.clone()));
+    }
+
+    let inexact_cases = [
+        (dbig!(1234e-3), dbig!(5678e-3), dbig!(1e-10), dbig!(7007e-3), AddOne),
+        (dbig!(15e-1), dbig!(15e-1), dbig!(1e-10), dbig!(23e-1), AddOne),
+        (dbig!(-15e-1), dbig!(15e-1), dbig!(-1e-10), dbig!(-23e-1), SubOne),
+    ];
+    for (a, b, c, d, rnd) in &inexact_cases {
+        assert_eq!(a.mul_add(b, c), *d);
+        if let Inexact(v, e) = a.context().mul_add(a.repr(), b.repr(), c.repr()) {
+            assert_eq!(v, *d);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+
+    // the product 2.25 is not rounded to 2.3 before the subtraction
+    let (a, b, c) = (dbig!(15e-1), dbig!(15e-1), dbig!(-1e-10));
+    assert_eq!(a.mul_add(&b, &c), dbig!(22e-1));
+}
+
+#[test]
+fn test_mul_add_distant_exponents() {
+    let context = Context::<mode::Up>::new(4);
+    let (a, b) = (dbig!(2), dbig!(3));
+    assert_eq!(
+        context.mul_add(a.repr(), b.repr(), dbig!(1e-100000).repr()),
+        Inexact(dbig!(6001e-3).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        context.mul_add(a.repr(), b.repr(), dbig!(-1e-100000).repr()),
+        Inexact(dbig!(6).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        context.mul_add(dbig!(1e-50000).repr(), dbig!(1e-50000).repr(), dbig!(-6).repr()),
+        Inexact(dbig!(-5999e-3).with_rounding(), NoOp)
+    );
+
+    let context = Context::<mode::Zero>::new(8);
+    assert_eq!(
+        context.mul_add(fbig!(0x1p100000).repr(), fbig!(0x3).repr(), fbig!(-0x1).repr()),
+        Inexact(fbig!(0xbfp99994), NoOp)
+    );
+}
+
+#[test]
+fn test_mul_sub() {
+    // error-free transformation of a product: a * b = p + e exactly
+    let (a, b) = (dbig!(1234e-3), dbig!(6789e-3));
+    let p = &a * &b;
+    assert_eq!(p, dbig!(8378e-3));
+    assert_eq!(a.mul_sub(&b, &p), dbig!(-374e-6));
+
+    let (a, b) = (fbig!(0xf), fbig!(0xf));
+    let p = &a * &b;
+    assert_eq!(a.mul_sub(&b, &p), fbig!(0x1));
+
+    let context = Context::<mode::HalfAway>::new(2);
+    assert_eq!(
+        context.mul_sub(dbig!(1234e-3).repr(), dbig!(5678e-3).repr(), dbig!(-1e-10).repr()),
+        Inexact(dbig!(7e0), NoOp)
+    );
+}
+
+#[test]
+fn test_dot_accumulator() {
+    // the intermediate sums are not rounded
+    let context = Context::<mode::HalfAway>::new(4);
+    let mut acc = DotAccumulator::new(context);
+    acc.add_product(dbig!(1e10).repr(), dbig!(1e10).repr());
+    acc.add(dbig!(1).repr());
+    acc.sub_product(dbig!(1e10).repr(), dbig!(1e10).repr());
+    assert_eq!(acc.value(), Exact(dbig!(1)));
+
+    acc.sub(dbig!(1e-10).repr());
+    assert_eq!(acc.value(), Inexact(dbig!(1), AddOne));
+
+    // evaluate 1 + x + x^2 / 2 + x^3 / 6 at x = 0.1
+    let context = Context::<mode::HalfAway>::new(10);
+    let x = dbig!(1e-1);
+    let mut acc = DotAccumulator::new(context);
+    acc.add(dbig!(1).repr());
+    acc.add(x.repr());
+    acc.add_product(x.square().repr(), dbig!(5e-1).repr());
+    acc.add_product(
+        x.powi(3.into()).repr(),
+        context.div(dbig!(1).repr(), dbig!(6).repr()).value().repr(),
+    );
+    assert_eq!(acc.value(), Inexact(dbig!(1105166667e-9), AddOne));
+
+    // empty accumulator
+    let acc = DotAccumulator::<mode::Zero, 2>::new(Context::new(10));
+    assert_eq!(acc.value(), Exact(fbig!(0)));
+}
+
+#[test]
+#[should_panic]
+fn test_mul_add_inf() {
+    let _ = dbig!(1).mul_add(&dashu_float::DBig::INFINITY, &dbig!(1));
+}
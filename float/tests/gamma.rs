@@ -0,0 +1,149 @@
+use dashu_base::{Approximation::*, Sign};
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DBig,
+};
+
+mod helper_macros;
+
+type HalfAway = Context<mode::HalfAway>;
+
+#[test]
+fn test_gamma_binary() {
+    let exact_cases = [
+        (fbig!(0x1), fbig!(0x1)),
+        (fbig!(0x2), fbig!(0x1)),
+        (fbig!(0x5), fbig!(0x18)),
+        (fbig!(0x0000000000000015), fbig!(0x870d9df20adp18)),
+    ];
+    for (x, gamma) in &exact_cases {
+        assert_eq!(x.gamma(), *gamma);
+        assert_eq!(x.context().gamma(x.repr()), Exact(gamma.clone()));
+    }
+
+    let inexact_cases = [
+        (fbig!(0x7), fbig!(0xbp6)),
+        (fbig!(0x8000p-16), fbig!(0xe2dfp-15)),
+        (fbig!(-0x30p-5), fbig!(0x97p-6)),
+        (fbig!(0xabcdp-12), fbig!(0xefbfp5)),
+    ];
+    for (x, gamma) in &inexact_cases {
+        assert_eq!(x.gamma(), *gamma);
+        assert_eq!(x.context().gamma(x.repr()), Inexact(gamma.clone(), NoOp));
+    }
+}
+
+#[test]
+fn test_gamma_decimal() {
+    // test cases: x, precision, gamma(x), rounding
+    let inexact_cases = [
+        (dbig!(-1.234), 4, dbig!(4172e-3), NoOp),
+        (dbig!(1000.5), 20, dbig!(12723011956950554642e2547), AddOne),
+        (dbig!(1e-30), 10, dbig!(1e30), AddOne),
+        (dbig!(-3.0000001), 10, dbig!(1666666457e-3), NoOp),
+        (dbig!(0.5), 30, dbig!(177245385090551602729816748334e-29), NoOp),
+        (dbig!(-7.5), 15, dbig!(223849328859689e-18), NoOp),
+        (dbig!(30), 10, dbig!(8841761994e21), AddOne),
+        (dbig!(171.624376956302725), 18, dbig!(179769313486235462e291), AddOne),
+    ];
+    for (x, precision, gamma, rnd) in &inexact_cases {
+        let context = HalfAway::new(*precision);
+        assert_eq!(context.gamma(x.repr()), Inexact(gamma.clone(), *rnd));
+    }
+
+    let x = dbig!(-3.0000001);
+    let gamma = Context::<mode::Up>::new(10).gamma(x.repr());
+    assert_eq!(gamma.map(|v| v.with_rounding()), Inexact(dbig!(1666666458e-3), AddOne));
+    let x = dbig!(1e-30);
+    let gamma = Context::<mode::Down>::new(10).gamma(x.repr());
+    assert_eq!(gamma.map(|v| v.with_rounding()), Inexact(dbig!(9999999999e20), NoOp));
+}
+
+#[test]
+fn test_ln_gamma() {
+    assert_eq!(fbig!(0x1).ln_gamma(), (fbig!(0), Sign::Positive));
+    assert_eq!(fbig!(0x2).ln_gamma(), (fbig!(0), Sign::Positive));
+    assert_eq!(fbig!(0xabcdp-12).ln_gamma(), (fbig!(0xe7d9p-12), Sign::Positive));
+    assert_eq!(fbig!(-0xabcdp-12).ln_gamma(), (fbig!(-0xf691p-12), Sign::Negative));
+
+    // test cases: x, precision, log|gamma(x)|, rounding, sign
+    let inexact_cases = [
+        (dbig!(1.0000001), 10, dbig!(-5772155827e-17), SubOne, Sign::Positive),
+        (dbig!(2.00000000001), 8, dbig!(42278434e-19), AddOne, Sign::Positive),
+        (dbig!(1e10), 20, dbig!(22025850928881058147e-8), NoOp, Sign::Positive),
+        (dbig!(-100.3), 12, dbig!(-363766206183e-9), SubOne, Sign::Negative),
+        (dbig!(100), 12, dbig!(35913420537e-8), AddOne, Sign::Positive),
+        (dbig!(1e-20), 10, dbig!(4605170186e-8), AddOne, Sign::Positive),
+    ];
+    for (x, precision, ln_gamma, rnd, sign) in &inexact_cases {
+        let context = HalfAway::new(*precision);
+        assert_eq!(context.ln_gamma(x.repr()), (Inexact(ln_gamma.clone(), *rnd), *sign));
+    }
+}
+
+#[test]
+fn test_digamma() {
+    assert_eq!(fbig!(0xabcdp-12).digamma(), fbig!(0x2539p-12));
+    assert_eq!(fbig!(0x03).digamma(), fbig!(0x3bp-6));
+
+    // test cases: x, precision, digamma(x), rounding
+    let inexact_cases = [
+        (dbig!(1.461632144968362), 10, dbig!(-330230404e-24), NoOp),
+        (dbig!(1), 20, dbig!(-57721566490153286061e-20), SubOne),
+        (dbig!(10), 20, dbig!(22517525890667211076e-19), NoOp),
+        (dbig!(0.5), 20, dbig!(-19635100260214234794e-19), NoOp),
+        (dbig!(1.5), 20, dbig!(36489973978576520559e-21), NoOp),
+        (dbig!(-0.5), 20, dbig!(36489973978576520559e-21), NoOp),
+        (dbig!(-2.99), 12, dbig!(-987138237244e-10), NoOp),
+        (dbig!(1e6), 15, dbig!(138155100579642e-13), AddOne),
+    ];
+    for (x, precision, digamma, rnd) in &inexact_cases {
+        let context = HalfAway::new(*precision);
+        assert_eq!(context.digamma(x.repr()), Inexact(digamma.clone(), *rnd));
+    }
+}
+
+#[test]
+fn test_beta() {
+    let context = Context::<mode::Zero>::new(8);
+    assert_eq!(context.beta(fbig!(0x1).repr(), fbig!(0x4).repr()), Exact(fbig!(0x1p-2)));
+    assert_eq!(
+        context.beta(fbig!(0x2).repr(), fbig!(0x2).repr()),
+        Inexact(fbig!(0xaap-10), NoOp)
+    );
+    assert_eq!(fbig!(0x03).beta(&fbig!(0x02)), fbig!(0x55p-10));
+    assert_eq!(fbig!(0xabcdp-12).beta(&fbig!(0x1p-2)), fbig!(0x8151p-14));
+    assert_eq!(fbig!(-0x3p-1).beta(&fbig!(0x1p-1)), fbig!(0));
+
+    // test cases: x, y, precision, beta(x, y), rounding
+    let inexact_cases = [
+        (dbig!(0.5), dbig!(0.5), 20, dbig!(31415926535897932385e-19), AddOne),
+        (dbig!(100.5), dbig!(200.25), 15, dbig!(187925719271739e-98), NoOp),
+        (dbig!(2), dbig!(3), 5, dbig!(83333e-6), NoOp),
+        (dbig!(-1.5), dbig!(0.25), 10, dbig!(2185047962e-9), AddOne),
+        (dbig!(1e-10), dbig!(3), 12, dbig!(99999999985e-1), NoOp),
+    ];
+    for (x, y, precision, beta, rnd) in &inexact_cases {
+        let context = HalfAway::new(*precision);
+        assert_eq!(context.beta(x.repr(), y.repr()), Inexact(beta.clone(), *rnd));
+        assert_eq!(context.beta(y.repr(), x.repr()), Inexact(beta.clone(), *rnd));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_gamma_pole() {
+    let _ = dbig!(-3).gamma();
+}
+
+#[test]
+#[should_panic]
+fn test_ln_gamma_pole() {
+    let _ = DBig::ZERO.ln_gamma();
+}
+
+#[test]
+#[should_panic]
+fn test_digamma_pole() {
+    let _ = dbig!(-1).digamma();
+}
@@ -0,0 +1,340 @@
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode::HalfAway, Rounding::*},
+    Context, DBig, Repr,
+};
+use dashu_int::IBig;
+
+mod helper_macros;
+
+#[test]
+fn test_sinh_binary() {
+    assert_eq!(fbig!(0).sinh(), fbig!(0));
+
+    let inexact_cases = [
+        (fbig!(0x1p-1), fbig!(0x1p-1)),
+        (fbig!(0x3p-2), fbig!(0xdp-4)),
+        (fbig!(-0x3p-2), fbig!(-0xdp-4)),
+        (fbig!(0x1234p-12), fbig!(0xb323p-15)),
+        (fbig!(-0x1234p-12), fbig!(-0xb323p-15)),
+        (fbig!(0x1p-200), fbig!(0x1p-200)),
+        (fbig!(0x1), fbig!(0x9p-3)),
+        (fbig!(0x10), fbig!(0x87p15)),
+        (fbig!(0x1234), fbig!(0x7c67p6707)),
+    ];
+    for (x, y) in &inexact_cases {
+        assert_eq!(x.sinh(), *y);
+        if let Inexact(v, _) = x.context().sinh(x.repr()) {
+            assert_eq!(v, *y);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_sinh_decimal() {
+    assert_eq!(dbig!(0).sinh(), dbig!(0));
+
+    let inexact_cases = [
+        (dbig!(1e-1), dbig!(1e-1), NoOp),
+        (dbig!(-1e-1), dbig!(-1e-1), NoOp),
+        (dbig!(1234e-3), dbig!(1572e-3), AddOne),
+        (dbig!(-1234e-3), dbig!(-1572e-3), SubOne),
+        (dbig!(1e-50), dbig!(1e-50), NoOp),
+        (dbig!(-1e-50), dbig!(-1e-50), NoOp),
+        (dbig!(1000e-50), dbig!(1e-47), NoOp),
+        (dbig!(12345678901234567890e-69), dbig!(1234567890123456789e-68), NoOp),
+        (dbig!(2), dbig!(4), AddOne),
+        (dbig!(10), dbig!(11e3), NoOp),
+        (dbig!(100), dbig!(134e41), NoOp),
+        (dbig!(1234e2), dbig!(4345e53588), NoOp),
+    ];
+    for (x, y, rnd) in &inexact_cases {
+        assert_eq!(x.sinh(), *y);
+        if let Inexact(v, e) = x.context().sinh(x.repr()) {
+            assert_eq!(v, *y);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_cosh_binary() {
+    assert_eq!(fbig!(0).cosh(), fbig!(1));
+
+    let inexact_cases = [
+        (fbig!(0x1p-1), fbig!(0x9p-3)),
+        (fbig!(0x3p-2), fbig!(0x5p-2)),
+        (fbig!(-0x3p-2), fbig!(0x5p-2)),
+        (fbig!(0x1234p-12), fbig!(0xdc2bp-15)),
+        (fbig!(-0x1234p-12), fbig!(0xdc2bp-15)),
+        (fbig!(0x1p-200), fbig!(0x1)),
+        (fbig!(0x1), fbig!(0x3p-1)),
+        (fbig!(0x10), fbig!(0x87p15)),
+        (fbig!(0x1234), fbig!(0x7c67p6707)),
+    ];
+    for (x, y) in &inexact_cases {
+        assert_eq!(x.cosh(), *y);
+        if let Inexact(v, _) = x.context().cosh(x.repr()) {
+            assert_eq!(v, *y);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_cosh_decimal() {
+    assert_eq!(dbig!(0).cosh(), dbig!(1));
+
+    let inexact_cases = [
+        (dbig!(1e-1), dbig!(1), NoOp),
+        (dbig!(-1e-1), dbig!(1), NoOp),
+        (dbig!(1234e-3), dbig!(1863e-3), NoOp),
+        (dbig!(-1234e-3), dbig!(1863e-3), NoOp),
+        (dbig!(1e-50), dbig!(1), NoOp),
+        (dbig!(-1e-50), dbig!(1), NoOp),
+        (dbig!(1000e-50), dbig!(1), NoOp),
+        (dbig!(12345678901234567890e-69), dbig!(1), NoOp),
+        (dbig!(2), dbig!(4), AddOne),
+        (dbig!(10), dbig!(11e3), NoOp),
+        (dbig!(100), dbig!(134e41), NoOp),
+        (dbig!(1234e2), dbig!(4345e53588), NoOp),
+    ];
+    for (x, y, rnd) in &inexact_cases {
+        assert_eq!(x.cosh(), *y);
+        if let Inexact(v, e) = x.context().cosh(x.repr()) {
+            assert_eq!(v, *y);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_tanh_binary() {
+    assert_eq!(fbig!(0).tanh(), fbig!(0));
+
+    let inexact_cases = [
+        (fbig!(0x1p-1), fbig!(0x7p-4)),
+        (fbig!(0x3p-2), fbig!(0x5p-3)),
+        (fbig!(-0x3p-2), fbig!(-0x5p-3)),
+        (fbig!(0x1234p-12), fbig!(0x6825p-15)),
+        (fbig!(-0x1234p-12), fbig!(-0x6825p-15)),
+        (fbig!(0x1p-200), fbig!(0xfp-204)),
+        (fbig!(0x1), fbig!(0x3p-2)),
+        (fbig!(0x10), fbig!(0xffp-8)),
+        (fbig!(0x1234), fbig!(0xffffp-16)),
+    ];
+    for (x, y) in &inexact_cases {
+        assert_eq!(x.tanh(), *y);
+        if let Inexact(v, _) = x.context().tanh(x.repr()) {
+            assert_eq!(v, *y);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_tanh_decimal() {
+    assert_eq!(dbig!(0).tanh(), dbig!(0));
+
+    let inexact_cases = [
+        (dbig!(1e-1), dbig!(1e-1), AddOne),
+        (dbig!(-1e-1), dbig!(-1e-1), SubOne),
+        (dbig!(1234e-3), dbig!(8437e-4), NoOp),
+        (dbig!(-1234e-3), dbig!(-8437e-4), NoOp),
+        (dbig!(1e-50), dbig!(1e-50), AddOne),
+        (dbig!(-1e-50), dbig!(-1e-50), SubOne),
+        (dbig!(1000e-50), dbig!(1e-47), AddOne),
+        (dbig!(12345678901234567890e-69), dbig!(1234567890123456789e-68), AddOne),
+        (dbig!(2), dbig!(1), AddOne),
+        (dbig!(10), dbig!(1), AddOne),
+        (dbig!(100), dbig!(1), AddOne),
+        (dbig!(1234e2), dbig!(1), AddOne),
+    ];
+    for (x, y, rnd) in &inexact_cases {
+        assert_eq!(x.tanh(), *y);
+        if let Inexact(v, e) = x.context().tanh(x.repr()) {
+            assert_eq!(v, *y);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_asinh_binary() {
+    assert_eq!(fbig!(0).asinh(), fbig!(0));
+
+    let inexact_cases = [
+        (fbig!(0x1p-1), fbig!(0xfp-5)),
+        (fbig!(0x3p-2), fbig!(0xbp-4)),
+        (fbig!(-0x3p-2), fbig!(-0xbp-4)),
+        (fbig!(0x1234p-12), fbig!(0x1f37p-13)),
+        (fbig!(-0x1234p-12), fbig!(-0x1f37p-13)),
+        (fbig!(0x1p-200), fbig!(0xfp-204)),
+        (fbig!(0x1), fbig!(0x7p-3)),
+        (fbig!(0x10), fbig!(0xddp-6)),
+        (fbig!(0x1234), fbig!(0x923dp-12)),
+    ];
+    for (x, y) in &inexact_cases {
+        assert_eq!(x.asinh(), *y);
+        if let Inexact(v, _) = x.context().asinh(x.repr()) {
+            assert_eq!(v, *y);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_asinh_decimal() {
+    assert_eq!(dbig!(0).asinh(), dbig!(0));
+
+    let inexact_cases = [
+        (dbig!(1e-1), dbig!(1e-1), AddOne),
+        (dbig!(-1e-1), dbig!(-1e-1), SubOne),
+        (dbig!(1234e-3), dbig!(1038e-3), AddOne),
+        (dbig!(-1234e-3), dbig!(-1038e-3), SubOne),
+        (dbig!(1e-50), dbig!(1e-50), AddOne),
+        (dbig!(-1e-50), dbig!(-1e-50), SubOne),
+        (dbig!(1000e-50), dbig!(1e-47), AddOne),
+        (dbig!(12345678901234567890e-69), dbig!(1234567890123456789e-68), AddOne),
+        (dbig!(2), dbig!(1), NoOp),
+        (dbig!(10), dbig!(3), AddOne),
+        (dbig!(100), dbig!(53e-1), AddOne),
+        (dbig!(1234e2), dbig!(1242e-2), AddOne),
+    ];
+    for (x, y, rnd) in &inexact_cases {
+        assert_eq!(x.asinh(), *y);
+        if let Inexact(v, e) = x.context().asinh(x.repr()) {
+            assert_eq!(v, *y);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_acosh_binary() {
+    assert_eq!(fbig!(1).acosh(), fbig!(0));
+
+    let inexact_cases = [
+        (fbig!(0x2), fbig!(0x5p-2)),
+        (fbig!(0x1234p-12), fbig!(0x109bp-13)),
+        (fbig!(0x10001p-16), fbig!(0x5a827p-26)),
+        (fbig!(0x1p100), fbig!(0x1p6)),
+    ];
+    for (x, y) in &inexact_cases {
+        assert_eq!(x.acosh(), *y);
+        if let Inexact(v, _) = x.context().acosh(x.repr()) {
+            assert_eq!(v, *y);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_acosh_decimal() {
+    assert_eq!(dbig!(1).acosh(), dbig!(0));
+
+    let inexact_cases = [
+        (dbig!(2), dbig!(1), NoOp),
+        (dbig!(1234e-3), dbig!(6714e-4), NoOp),
+        (dbig!(10001e-4), dbig!(14142e-6), NoOp),
+        (dbig!(1000000000000000000001e-21), dbig!(4472135954999579392818e-32), AddOne),
+        (dbig!(10), dbig!(3), AddOne),
+        (dbig!(1e100), dbig!(2e2), NoOp),
+    ];
+    for (x, y, rnd) in &inexact_cases {
+        assert_eq!(x.acosh(), *y);
+        if let Inexact(v, e) = x.context().acosh(x.repr()) {
+            assert_eq!(v, *y);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_atanh_binary() {
+    assert_eq!(fbig!(0).atanh(), fbig!(0));
+
+    let inexact_cases = [
+        (fbig!(0x1p-1), fbig!(0x1p-1)),
+        (fbig!(-0x3p-2), fbig!(-0xfp-4)),
+        (fbig!(0xffffp-16), fbig!(0xbc89p-13)),
+        (fbig!(0x1p-200), fbig!(0x1p-200)),
+    ];
+    for (x, y) in &inexact_cases {
+        assert_eq!(x.atanh(), *y);
+        if let Inexact(v, _) = x.context().atanh(x.repr()) {
+            assert_eq!(v, *y);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_atanh_decimal() {
+    assert_eq!(dbig!(0).atanh(), dbig!(0));
+
+    let inexact_cases = [
+        (dbig!(1e-1), dbig!(1e-1), NoOp),
+        (dbig!(-1e-1), dbig!(-1e-1), NoOp),
+        (dbig!(1234e-4), dbig!(124e-3), NoOp),
+        (dbig!(-1234e-4), dbig!(-124e-3), NoOp),
+        (dbig!(9999e-4), dbig!(4952e-3), AddOne),
+        (dbig!(1e-50), dbig!(1e-50), NoOp),
+        (dbig!(12345678901234567890e-69), dbig!(1234567890123456789e-68), NoOp),
+    ];
+    for (x, y, rnd) in &inexact_cases {
+        assert_eq!(x.atanh(), *y);
+        if let Inexact(v, e) = x.context().atanh(x.repr()) {
+            assert_eq!(v, *y);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_long_input() {
+    // the input is not rounded to the working precision before the evaluation
+    let context = Context::<HalfAway>::new(10);
+    let pow = IBig::from(10).pow(100);
+    let one_plus = Repr::new(&pow + IBig::ONE, -100); // 1 + 10^-100
+    assert_eq!(context.acosh(&one_plus), Inexact(dbig!(1414213562e-59), NoOp));
+    let one_minus = Repr::new(pow - IBig::ONE, -100); // 1 - 10^-100
+    assert_eq!(context.atanh(&one_minus), Inexact(dbig!(1154758282e-7), NoOp));
+}
+
+#[test]
+#[should_panic]
+fn test_acosh_out_of_domain() {
+    let _ = DBig::from_str_native("0.999").unwrap().acosh();
+}
+
+#[test]
+#[should_panic]
+fn test_atanh_out_of_domain() {
+    let _ = dbig!(-1).atanh();
+}
+
+#[test]
+#[should_panic]
+fn test_sinh_inf() {
+    let _ = DBig::INFINITY.sinh();
+}
@@ -0,0 +1,378 @@
+use core::cmp::Ordering;
+
+use dashu_base::{Abs, Approximation::*};
+use dashu_float::{
+    round::mode::{Down, HalfAway, HalfEven, Zero},
+    Context, DBig, FBig, Repr,
+};
+
+mod helper_macros;
+
+type FBin = FBig<HalfEven, 2>;
+
+fn ieee(x: DBig) -> DBig {
+    x.with_ieee(true)
+}
+
+fn assert_nan(x: &DBig) {
+    assert!(x.repr().is_nan(), "{:?} is not NaN", x);
+}
+
+fn assert_neg_zero(x: &DBig) {
+    assert!(x.repr().is_neg_zero(), "{:?} is not -0", x);
+}
+
+fn assert_pos_zero(x: &DBig) {
+    assert!(x.repr().is_zero() && !x.repr().is_neg_zero(), "{:?} is not +0", x);
+}
+
+#[test]
+fn test_special_repr() {
+    let nan = Repr::<2>::nan();
+    assert!(nan.is_nan() && !nan.is_finite() && !nan.is_infinite() && !nan.is_zero());
+    let neg_zero = Repr::<2>::neg_zero();
+    assert!(neg_zero.is_zero() && neg_zero.is_neg_zero() && neg_zero.is_finite());
+    assert_eq!(neg_zero, Repr::zero());
+    assert_eq!(neg_zero.digits(), 0);
+    assert!(Repr::<2>::infinity().is_infinite() && !Repr::<2>::infinity().is_nan());
+
+    let ctx = Context::<Zero>::new(10);
+    assert!(!ctx.is_ieee());
+    let ieee_ctx = ctx.with_ieee(true);
+    assert!(ieee_ctx.is_ieee());
+    assert!(Context::max(ctx, ieee_ctx).is_ieee());
+    assert!(Context::max(ieee_ctx, ctx).is_ieee());
+    assert!(!ieee_ctx.with_ieee(false).is_ieee());
+
+    // the mode is preserved by the conversions
+    let x = ieee(dbig!(1.5));
+    assert!(x.context().is_ieee());
+    assert!(x.clone().with_precision(1).value().context().is_ieee());
+    assert!(x.clone().with_rounding::<Zero>().context().is_ieee());
+    assert!(x.with_base::<2>().value().context().is_ieee());
+}
+
+#[test]
+fn test_add_sub() {
+    let inf = ieee(DBig::INFINITY);
+    let neg_inf = ieee(DBig::NEG_INFINITY);
+    let nan = ieee(DBig::NAN);
+    let zero = ieee(DBig::ZERO);
+    let neg_zero = -&zero;
+    let one = ieee(dbig!(1));
+
+    assert_nan(&(&inf - &inf));
+    assert_nan(&(&inf + &neg_inf));
+    assert_nan(&(&nan + &one));
+    assert_nan(&(&one - &nan));
+    assert_eq!(&inf + &inf, DBig::INFINITY);
+    assert_eq!(&inf - &neg_inf, DBig::INFINITY);
+    assert_eq!(&neg_inf + &one, DBig::NEG_INFINITY);
+    assert_eq!(&one - &inf, DBig::NEG_INFINITY);
+
+    // sign of zeros
+    assert_neg_zero(&(&neg_zero + &neg_zero));
+    assert_neg_zero(&(&neg_zero - &zero));
+    assert_pos_zero(&(&neg_zero + &zero));
+    assert_pos_zero(&(&zero - &zero));
+    assert_pos_zero(&(&neg_zero - &neg_zero));
+    assert_pos_zero(&(&one - &one));
+    assert_pos_zero(&(dbig!(-1.5).with_ieee(true) + dbig!(1.5)));
+    assert_eq!(&neg_zero + &one, DBig::ONE);
+    assert_eq!(&one + &neg_zero, DBig::ONE);
+
+    // exact cancellation gives -0 when rounding toward -inf
+    let one = one.with_rounding::<Down>();
+    assert!((&one - &one).repr().is_neg_zero());
+    let ctx = Context::<Down>::new(4).with_ieee(true);
+    assert!(ctx
+        .sub(&Repr::<2>::one(), &Repr::one())
+        .value()
+        .repr()
+        .is_neg_zero());
+    assert!(ctx
+        .add(&Repr::<2>::neg_zero(), &Repr::zero())
+        .value()
+        .repr()
+        .is_neg_zero());
+
+    // context methods
+    let ctx = Context::<HalfAway>::new(4).with_ieee(true);
+    assert!(ctx
+        .sub(&Repr::<10>::infinity(), &Repr::infinity())
+        .value()
+        .repr()
+        .is_nan());
+    assert_eq!(ctx.add(&Repr::<10>::infinity(), &Repr::one()), Exact(DBig::INFINITY));
+    assert!(ctx
+        .add(&Repr::<10>::neg_zero(), &Repr::neg_zero())
+        .value()
+        .repr()
+        .is_neg_zero());
+}
+
+#[test]
+fn test_mul() {
+    let inf = ieee(DBig::INFINITY);
+    let nan = ieee(DBig::NAN);
+    let zero = ieee(DBig::ZERO);
+    let neg_zero = -&zero;
+    let two = ieee(dbig!(2));
+    let neg_two = ieee(dbig!(-2));
+
+    assert_nan(&(&inf * &zero));
+    assert_nan(&(&neg_zero * &inf));
+    assert_nan(&(&nan * &two));
+    assert_eq!(&inf * &neg_two, DBig::NEG_INFINITY);
+    assert_eq!(-&inf * &neg_two, DBig::INFINITY);
+
+    assert_neg_zero(&(&neg_two * &zero));
+    assert_neg_zero(&(&zero * &neg_two));
+    assert_neg_zero(&(&neg_zero * &two));
+    assert_pos_zero(&(&neg_zero * &neg_zero));
+    assert_pos_zero(&(&neg_zero * &neg_two));
+    assert_pos_zero(&neg_zero.square());
+
+    let ctx = Context::<HalfAway>::new(4).with_ieee(true);
+    assert!(ctx
+        .mul(&Repr::<10>::neg_one(), &Repr::zero())
+        .value()
+        .repr()
+        .is_neg_zero());
+    assert!(ctx
+        .mul(&Repr::<10>::zero(), &Repr::infinity())
+        .value()
+        .repr()
+        .is_nan());
+}
+
+#[test]
+fn test_div() {
+    let inf = ieee(DBig::INFINITY);
+    let nan = ieee(DBig::NAN);
+    let zero = ieee(DBig::ZERO);
+    let neg_zero = -&zero;
+    let two = ieee(dbig!(2));
+
+    assert_nan(&(&zero / &zero));
+    assert_nan(&(&neg_zero / &zero));
+    assert_nan(&(&inf / &inf));
+    assert_nan(&(&nan / &two));
+    assert_nan(&(&two / &nan));
+
+    assert_eq!(&two / &zero, DBig::INFINITY);
+    assert_eq!(&two / &neg_zero, DBig::NEG_INFINITY);
+    assert_eq!(-&two / &neg_zero, DBig::INFINITY);
+    assert_eq!(&inf / -&two, DBig::NEG_INFINITY);
+    assert_pos_zero(&(&two / &inf));
+    assert_neg_zero(&(-&two / &inf));
+    assert_neg_zero(&(&neg_zero / &two));
+    assert_pos_zero(&(&neg_zero / -&two));
+    assert_eq!(&two / &two, DBig::ONE);
+
+    let ctx = Context::<HalfAway>::new(4).with_ieee(true);
+    assert_eq!(ctx.div(&Repr::<10>::one(), &Repr::neg_zero()), Exact(DBig::NEG_INFINITY));
+    assert!(ctx
+        .div(&Repr::<10>::zero(), &Repr::zero())
+        .value()
+        .repr()
+        .is_nan());
+}
+
+#[test]
+fn test_sqrt() {
+    let ctx = Context::<HalfAway>::new(4).with_ieee(true);
+    assert!(ctx
+        .sqrt(&Repr::<10>::neg_zero())
+        .value()
+        .repr()
+        .is_neg_zero());
+    assert!(ctx.sqrt(&Repr::<10>::neg_one()).value().repr().is_nan());
+    assert!(ctx
+        .sqrt(&Repr::<10>::neg_infinity())
+        .value()
+        .repr()
+        .is_nan());
+    assert!(ctx.sqrt(&Repr::<10>::nan()).value().repr().is_nan());
+    assert_eq!(ctx.sqrt(&Repr::<10>::infinity()), Exact(DBig::INFINITY));
+    assert_eq!(ctx.sqrt(&Repr::<10>::new(4.into(), 0)), Exact(dbig!(2)));
+
+    assert_neg_zero(&(-ieee(DBig::ZERO)).sqrt());
+    assert_nan(&ieee(dbig!(-4)).sqrt());
+}
+
+#[test]
+fn test_nth_root() {
+    let ctx = Context::<HalfAway>::new(4).with_ieee(true);
+    assert!(ctx
+        .cbrt(&Repr::<10>::neg_zero())
+        .value()
+        .repr()
+        .is_neg_zero());
+    assert_eq!(ctx.cbrt(&Repr::<10>::neg_infinity()), Exact(DBig::NEG_INFINITY));
+    assert_eq!(ctx.cbrt(&Repr::<10>::infinity()), Exact(DBig::INFINITY));
+    assert!(ctx.cbrt(&Repr::<10>::nan()).value().repr().is_nan());
+    assert_eq!(ctx.cbrt(&Repr::<10>::new((-8).into(), 0)), Exact(dbig!(-2)));
+
+    // the even roots of negative numbers are invalid, except that the root of -0 is +0
+    assert!(ctx
+        .nth_root(&Repr::<10>::neg_one(), 4)
+        .value()
+        .repr()
+        .is_nan());
+    assert!(ctx
+        .nth_root(&Repr::<10>::neg_infinity(), 4)
+        .value()
+        .repr()
+        .is_nan());
+    let zero = ctx.nth_root(&Repr::<10>::neg_zero(), 4).value();
+    assert!(zero.repr().is_zero() && !zero.repr().is_neg_zero());
+    assert!(ctx.nth_root(&Repr::<10>::nan(), 5).value().repr().is_nan());
+    assert_eq!(ctx.nth_root(&Repr::<10>::neg_infinity(), 1), Exact(DBig::NEG_INFINITY));
+
+    assert_neg_zero(&(-ieee(DBig::ZERO)).cbrt());
+    assert_neg_zero(&(-ieee(DBig::ZERO)).nth_root(5));
+    assert_eq!(ieee(DBig::NEG_INFINITY).cbrt(), DBig::NEG_INFINITY);
+    assert_nan(&ieee(DBig::NAN).cbrt());
+    assert_nan(&ieee(dbig!(-16)).nth_root(4));
+}
+
+#[test]
+fn test_sign() {
+    let zero = ieee(DBig::ZERO);
+    assert_neg_zero(&-&zero);
+    assert_pos_zero(&-(-&zero));
+    assert_pos_zero(&(-&zero).abs());
+    assert_nan(&-ieee(DBig::NAN));
+
+    // without the IEEE mode, the zero is not negated
+    assert_pos_zero(&-DBig::ZERO);
+
+    // the infinities are negated regardless of the mode
+    assert_eq!(-DBig::INFINITY, DBig::NEG_INFINITY);
+    assert_eq!(-DBig::NEG_INFINITY, DBig::INFINITY);
+    assert_eq!(DBig::NEG_INFINITY.abs(), DBig::INFINITY);
+
+    assert_eq!((-&zero).signum(), DBig::ZERO);
+    assert_eq!(DBig::NEG_INFINITY.signum(), DBig::NEG_ONE);
+    assert_nan(&DBig::NAN.signum());
+}
+
+#[test]
+fn test_cmp() {
+    let neg_zero = -ieee(DBig::ZERO);
+    assert_eq!(neg_zero, DBig::ZERO);
+    assert_eq!(DBig::ZERO, neg_zero);
+    assert_eq!(neg_zero.cmp(&DBig::ZERO), Ordering::Equal);
+    assert!(neg_zero > dbig!(-1e-10) && neg_zero < dbig!(1e-10));
+
+    // NaN is equal to itself and greater than all other numbers
+    assert_eq!(DBig::NAN, DBig::NAN);
+    assert_ne!(DBig::NAN, DBig::INFINITY);
+    assert_ne!(DBig::NAN, DBig::ZERO);
+    assert!(DBig::NAN > DBig::INFINITY);
+    assert!(DBig::NEG_INFINITY < DBig::NAN);
+    assert!(dbig!(1e100) < DBig::NAN);
+}
+
+#[test]
+fn test_format() {
+    let neg_zero = -ieee(DBig::ZERO);
+    assert_eq!(neg_zero.to_string(), "-0");
+    assert_eq!(format!("{:.2}", neg_zero), "-0.00");
+    assert_eq!(format!("{:>4}", neg_zero), "  -0");
+    assert_eq!(format!("{:?}", neg_zero.repr()), "-0");
+    assert_eq!(DBig::NAN.to_string(), "NaN");
+    assert_eq!(format!("{:?}", DBig::NAN), "NaN");
+    assert_eq!(format!("{:?}", Repr::<2>::nan()), "NaN");
+}
+
+#[test]
+fn test_f64_conversion() {
+    // the sign of zero is only kept in the IEEE mode
+    let zero = FBin::try_from(-0f64).unwrap();
+    assert!(!zero.repr().is_neg_zero());
+    assert!(!zero.to_f64().value().is_sign_negative());
+    assert_eq!(zero.to_string(), "0");
+    assert_eq!(zero.sqrt().to_string(), "0");
+    assert!(!FBin::try_from(-0f32).unwrap().repr().is_neg_zero());
+
+    let neg_zero = -FBin::ZERO.with_ieee(true);
+    assert!(neg_zero.repr().is_neg_zero());
+    assert_eq!(neg_zero.to_f64(), Exact(-0f64));
+    assert!(neg_zero.to_f64().value().is_sign_negative());
+    assert!(neg_zero.to_f32().value().is_sign_negative());
+    assert!(!FBin::try_from(0f64)
+        .unwrap()
+        .to_f64()
+        .value()
+        .is_sign_negative());
+    assert!(FBin::NAN.to_f64().value().is_nan());
+    assert!(FBin::NAN.to_f32().value().is_nan());
+}
+
+/// The operations under the IEEE mode with 53 bits of precision and HalfEven rounding
+/// should agree with the f64 operations (when there's no overflow or underflow).
+#[test]
+fn test_mirror_f64() {
+    let values = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        0.1,
+        -3.5,
+        7e10,
+        1.0 / 3.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+    let to_fbig = |f: f64| {
+        if f.is_nan() {
+            FBin::NAN.with_ieee(true)
+        } else if f == 0. && f.is_sign_negative() {
+            -FBin::ZERO.with_ieee(true)
+        } else {
+            FBin::try_from(f).unwrap().with_ieee(true)
+        }
+    };
+    let check = |op: &str, expected: f64, actual: FBin| {
+        let actual = actual.to_f64().value();
+        if expected.is_nan() {
+            assert!(actual.is_nan(), "{}: expect NaN, got {}", op, actual);
+        } else {
+            assert_eq!(expected.to_bits(), actual.to_bits(), "{}: {} != {}", op, expected, actual);
+        }
+    };
+
+    for &a in &values {
+        for &b in &values {
+            let (fa, fb) = (to_fbig(a), to_fbig(b));
+            check(&format!("{} + {}", a, b), a + b, &fa + &fb);
+            check(&format!("{} - {}", a, b), a - b, &fa - &fb);
+            check(&format!("{} * {}", a, b), a * b, &fa * &fb);
+            check(&format!("{} / {}", a, b), a / b, &fa / &fb);
+        }
+        check(&format!("-{}", a), -a, -to_fbig(a));
+        check(&format!("sqrt({})", a), a.sqrt(), to_fbig(a).sqrt());
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_inf_sub_without_ieee() {
+    let _ = DBig::INFINITY - DBig::INFINITY;
+}
+
+#[test]
+#[should_panic]
+fn test_nan_add_without_ieee() {
+    let _ = DBig::NAN + DBig::ONE;
+}
+
+#[test]
+#[should_panic]
+fn test_zero_div_without_ieee() {
+    let _ = DBig::ZERO / DBig::ZERO;
+}
@@ -0,0 +1,237 @@
+use dashu_float::{
+    round::mode::{Down, HalfEven, Up},
+    Context, DBig, FBig, Interval, Repr,
+};
+use dashu_int::IBig;
+
+mod helper_macros;
+
+type DInterval = Interval<10>;
+
+/// Create a decimal interval with the given precision
+fn interval(lower: &str, upper: &str, precision: usize) -> DInterval {
+    Interval::new(DBig::from_str_native(lower).unwrap(), DBig::from_str_native(upper).unwrap())
+        .with_precision(precision)
+}
+
+fn assert_endpoints(x: &DInterval, lower: &str, upper: &str) {
+    assert_eq!(x.lower(), &DBig::from_str_native(lower).unwrap(), "lower of {:?}", x);
+    assert_eq!(x.upper(), &DBig::from_str_native(upper).unwrap(), "upper of {:?}", x);
+}
+
+#[test]
+fn test_construct() {
+    let a = interval("1.2", "1.35", 3);
+    assert_eq!(a.precision(), 3);
+    assert!(!a.is_point());
+
+    let b = Interval::from_point(dbig!(1.25));
+    assert!(b.is_point());
+    assert_eq!(b, DInterval::from(dbig!(1.25)));
+    assert_eq!(DInterval::from(ibig!(3)), Interval::from_point(dbig!(3)));
+
+    let c = Interval::from_ball(&dbig!(1.5), &dbig!(0.25));
+    assert_endpoints(&c, "1.25", "1.75");
+    // the endpoints are rounded outward
+    let c = Interval::from_ball(&dbig!(1.23), &dbig!(0.01)).with_precision(2);
+    assert_endpoints(&c, "1.2", "1.3");
+    let c = Interval::from_ball(&dbig!(-1.23), &dbig!(0.001));
+    assert_endpoints(&c, "-1.231", "-1.229");
+
+    let (lower, upper) = a.into_parts();
+    assert_eq!(lower, dbig!(1.2));
+    assert_eq!(upper, dbig!(1.35));
+}
+
+#[test]
+#[should_panic]
+fn test_construct_reversed() {
+    let _ = interval("1.3", "1.2", 3);
+}
+
+#[test]
+#[should_panic]
+fn test_construct_inf() {
+    let _ = Interval::new(DBig::ZERO, DBig::INFINITY);
+}
+
+#[test]
+fn test_predicates() {
+    let a = interval("1.2", "1.3", 3);
+    let b = interval("1.25", "1.3", 3);
+    let c = interval("1.3", "1.4", 3);
+    let d = interval("1.35", "1.4", 3);
+
+    assert!(a.contains(&dbig!(1.2)));
+    assert!(a.contains(&dbig!(1.25)));
+    assert!(a.contains(&dbig!(1.3)));
+    assert!(!a.contains(&dbig!(1.31)));
+    assert!(!a.contains(&dbig!(-1.25)));
+
+    assert!(a.encloses(&a));
+    assert!(a.encloses(&b));
+    assert!(!b.encloses(&a));
+    assert!(!a.encloses(&c));
+
+    assert!(a.overlaps(&b) && b.overlaps(&a));
+    assert!(a.overlaps(&c) && c.overlaps(&a));
+    assert!(!a.overlaps(&d) && !d.overlaps(&a));
+
+    assert_eq!(a.width(), dbig!(0.1));
+    assert_eq!(interval("1", "1.00001", 2).width(), dbig!(0.1));
+}
+
+#[test]
+fn test_add_sub() {
+    let a = interval("1.2", "1.3", 3);
+    let b = interval("2.05", "2.15", 3);
+    assert_endpoints(&(&a + &b), "3.25", "3.45");
+    assert_endpoints(&(&a - &b), "-0.95", "-0.75");
+    assert_endpoints(&(b.clone() - a.clone()), "0.75", "0.95");
+    assert_endpoints(&-&a, "-1.3", "-1.2");
+
+    // the rounding is outward
+    let c = interval("0.001", "0.002", 3);
+    let d = interval("1", "1", 3);
+    assert_endpoints(&(&c + &d), "1.00", "1.01");
+    assert_endpoints(&(&d - &c), "0.998", "0.999");
+}
+
+#[test]
+fn test_mul_div() {
+    let a = interval("-2", "3", 2);
+    let b = interval("-5", "4", 2);
+    assert_endpoints(&(&a * &b), "-15", "12");
+    let c = interval("-2", "-1", 2);
+    assert_endpoints(&(&a * &c), "-6", "4");
+    assert_endpoints(&(&c * &c), "1", "4");
+
+    let a = interval("1", "2", 3);
+    let b = interval("3", "4", 3);
+    assert_endpoints(&(&a / &b), "0.25", "0.667");
+    assert_endpoints(&(&a / -&b), "-0.667", "-0.25");
+    assert_endpoints(&(-&a / &b), "-0.667", "-0.25");
+    assert_endpoints(&(&b / &a), "1.5", "4");
+
+    let x = interval("3.140", "3.142", 4);
+    assert_endpoints(&(&x * &x), "9.859", "9.873");
+}
+
+#[test]
+#[should_panic]
+fn test_div_by_0() {
+    let _ = interval("1", "2", 3) / interval("-1", "1", 3);
+}
+
+#[test]
+fn test_functions() {
+    let a = interval("0", "1", 5);
+    assert_endpoints(&a.exp(), "1", "2.7183");
+    let b = interval("-1", "1", 5);
+    assert_endpoints(&b.exp(), "0.36787", "2.7183");
+    let c = interval("1", "2", 5);
+    assert_endpoints(&c.ln(), "0", "0.69315");
+    assert_endpoints(&c.sqrt(), "1", "1.4143");
+    let d = interval("-1", "4", 5);
+    assert_endpoints(&d.sqrt(), "0", "2");
+
+    // the result always contains the exact value
+    let x = Interval::from_point(dbig!(2.00000000000000000000));
+    let ln2 = x.ln();
+    assert!(ln2.contains(&DBig::from_str_native("0.693147180559945309417232").unwrap()));
+    assert_eq!(ln2.exp().to_string(), "2.0000000000000000000");
+
+    // binary intervals
+    let y = Interval::<2>::from_point(fbig!(0x3p-1).with_precision(40).value());
+    let sqrt = y.sqrt();
+    assert!(sqrt.lower() < sqrt.upper());
+    assert_eq!(sqrt.precision(), 40);
+    let square = &sqrt * &sqrt;
+    assert!(square.contains(&fbig!(0x3p-1)));
+}
+
+#[test]
+fn test_exp_huge_argument() {
+    // the reduction of a huge argument still gives rigorous bounds
+    let cases = [
+        (943660903i64, 1, 61),
+        (-943660903, 1, 61),
+        (123456789123, 3, 100),
+        (987654321987654321, -2, 64),
+        (3, 40, 53),
+        (-7, 38, 30),
+        (1, 62, 24),
+    ];
+    for (significand, exponent, precision) in cases {
+        let x = Repr::<2>::new(IBig::from(significand), exponent);
+        let exp = Interval::from_point(FBig::<HalfEven, 2>::from_repr(x.clone(), Context::new(64)))
+            .with_precision(precision)
+            .exp();
+        let exact = Context::<HalfEven>::new(300).exp(&x).value();
+        assert!(exp.lower() <= exp.upper(), "exp({:?}) = {:?}", x, exp);
+        assert!(exp.contains(&exact), "exp({:?}) = {:?}", x, exp);
+    }
+}
+
+#[test]
+fn test_exp_ln_containment() {
+    // the bounds contain the exact values, which are approximated with a much higher precision
+    let points = [
+        (15i64, -1),
+        (407, -1),
+        (27573, -14),
+        (1, 0),
+        (-3, -2),
+        (12345, -10),
+        (-99, 1),
+        (7, 5),
+        (3, -20),
+    ];
+    for (significand, exponent) in points {
+        let x = Repr::<2>::new(IBig::from(significand), exponent);
+        let exp = Context::<HalfEven>::new(256).exp(&x).value();
+        let ln = (significand > 0).then(|| Context::<HalfEven>::new(256).ln(&x).value());
+        for precision in 2..=12 {
+            let point = FBig::<HalfEven, 2>::from_repr(x.clone(), Context::new(32));
+            let point = Interval::from_point(point).with_precision(precision);
+            assert!(point.exp().contains(&exp), "exp({:?}) = {:?}", x, point.exp());
+            if let Some(ln) = &ln {
+                assert!(point.ln().contains(ln), "ln({:?}) = {:?}", x, point.ln());
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_ln_non_positive() {
+    let _ = interval("0", "1", 2).ln();
+}
+
+#[test]
+#[should_panic]
+fn test_sqrt_negative() {
+    let _ = interval("-2", "-1", 2).sqrt();
+}
+
+#[test]
+fn test_base_conversion() {
+    let a = Interval::from_point(FBig::<Down>::try_from(0.1f64).unwrap());
+    let b = a.with_base::<10>();
+    assert!(b.contains(
+        &FBig::<Up, 10>::from_str_native("0.1000000000000000055511151231257827").unwrap()
+    ));
+    assert_eq!(b.width(), dbig!(1e-15));
+}
+
+#[test]
+fn test_print() {
+    assert_eq!(Interval::from_point(dbig!(1.5)).to_string(), "1.5");
+    assert_eq!(interval("0.149", "0.150", 3).to_string(), "0.15");
+    assert_eq!(interval("0.0999", "0.1001", 4).to_string(), "0.10");
+    assert_eq!(interval("1.2344", "1.2346", 5).to_string(), "1.23");
+    assert_eq!(interval("99.9", "100.1", 4).to_string(), "100");
+    assert_eq!(interval("-0.001", "0.001", 1).to_string(), "[-0.001, 0.001]");
+    assert_eq!(interval("1", "9", 1).to_string(), "[1, 9]");
+    assert!(format!("{:?}", interval("1", "9", 1)).starts_with("Interval"));
+}
@@ -307,6 +307,8 @@ fn test_format_binary() {
     assert_eq!(format!("{:8.4}", fbig!(0x1p-5)), "  0.0000");
     assert_eq!(format!("{:8.4}", fbig!(-0x1p-5)), " -0.0000");
 
+    assert_eq!(format!("{:8}", fbig!(0x5)), "     101");
+    assert_eq!(format!("{:8}", fbig!(-0x5)), "    -101");
     assert_eq!(format!("{:16}", fbig!(0x123p-4)), "      10010.0011");
     assert_eq!(format!("{:16}", fbig!(-0x123p-4)), "     -10010.0011");
     assert_eq!(format!("{:+16}", fbig!(0x123p-4)), "     +10010.0011");
@@ -439,6 +441,8 @@ fn test_format_decimal() {
     assert_eq!(format!("{:8.4}", dbig!(99e-5)), "  0.0010");
     assert_eq!(format!("{:8.4}", dbig!(-99e-5)), " -0.0010");
 
+    assert_eq!(format!("{:6}", dbig!(1234)), "  1234");
+    assert_eq!(format!("{:<6}", dbig!(-12)), "-12   ");
     assert_eq!(format!("{:8}", dbig!(123e-2)), "    1.23");
     assert_eq!(format!("{:8}", dbig!(-123e-2)), "   -1.23");
     assert_eq!(format!("{:+8}", dbig!(123e-2)), "   +1.23");
@@ -1,4 +1,8 @@
-use dashu_float::DBig;
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DBig, SumExact,
+};
 type FBig = dashu_float::FBig;
 
 mod helper_macros;
@@ -82,3 +86,68 @@ fn test_prod() {
     assert_eq!(nums.iter().product::<DBig>(), dbig!(0));
     assert_eq!(nums.into_iter().product::<DBig>(), dbig!(0));
 }
+
+#[test]
+fn test_sum_exact() {
+    let nums = [fbig!(0x1p100000), fbig!(0x1), fbig!(-0x1p100000)];
+    assert_eq!(FBig::sum_exact(&nums), fbig!(0x1));
+    assert_eq!(nums.iter().sum::<FBig>(), fbig!(0));
+    assert_eq!(FBig::sum_exact(&nums[..0]), fbig!(0));
+    assert_eq!(FBig::sum_exact(&nums[..2]), fbig!(0x1p100000));
+    assert_eq!(nums.iter().sum_exact(), fbig!(0x1));
+    assert_eq!(nums[..2].iter().sum_exact(), fbig!(0x1p100000));
+
+    let nums = [
+        dbig!(1e-100000),
+        dbig!(1234),
+        dbig!(-1234),
+        dbig!(-1e-100000),
+    ];
+    assert_eq!(DBig::sum_exact(&nums), dbig!(0));
+    assert_eq!(DBig::sum_exact(nums.clone().into_iter().skip(1)), dbig!(-1e-100000));
+    assert_eq!(nums.into_iter().skip(1).sum_exact(), dbig!(-1e-100000));
+}
+
+#[test]
+fn test_context_sum() {
+    let context = Context::<mode::Zero>::new(8);
+    let nums = [fbig!(0xff), fbig!(0x1p-1000), fbig!(-0x1p-1001)];
+    let reprs = || nums.iter().map(|x| x.repr());
+    assert_eq!(context.sum(reprs().take(0)), Exact(fbig!(0)));
+    assert_eq!(context.sum(reprs().take(1)), Exact(fbig!(0xff)));
+    assert_eq!(context.sum(reprs()), Inexact(fbig!(0xff), NoOp));
+    assert_eq!(context.sum(reprs().skip(1)), Exact(fbig!(0x1p-1001)));
+    assert_eq!(context.sum([nums[0].repr(), nums[2].repr()]), Inexact(fbig!(0xfe), NoOp));
+
+    let context = Context::<mode::HalfAway>::new(3);
+    let nums = [dbig!(1e50), dbig!(9.995), dbig!(-1e50), dbig!(1e-50)];
+    let reprs = || nums.iter().map(|x| x.repr());
+    assert_eq!(context.sum(reprs().take(3)), Inexact(dbig!(10.0), AddOne));
+    assert_eq!(context.sum(reprs()), Inexact(dbig!(10.0), AddOne));
+    assert_eq!(context.sum(reprs().take(2)), Inexact(dbig!(1.00e50), NoOp));
+}
+
+#[test]
+fn test_context_dot() {
+    let context = Context::<mode::HalfAway>::new(4);
+    let a = [dbig!(1.234), dbig!(-5.678), dbig!(1e100)];
+    let b = [dbig!(9.876), dbig!(2.146), dbig!(1e-200)];
+    let dot =
+        |n: usize| context.dot(a.iter().take(n).map(|x| x.repr()), b.iter().map(|x| x.repr()));
+    assert_eq!(dot(0), Exact(dbig!(0)));
+    assert_eq!(dot(1), Inexact(dbig!(12.19), AddOne));
+    assert_eq!(dot(2), Exact(dbig!(0.001996)));
+    assert_eq!(dot(3), Inexact(dbig!(0.001996), NoOp));
+
+    let context = Context::<mode::Zero>::new(4);
+    let a = [fbig!(0x1p10000), fbig!(-0x1p10000)];
+    let b = [fbig!(0x1p-10000), fbig!(0x1p-10000)];
+    assert_eq!(
+        context.dot(a.iter().map(|x| x.repr()), b.iter().map(|x| x.repr())),
+        Exact(fbig!(0))
+    );
+    assert_eq!(
+        context.dot(a.iter().map(|x| x.repr()), b.iter().rev().map(|x| x.repr())),
+        Exact(fbig!(0))
+    );
+}
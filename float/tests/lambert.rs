@@ -0,0 +1,146 @@
+use core::str::FromStr;
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DBig,
+};
+
+mod helper_macros;
+
+type HalfAway = Context<mode::HalfAway>;
+
+#[test]
+fn test_lambert_w0_binary() {
+    assert_eq!(fbig!(0).lambert_w0(), fbig!(0));
+
+    let inexact_cases = [
+        (fbig!(0x1), fbig!(0x9p-4)),
+        (fbig!(0x3p-2), fbig!(0xfp-5)),
+        (fbig!(-0x5p-4), fbig!(-0x1p-1)),
+        (fbig!(0x1p-100), fbig!(0xfp-104)),
+        (fbig!(0x1234), fbig!(0x3485p-11)),
+        (fbig!(-0x5ep-8), fbig!(-0xfp-4)),
+    ];
+    for (x, w) in &inexact_cases {
+        assert_eq!(x.lambert_w0(), *w);
+        if let Inexact(v, _) = x.context().lambert_w0(x.repr()) {
+            assert_eq!(v, *w);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_lambert_w0_decimal() {
+    // test cases: x, precision, W₀(x), rounding
+    let inexact_cases = [
+        (dbig!(1), 3, dbig!(567e-3), NoOp),
+        (dbig!(1.234), 4, dbig!(6465e-4), AddOne),
+        (dbig!(-0.1234), 4, dbig!(-1423e-4), SubOne),
+        (dbig!(-0.36), 2, dbig!(-81e-2), SubOne),
+        (dbig!(-0.3678794), 7, dbig!(-999527e-6), SubOne),
+        (dbig!(-0.367879441171), 12, dbig!(-999998449288e-12), NoOp),
+        (dbig!(10), 2, dbig!(17e-1), NoOp),
+        (dbig!(1e100), 3, dbig!(225), AddOne),
+        (dbig!(1e-30), 1, dbig!(1e-30), AddOne),
+        (dbig!(-1e-30), 1, dbig!(-1e-30), NoOp),
+        (dbig!(2.718281828), 10, dbig!(9999999999e-10), NoOp),
+        (dbig!(-0.25), 1, dbig!(-4e-1), SubOne),
+    ];
+    for (x, precision, w, rnd) in &inexact_cases {
+        let context = HalfAway::new(*precision);
+        assert_eq!(context.lambert_w0(x.repr()), Inexact(w.clone(), *rnd));
+    }
+
+    // directed rounding
+    let context = Context::<mode::Up>::new(5);
+    let w = context.lambert_w0(dbig!(1.234).repr());
+    assert_eq!(w.map(|v| v.with_rounding()), Inexact(dbig!(64648e-5), AddOne));
+    let w = context.lambert_w0(dbig!(-0.3678794).repr());
+    assert_eq!(w.map(|v| v.with_rounding()), Inexact(dbig!(-99952e-5), NoOp));
+    let w = context.lambert_w0(dbig!(1e-30).repr());
+    assert_eq!(w.map(|v| v.with_rounding()), Inexact(dbig!(1e-30), AddOne));
+    let context = Context::<mode::Down>::new(5);
+    let w = context.lambert_w0(dbig!(1.234).repr());
+    assert_eq!(w.map(|v| v.with_rounding()), Inexact(dbig!(64647e-5), NoOp));
+    let w = context.lambert_w0(dbig!(-0.3678794).repr());
+    assert_eq!(w.map(|v| v.with_rounding()), Inexact(dbig!(-99953e-5), SubOne));
+    let w = context.lambert_w0(dbig!(1e-30).repr());
+    assert_eq!(w.map(|v| v.with_rounding()), Inexact(dbig!(99999e-35), NoOp));
+
+    // high precision
+    let context = HalfAway::new(100);
+    let omega = DBig::from_str("0.5671432904097838729999686622103555497538157871865125081351310792230457930866845666932194469617522946").unwrap();
+    assert_eq!(context.lambert_w0(dbig!(1).repr()), Inexact(omega, AddOne));
+}
+
+#[test]
+fn test_lambert_wm1_binary() {
+    let inexact_cases = [
+        (fbig!(-0x5p-4), fbig!(-0xdp-3)),
+        (fbig!(-0x1p-20), fbig!(-0x1p4)),
+        (fbig!(-0x5ep-8), fbig!(-0x11p-4)),
+    ];
+    for (x, w) in &inexact_cases {
+        assert_eq!(x.lambert_wm1(), *w);
+        if let Inexact(v, _) = x.context().lambert_wm1(x.repr()) {
+            assert_eq!(v, *w);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_lambert_wm1_decimal() {
+    // test cases: x, precision, W₋₁(x), rounding
+    let inexact_cases = [
+        (dbig!(-0.1234), 4, dbig!(-328e-2), NoOp),
+        (dbig!(-0.36), 2, dbig!(-12e-1), NoOp),
+        (dbig!(-0.3678794), 7, dbig!(-1000473e-6), NoOp),
+        (dbig!(-0.367879441171), 12, dbig!(-100000155071e-11), NoOp),
+        (dbig!(-1e-10), 2, dbig!(-26), NoOp),
+        (dbig!(-1e-1000), 3, dbig!(-231e1), NoOp),
+        (dbig!(-0.2), 1, dbig!(-3), SubOne),
+    ];
+    for (x, precision, w, rnd) in &inexact_cases {
+        let context = HalfAway::new(*precision);
+        assert_eq!(context.lambert_wm1(x.repr()), Inexact(w.clone(), *rnd));
+    }
+
+    // directed rounding
+    let context = Context::<mode::Up>::new(5);
+    let w = context.lambert_wm1(dbig!(-0.1234).repr());
+    assert_eq!(w.map(|v| v.with_rounding()), Inexact(dbig!(-32802e-4), NoOp));
+    let w = context.lambert_wm1(dbig!(-1e-10).repr());
+    assert_eq!(w.map(|v| v.with_rounding()), Inexact(dbig!(-26295e-3), NoOp));
+    let context = Context::<mode::Down>::new(5);
+    let w = context.lambert_wm1(dbig!(-0.1234).repr());
+    assert_eq!(w.map(|v| v.with_rounding()), Inexact(dbig!(-32803e-4), SubOne));
+    let w = context.lambert_wm1(dbig!(-1e-10).repr());
+    assert_eq!(w.map(|v| v.with_rounding()), Inexact(dbig!(-26296e-3), SubOne));
+
+    // high precision
+    let context = HalfAway::new(100);
+    let w = DBig::from_str("-1.781337023421627611974170281512745260821558356454461408571419292426296682540163764423775917550185065").unwrap();
+    assert_eq!(context.lambert_wm1(dbig!(-0.3).repr()), Inexact(w, NoOp));
+}
+
+#[test]
+#[should_panic]
+fn test_lambert_w0_out_of_domain() {
+    let _ = dbig!(-0.3679).lambert_w0();
+}
+
+#[test]
+#[should_panic]
+fn test_lambert_wm1_out_of_domain() {
+    let _ = dbig!(0.1).lambert_wm1();
+}
+
+#[test]
+#[should_panic]
+fn test_lambert_wm1_zero() {
+    let _ = dbig!(0).lambert_wm1();
+}
@@ -1,5 +1,9 @@
+use core::str::FromStr;
 use dashu_base::Approximation::*;
-use dashu_float::{round::Rounding::*, DBig};
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DBig, FBig,
+};
 
 mod helper_macros;
 
@@ -87,6 +91,63 @@ fn test_ln_decimal() {
     }
 }
 
+#[test]
+fn test_ln_directed_rounding() {
+    let x = dbig!(1234.5);
+    let ln = Context::<mode::Up>::new(10).ln(x.repr());
+    assert_eq!(ln.map(|v| v.with_rounding()), Inexact(dbig!(7118421309e-9), AddOne));
+    let x = dbig!(0.5);
+    let ln = Context::<mode::Away>::new(10).ln(x.repr());
+    assert_eq!(ln.map(|v| v.with_rounding()), Inexact(dbig!(-6931471806e-10), SubOne));
+    let ln = Context::<mode::Up>::new(10).ln(x.repr());
+    assert_eq!(ln.map(|v| v.with_rounding()), Inexact(dbig!(-6931471805e-10), NoOp));
+
+    let ln_1p = Context::<mode::Up>::new(10).ln_1p(x.repr());
+    assert_eq!(ln_1p.map(|v| v.with_rounding()), Inexact(dbig!(4054651082e-10), AddOne));
+    let ln_1p = Context::<mode::Away>::new(10).ln_1p((-x).repr());
+    assert_eq!(ln_1p.map(|v| v.with_rounding()), Inexact(dbig!(-6931471806e-10), SubOne));
+
+    let ln = Context::<mode::Up>::new(20).ln(fbig!(0x3).repr());
+    assert_eq!(ln.map(|v| v.with_rounding()), Inexact(fbig!(0x464fbp-18), AddOne));
+
+    // the results are correctly rounded even if the precision is low
+    let x = fbig!(0x6bb5p-14);
+    let ln = Context::<mode::Up>::new(2).ln(x.repr());
+    assert_eq!(ln.map(|v| v.with_rounding()), Inexact(fbig!(0x3p-2), AddOne));
+    let ln = Context::<mode::Down>::new(2).ln(x.repr());
+    assert_eq!(ln.map(|v| v.with_rounding()), Inexact(fbig!(0x1p-1), NoOp));
+
+    // the inputs close to one are not rounded before the evaluation
+    let x = dbig!(1.000000000000000000000000000001);
+    let ln = Context::<mode::Down>::new(10).ln(x.repr());
+    assert_eq!(ln.map(|v| v.with_rounding()), Inexact(dbig!(9999999999e-40), NoOp));
+    let ln_1p = Context::<mode::Up>::new(10).ln_1p(dbig!(1e-40).repr());
+    assert_eq!(ln_1p.map(|v| v.with_rounding()), Inexact(dbig!(1e-40), AddOne));
+}
+
+#[test]
+fn test_ln_high_precision() {
+    // the logarithm is evaluated with the AGM at high precision
+    let context = Context::<mode::HalfAway>::new(400);
+    let cases = [
+        ("3", "1098612288668109691395245236922525704647490557822749451734694333637494293218608966873615754813732088787970029065957865742368004225930519821052801870767277410603162769183381367179373698844360959903742570316795911521145591917750671347054940166775580222203170252946897560690106521505642868138036317373298577782366991654792131818149020030103823630122248652748198225991097452490896458053467008845965085748e-399", NoOp),
+        ("0.7", "-3566749439387323789126387112411844779640167590469117875739377510299927469252832124483387065017267713489060898364351077216857732074050199135173292739348809135750827402212798974615405458177658241158885316041118259647932686771260712599564110180754685262609233044769468442386824917708984655460980069343931676020341126764081406877722160771689713370818963208616742760637209731691661381252250519654109398351e-400", SubOne),
+        ("1.001", "9995003330835331668093989205350114607550623931665519970196668289003249576587195542962547622009121511322600598931151652828995130117409823306664956409615242944418302858478815448862336470960198391187163235722096267358137901749407633380957988070685939836667406937716348287292555232596204528920260639223960024007936704989020960597736711242365708661318076741798484965243560195043702419821300400282767867901e-403", NoOp),
+        ("1e-100", "-2302585092994045684017991454684364207601101488628772976033327900967572609677352480235997205089598298341967784042286248633409525465082806756666287369098781689482907208325554680843799894826233198528393505308965377732628846163366222287698219886746543667474404243274365155048934314939391479619404400222105101714174800368808401264708068556774321622835522011480466371565912137345074785694768346361679210181e-397", SubOne),
+        ("1.000000000000000000000000000001", "9999999999999999999999999999995000000000000000000000000000003333333333333333333333333333330833333333333333333333333333335333333333333333333333333333331666666666666666666666666666668095238095238095238095238095236845238095238095238095238095239206349206349206349206349206348206349206349206349206349206350115440115440115440115440115439282106782106782106782106782107551337551337551337551337551336837051837e-430", NoOp),
+        ("12345.6789", "9421061394191835297121967529225747379309275886505310071051778341221927028546941856712127599831909502110605392596139325886645846175440315175295124019852146220276168854818471478991964304871414837907806071254015221482422590053924307452341068941800898853291489319903562068299567920870974994679536661729801003447805429592134946032828270317809877492115180647526803555855975962235487768615179790433406219811e-399", AddOne),
+    ];
+    for (x, ln, rnd) in &cases {
+        let x = DBig::from_str(x).unwrap();
+        let ln = DBig::from_str(ln).unwrap();
+        assert_eq!(context.ln(x.repr()), Inexact(ln, *rnd));
+    }
+
+    let context = Context::<mode::Zero>::new(1500);
+    let ln3 = FBig::<mode::Zero>::from_str("0x464fa9eab40c2a5da9066355414edf2d6f8510b66df8237c0f65fbba94524d632aa5e0b48331a614141c7dccc0e6a3b558970541c7a9ef2873cdf63c4409319921b42538879d342951f7dba47f245d52c7c2cbc8ee208039ca605ba0ad0f9919342610311e7c1953795730139e28f7c63d1afec4c1042629f2711b620ef65c68bfa9266ee03b78578b5075c4f05076a8aa764b8b722ce15cab4686f676dcd232a580dd84c8c6c59f8620c71f4ca634c2324b1dbb50e942b472812d5p-1498").unwrap();
+    assert_eq!(context.ln(fbig!(0x3).repr()).value(), ln3);
+}
+
 #[test]
 #[should_panic]
 fn test_ln_unlimited_precision() {
@@ -195,3 +256,200 @@ fn test_ln_1p_unlimited_precision() {
 fn test_ln_1p_inf() {
     let _ = DBig::INFINITY.ln_1p();
 }
+
+#[test]
+fn test_log2_binary() {
+    let exact_cases = [
+        (fbig!(0x1), fbig!(0)),
+        (fbig!(0x8), fbig!(0x3)),
+        (fbig!(0x1p-4), fbig!(-0x4)),
+        (fbig!(0x1p-2), fbig!(-0x2)),
+    ];
+    for (x, log) in &exact_cases {
+        assert_eq!(x.log2(), *log);
+        assert_eq!(x.context().log2(x.repr()), Exact(log.clone()));
+    }
+
+    let inexact_cases = [
+        (fbig!(0x3), fbig!(0x3p-1)),
+        (fbig!(0x0003), fbig!(0x657p-10)),
+        (fbig!(0x1234p-12), fbig!(0x2fa5p-16)),
+        (fbig!(0xffffp-16), fbig!(-0x5c55p-30)),
+        (fbig!(0x1p-200), fbig!(-0x3p6)),
+        (fbig!(0x1p200), fbig!(0x3p6)),
+        (fbig!(0x5), fbig!(0x9p-2)),
+        (fbig!(0x1000001), fbig!(0x3p3)),
+    ];
+    for (x, log) in &inexact_cases {
+        assert_eq!(x.log2(), *log);
+        if let Inexact(v, e) = x.context().log2(x.repr()) {
+            assert_eq!(v, *log);
+            assert_eq!(e, NoOp);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_log2_decimal() {
+    let exact_cases = [
+        (dbig!(1), dbig!(0)),
+        (dbig!(8), dbig!(3)),
+        (dbig!(125e-3), dbig!(-3)),
+        (dbig!(1024), dbig!(10)),
+    ];
+    for (x, log) in &exact_cases {
+        assert_eq!(x.log2(), *log);
+        assert_eq!(x.context().log2(x.repr()), Exact(log.clone()));
+    }
+
+    let inexact_cases = [
+        (dbig!(3), dbig!(2), AddOne),
+        (dbig!(0003), dbig!(1585e-3), AddOne),
+        (dbig!(1234e-3), dbig!(3033e-4), NoOp),
+        (dbig!(9999e-4), dbig!(-1443e-7), SubOne),
+        (dbig!(1e-50), dbig!(-2e2), SubOne),
+        (dbig!(12345678901234567890e-10), dbig!(30201359027892105673e-18), NoOp),
+        (dbig!(2e100), dbig!(3e2), NoOp),
+        (dbig!(5), dbig!(2), NoOp),
+        (dbig!(7e-3), dbig!(-7), NoOp),
+    ];
+    for (x, log, rnd) in &inexact_cases {
+        assert_eq!(x.log2(), *log);
+        if let Inexact(v, e) = x.context().log2(x.repr()) {
+            assert_eq!(v, *log);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_log10_binary() {
+    let exact_cases = [(fbig!(0x1), fbig!(0)), (fbig!(0x64), fbig!(0x2))];
+    for (x, log) in &exact_cases {
+        assert_eq!(x.log10(), *log);
+        assert_eq!(x.context().log10(x.repr()), Exact(log.clone()));
+    }
+
+    let inexact_cases = [
+        (fbig!(0x3), fbig!(0xfp-5)),
+        (fbig!(0x0003), fbig!(0xf449p-17)),
+        (fbig!(0x1234p-12), fbig!(0xe57bp-20)),
+        (fbig!(0xffffp-16), fbig!(-0x3797p-31)),
+        (fbig!(0x1p-200), fbig!(-0xfp2)),
+        (fbig!(0x1p200), fbig!(0xfp2)),
+        (fbig!(0x5), fbig!(0xbp-4)),
+        (fbig!(0x1000001), fbig!(0xe730e7dp-25)),
+    ];
+    for (x, log) in &inexact_cases {
+        assert_eq!(x.log10(), *log);
+        if let Inexact(v, e) = x.context().log10(x.repr()) {
+            assert_eq!(v, *log);
+            assert_eq!(e, NoOp);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_log10_decimal() {
+    let exact_cases = [
+        (dbig!(1), dbig!(0)),
+        (dbig!(1000), dbig!(3)),
+        (dbig!(1e-50), dbig!(-5e1)),
+        (dbig!(00001e-12345), dbig!(-12345)),
+        (dbig!(1e100).with_precision(1000).value(), dbig!(100)),
+    ];
+    for (x, log) in &exact_cases {
+        assert_eq!(x.log10(), *log);
+        assert_eq!(x.context().log10(x.repr()), Exact(log.clone()));
+    }
+
+    let inexact_cases = [
+        (dbig!(3), dbig!(5e-1), AddOne),
+        (dbig!(0003), dbig!(4771e-4), NoOp),
+        (dbig!(1234e-3), dbig!(9132e-5), AddOne),
+        (dbig!(9999e-4), dbig!(-4343e-8), NoOp),
+        (dbig!(12345678901234567890e-10), dbig!(90915149772126998957e-19), NoOp),
+        (dbig!(2e100), dbig!(1e2), NoOp),
+        (dbig!(5), dbig!(7e-1), AddOne),
+        (dbig!(7e-3), dbig!(-2), NoOp),
+    ];
+    for (x, log, rnd) in &inexact_cases {
+        assert_eq!(x.log10(), *log);
+        if let Inexact(v, e) = x.context().log10(x.repr()) {
+            assert_eq!(v, *log);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_log_with_base() {
+    let exact_cases = [
+        (dbig!(1), dbig!(7), dbig!(0)),
+        (dbig!(343), dbig!(7), dbig!(3)),
+        (dbig!(125e-3), dbig!(2), dbig!(-3)),
+        (dbig!(16), dbig!(25e-2), dbig!(-2)),
+        (dbig!(2), dbig!(4), dbig!(5e-1)),
+        (dbig!(5e-1), dbig!(4), dbig!(-5e-1)),
+        (dbig!(08), dbig!(16), dbig!(75e-2)),
+        (dbig!(0027), dbig!(81), dbig!(75e-2)),
+        (dbig!(10e-4), dbig!(100), dbig!(-15e-1)),
+    ];
+    for (x, b, log) in &exact_cases {
+        assert_eq!(x.log(b), *log);
+        assert_eq!(x.context().log(x.repr(), b.repr()), Exact(log.clone()));
+    }
+
+    let inexact_cases = [
+        (dbig!(1234e-3), dbig!(5678e-3), dbig!(1211e-4), AddOne),
+        (dbig!(2), dbig!(3), dbig!(6e-1), NoOp),
+        (dbig!(100), dbig!(7), dbig!(237e-2), AddOne),
+        (dbig!(1e-10), dbig!(5e-1), dbig!(3e1), NoOp),
+        (dbig!(4), dbig!(8), dbig!(7e-1), AddOne),
+        (dbig!(8), dbig!(4), dbig!(2), AddOne),
+    ];
+    for (x, b, log, rnd) in &inexact_cases {
+        assert_eq!(x.log(b), *log);
+        if let Inexact(v, e) = x.context().log(x.repr(), b.repr()) {
+            assert_eq!(v, *log);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+
+    let inexact_cases = [
+        (fbig!(0x1234p-12), fbig!(0x3), fbig!(0x3c1fp-17)),
+        (fbig!(0x3), fbig!(0x1p-4), fbig!(-0x3p-3)),
+    ];
+    for (x, b, log) in &inexact_cases {
+        assert_eq!(x.log(b), *log);
+    }
+
+    // the exponent is rational
+    let context = Context::<mode::Down>::new(2);
+    let log = context.log(dbig!(2).repr(), dbig!(4).repr());
+    assert_eq!(log.map(|v| v.with_rounding()), Exact(dbig!(5e-1)));
+    let log = context.log(fbig!(0x2).repr(), fbig!(0x1p128).repr());
+    assert_eq!(log.map(|v| v.with_rounding()), Exact(fbig!(0x1p-7)));
+}
+
+#[test]
+#[should_panic]
+fn test_log2_non_positive() {
+    let _ = dbig!(0).log2();
+}
+
+#[test]
+#[should_panic]
+fn test_log_base_one() {
+    let _ = dbig!(2).log(&dbig!(1));
+}
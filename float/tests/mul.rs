@@ -3,7 +3,15 @@ use core::{
     ops::{Mul, MulAssign},
 };
 use dashu_base::Approximation::*;
-use dashu_float::{round::Rounding::*, Context};
+use dashu_float::{
+    round::{
+        mode::{Down, HalfAway, HalfEven, ToOdd, Up, Zero},
+        Round,
+        Rounding::*,
+    },
+    Context, FBig, Repr,
+};
+use dashu_int::{IBig, UBig, Word};
 
 mod helper_macros;
 
@@ -141,3 +149,109 @@ fn test_mul_zero_precision() {
     test_mul(&a, &a, &dbig!(9801));
     test_mul(&a, &b, &dbig!(-98e2));
 }
+
+#[test]
+fn test_mul_long_operands() {
+    // the operands with many more digits than the precision are not rounded before multiplying
+    let context = Context::<HalfAway>::new(7);
+    let a = Repr::<2>::new((-2939461).into(), -4);
+    let b = Repr::<2>::new(2747005.into(), 2);
+    let c = Repr::new((-59).into(), 35);
+    assert_eq!(context.mul(&a, &b), Inexact(FBig::from_repr(c, context), SubOne));
+
+    let context = Context::<HalfAway>::new(2);
+    let a = Repr::<10>::new(12449.into(), -4);
+    assert_eq!(context.square(&a).value().repr(), &Repr::new(15.into(), -1));
+
+    // the product of the operands truncated to twice the precision is on the other side of one
+    let context = Context::<Zero>::new(8);
+    let a = Repr::<2>::new((IBig::ONE << 24) + 1, -24); // 1 + 2^-24
+    let b = Repr::<2>::new((IBig::ONE << 23) - 1, -23); // 1 - 2^-23
+    let c = Repr::new(0xff.into(), -8);
+    assert_eq!(context.mul(&a, &b), Inexact(FBig::from_repr(c, context), NoOp));
+
+    // the product of long operands can be exact
+    let context = Context::<Down>::new(3);
+    let a = Repr::<10>::new(IBig::from(2).pow(100), 0);
+    let b = Repr::<10>::new(IBig::from(5).pow(100), -200);
+    let c = Repr::new(1.into(), -100);
+    assert_eq!(context.mul(&a, &b), Exact(FBig::from_repr(c, context)));
+    let a = Repr::<10>::new(IBig::from(10).pow(60) - 1, -60);
+    let c = Repr::new(999.into(), -3);
+    assert_eq!(context.square(&a), Inexact(FBig::from_repr(c, context), NoOp));
+}
+
+/// Check that the product under the context is the same as the rounded exact product
+fn check_mul<R: Round, const B: Word>(a: &Repr<B>, b: &Repr<B>, precision: usize) {
+    // the operands are short enough to get an exact product with this precision
+    let exact_context = Context::<R>::new(10000);
+    let context = Context::<R>::new(precision);
+    let exact = exact_context.mul(a, b).value();
+    assert_eq!(context.mul(a, b), exact.with_precision(precision));
+    let exact = exact_context.square(a).value();
+    assert_eq!(context.square(a), exact.with_precision(precision));
+}
+
+fn check_mul_all_modes<const B: Word>(a: &Repr<B>, b: &Repr<B>, precision: usize) {
+    check_mul::<Zero, B>(a, b, precision);
+    check_mul::<Up, B>(a, b, precision);
+    check_mul::<Down, B>(a, b, precision);
+    check_mul::<HalfEven, B>(a, b, precision);
+    check_mul::<HalfAway, B>(a, b, precision);
+    check_mul::<ToOdd, B>(a, b, precision);
+}
+
+#[test]
+fn test_mul_long_operands_random() {
+    // a simple linear congruential generator
+    let mut state = 1u64;
+    let mut next = |words: usize| {
+        let mut value = IBig::ZERO;
+        for _ in 0..words {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            value = (value << 64) + state;
+        }
+        value
+    };
+
+    for (words, precision) in [(2, 3), (3, 10), (5, 20), (8, 50)] {
+        for _ in 0..5 {
+            let (a, b) = (next(words), -next(words / 2 + 1));
+            check_mul_all_modes(&Repr::<2>::new(a.clone(), -10), &Repr::new(b.clone(), 3), precision);
+            check_mul_all_modes(&Repr::<10>::new(a, 5), &Repr::new(b, -7), precision);
+        }
+    }
+
+    // the products close to the rounding boundaries
+    for k in [10, 30, 100] {
+        let one = IBig::ONE << k;
+        let (a, b) = (Repr::<2>::new(&one + 1, -(k as isize)), Repr::<2>::new(&one - 1, 0));
+        check_mul_all_modes(&a, &b, 4);
+        check_mul_all_modes(&b, &b, 4);
+        let one = IBig::from(10).pow(k);
+        let (a, b) = (Repr::<10>::new(&one + 1, 0), Repr::<10>::new(&one - 1, 0));
+        check_mul_all_modes(&a, &b, 4);
+        check_mul_all_modes(&b, &b, 4);
+    }
+}
+
+#[test]
+fn test_mul_int() {
+    let a = dbig!(1.25);
+    assert_eq!(&a * 4u8, dbig!(5.00));
+    assert_eq!(4u8 * &a, dbig!(5.00));
+    assert_eq!(a.clone() * IBig::from(-1000), dbig!(-1250));
+    assert_eq!(fbig!(0x3p-1) * 1024usize, fbig!(0x600));
+    assert_eq!(-3i128 * fbig!(0x3p-1), fbig!(-0x9p-1));
+
+    // the trailing zeros of the integer don't increase the precision
+    assert_eq!((UBig::from(1000u16) * a.clone()).precision(), 3);
+    let big = IBig::from(10).pow(1000);
+    let product = dbig!(1.234567891) * &big;
+    assert_eq!(product.precision(), 10);
+    assert_eq!(product.repr(), &Repr::new(1234567891.into(), 991));
+
+    let mut b = dbig!(-2.5);
+    b *= 3i8;
+    assert_eq!(b, dbig!(-7.5));
+}
@@ -0,0 +1,137 @@
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode::HalfEven, Rounding::*},
+    DBig, DynFBig, FBig,
+};
+use dashu_int::{error::ParseError, Word};
+
+mod helper_macros;
+
+type Dyn = DynFBig<HalfEven>;
+
+fn dyn_float(src: &str, base: Word) -> Dyn {
+    Dyn::from_str_with_base(src, base).unwrap()
+}
+
+#[test]
+fn test_parse() {
+    for (src, base, precision) in [
+        ("1.01", 2, 3),
+        ("-7.7", 8, 2),
+        ("12.345", 10, 5),
+        ("ff.8", 16, 3),
+    ] {
+        let x = dyn_float(src, base);
+        assert_eq!(x.base(), base);
+        assert_eq!(x.precision(), precision);
+        assert_eq!(x.to_string(), src);
+    }
+
+    assert_eq!(Dyn::from_str_with_base("1.2", 3), Err(ParseError::UnsupportedRadix));
+    assert_eq!(Dyn::from_str_with_base("1.2", 36), Err(ParseError::UnsupportedRadix));
+    assert_eq!(Dyn::from_str_with_base("1.2", 2), Err(ParseError::InvalidDigit));
+    assert_eq!(Dyn::BASES, [2, 8, 10, 16]);
+}
+
+#[test]
+fn test_from_fbig() {
+    let x = Dyn::from(
+        DBig::from_str_native("1.25")
+            .unwrap()
+            .with_rounding::<HalfEven>(),
+    );
+    assert_eq!(x.base(), 10);
+    assert_eq!(x, dyn_float("1.25", 10));
+    assert_eq!(x.context().precision(), 3);
+
+    let y = Dyn::from(FBig::<HalfEven, 16>::from_str_native("a.b").unwrap());
+    assert_eq!(y.base(), 16);
+    assert_ne!(x, y);
+}
+
+#[test]
+fn test_conversion() {
+    let x = dyn_float("0.0011", 2); // 0.1875
+
+    // exact conversions between the powers of two
+    assert_eq!(x.clone().with_radix(16), Exact(dyn_float("0.3", 16)));
+    assert_eq!(x.clone().with_radix(8), Inexact(dyn_float("0.2", 8), AddOne));
+    assert_eq!(x.clone().with_radix(2), Exact(x.clone()));
+
+    // the precision is adjusted for the new base
+    assert_eq!(x.clone().with_radix(10), Inexact(dyn_float("0.2", 10), AddOne));
+    assert_eq!(x.clone().with_radix_and_precision(10, 4), Exact(dyn_float("0.1875", 10)));
+
+    // conversions to FBig
+    assert_eq!(
+        x.clone().with_base_and_precision::<10>(4),
+        Exact(DBig::from_str_native("0.1875").unwrap().with_rounding())
+    );
+    let y = dyn_float("0.1", 10).with_base::<2>(); // precision: 6 bits
+    assert_eq!(y, Inexact(FBig::from_parts(51.into(), -9), NoOp));
+}
+
+#[test]
+#[should_panic]
+fn test_unsupported_base() {
+    let _ = dyn_float("1", 10).with_radix(3);
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = dyn_float("1.50", 10);
+    let b = dyn_float("0.11", 2); // 0.75
+    let c = dyn_float("1.4", 16); // 1.25
+
+    // same base
+    assert_eq!(&a + &a, dyn_float("3.00", 10));
+    assert_eq!(&b * &b, dyn_float("0.100", 2)); // 0.1001 rounded to 3 digits
+
+    // the right operand is converted to the base of the left operand
+    let sum = &a + &dyn_float("0.110000", 2);
+    assert_eq!(sum.base(), 10);
+    assert_eq!(sum, dyn_float("2.25", 10));
+    // the right operand is converted exactly, so the result is rounded only once
+    assert_eq!(&a + &b, dyn_float("2.25", 10));
+    assert_eq!(&b + &a, dyn_float("10.01", 2));
+    assert_eq!(&a * &b, dyn_float("1.12", 10)); // 1.125 is rounded to even
+    assert_eq!(&b * &a, dyn_float("1.001", 2));
+    // 0.1 is not representable in base 2, it's converted with extra digits
+    let d = dyn_float("0.1", 10);
+    assert_eq!(&b + &d, dyn_float("0.1101101", 2));
+    assert_eq!(&d + &b, dyn_float("0.85", 10));
+    // the right operand has unlimited precision
+    let e = Dyn::from(
+        FBig::<HalfEven, 10>::from_str_native("0.1")
+            .unwrap()
+            .with_precision(0)
+            .value(),
+    );
+    assert_eq!(&b + &e, dyn_float("0.111", 2));
+    assert_eq!(&e + &b, dyn_float("0.8", 10)); // 0.85 is rounded to even
+    let diff = &b - &c;
+    assert_eq!(diff.base(), 2);
+    assert_eq!(diff, dyn_float("-0.1", 2));
+    assert_eq!(c.clone() * b.clone(), dyn_float("0.f", 16));
+    assert_eq!(&c / a.clone(), dyn_float("0.d55", 16));
+    assert_eq!(a.clone() / &c, dyn_float("1.20", 10));
+    assert_eq!(-&a, dyn_float("-1.50", 10));
+    assert_eq!(-b, dyn_float("-0.11", 2));
+}
+
+#[test]
+fn test_format() {
+    let a = dyn_float("12.5", 10);
+    assert_eq!(format!("{:.0}", a), "12");
+    assert_eq!(
+        format!("{:?}", a),
+        format!(
+            "{:?}",
+            DBig::from_str_native("12.5")
+                .unwrap()
+                .with_rounding::<HalfEven>()
+        )
+    );
+    let b = dyn_float("f.f", 16);
+    assert_eq!(b.to_string(), "f.f");
+}
@@ -0,0 +1,305 @@
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{
+        mode::{Down, HalfAway, HalfEven, Up, Zero},
+        Round,
+        Rounding::*,
+    },
+    Context, DBig, FBig, Repr,
+};
+use dashu_int::IBig;
+
+mod helper_macros;
+
+type FBin<R> = FBig<R, 2>;
+
+/// Context of the IEEE 754 binary32 format
+fn binary32<R: Round>() -> Context<R> {
+    Context::new(24)
+        .with_exponent_range(-126, 127)
+        .with_ieee(true)
+}
+
+/// Context of the IEEE 754 binary16 format
+fn binary16<R: Round>() -> Context<R> {
+    Context::new(11).with_exponent_range(-14, 15)
+}
+
+/// Context of the IEEE 754 decimal64 format
+fn decimal64() -> Context<HalfAway> {
+    Context::new(16).with_exponent_range(-383, 384)
+}
+
+fn bin(significand: i64, exponent: isize) -> Repr<2> {
+    Repr::new(significand.into(), exponent)
+}
+
+fn from_f32<R: Round>(f: f32) -> FBin<R> {
+    let repr = if f.is_nan() {
+        Repr::nan()
+    } else if f == 0. && f.is_sign_negative() {
+        Repr::neg_zero()
+    } else {
+        FBin::<R>::try_from(f).unwrap().into_repr()
+    };
+    FBin::from_repr(repr, binary32())
+}
+
+#[test]
+fn test_context() {
+    let ctx = Context::<Zero>::new(10);
+    assert_eq!(ctx.exponent_range(), (isize::MIN, isize::MAX));
+    assert!(ctx.has_subnormals());
+
+    let bounded = ctx.with_exponent_range(-10, 10);
+    assert_eq!(bounded.exponent_range(), (-10, 10));
+    assert!(!bounded.with_subnormals(false).has_subnormals());
+    assert_eq!(Context::max(ctx, bounded).exponent_range(), (-10, 10));
+    assert_eq!(Context::max(bounded, ctx).exponent_range(), (-10, 10));
+
+    let other = ctx.with_exponent_range(-20, 5).with_subnormals(false);
+    assert_eq!(Context::max(bounded, other).exponent_range(), (-10, 5));
+    assert!(!Context::max(bounded, other).has_subnormals());
+
+    // the settings are preserved by the conversions
+    let x = FBin::<Zero>::from_repr(Repr::one(), bounded);
+    assert_eq!(
+        x.clone()
+            .with_precision(5)
+            .value()
+            .context()
+            .exponent_range(),
+        (-10, 10)
+    );
+    assert_eq!(x.with_rounding::<Up>().context().exponent_range(), (-10, 10));
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_range() {
+    let _ = Context::<Zero>::new(10).with_exponent_range(1, 0);
+}
+
+#[test]
+fn test_overflow() {
+    let max = bin((1 << 24) - 1, 104); // f32::MAX
+    let neg_max = bin(-((1 << 24) - 1), 104);
+    let ulp = bin(1, 104);
+    let half_ulp = bin(1, 103);
+
+    // rounding to the nearest
+    let ctx = binary32::<HalfEven>();
+    let max_f = FBin::from_repr(max.clone(), ctx);
+    assert_eq!(ctx.add(&max, &Repr::zero()), Exact(max_f.clone()));
+    assert_eq!(ctx.add(&max, &bin(1, 80)), Inexact(max_f, NoOp));
+    assert_eq!(ctx.add(&max, &half_ulp), Inexact(FBin::INFINITY, AddOne));
+    assert_eq!(ctx.sub(&neg_max, &half_ulp), Inexact(FBin::NEG_INFINITY, SubOne));
+    assert_eq!(ctx.mul(&max, &max), Inexact(FBin::INFINITY, AddOne));
+    assert_eq!(ctx.div(&neg_max, &bin(1, -1)), Inexact(FBin::NEG_INFINITY, SubOne));
+    assert_eq!(ctx.sqrt(&max).value().repr(), &bin((1 << 24) - 1, 40));
+    assert_eq!(ctx.cbrt(&bin(-1, 400)), Inexact(FBin::NEG_INFINITY, SubOne));
+    let ctx = Context::<HalfEven>::new(5).with_exponent_range(-50, 50);
+    assert_eq!(
+        ctx.cbrt(&Repr::<10>::new((-1).into(), 300)),
+        Inexact(FBig::NEG_INFINITY, SubOne)
+    );
+
+    // directed rounding
+    let ctx = binary32::<Zero>();
+    assert_eq!(ctx.add(&max, &ulp), Inexact(FBin::from_repr(max.clone(), ctx), NoOp));
+    assert_eq!(ctx.mul(&neg_max, &max), Inexact(FBin::from_repr(neg_max.clone(), ctx), NoOp));
+    assert_eq!(binary32::<Up>().add(&max, &ulp), Inexact(FBin::INFINITY, AddOne));
+    assert_eq!(binary32::<Up>().sub(&neg_max, &ulp).value().repr(), &neg_max);
+    assert_eq!(binary32::<Down>().add(&max, &ulp).value().repr(), &max);
+    assert_eq!(binary32::<Down>().sub(&neg_max, &ulp), Inexact(FBin::NEG_INFINITY, SubOne));
+
+    // exact values out of the range also overflow
+    let huge = IBig::ONE << 200;
+    assert_eq!(
+        binary32::<Zero>()
+            .convert_int::<2>(huge.clone())
+            .value()
+            .repr(),
+        &max
+    );
+    assert_eq!(
+        binary32::<HalfEven>().convert_int::<2>(-huge),
+        Inexact(FBin::NEG_INFINITY, SubOne)
+    );
+
+    // the largest finite number in decimal
+    let max = Repr::<10>::new(IBig::from(10).pow(16) - 1, 369);
+    let half_ulp = Repr::<10>::new(5.into(), 368);
+    assert_eq!(decimal64().add(&max, &half_ulp), Inexact(DBig::INFINITY, AddOne));
+    assert_eq!(
+        decimal64()
+            .add(&max, &Repr::new(4999.into(), 365))
+            .value()
+            .repr(),
+        &max
+    );
+}
+
+#[test]
+fn test_subnormal() {
+    let ctx = binary32::<HalfEven>();
+    let tiny = bin(1, -149); // the smallest subnormal number
+    let min_normal = bin(1, -126);
+
+    // rounding of the subnormal numbers
+    assert_eq!(ctx.mul(&tiny, &bin(3, -2)), Inexact(FBin::from_repr(tiny.clone(), ctx), AddOne));
+    assert_eq!(ctx.mul(&tiny, &bin(3, -1)).value().repr(), &bin(1, -148));
+    assert_eq!(ctx.mul(&tiny, &bin(7, -1)).value().repr(), &bin(1, -147));
+    assert_eq!(ctx.div(&min_normal, &bin(3, 0)).value().repr(), &bin(0x2aaaab, -149));
+    assert_eq!(ctx.sub(&min_normal, &tiny), Exact(FBin::from_repr(bin(0x7fffff, -149), ctx)));
+    assert_eq!(
+        ctx.add(&bin(1, -140), &bin(1, -160)),
+        Inexact(FBin::from_repr(bin(1, -140), ctx), NoOp)
+    );
+    assert_eq!(ctx.sqrt(&bin(1, -299)), Inexact(FBin::from_repr(tiny.clone(), ctx), AddOne));
+    assert_eq!(ctx.cbrt(&bin(1, -448)), Inexact(FBin::from_repr(tiny.clone(), ctx), AddOne));
+    assert_eq!(ctx.cbrt(&bin(-27, -447)), Exact(FBin::from_repr(bin(-3, -149), ctx)));
+    assert_eq!(ctx.cbrt(&bin(-125, -450)), Inexact(FBin::from_repr(bin(-1, -148), ctx), NoOp));
+    // the root 2.5000..01 * 2^-149 is rounded only once
+    assert_eq!(ctx.cbrt(&bin((125 << 40) + 1, -490)).value().repr(), &bin(3, -149));
+
+    // underflow to zero keeps the sign in the IEEE mode
+    let zero = ctx.mul(&tiny, &bin(1, -1));
+    assert_eq!(zero, Inexact(FBin::ZERO, NoOp));
+    assert!(!zero.value().repr().is_neg_zero());
+    let neg_zero = ctx.mul(&tiny, &bin(-1, -1));
+    assert_eq!(neg_zero, Inexact(FBin::ZERO, NoOp));
+    assert!(neg_zero.value().repr().is_neg_zero());
+    assert_eq!(ctx.mul(&tiny, &tiny).value(), FBin::<HalfEven>::ZERO);
+    let non_ieee = ctx.with_ieee(false).mul(&tiny, &bin(-1, -1)).value();
+    assert!(!non_ieee.repr().is_neg_zero());
+
+    // directed rounding
+    assert_eq!(
+        binary32::<Up>().mul(&tiny, &tiny),
+        Inexact(FBin::from_repr(tiny.clone(), binary32()), AddOne)
+    );
+    assert_eq!(binary32::<Down>().mul(&tiny, &bin(-1, -20)).value().repr(), &bin(-1, -149));
+    assert_eq!(binary32::<Zero>().mul(&tiny, &bin(-1, -20)).value(), FBin::<Zero>::ZERO);
+
+    // the smallest subnormal number in decimal
+    let tiny = Repr::<10>::new(1.into(), -398);
+    assert_eq!(
+        decimal64()
+            .mul(&tiny, &Repr::new(5.into(), -1))
+            .value()
+            .repr(),
+        &tiny
+    );
+    assert_eq!(decimal64().mul(&tiny, &Repr::new(4.into(), -1)).value(), DBig::ZERO);
+    let x = Repr::<10>::new(1234567890123456i64.into(), -405);
+    assert_eq!(
+        decimal64().mul(&x, &Repr::one()),
+        Inexact(DBig::from_repr(Repr::new(123456789.into(), -398), decimal64()), NoOp)
+    );
+}
+
+#[test]
+fn test_no_subnormal() {
+    let ctx = binary32::<HalfEven>().with_subnormals(false);
+    let min_normal = bin(1, -126);
+
+    // normal numbers with small exponents are not affected
+    assert_eq!(ctx.mul(&min_normal, &bin(3, -1)).value().repr(), &bin(3, -127));
+    assert_eq!(ctx.sub(&bin(3, -126), &min_normal).value().repr(), &bin(1, -125));
+
+    // the results are rounded to either zero or the smallest normal number
+    assert_eq!(ctx.mul(&min_normal, &bin(3, -2)).value().repr(), &min_normal);
+    assert_eq!(ctx.mul(&min_normal, &bin(1, -1)).value(), FBin::<HalfEven>::ZERO);
+    assert_eq!(ctx.sub(&bin(3, -126), &bin(5, -127)).value(), FBin::<HalfEven>::ZERO);
+    assert_eq!(
+        binary32::<Up>()
+            .with_subnormals(false)
+            .mul(&min_normal, &bin(1, -20))
+            .value()
+            .repr(),
+        &min_normal
+    );
+    assert_eq!(
+        binary32::<Zero>()
+            .with_subnormals(false)
+            .mul(&min_normal, &bin(-1, -1)),
+        Inexact(FBin::ZERO, NoOp)
+    );
+}
+
+#[test]
+fn test_mirror_f32() {
+    let mut values = vec![
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        0.1,
+        -3.0,
+        1e30,
+        -1e-30,
+        16777215.0,
+        f32::MAX,
+        -f32::MAX,
+        f32::MIN_POSITIVE,
+        -f32::MIN_POSITIVE,
+        1.5e-39,
+        -3e-42,
+        1e-45,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+    ];
+    // pseudo-random values from a linear congruential generator
+    let mut seed = 1u32;
+    for _ in 0..40 {
+        seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+        let value = f32::from_bits(seed);
+        if value.is_finite() {
+            values.push(value);
+        }
+    }
+
+    let check = |op: &str, expected: f32, actual: FBin<HalfEven>| {
+        let value = actual.to_f32().value();
+        if expected.is_nan() {
+            assert!(value.is_nan(), "{}: expect NaN, got {}", op, value);
+        } else {
+            assert_eq!(expected.to_bits(), value.to_bits(), "{}: {} != {}", op, expected, value);
+        }
+
+        let expected = from_f32::<HalfEven>(expected);
+        assert_eq!(expected.repr(), actual.repr(), "{}: {:?} != {:?}", op, expected, actual);
+    };
+
+    for &a in &values {
+        for &b in &values {
+            let (fa, fb) = (from_f32::<HalfEven>(a), from_f32::<HalfEven>(b));
+            check(&format!("{} + {}", a, b), a + b, &fa + &fb);
+            check(&format!("{} - {}", a, b), a - b, &fa - &fb);
+            check(&format!("{} * {}", a, b), a * b, &fa * &fb);
+            check(&format!("{} / {}", a, b), a / b, &fa / &fb);
+        }
+        check(&format!("sqrt({})", a), a.sqrt(), from_f32::<HalfEven>(a).sqrt());
+    }
+}
+
+#[test]
+fn test_exp_range() {
+    // the largest finite number of binary16 is 65504, and the numbers above 65520 overflow
+    let ctx = binary16::<HalfEven>();
+    let max = bin(2047, 5);
+    assert_eq!(ctx.exp(&bin(12, 0)), Inexact(FBin::INFINITY, AddOne));
+    assert_eq!(ctx.exp_m1(&bin(12, 0)), Inexact(FBin::INFINITY, AddOne));
+    assert_eq!(ctx.exp(&bin(11628855, -20)), Inexact(FBin::INFINITY, AddOne)); // e^x ≈ 65521.94
+    assert_eq!(ctx.exp(&bin(11628791, -20)).value().repr(), &max); // e^x ≈ 65517.94
+    assert_eq!(binary16::<Zero>().exp(&bin(12, 0)).value().repr(), &max);
+
+    // e^-20 is less than half of the smallest subnormal number 2^-24
+    assert_eq!(ctx.exp(&bin(-20, 0)), Inexact(FBin::ZERO, NoOp));
+    assert_eq!(binary16::<Up>().exp(&bin(-20, 0)).value().repr(), &bin(1, -24));
+    // e^-10 ≈ 4.54e-5 is subnormal
+    assert_eq!(ctx.exp(&bin(-10, 0)).value().repr(), &bin(381, -23));
+    assert_eq!(ctx.exp_m1(&bin(-20, 0)).value().repr(), &bin(-1, 0));
+}
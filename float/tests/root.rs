@@ -1,5 +1,9 @@
 use dashu_base::Approximation::*;
-use dashu_float::{round::Rounding::*, DBig};
+use dashu_base::Root;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DBig, FBig,
+};
 
 mod helper_macros;
 
@@ -45,6 +49,19 @@ fn test_sqrt_binary() {
     }
 }
 
+#[test]
+fn test_sqrt_rounding() {
+    // the results are rounded only once
+    let context = Context::<mode::HalfEven>::new(24);
+    let x = FBig::<mode::HalfEven>::try_from(1.9093302e13f32).unwrap();
+    assert_eq!(context.sqrt(x.repr()).value().to_f32().value(), 4369588.5);
+
+    // the result is inexact if the truncated digits are not zero
+    let context = Context::<mode::HalfAway>::new(2);
+    let x = dbig!(1.0000001);
+    assert_eq!(context.sqrt(x.repr()), Inexact(dbig!(1), NoOp));
+}
+
 #[test]
 fn test_sqrt_decimal() {
     let exact_cases = [
@@ -104,3 +121,89 @@ fn test_sqrt_inf() {
 fn test_sqrt_negative() {
     let _ = DBig::NEG_ONE.sqrt();
 }
+
+#[test]
+fn test_nth_root_binary() {
+    // test cases: x, n, root
+    let exact_cases = [
+        (fbig!(0), 3, fbig!(0)),
+        (fbig!(0x8), 3, fbig!(0x2)),
+        (fbig!(-0x1b), 3, fbig!(-0x3)),
+        (fbig!(0x1p-30), 5, fbig!(0x1p-6)),
+        (fbig!(0x10000), 16, fbig!(0x2)),
+        (fbig!(-0x3), 1, fbig!(-0x3)),
+    ];
+    for (x, n, root) in &exact_cases {
+        assert_eq!(x.nth_root(*n), *root);
+        assert_eq!(x.context().nth_root(x.repr(), *n), Exact(root.clone()));
+        if *n == 3 {
+            assert_eq!(x.cbrt(), *root);
+            assert_eq!(Root::cbrt(x), *root);
+            assert_eq!(x.context().cbrt(x.repr()), Exact(root.clone()));
+        }
+    }
+
+    let inexact_cases = [
+        (fbig!(0x3), 3, fbig!(0xbp-3)),
+        (fbig!(0x0003), 3, fbig!(0xb89bp-15)),
+        (fbig!(0x0000000000000003), 5, fbig!(0x4fba0e4350e556bbp-62)),
+        (fbig!(-0x3000), 3, fbig!(-0xb89bp-11)),
+        (fbig!(0xffff), 7, fbig!(0x1381p-10)),
+        (
+            fbig!(0x2).with_precision(200).value(),
+            3,
+            fbig!(0xa14517cc6b9457111eed5b8adf128686144788148b18fde03p-195),
+        ),
+    ];
+    for (x, n, root) in &inexact_cases {
+        assert_eq!(x.nth_root(*n), *root);
+        assert_eq!(x.context().nth_root(x.repr(), *n), Inexact(root.clone(), NoOp));
+    }
+}
+
+#[test]
+fn test_nth_root_decimal() {
+    let exact_cases = [
+        (dbig!(-3.375), 3, dbig!(-1.5)),
+        (dbig!(1e-100), 5, dbig!(1e-20)),
+        (dbig!(0.0016), 4, dbig!(0.2)),
+        (dbig!(1881365963625), 3, dbig!(12345)),
+    ];
+    for (x, n, root) in &exact_cases {
+        assert_eq!(x.nth_root(*n), *root);
+        assert_eq!(x.context().nth_root(x.repr(), *n), Exact(root.clone()));
+    }
+
+    // test cases: x, n, precision, root, rounding
+    let inexact_cases = [
+        (dbig!(1.23), 3, 3, dbig!(1.07), NoOp),
+        (dbig!(-1.23), 3, 3, dbig!(-1.07), NoOp),
+        (dbig!(2), 3, 30, dbig!(1.25992104989487316476721060728), AddOne),
+        (dbig!(1e-100), 7, 5, dbig!(5.1795e-15), AddOne),
+        (dbig!(-9.99), 5, 4, dbig!(-1.585), SubOne),
+        (dbig!(123456789), 4, 10, dbig!(105.4092551), AddOne),
+        (dbig!(1881365963625), 3, 4, dbig!(1.235e4), AddOne),
+    ];
+    for (x, n, precision, root, rnd) in &inexact_cases {
+        let context = Context::<mode::HalfAway>::new(*precision);
+        assert_eq!(context.nth_root(x.repr(), *n), Inexact(root.clone(), *rnd));
+    }
+
+    let x = dbig!(1.23);
+    let root = Context::<mode::Up>::new(3).nth_root(x.repr(), 4);
+    assert_eq!(root.map(|v| v.with_rounding()), Inexact(dbig!(1.06), AddOne));
+    let root = Context::<mode::Down>::new(3).cbrt((-x).repr());
+    assert_eq!(root.map(|v| v.with_rounding()), Inexact(dbig!(-1.08), SubOne));
+}
+
+#[test]
+#[should_panic]
+fn test_nth_root_zeroth() {
+    let _ = DBig::ONE.nth_root(0);
+}
+
+#[test]
+#[should_panic]
+fn test_nth_root_negative() {
+    let _ = DBig::NEG_ONE.nth_root(4);
+}
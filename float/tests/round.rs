@@ -0,0 +1,237 @@
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{
+        mode::{HalfAway, HalfEven, Stochastic, ToOdd, Zero},
+        Round,
+        Rounding::*,
+    },
+    Context, FBig, Repr,
+};
+use dashu_int::{IBig, Word};
+
+mod helper_macros;
+
+fn bin(significand: i64, exponent: isize) -> Repr<2> {
+    Repr::new(significand.into(), exponent)
+}
+
+fn dec(significand: i64, exponent: isize) -> Repr<10> {
+    Repr::new(significand.into(), exponent)
+}
+
+/// Pseudo-random numbers from a linear congruential generator
+fn lcg(seed: &mut u64) -> i64 {
+    *seed = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    (*seed >> 40) as i64 - (1 << 23)
+}
+
+#[test]
+fn test_to_odd() {
+    let context = Context::<ToOdd>::new(4);
+    let one = Repr::<2>::one();
+    let three = bin(3, 0);
+
+    // 1/3 = 0.0101010..b, 2/3 = 0.101010..b
+    assert_eq!(
+        context.div(&one, &three),
+        Inexact(FBig::from_repr(bin(11, -5), context), AddOne)
+    );
+    assert_eq!(context.div(&bin(2, 0), &three).value().repr(), &bin(11, -4));
+    assert_eq!(
+        context.div(&bin(-2, 0), &three),
+        Inexact(FBig::from_repr(bin(-11, -4), context), SubOne)
+    );
+    assert_eq!(
+        context.div(&bin(5, 0), &three),
+        Inexact(FBig::from_repr(bin(13, -3), context), NoOp)
+    );
+    assert_eq!(context.add(&bin(15, 0), &bin(1, -10)).value().repr(), &bin(15, 0));
+    assert_eq!(context.add(&bin(14, 0), &bin(1, -10)).value().repr(), &bin(15, 0));
+
+    // exact results are not affected
+    assert_eq!(context.mul(&bin(6, 0), &bin(2, 0)), Exact(FBig::from_repr(bin(12, 0), context)));
+    assert_eq!(context.sqrt(&bin(16, 0)), Exact(FBig::from_repr(bin(4, 0), context)));
+
+    // decimal
+    let context = Context::<ToOdd>::new(3);
+    let three = dec(3, 0);
+    assert_eq!(context.div(&Repr::one(), &three).value().repr(), &dec(333, -3));
+    assert_eq!(context.div(&dec(2, 0), &three).value().repr(), &dec(667, -3));
+    assert_eq!(context.sqrt(&dec(2, 0)).value().repr(), &dec(141, -2));
+    assert_eq!(context.sqrt(&dec(3, 0)).value().repr(), &dec(173, -2));
+
+    // overflow saturates to the largest finite number
+    let context = Context::<ToOdd>::new(4).with_exponent_range(-10, 10);
+    let max = bin(15, 7);
+    assert_eq!(context.mul(&max, &max), Inexact(FBig::from_repr(max, context), NoOp));
+}
+
+fn check_double_rounding<R: Round, const B: Word>(precision: usize) {
+    // the IEEE mode is enabled so that the sums are rounded without the extra digit
+    let high = Context::<ToOdd>::new(precision + 2).with_ieee(true);
+    let low = Context::<R>::new(precision).with_ieee(true);
+
+    let mut seed = 7;
+    for _ in 0..200 {
+        let a = Repr::<B>::new(lcg(&mut seed).into(), lcg(&mut seed) as isize % 8);
+        let b = Repr::<B>::new(lcg(&mut seed).into(), lcg(&mut seed) as isize % 8);
+        if b.is_zero() {
+            continue;
+        }
+
+        let expected = [
+            low.add(&a, &b),
+            low.sub(&a, &b),
+            low.mul(&a, &b),
+            low.div(&a, &b),
+        ];
+        let actual = [
+            high.add(&a, &b),
+            high.sub(&a, &b),
+            high.mul(&a, &b),
+            high.div(&a, &b),
+        ];
+        for (exp, act) in expected.iter().zip(actual) {
+            let act = act.value().with_rounding::<R>().with_precision(precision);
+            assert_eq!(exp.value_ref().repr(), act.value().repr(), "{:?} {:?}", a, b);
+        }
+
+        if a.significand() > &IBig::ZERO {
+            let act = high.sqrt(&a).value();
+            let act = act.with_rounding::<R>().with_precision(precision);
+            assert_eq!(low.sqrt(&a).value().repr(), act.value().repr());
+        }
+    }
+}
+
+#[test]
+fn test_double_rounding() {
+    check_double_rounding::<HalfEven, 2>(10);
+    check_double_rounding::<HalfAway, 2>(7);
+    check_double_rounding::<Zero, 2>(12);
+    check_double_rounding::<HalfEven, 10>(4);
+    check_double_rounding::<HalfAway, 10>(3);
+}
+
+#[test]
+fn test_stochastic() {
+    let context = Context::<Stochastic>::new(1);
+    let x = dec(13, -1);
+    let neg_x = dec(-17, -1);
+
+    // a generator that always returns zero rounds all the inexact results away from zero
+    Stochastic::set_rng(|| 0);
+    assert_eq!(
+        context.mul(&x, &Repr::one()),
+        Inexact(FBig::from_repr(dec(2, 0), context), AddOne)
+    );
+    assert_eq!(context.mul(&neg_x, &Repr::one()).value().repr(), &dec(-2, 0));
+    assert_eq!(context.div(&Repr::one(), &dec(3, 0)).value().repr(), &dec(4, -1));
+    assert_eq!(context.mul(&dec(2, 0), &dec(3, 0)), Exact(FBig::from_repr(dec(6, 0), context)));
+
+    // a generator that always returns the maximum rounds all the results toward zero
+    Stochastic::set_rng(|| u64::MAX);
+    assert_eq!(
+        context.mul(&x, &Repr::one()),
+        Inexact(FBig::from_repr(dec(1, 0), context), NoOp)
+    );
+    assert_eq!(context.mul(&neg_x, &Repr::one()).value().repr(), &dec(-1, 0));
+    assert_eq!(context.div(&dec(2, 0), &dec(3, 0)).value().repr(), &dec(6, -1));
+
+    // the generator in the middle
+    Stochastic::set_rng(|| 1 << 63);
+    assert_eq!(context.div(&Repr::one(), &dec(3, 0)).value().repr(), &dec(3, -1));
+    assert_eq!(context.div(&dec(2, 0), &dec(3, 0)).value().repr(), &dec(7, -1));
+
+    // the results are reproducible with the same seed
+    let samples = || {
+        (0..20)
+            .map(|_| context.div(&Repr::one(), &dec(7, 0)).value())
+            .collect::<Vec<_>>()
+    };
+    Stochastic::seed(1);
+    let first = samples();
+    Stochastic::seed(1);
+    assert_eq!(first, samples());
+    assert!(first.iter().any(|v| v.repr() == &dec(1, -1)));
+    assert!(first.iter().any(|v| v.repr() == &dec(2, -1)));
+
+    // the expectation of the rounded results is the exact value
+    // 1/3 = 0.0101010..b is rounded to 0.01011b with the probability 2/3
+    Stochastic::seed(2024);
+    let context = Context::<Stochastic>::new(4);
+    let up = (0..3000)
+        .filter(|_| context.div(&bin(1, 0), &bin(3, 0)).value().repr() == &bin(11, -5))
+        .count();
+    assert!(up > 1900 && up < 2100, "{}", up);
+}
+
+#[test]
+fn test_stochastic_tiny_addend() {
+    // the tiny operand of an addition is kept exactly within 64 bits below the rounding
+    // position, so that it only has a tiny chance to change the sum
+    let context = Context::<Stochastic>::new(4);
+    Stochastic::set_rng(|| 1 << 40);
+    assert_eq!(
+        context.add(&bin(8, 0), &bin(1, -30)),
+        Inexact(FBig::from_repr(bin(8, 0), context), NoOp)
+    );
+    Stochastic::set_rng(|| 1 << 30);
+    assert_eq!(
+        context.add(&bin(8, 0), &bin(1, -30)),
+        Inexact(FBig::from_repr(bin(9, 0), context), AddOne)
+    );
+
+    let context = Context::<Stochastic>::new(3);
+    Stochastic::set_rng(|| 1 << 40);
+    assert_eq!(context.add(&dec(12, 0), &dec(1, -10)).value().repr(), &dec(12, 0));
+    assert_eq!(context.sub(&dec(-12, 0), &dec(1, -10)).value().repr(), &dec(-12, 0));
+    Stochastic::set_rng(|| 1 << 25);
+    assert_eq!(context.add(&dec(12, 0), &dec(1, -10)).value().repr(), &dec(121, -1));
+    assert_eq!(context.sub(&dec(-12, 0), &dec(1, -10)).value().repr(), &dec(-121, -1));
+}
+
+#[test]
+fn test_stochastic_tiny_fused() {
+    // the tiny operand of the fused operations only has a negligible chance to change the result
+    let context = Context::<Stochastic>::new(4);
+    let tiny = bin(1, -100);
+    Stochastic::seed(2024);
+    let up = (0..10000)
+        .filter(|_| {
+            context
+                .mul_add(&bin(1, 0), &bin(1, 0), &tiny)
+                .value()
+                .repr()
+                != &bin(1, 0)
+        })
+        .count();
+    assert_eq!(up, 0);
+    let up = (0..10000)
+        .filter(|_| context.sum([&bin(1, 0), &tiny]).value().repr() != &bin(1, 0))
+        .count();
+    assert_eq!(up, 0);
+    let up = (0..10000)
+        .filter(|_| context.sum([&bin(-1, 0), &-tiny.clone()]).value().repr() != &bin(-1, 0))
+        .count();
+    assert_eq!(up, 0);
+
+    // the fused operations still round with the exact probability when the operand is not tiny
+    let small = bin(1, -5); // 1 + 2^-5 is rounded up to 1 + 2^-3 with the probability 1/4
+    let up = (0..4000)
+        .filter(|_| {
+            context
+                .mul_add(&bin(1, 0), &bin(1, 0), &small)
+                .value()
+                .repr()
+                != &bin(1, 0)
+        })
+        .count();
+    assert!(up > 900 && up < 1100, "{}", up);
+    let up = (0..4000)
+        .filter(|_| context.sum([&bin(1, 0), &small]).value().repr() != &bin(1, 0))
+        .count();
+    assert!(up > 900 && up < 1100, "{}", up);
+}
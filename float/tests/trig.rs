@@ -0,0 +1,452 @@
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DBig,
+};
+
+mod helper_macros;
+
+#[test]
+fn test_sin_binary() {
+    assert_eq!(fbig!(0).sin(), fbig!(0));
+
+    let inexact_cases = [
+        (fbig!(0x1), fbig!(0xdp-4)),
+        (fbig!(0x3), fbig!(0x9p-6)),
+        (fbig!(-0x3), fbig!(-0x9p-6)),
+        (fbig!(0x1234p-12), fbig!(0x3a17p-14)),
+        (fbig!(0xc90fdaa2p-30), fbig!(0x85a308d3p-64)),
+        (fbig!(0xc90fdaa22168c235p-63), fbig!(0xffffffffffffffffp-64)),
+        (fbig!(0xffffffff), fbig!(0x7f263c87p-32)),
+        (fbig!(0x1p-100), fbig!(0xfp-104)),
+        (fbig!(0x0001p-100), fbig!(0xffffp-116)),
+    ];
+    for (x, y) in &inexact_cases {
+        assert_eq!(x.sin(), *y);
+        if let Inexact(v, _) = x.context().sin(x.repr()) {
+            assert_eq!(v, *y);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_sin_decimal() {
+    assert_eq!(dbig!(0).sin(), dbig!(0));
+
+    let inexact_cases = [
+        (dbig!(1), dbig!(8e-1), NoOp),
+        (dbig!(2), dbig!(9e-1), NoOp),
+        (dbig!(3), dbig!(1e-1), NoOp),
+        (dbig!(-3), dbig!(-1e-1), NoOp),
+        (dbig!(1234e-3), dbig!(9438e-4), NoOp),
+        (dbig!(-1234e-3), dbig!(-9438e-4), NoOp),
+        (dbig!(0001), dbig!(8415e-4), AddOne),
+        (dbig!(31416e-4), dbig!(-73464e-10), NoOp),
+        (dbig!(15708e-4), dbig!(1), AddOne),
+        (dbig!(1000000), dbig!(-3499935e-7), NoOp),
+        (dbig!(12345678901234567890e-10), dbig!(95909281144646639909e-20), AddOne),
+        (dbig!(1e-5), dbig!(1e-5), AddOne),
+        (dbig!(00001e-5), dbig!(1e-5), AddOne),
+    ];
+    for (x, y, rnd) in &inexact_cases {
+        assert_eq!(x.sin(), *y);
+        if let Inexact(v, e) = x.context().sin(x.repr()) {
+            assert_eq!(v, *y);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_cos_binary() {
+    assert_eq!(fbig!(0).cos(), fbig!(1));
+
+    let inexact_cases = [
+        (fbig!(0x1), fbig!(0x1p-1)),
+        (fbig!(0x3), fbig!(-0xfp-4)),
+        (fbig!(-0x3), fbig!(-0xfp-4)),
+        (fbig!(0x1234p-12), fbig!(0xd6e1p-17)),
+        (fbig!(0xc90fdaa2p-30), fbig!(-0xffffffffp-32)),
+        (fbig!(0xc90fdaa22168c235p-63), fbig!(-0xece675d1fc8f8cbbp-129)),
+        (fbig!(0xffffffff), fbig!(-0xde3102cbp-32)),
+        (fbig!(0x1p-100), fbig!(0xfp-4)),
+        (fbig!(0x0001p-100), fbig!(0xffffp-16)),
+    ];
+    for (x, y) in &inexact_cases {
+        assert_eq!(x.cos(), *y);
+        if let Inexact(v, _) = x.context().cos(x.repr()) {
+            assert_eq!(v, *y);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_cos_decimal() {
+    assert_eq!(dbig!(0).cos(), dbig!(1));
+
+    let inexact_cases = [
+        (dbig!(1), dbig!(5e-1), NoOp),
+        (dbig!(2), dbig!(-4e-1), NoOp),
+        (dbig!(3), dbig!(-1), SubOne),
+        (dbig!(-3), dbig!(-1), SubOne),
+        (dbig!(1234e-3), dbig!(3305e-4), AddOne),
+        (dbig!(-1234e-3), dbig!(3305e-4), AddOne),
+        (dbig!(0001), dbig!(5403e-4), NoOp),
+        (dbig!(31416e-4), dbig!(-1), SubOne),
+        (dbig!(15708e-4), dbig!(-36732e-10), NoOp),
+        (dbig!(1000000), dbig!(9367521e-7), NoOp),
+        (dbig!(12345678901234567890e-10), dbig!(-28309182084919523751e-20), SubOne),
+        (dbig!(1e-5), dbig!(1), AddOne),
+        (dbig!(00001e-5), dbig!(1), AddOne),
+    ];
+    for (x, y, rnd) in &inexact_cases {
+        assert_eq!(x.cos(), *y);
+        if let Inexact(v, e) = x.context().cos(x.repr()) {
+            assert_eq!(v, *y);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_tan_binary() {
+    assert_eq!(fbig!(0).tan(), fbig!(0));
+
+    let inexact_cases = [
+        (fbig!(0x1), fbig!(0x3p-1)),
+        (fbig!(0x3), fbig!(-0x9p-6)),
+        (fbig!(-0x3), fbig!(0x9p-6)),
+        (fbig!(0x1234p-12), fbig!(0x4535p-13)),
+        (fbig!(0xc90fdaa2p-30), fbig!(-0x85a308d3p-64)),
+        (fbig!(0xc90fdaa22168c235p-63), fbig!(-0x4528f026d55ed1afp3)),
+        (fbig!(0xffffffff), fbig!(-0x124fe21bp-29)),
+        (fbig!(0x1p-100), fbig!(0x1p-100)),
+        (fbig!(0x0001p-100), fbig!(0x1p-100)),
+    ];
+    for (x, y) in &inexact_cases {
+        assert_eq!(x.tan(), *y);
+        if let Inexact(v, _) = x.context().tan(x.repr()) {
+            assert_eq!(v, *y);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_tan_decimal() {
+    assert_eq!(dbig!(0).tan(), dbig!(0));
+
+    let inexact_cases = [
+        (dbig!(1), dbig!(2), AddOne),
+        (dbig!(2), dbig!(-2), NoOp),
+        (dbig!(3), dbig!(-1e-1), NoOp),
+        (dbig!(-3), dbig!(1e-1), NoOp),
+        (dbig!(1234e-3), dbig!(2856e-3), NoOp),
+        (dbig!(-1234e-3), dbig!(-2856e-3), NoOp),
+        (dbig!(0001), dbig!(1557e-3), NoOp),
+        (dbig!(31416e-4), dbig!(73464e-10), NoOp),
+        (dbig!(15708e-4), dbig!(-27224e1), NoOp),
+        (dbig!(1000000), dbig!(-3736245e-7), SubOne),
+        (dbig!(12345678901234567890e-10), dbig!(-3387921306131204218e-18), NoOp),
+        (dbig!(1e-5), dbig!(1e-5), NoOp),
+        (dbig!(00001e-5), dbig!(1e-5), NoOp),
+    ];
+    for (x, y, rnd) in &inexact_cases {
+        assert_eq!(x.tan(), *y);
+        if let Inexact(v, e) = x.context().tan(x.repr()) {
+            assert_eq!(v, *y);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+#[test]
+fn test_trig_huge_arguments() {
+    let context = Context::<mode::HalfAway>::new(20);
+    let x = dbig!(1e100);
+    assert_eq!(context.sin(x.repr()), Inexact(dbig!(-37237612366127668826e-20), NoOp));
+    assert_eq!(context.cos(x.repr()), Inexact(dbig!(-92808190507465534346e-20), SubOne));
+    assert_eq!(context.tan(x.repr()), Inexact(dbig!(40123196199081435419e-20), AddOne));
+
+    let x = dbig!(12345678901234567890);
+    assert_eq!(context.sin(x.repr()), Inexact(dbig!(51635021078375773948e-20), AddOne));
+
+    let context = Context::<mode::HalfAway>::new(10);
+    let x = dbig!(1e1000);
+    assert_eq!(context.sin(x.repr()), Inexact(dbig!(6533597982e-10), NoOp));
+
+    let context = Context::<mode::Zero>::new(64);
+    let x = fbig!(0x1p1000);
+    assert_eq!(context.sin(x.repr()).value(), fbig!(-0x28c1715c3910dc9bp-64));
+    assert_eq!(context.cos(x.repr()).value(), fbig!(0x3f2f0a2c19102a2fp-62));
+}
+
+#[test]
+fn test_sin_cos() {
+    let context = Context::<mode::HalfAway>::new(20);
+    let x = dbig!(1e100);
+    assert_eq!(context.sin_cos(x.repr()), (context.sin(x.repr()), context.cos(x.repr())));
+
+    let x = DBig::from_str_native("-1.234").unwrap();
+    assert_eq!(x.sin_cos(), (x.sin(), x.cos()));
+    assert_eq!(dbig!(0).sin_cos(), (dbig!(0), dbig!(1)));
+}
+
+#[test]
+#[should_panic]
+fn test_sin_inf() {
+    let _ = DBig::INFINITY.sin();
+}
+
+#[test]
+#[should_panic]
+fn test_cos_unlimited_precision() {
+    let _ = dbig!(1).with_precision(0).value().cos();
+}
+
+#[test]
+fn test_asin_binary() {
+    let inexact_cases = [
+        (fbig!(0x1p-1), fbig!(0x1p-1)),
+        (fbig!(0x3p-2), fbig!(0xdp-4)),
+        (fbig!(-0x3p-2), fbig!(-0xdp-4)),
+        (fbig!(0xffffp-16), fbig!(0x642dp-14)),
+        (fbig!(-0xffffp-16), fbig!(-0x642dp-14)),
+        (fbig!(0x1234p-16), fbig!(0x91bfp-19)),
+        (fbig!(0x0001p-20), fbig!(0x1p-20)),
+    ];
+    for (x, y) in &inexact_cases {
+        assert_eq!(x.asin(), *y);
+        if let Inexact(v, _) = x.context().asin(x.repr()) {
+            assert_eq!(v, *y);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_asin_decimal() {
+    let inexact_cases = [
+        (dbig!(1e-1), dbig!(1e-1), NoOp),
+        (dbig!(-1e-1), dbig!(-1e-1), NoOp),
+        (dbig!(5e-1), dbig!(5e-1), NoOp),
+        (dbig!(9e-1), dbig!(1), NoOp),
+        (dbig!(1234e-4), dbig!(1237e-4), NoOp),
+        (dbig!(-1234e-4), dbig!(-1237e-4), NoOp),
+        (dbig!(9999e-4), dbig!(1557e-3), AddOne),
+        (dbig!(-9999e-4), dbig!(-1557e-3), SubOne),
+        (dbig!(99999999999999999999e-20), dbig!(1570796326653475263e-18), AddOne),
+        (dbig!(00001e-5), dbig!(1e-5), NoOp),
+        (dbig!(1e-30), dbig!(1e-30), NoOp),
+    ];
+    for (x, y, rnd) in &inexact_cases {
+        assert_eq!(x.asin(), *y);
+        if let Inexact(v, e) = x.context().asin(x.repr()) {
+            assert_eq!(v, *y);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_acos_binary() {
+    let inexact_cases = [
+        (fbig!(0x1p-1), fbig!(0x1)),
+        (fbig!(0x3p-2), fbig!(0xbp-4)),
+        (fbig!(-0x3p-2), fbig!(0x9p-2)),
+        (fbig!(0xffffp-16), fbig!(0xb505p-23)),
+        (fbig!(-0xffffp-16), fbig!(0xc8b5p-14)),
+        (fbig!(0x1234p-16), fbig!(0xbff3p-15)),
+        (fbig!(0x0001p-20), fbig!(0xc90fp-15)),
+    ];
+    for (x, y) in &inexact_cases {
+        assert_eq!(x.acos(), *y);
+        if let Inexact(v, _) = x.context().acos(x.repr()) {
+            assert_eq!(v, *y);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_acos_decimal() {
+    let inexact_cases = [
+        (dbig!(1e-1), dbig!(1), NoOp),
+        (dbig!(-1e-1), dbig!(2), AddOne),
+        (dbig!(5e-1), dbig!(1), NoOp),
+        (dbig!(9e-1), dbig!(5e-1), AddOne),
+        (dbig!(1234e-4), dbig!(1447e-3), NoOp),
+        (dbig!(-1234e-4), dbig!(1695e-3), AddOne),
+        (dbig!(9999e-4), dbig!(1414e-5), NoOp),
+        (dbig!(-9999e-4), dbig!(3127e-3), NoOp),
+        (dbig!(99999999999999999999e-20), dbig!(14142135623730950488e-29), NoOp),
+        (dbig!(00001e-5), dbig!(15708e-4), AddOne),
+        (dbig!(1e-30), dbig!(2), AddOne),
+    ];
+    for (x, y, rnd) in &inexact_cases {
+        assert_eq!(x.acos(), *y);
+        if let Inexact(v, e) = x.context().acos(x.repr()) {
+            assert_eq!(v, *y);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_atan_binary() {
+    let inexact_cases = [
+        (fbig!(0x1p-1), fbig!(0x7p-4)),
+        (fbig!(0x3p-2), fbig!(0x5p-3)),
+        (fbig!(-0x3p-2), fbig!(-0x5p-3)),
+        (fbig!(0xffffp-16), fbig!(0xc90fp-16)),
+        (fbig!(-0xffffp-16), fbig!(-0xc90fp-16)),
+        (fbig!(0x1234p-16), fbig!(0x9161p-19)),
+        (fbig!(0x0001p-20), fbig!(0xffffp-36)),
+        (fbig!(0x1), fbig!(0x3p-2)),
+        (fbig!(0x3), fbig!(0x9p-3)),
+        (fbig!(-0x5), fbig!(-0x5p-2)),
+        (fbig!(0x1p100), fbig!(0x3p-1)),
+        (fbig!(0x0001p-100), fbig!(0xffffp-116)),
+    ];
+    for (x, y) in &inexact_cases {
+        assert_eq!(x.atan(), *y);
+        if let Inexact(v, _) = x.context().atan(x.repr()) {
+            assert_eq!(v, *y);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_atan_decimal() {
+    let inexact_cases = [
+        (dbig!(1e-1), dbig!(1e-1), AddOne),
+        (dbig!(-1e-1), dbig!(-1e-1), SubOne),
+        (dbig!(5e-1), dbig!(5e-1), AddOne),
+        (dbig!(9e-1), dbig!(7e-1), NoOp),
+        (dbig!(1234e-4), dbig!(1228e-4), AddOne),
+        (dbig!(-1234e-4), dbig!(-1228e-4), SubOne),
+        (dbig!(9999e-4), dbig!(7853e-4), NoOp),
+        (dbig!(-9999e-4), dbig!(-7853e-4), NoOp),
+        (dbig!(99999999999999999999e-20), dbig!(78539816339744830961e-20), NoOp),
+        (dbig!(00001e-5), dbig!(1e-5), AddOne),
+        (dbig!(1e-30), dbig!(1e-30), AddOne),
+        (dbig!(1), dbig!(8e-1), AddOne),
+        (dbig!(2), dbig!(1), NoOp),
+        (dbig!(-2), dbig!(-1), NoOp),
+        (dbig!(1234e-3), dbig!(8898e-4), AddOne),
+        (dbig!(1e10), dbig!(2), AddOne),
+        (dbig!(12345e20), dbig!(15708e-4), AddOne),
+        (dbig!(-1e100), dbig!(-2), SubOne),
+    ];
+    for (x, y, rnd) in &inexact_cases {
+        assert_eq!(x.atan(), *y);
+        if let Inexact(v, e) = x.context().atan(x.repr()) {
+            assert_eq!(v, *y);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_inverse_trig_domain_edges() {
+    let context = Context::<mode::HalfAway>::new(20);
+    let half_pi = Inexact(dbig!(15707963267948966192e-19), NoOp);
+    let neg_half_pi = Inexact(dbig!(-15707963267948966192e-19), NoOp);
+    let pi = Inexact(dbig!(31415926535897932385e-19), AddOne);
+
+    assert_eq!(context.asin(dbig!(0).repr()), Exact(dbig!(0)));
+    assert_eq!(context.asin(dbig!(1).repr()), half_pi);
+    assert_eq!(context.asin(dbig!(-1).repr()), neg_half_pi);
+
+    assert_eq!(context.acos(dbig!(0).repr()), half_pi);
+    assert_eq!(context.acos(dbig!(1).repr()), Exact(dbig!(0)));
+    assert_eq!(context.acos(dbig!(-1).repr()), pi);
+
+    assert_eq!(context.atan(dbig!(0).repr()), Exact(dbig!(0)));
+    assert_eq!(context.atan(DBig::INFINITY.repr()), half_pi);
+    assert_eq!(context.atan(DBig::NEG_INFINITY.repr()), neg_half_pi);
+}
+
+#[test]
+#[should_panic]
+fn test_asin_out_of_domain() {
+    let _ = dbig!(1001e-3).asin();
+}
+
+#[test]
+#[should_panic]
+fn test_acos_out_of_domain() {
+    let _ = dbig!(-1001e-3).acos();
+}
+
+#[test]
+#[should_panic]
+fn test_asin_inf() {
+    let _ = DBig::INFINITY.asin();
+}
+
+#[test]
+fn test_atan2() {
+    let context = Context::<mode::HalfAway>::new(20);
+    let atan2 = |y: DBig, x: DBig| context.atan2(y.repr(), x.repr());
+
+    // quadrants
+    assert_eq!(atan2(dbig!(3), dbig!(4)), Inexact(dbig!(6435011087932843868e-19), NoOp));
+    assert_eq!(atan2(dbig!(3), dbig!(-4)), Inexact(dbig!(24980915447965088517e-19), AddOne));
+    assert_eq!(atan2(dbig!(-3), dbig!(-4)), Inexact(dbig!(-24980915447965088517e-19), SubOne));
+    assert_eq!(atan2(dbig!(-3), dbig!(4)), Inexact(dbig!(-6435011087932843868e-19), NoOp));
+    assert_eq!(atan2(dbig!(1e-30), dbig!(1)), Inexact(dbig!(1e-30), AddOne));
+    assert_eq!(atan2(dbig!(1e-30), dbig!(-1)), Inexact(dbig!(31415926535897932385e-19), AddOne));
+    assert_eq!(
+        atan2(dbig!(-1e-30), dbig!(-1)),
+        Inexact(dbig!(-31415926535897932385e-19), SubOne)
+    );
+    assert_eq!(atan2(dbig!(1), dbig!(-1e-30)), Inexact(dbig!(15707963267948966192e-19), NoOp));
+
+    // zeros
+    assert_eq!(atan2(dbig!(0), dbig!(0)), Exact(dbig!(0)));
+    assert_eq!(atan2(dbig!(0), dbig!(2)), Exact(dbig!(0)));
+    assert_eq!(atan2(dbig!(0), dbig!(-2)), Inexact(dbig!(31415926535897932385e-19), AddOne));
+    assert_eq!(atan2(dbig!(2), dbig!(0)), Inexact(dbig!(15707963267948966192e-19), NoOp));
+    assert_eq!(atan2(dbig!(-2), dbig!(0)), Inexact(dbig!(-15707963267948966192e-19), NoOp));
+
+    // infinities
+    let (inf, neg_inf) = (DBig::INFINITY, DBig::NEG_INFINITY);
+    assert_eq!(atan2(inf.clone(), dbig!(2)), Inexact(dbig!(15707963267948966192e-19), NoOp));
+    assert_eq!(atan2(dbig!(2), inf.clone()), Exact(dbig!(0)));
+    assert_eq!(
+        atan2(dbig!(-2), neg_inf.clone()),
+        Inexact(dbig!(-31415926535897932385e-19), SubOne)
+    );
+    assert_eq!(
+        atan2(inf.clone(), inf.clone()),
+        Inexact(dbig!(78539816339744830962e-20), AddOne)
+    );
+    assert_eq!(atan2(inf, neg_inf.clone()), Inexact(dbig!(23561944901923449288e-19), NoOp));
+    assert_eq!(atan2(neg_inf.clone(), neg_inf), Inexact(dbig!(-23561944901923449288e-19), NoOp));
+
+    // the method on FBig
+    let y = DBig::from_str_native("-1.234").unwrap();
+    let x = DBig::from_str_native("5.678").unwrap();
+    assert_eq!(y.atan2(&x), -((-&y).atan2(&x)));
+}
@@ -0,0 +1,185 @@
+use core::str::FromStr;
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DBig,
+};
+
+mod helper_macros;
+
+type HalfAway = Context<mode::HalfAway>;
+
+#[test]
+fn test_zeta_binary() {
+    assert_eq!(fbig!(0).zeta(), fbig!(-0x1p-1));
+    assert_eq!(fbig!(-0x2).zeta(), fbig!(0));
+    assert_eq!(fbig!(-0x1234).zeta(), fbig!(0));
+
+    let inexact_cases = [
+        (fbig!(0x2), fbig!(0xdp-3)),
+        (fbig!(0x3), fbig!(0x9p-3)),
+        (fbig!(0x1p-1), fbig!(-0xbp-3)),
+        (fbig!(-0x3p-1), fbig!(-0xdp-9)),
+        (fbig!(0x11p-4), fbig!(0x21p-1)),
+        (fbig!(-0x1), fbig!(-0x5p-6)),
+        (fbig!(0x1234p-4), fbig!(0x1)),
+        (fbig!(0x1p-40), fbig!(-0x1p-1)),
+        (fbig!(-0xabcdp-8), fbig!(0xe739p558)),
+        (fbig!(0x15), fbig!(0x1)),
+    ];
+    for (s, zeta) in &inexact_cases {
+        assert_eq!(s.zeta(), *zeta);
+        if let Inexact(v, _) = s.context().zeta(s.repr()) {
+            assert_eq!(v, *zeta);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_zeta_decimal() {
+    assert_eq!(HalfAway::new(2).zeta(dbig!(0).repr()), Exact(dbig!(-5e-1)));
+    assert_eq!(HalfAway::new(2).zeta(dbig!(-8).repr()), Exact(dbig!(0)));
+
+    // test cases: s, precision, zeta(s), rounding
+    let inexact_cases = [
+        (dbig!(2), 1, dbig!(2), AddOne),
+        (dbig!(3), 1, dbig!(1), NoOp),
+        (dbig!(0.5), 2, dbig!(-15e-1), SubOne),
+        (dbig!(-1.5), 2, dbig!(-25e-3), NoOp),
+        (dbig!(1.001), 4, dbig!(1001), AddOne),
+        (dbig!(-1), 1, dbig!(-8e-2), NoOp),
+        (dbig!(-13), 2, dbig!(-83e-3), NoOp),
+        (dbig!(1e-10), 1, dbig!(-5e-1), NoOp),
+        (dbig!(-20.5), 3, dbig!(-108), NoOp),
+        (dbig!(50), 2, dbig!(1), NoOp),
+        (dbig!(-0.999), 4, dbig!(-835e-4), SubOne),
+    ];
+    for (s, precision, zeta, rnd) in &inexact_cases {
+        let context = HalfAway::new(*precision);
+        assert_eq!(context.zeta(s.repr()), Inexact(zeta.clone(), *rnd));
+    }
+
+    // directed rounding
+    let context = Context::<mode::Up>::new(10);
+    let zeta = context.zeta(dbig!(1.001).repr());
+    assert_eq!(zeta.map(|v| v.with_rounding()), Inexact(dbig!(1000577289e-6), AddOne));
+    let context = Context::<mode::Up>::new(5);
+    let zeta = context.zeta(dbig!(-3).repr());
+    assert_eq!(zeta.map(|v| v.with_rounding()), Inexact(dbig!(83334e-7), AddOne));
+    let zeta = context.zeta(dbig!(30).repr());
+    assert_eq!(zeta.map(|v| v.with_rounding()), Inexact(dbig!(10001e-4), AddOne));
+    let context = Context::<mode::Down>::new(5);
+    let zeta = context.zeta(dbig!(30).repr());
+    assert_eq!(zeta.map(|v| v.with_rounding()), Inexact(dbig!(1), NoOp));
+
+    // high precision
+    let context = HalfAway::new(100);
+    let zeta3 = DBig::from_str("1.202056903159594285399738161511449990764986292340498881792271555341838205786313090186455873609335258").unwrap();
+    assert_eq!(context.zeta(dbig!(3).repr()), Inexact(zeta3, NoOp));
+    let zeta_half = DBig::from_str("-1.460354508809586812889499152515298012467229331012581490542886087825530529474500625276419375463356820").unwrap();
+    assert_eq!(context.zeta(dbig!(0.5).repr()), Inexact(zeta_half, SubOne));
+}
+
+#[test]
+#[should_panic]
+fn test_zeta_pole() {
+    let _ = dbig!(1).zeta();
+}
+
+#[test]
+fn test_polylog_binary() {
+    assert_eq!(fbig!(0).polylog(2), fbig!(0));
+
+    let inexact_cases = [
+        (2, fbig!(0x1p-1), fbig!(0x9p-4)),
+        (2, fbig!(0xfp-4), fbig!(0xbp-3)),
+        (2, fbig!(-0xfp-4), fbig!(-0x3p-2)),
+        (3, fbig!(-0x5), fbig!(-0x7p-1)),
+        (4, fbig!(0x1p-100), fbig!(0x1p-100)),
+        (2, fbig!(0x1), fbig!(0xdp-3)),
+    ];
+    for (n, x, li) in &inexact_cases {
+        assert_eq!(x.polylog(*n), *li);
+        if let Inexact(v, _) = x.context().polylog(*n, x.repr()) {
+            assert_eq!(v, *li);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+}
+
+#[test]
+fn test_polylog_decimal() {
+    // test cases: n, x, precision, Liₙ(x), rounding
+    let exact_cases = [
+        (0, dbig!(0.5), 2, dbig!(1)),
+        (-2, dbig!(0.5), 2, dbig!(6)),
+        (-1, dbig!(3), 2, dbig!(75e-2)),
+        (-5, dbig!(0), 2, dbig!(0)),
+    ];
+    for (n, x, precision, li) in &exact_cases {
+        let context = HalfAway::new(*precision);
+        assert_eq!(context.polylog(*n, x.repr()), Exact(li.clone()));
+    }
+
+    let inexact_cases = [
+        (-3, dbig!(-0.5), 3, dbig!(741e-4), AddOne),
+        (-1, dbig!(0.1), 5, dbig!(12346e-5), AddOne),
+        (2, dbig!(0.25), 3, dbig!(268e-3), AddOne),
+        (2, dbig!(0.75), 3, dbig!(978e-3), NoOp),
+        (2, dbig!(-0.75), 3, dbig!(-643e-3), SubOne),
+        (2, dbig!(-3), 1, dbig!(-2), SubOne),
+        (2, dbig!(1), 1, dbig!(2), AddOne),
+        (2, dbig!(-1), 1, dbig!(-8e-1), NoOp),
+        (3, dbig!(0.999), 4, dbig!(12e-1), NoOp),
+        (3, dbig!(-12.34), 4, dbig!(-6858e-3), NoOp),
+        (5, dbig!(0.6), 2, dbig!(61e-2), NoOp),
+        (5, dbig!(-0.6), 2, dbig!(-59e-2), SubOne),
+        (1, dbig!(0.5), 2, dbig!(69e-2), NoOp),
+        (1, dbig!(-12.34), 4, dbig!(-2591e-3), SubOne),
+        (2, dbig!(1e-30), 1, dbig!(1e-30), NoOp),
+        (4, dbig!(-1e-30), 1, dbig!(-1e-30), SubOne),
+        (7, dbig!(-99.99), 4, dbig!(-7702e-2), SubOne),
+        (20, dbig!(0.99), 3, dbig!(99e-2), NoOp),
+        (3, dbig!(-1.0001), 6, dbig!(-901625e-6), SubOne),
+        (
+            2,
+            dbig!(0.123456789012345678901234567890),
+            30,
+            dbig!(12749203335804235208546270364e-29),
+            NoOp,
+        ),
+    ];
+    for (n, x, precision, li, rnd) in &inexact_cases {
+        let context = HalfAway::new(*precision);
+        assert_eq!(context.polylog(*n, x.repr()), Inexact(li.clone(), *rnd));
+    }
+
+    // directed rounding
+    let context = Context::<mode::Up>::new(5);
+    let li = context.polylog(2, dbig!(1e-30).repr());
+    assert_eq!(li.map(|v| v.with_rounding()), Inexact(dbig!(10001e-34), AddOne));
+    let li = context.polylog(3, dbig!(-0.7).repr());
+    assert_eq!(li.map(|v| v.with_rounding()), Inexact(dbig!(-64866e-5), NoOp));
+    let li = context.polylog(1, dbig!(0.5).repr());
+    assert_eq!(li.map(|v| v.with_rounding()), Inexact(dbig!(69315e-5), AddOne));
+    let context = Context::<mode::Down>::new(5);
+    let li = context.polylog(2, dbig!(1e-30).repr());
+    assert_eq!(li.map(|v| v.with_rounding()), Inexact(dbig!(1e-30), NoOp));
+    let li = context.polylog(3, dbig!(-0.7).repr());
+    assert_eq!(li.map(|v| v.with_rounding()), Inexact(dbig!(-64867e-5), SubOne));
+}
+
+#[test]
+#[should_panic]
+fn test_polylog_out_of_domain() {
+    let _ = dbig!(1.5).polylog(2);
+}
+
+#[test]
+#[should_panic]
+fn test_polylog_pole() {
+    let _ = dbig!(1).polylog(0);
+}
@@ -172,8 +172,8 @@ mod repr {
             // shortcut
             let bits = self.bit_len();
             if bits <= n {
-                // the result must be 1
-                return Repr::one();
+                // the result must be 0 or 1
+                return if bits == 0 { Repr::zero() } else { Repr::one() };
             }
 
             // then use newton's method
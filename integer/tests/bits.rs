@@ -68,6 +68,15 @@ fn test_clear_high_bits() {
     assert_eq!(a, ubig!(0xa));
     a.clear_high_bits(0);
     assert_eq!(a, ubig!(0));
+
+    // clear at the word boundaries
+    let mut a = (ubig!(0xf1) << 192) + (ubig!(1) << 128) + (ubig!(1) << 64) + ubig!(1);
+    a.clear_high_bits(192);
+    assert_eq!(a, (ubig!(1) << 128) + (ubig!(1) << 64) + ubig!(1));
+    a.clear_high_bits(128);
+    assert_eq!(a, (ubig!(1) << 64) + ubig!(1));
+    a.clear_high_bits(64);
+    assert_eq!(a, ubig!(1));
 }
 
 #[test]
@@ -98,6 +107,15 @@ fn test_split_bits() {
     let (a, b) = a.split_bits(65);
     assert_eq!(a, ubig!(0x2468acf12130eca));
     assert_eq!(b, ubig!(0x567890987654321));
+
+    // split at the word boundaries
+    let a = (ubig!(1) << 192) + (ubig!(1) << 128) + (ubig!(1) << 64) + ubig!(1);
+    let (a, b) = a.split_bits(128);
+    assert_eq!(a, (ubig!(1) << 64) + ubig!(1));
+    assert_eq!(b, (ubig!(1) << 64) + ubig!(1));
+    let (a, b) = a.split_bits(64);
+    assert_eq!(a, ubig!(1));
+    assert_eq!(b, ubig!(1));
 }
 
 #[test]
@@ -93,6 +93,8 @@ fn test_nth_root() {
     assert_eq!(ubig!(2).nth_root(1), ubig!(2));
     assert_eq!(ubig!(2).nth_root(2), ubig!(1));
     assert_eq!(ubig!(2).nth_root(3), ubig!(1));
+    assert_eq!(ubig!(0).nth_root(3), ubig!(0));
+    assert_eq!(ibig!(0).nth_root(5), ibig!(0));
     assert_eq!(ibig!(-2).nth_root(1), ibig!(-2));
     assert_eq!(ibig!(2).nth_root(2), ibig!(1));
     assert_eq!(ibig!(-2).nth_root(3), ibig!(-1));
@@ -0,0 +1,109 @@
+//! Addition and subtraction operators.
+
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use dashu_base::{Gcd, UnsignedAbs};
+use dashu_int::{IBig, UBig};
+
+use crate::{
+    helper_macros,
+    rbig::{RBig, Relaxed},
+    repr::Repr,
+};
+
+impl Repr {
+    /// Add or subtract two reduced fractions, the result is also reduced.
+    ///
+    /// The algorithm follows Knuth (TAOCP vol. 2, 4.5.1), which keeps the intermediate
+    /// numbers small by dividing out the gcd of the denominators first.
+    fn add_sub_reduced(&self, rhs: &Repr, negate_rhs: bool) -> Repr {
+        let (a, b) = (&self.numerator, &self.denominator);
+        let (c, d) = (&rhs.numerator, &rhs.denominator);
+
+        let g = b.gcd(d);
+        if g.is_one() {
+            // (a*d ± c*b) / (b*d)
+            let ad = a * d;
+            let cb = c * b;
+            let numerator = if negate_rhs { ad - cb } else { ad + cb };
+            return Repr {
+                numerator,
+                denominator: b * d,
+            };
+        }
+
+        // t = a*(d/g) ± c*(b/g), g2 = gcd(t, g), result = (t/g2) / ((b/g)*(d/g2))
+        let bg = b / &g;
+        let dg = d / &g;
+        let ad = a * &dg;
+        let cb = c * &bg;
+        let t = if negate_rhs { ad - cb } else { ad + cb };
+        if t.is_zero() {
+            return Repr::zero();
+        }
+        let g2 = (&t).unsigned_abs().gcd(&g);
+        if g2.is_one() {
+            Repr {
+                numerator: t,
+                denominator: bg * d,
+            }
+        } else {
+            Repr {
+                numerator: t / &g2,
+                denominator: bg * (d / g2),
+            }
+        }
+    }
+
+    /// Add or subtract two fractions without full reduction.
+    fn add_sub_relaxed(&self, rhs: &Repr, negate_rhs: bool) -> Repr {
+        let (a, b) = (&self.numerator, &self.denominator);
+        let (c, d) = (&rhs.numerator, &rhs.denominator);
+
+        let numerator = if b == d {
+            if negate_rhs {
+                a - c
+            } else {
+                a + c
+            }
+        } else {
+            let ad = a * d;
+            let cb = c * b;
+            if negate_rhs {
+                ad - cb
+            } else {
+                ad + cb
+            }
+        };
+        let denominator = if b == d { b.clone() } else { b * d };
+        Repr {
+            numerator,
+            denominator,
+        }
+        .reduce2()
+    }
+}
+
+macro_rules! impl_add_sub {
+    ($t:ident, $method:ident) => {
+        impl<'l, 'r> Add<&'r $t> for &'l $t {
+            type Output = $t;
+            #[inline]
+            fn add(self, rhs: &$t) -> $t {
+                $t(self.0.$method(&rhs.0, false))
+            }
+        }
+
+        impl<'l, 'r> Sub<&'r $t> for &'l $t {
+            type Output = $t;
+            #[inline]
+            fn sub(self, rhs: &$t) -> $t {
+                $t(self.0.$method(&rhs.0, true))
+            }
+        }
+
+        helper_macros::impl_arith_ops!(impl Add for $t, add, AddAssign, add_assign);
+        helper_macros::impl_arith_ops!(impl Sub for $t, sub, SubAssign, sub_assign);
+    };
+}
+impl_add_sub!(RBig, add_sub_reduced);
+impl_add_sub!(Relaxed, add_sub_relaxed);
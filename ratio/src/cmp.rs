@@ -0,0 +1,205 @@
+//! Comparisons between rational numbers, and with integers and floats.
+
+use core::cmp::Ordering;
+use dashu_base::Sign;
+use dashu_float::{round::Round, FBig, Repr as FloatRepr};
+use dashu_int::{IBig, UBig, Word};
+
+use crate::{
+    rbig::{RBig, Relaxed},
+    repr::Repr,
+};
+
+impl Repr {
+    /// Test the equality of two fractions by their values.
+    fn value_eq(&self, rhs: &Repr) -> bool {
+        if self.denominator == rhs.denominator {
+            return self.numerator == rhs.numerator;
+        }
+        if self.numerator.sign() != rhs.numerator.sign() {
+            return false;
+        }
+        &self.numerator * &rhs.denominator == &rhs.numerator * &self.denominator
+    }
+
+    /// Compare two fractions by their values.
+    fn value_cmp(&self, rhs: &Repr) -> Ordering {
+        // compare the signs first
+        let lhs_sign = self.numerator.signum();
+        let rhs_sign = rhs.numerator.signum();
+        match lhs_sign.cmp(&rhs_sign) {
+            Ordering::Equal => {}
+            ord => return ord,
+        }
+        if self.denominator == rhs.denominator {
+            return self.numerator.cmp(&rhs.numerator);
+        }
+        (&self.numerator * &rhs.denominator).cmp(&(&rhs.numerator * &self.denominator))
+    }
+
+    /// Compare the fraction with an integer.
+    fn int_cmp(&self, rhs: &IBig) -> Ordering {
+        if self.denominator.is_one() {
+            return self.numerator.cmp(rhs);
+        }
+        self.numerator.cmp(&(rhs * &self.denominator))
+    }
+
+    /// Compare the fraction with a float number. As in the comparison between float numbers,
+    /// the NaN is greater than any fraction.
+    fn float_cmp<const B: Word>(&self, rhs: &FloatRepr<B>) -> Ordering {
+        if rhs.is_nan() {
+            return Ordering::Less;
+        }
+        if rhs.is_infinite() {
+            return match rhs.sign() {
+                Sign::Positive => Ordering::Less,
+                Sign::Negative => Ordering::Greater,
+            };
+        }
+
+        let exp = rhs.exponent();
+        let signif = rhs.significand();
+        if exp >= 0 {
+            let scale = UBig::from(B).pow(exp as usize);
+            self.numerator.cmp(&(signif * scale * &self.denominator))
+        } else {
+            let scale = UBig::from(B).pow(exp.unsigned_abs());
+            (&self.numerator * scale).cmp(&(signif * &self.denominator))
+        }
+    }
+}
+
+impl PartialOrd for RBig {
+    #[inline]
+    fn partial_cmp(&self, other: &RBig) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RBig {
+    #[inline]
+    fn cmp(&self, other: &RBig) -> Ordering {
+        self.0.value_cmp(&other.0)
+    }
+}
+
+impl PartialEq for Relaxed {
+    #[inline]
+    fn eq(&self, other: &Relaxed) -> bool {
+        self.0.value_eq(&other.0)
+    }
+}
+impl Eq for Relaxed {}
+
+impl PartialOrd for Relaxed {
+    #[inline]
+    fn partial_cmp(&self, other: &Relaxed) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Relaxed {
+    #[inline]
+    fn cmp(&self, other: &Relaxed) -> Ordering {
+        self.0.value_cmp(&other.0)
+    }
+}
+
+impl PartialEq<Relaxed> for RBig {
+    #[inline]
+    fn eq(&self, other: &Relaxed) -> bool {
+        self.0.value_eq(&other.0)
+    }
+}
+
+impl PartialEq<RBig> for Relaxed {
+    #[inline]
+    fn eq(&self, other: &RBig) -> bool {
+        self.0.value_eq(&other.0)
+    }
+}
+
+impl PartialOrd<Relaxed> for RBig {
+    #[inline]
+    fn partial_cmp(&self, other: &Relaxed) -> Option<Ordering> {
+        Some(self.0.value_cmp(&other.0))
+    }
+}
+
+impl PartialOrd<RBig> for Relaxed {
+    #[inline]
+    fn partial_cmp(&self, other: &RBig) -> Option<Ordering> {
+        Some(self.0.value_cmp(&other.0))
+    }
+}
+
+macro_rules! impl_cmp_with_int {
+    ($t:ty, $int:ty) => {
+        impl PartialEq<$int> for $t {
+            #[inline]
+            fn eq(&self, other: &$int) -> bool {
+                self.0.int_cmp(&IBig::from(other.clone())) == Ordering::Equal
+            }
+        }
+
+        impl PartialEq<$t> for $int {
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                other.eq(self)
+            }
+        }
+
+        impl PartialOrd<$int> for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &$int) -> Option<Ordering> {
+                Some(self.0.int_cmp(&IBig::from(other.clone())))
+            }
+        }
+
+        impl PartialOrd<$t> for $int {
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+impl_cmp_with_int!(RBig, IBig);
+impl_cmp_with_int!(RBig, UBig);
+impl_cmp_with_int!(Relaxed, IBig);
+impl_cmp_with_int!(Relaxed, UBig);
+
+macro_rules! impl_cmp_with_float {
+    ($t:ty) => {
+        impl<R: Round, const B: Word> PartialEq<FBig<R, B>> for $t {
+            #[inline]
+            fn eq(&self, other: &FBig<R, B>) -> bool {
+                self.0.float_cmp(other.repr()) == Ordering::Equal
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialEq<$t> for FBig<R, B> {
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                other.eq(self)
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialOrd<FBig<R, B>> for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &FBig<R, B>) -> Option<Ordering> {
+                Some(self.0.float_cmp(other.repr()))
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialOrd<$t> for FBig<R, B> {
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+impl_cmp_with_float!(RBig);
+impl_cmp_with_float!(Relaxed);
@@ -0,0 +1,437 @@
+//! Continued fraction expansions and rational approximations.
+
+use core::convert::TryFrom;
+use dashu_base::{DivRem, Sign, UnsignedAbs};
+use dashu_float::{round::Round, FBig};
+use dashu_int::{error::OutOfBoundsError, IBig, UBig, Word};
+
+use crate::{
+    rbig::{RBig, Relaxed},
+    repr::Repr,
+};
+
+/// Floor division of two integers, the divisor must be non-zero.
+fn floor_div(lhs: &IBig, rhs: &IBig) -> IBig {
+    let (q, r) = lhs.div_rem(rhs);
+    if !r.is_zero() && r.sign() != rhs.sign() {
+        q - IBig::ONE
+    } else {
+        q
+    }
+}
+
+/// An iterator over the partial quotients of the (simple) continued fraction
+/// of a rational number.
+///
+/// The expansion `[a0; a1, a2, ..., an]` is finite, the first term is the floor of
+/// the number, and the following terms are all positive. The last term is never 1
+/// unless the expansion has only one term.
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::{IBig, UBig};
+/// use dashu_ratio::ContinuedFraction;
+///
+/// // 415/93 = [4; 2, 6, 7]
+/// let cf = ContinuedFraction::new(IBig::from(415), UBig::from(93u8));
+/// let terms: Vec<_> = cf.collect();
+/// assert_eq!(terms, [4, 2, 6, 7].map(IBig::from));
+///
+/// // -415/93 = [-5; 1, 1, 6, 7]
+/// let cf = ContinuedFraction::new(IBig::from(-415), UBig::from(93u8));
+/// let terms: Vec<_> = cf.collect();
+/// assert_eq!(terms, [-5, 1, 1, 6, 7].map(IBig::from));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ContinuedFraction {
+    numerator: IBig,
+    denominator: IBig,
+}
+
+impl ContinuedFraction {
+    /// Create the continued fraction expansion of `numerator / denominator`.
+    /// The fraction doesn't need to be reduced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero.
+    #[inline]
+    pub fn new(numerator: IBig, denominator: UBig) -> Self {
+        let repr = Repr::from_signed(numerator, denominator.into());
+        Self {
+            numerator: repr.numerator,
+            denominator: repr.denominator.into(),
+        }
+    }
+
+    /// Create an iterator over the convergents of the continued fraction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::{IBig, UBig};
+    /// use dashu_ratio::ContinuedFraction;
+    ///
+    /// let cf = ContinuedFraction::new(IBig::from(415), UBig::from(93u8));
+    /// let convergents: Vec<_> = cf.convergents().map(|r| r.to_string()).collect();
+    /// assert_eq!(convergents, ["4", "9/2", "58/13", "415/93"]);
+    /// ```
+    #[inline]
+    pub fn convergents(self) -> Convergents<Self> {
+        Convergents::new(self)
+    }
+}
+
+impl Iterator for ContinuedFraction {
+    type Item = IBig;
+
+    fn next(&mut self) -> Option<IBig> {
+        if self.denominator.is_zero() {
+            return None;
+        }
+        let a = floor_div(&self.numerator, &self.denominator);
+        let rem = &self.numerator - &a * &self.denominator;
+        self.numerator = core::mem::replace(&mut self.denominator, rem);
+        Some(a)
+    }
+}
+
+impl<R: Round, const B: Word> TryFrom<&FBig<R, B>> for ContinuedFraction {
+    type Error = OutOfBoundsError;
+
+    /// Expand the exact value of a float number into a continued fraction.
+    /// Returns [OutOfBoundsError] if the float is infinite or NaN.
+    #[inline]
+    fn try_from(f: &FBig<R, B>) -> Result<Self, Self::Error> {
+        Ok(Relaxed::try_from(f)?.continued_fraction())
+    }
+}
+
+impl<R: Round, const B: Word> TryFrom<FBig<R, B>> for ContinuedFraction {
+    type Error = OutOfBoundsError;
+
+    /// Expand the exact value of a float number into a continued fraction.
+    /// Returns [OutOfBoundsError] if the float is infinite or NaN.
+    #[inline]
+    fn try_from(f: FBig<R, B>) -> Result<Self, Self::Error> {
+        Self::try_from(&f)
+    }
+}
+
+/// An iterator over the partial quotients of the continued fraction of a
+/// quadratic irrational `(p + √d) / q`.
+///
+/// The expansion of a quadratic irrational is infinite and eventually periodic, so the
+/// iterator never ends, unless `d` is a perfect square and the number is actually rational.
+/// To expand `(p - √d) / q`, use `(-p + √d) / -q` instead.
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::{IBig, UBig};
+/// use dashu_ratio::QuadraticContinuedFraction;
+///
+/// // √7 = [2; 1, 1, 1, 4, 1, 1, 1, 4, ...]
+/// let cf = QuadraticContinuedFraction::new(IBig::ZERO, UBig::from(7u8), IBig::ONE);
+/// let terms: Vec<_> = cf.take(9).collect();
+/// assert_eq!(terms, [2, 1, 1, 1, 4, 1, 1, 1, 4].map(IBig::from));
+///
+/// // the golden ratio (1 + √5) / 2 = [1; 1, 1, ...]
+/// let cf = QuadraticContinuedFraction::new(IBig::ONE, UBig::from(5u8), IBig::from(2));
+/// assert!(cf.take(10).all(|a| a == IBig::ONE));
+/// ```
+#[derive(Clone, Debug)]
+pub struct QuadraticContinuedFraction(QuadraticRepr);
+
+#[derive(Clone, Debug)]
+enum QuadraticRepr {
+    /// `(p + √d) / q` where `d` is not a perfect square and `q` divides `d - p^2`.
+    /// `sqrt` is the floor of `√d`.
+    Irrational {
+        p: IBig,
+        d: IBig,
+        q: IBig,
+        sqrt: IBig,
+    },
+    /// The number is rational when `d` is a perfect square.
+    Rational(ContinuedFraction),
+}
+
+impl QuadraticContinuedFraction {
+    /// Create the continued fraction expansion of `(p + √d) / q`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `q` is zero.
+    pub fn new(p: IBig, d: UBig, q: IBig) -> Self {
+        if q.is_zero() {
+            crate::error::panic_divide_by_0()
+        }
+
+        let (sqrt, rem) = d.sqrt_rem();
+        if rem.is_zero() {
+            let repr = Repr::from_signed(p + IBig::from(sqrt), q);
+            return Self(QuadraticRepr::Rational(ContinuedFraction {
+                numerator: repr.numerator,
+                denominator: repr.denominator.into(),
+            }));
+        }
+
+        // make sure that q divides d - p^2, by scaling all the numbers with |q|:
+        // (p + √d) / q = (p|q| + √(d q^2)) / (q|q|)
+        let d = IBig::from(d);
+        let (p, d, q) = if (&d - &p * &p) % &q == IBig::ZERO {
+            (p, d, q)
+        } else {
+            let q_abs = IBig::from((&q).unsigned_abs());
+            let d = d * &q * &q;
+            (p * &q_abs, d, q * q_abs)
+        };
+        let sqrt = IBig::from((&d).unsigned_abs().sqrt());
+        Self(QuadraticRepr::Irrational { p, d, q, sqrt })
+    }
+
+    /// Create an iterator over the convergents of the continued fraction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::{IBig, UBig};
+    /// use dashu_ratio::QuadraticContinuedFraction;
+    ///
+    /// let cf = QuadraticContinuedFraction::new(IBig::ZERO, UBig::from(2u8), IBig::ONE);
+    /// let convergents: Vec<_> = cf.convergents().take(5).map(|r| r.to_string()).collect();
+    /// assert_eq!(convergents, ["1", "3/2", "7/5", "17/12", "41/29"]);
+    /// ```
+    #[inline]
+    pub fn convergents(self) -> Convergents<Self> {
+        Convergents::new(self)
+    }
+}
+
+impl Iterator for QuadraticContinuedFraction {
+    type Item = IBig;
+
+    fn next(&mut self) -> Option<IBig> {
+        match &mut self.0 {
+            QuadraticRepr::Rational(cf) => cf.next(),
+            QuadraticRepr::Irrational { p, d, q, sqrt } => {
+                // a = floor((p + √d) / q), since √d is strictly between sqrt and sqrt + 1,
+                // the floor can be calculated with integers only.
+                let a = if q.sign() == Sign::Positive {
+                    floor_div(&(&*p + &*sqrt), q)
+                } else {
+                    floor_div(&(&*p + &*sqrt + IBig::ONE), q)
+                };
+
+                // p' = aq - p, q' = (d - p'^2) / q
+                let new_p = &a * &*q - &*p;
+                let new_q = (&*d - &new_p * &new_p) / &*q;
+                *p = new_p;
+                *q = new_q;
+                Some(a)
+            }
+        }
+    }
+}
+
+/// An iterator over the convergents of a continued fraction, given an iterator
+/// of the partial quotients.
+///
+/// The convergents are always in the reduced form.
+#[derive(Clone, Debug)]
+pub struct Convergents<I> {
+    terms: I,
+    // (h_{n-1}, k_{n-1}) and (h_{n-2}, k_{n-2})
+    last: (IBig, IBig),
+    last2: (IBig, IBig),
+}
+
+impl<I: Iterator<Item = IBig>> Convergents<I> {
+    /// Create the iterator of convergents from an iterator of partial quotients.
+    /// All the terms except for the first one should be positive.
+    #[inline]
+    pub fn new(terms: I) -> Self {
+        Self {
+            terms,
+            last: (IBig::ONE, IBig::ZERO),
+            last2: (IBig::ZERO, IBig::ONE),
+        }
+    }
+}
+
+impl<I: Iterator<Item = IBig>> Iterator for Convergents<I> {
+    type Item = RBig;
+
+    fn next(&mut self) -> Option<RBig> {
+        let a = self.terms.next()?;
+        let h = &a * &self.last.0 + &self.last2.0;
+        let k = a * &self.last.1 + &self.last2.1;
+        let last = core::mem::replace(&mut self.last, (h.clone(), k.clone()));
+        self.last2 = last;
+
+        // the convergents are coprime, and the denominators are positive
+        let (sign, denominator) = k.into_parts();
+        Some(RBig(Repr {
+            numerator: sign * h,
+            denominator,
+        }))
+    }
+}
+
+impl RBig {
+    /// Get the continued fraction expansion of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::{error::ParseError, IBig};
+    /// use dashu_ratio::RBig;
+    ///
+    /// let a: RBig = "-7/3".parse()?;
+    /// let terms: Vec<_> = a.continued_fraction().collect();
+    /// assert_eq!(terms, [-3, 1, 2].map(IBig::from));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn continued_fraction(&self) -> ContinuedFraction {
+        ContinuedFraction {
+            numerator: self.0.numerator.clone(),
+            denominator: self.0.denominator.clone().into(),
+        }
+    }
+
+    /// Find the rational number closest to this number, whose denominator is not larger
+    /// than `max_denominator`. If there are two closest numbers, the one with the
+    /// smaller denominator is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::{error::ParseError, UBig};
+    /// use dashu_ratio::RBig;
+    ///
+    /// let pi: RBig = "3141592653589793/1000000000000000".parse()?;
+    /// assert_eq!(pi.best_rational_approx(&UBig::from(10u8)), "22/7".parse::<RBig>()?);
+    /// assert_eq!(pi.best_rational_approx(&UBig::from(1000u16)), "355/113".parse::<RBig>()?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_denominator` is zero.
+    pub fn best_rational_approx(&self, max_denominator: &UBig) -> RBig {
+        if max_denominator.is_zero() {
+            crate::error::panic_divide_by_0()
+        }
+        if &self.0.denominator <= max_denominator {
+            return self.clone();
+        }
+
+        // follow the convergents until the denominator exceeds the limit
+        let max_den = IBig::from(max_denominator.clone());
+        let (mut p0, mut q0, mut p1, mut q1) = (IBig::ZERO, IBig::ONE, IBig::ONE, IBig::ZERO);
+        let mut n = self.0.numerator.clone();
+        let mut d = IBig::from(self.0.denominator.clone());
+        loop {
+            let a = floor_div(&n, &d);
+            let q2 = &q0 + &a * &q1;
+            if q2 > max_den {
+                break;
+            }
+            let p2 = &p0 + &a * &p1;
+            p0 = core::mem::replace(&mut p1, p2);
+            q0 = core::mem::replace(&mut q1, q2);
+            let rem = &n - a * &d;
+            n = core::mem::replace(&mut d, rem);
+        }
+
+        // the best approximation is either the last convergent p1/q1, or the
+        // semiconvergent (p0 + k*p1) / (q0 + k*q1) with the largest possible k.
+        // The distance between them is 1/(q1*(q0+k*q1)), and the distance from p1/q1
+        // to self is d/(q1*denominator), so compare 2*d*(q0+k*q1) with the denominator.
+        let k = (&max_den - &q0) / &q1;
+        let p_semi = p0 + &k * &p1;
+        let q_semi = q0 + k * &q1;
+        let (p, q) = if IBig::from(2) * d * &q_semi <= self.0.denominator {
+            (p1, q1)
+        } else {
+            (p_semi, q_semi)
+        };
+        RBig(Repr {
+            numerator: p,
+            denominator: q.unsigned_abs(),
+        })
+    }
+
+    /// Find the simplest rational number in the closed interval between `a` and `b`.
+    ///
+    /// The simplest number is the one with the smallest denominator, and if there are
+    /// multiple candidates, the one with the smallest absolute value. The order of
+    /// the two bounds doesn't matter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_ratio::RBig;
+    ///
+    /// let a: RBig = "3141/1000".parse()?;
+    /// let b: RBig = "3142/1000".parse()?;
+    /// assert_eq!(RBig::simplest_between(&a, &b), "245/78".parse::<RBig>()?);
+    /// assert_eq!(RBig::simplest_between(&-b, &a), RBig::ZERO);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn simplest_between(a: &RBig, b: &RBig) -> RBig {
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        if lo.sign() == Sign::Negative && hi.sign() == Sign::Negative {
+            // both are negative, and zero is not in the interval
+            return -Self::simplest_between_positive(-hi, -lo);
+        }
+        if lo.sign() == Sign::Negative || lo.is_zero() {
+            return RBig::ZERO;
+        }
+        Self::simplest_between_positive(lo.clone(), hi.clone())
+    }
+
+    /// Find the simplest rational number in the closed interval [lo, hi], where 0 < lo <= hi.
+    fn simplest_between_positive(mut lo: RBig, mut hi: RBig) -> RBig {
+        // collect the continued fraction terms of the result
+        let mut terms = alloc::vec::Vec::new();
+        loop {
+            let fl = lo.floor();
+            if lo.is_int() {
+                terms.push(fl);
+                break;
+            }
+            let next = &fl + IBig::ONE;
+            if hi >= next {
+                terms.push(next);
+                break;
+            }
+
+            // lo and hi have the same integral part
+            let new_lo = (&hi - &fl).inv();
+            let new_hi = (&lo - &fl).inv();
+            terms.push(fl);
+            lo = new_lo;
+            hi = new_hi;
+        }
+        Convergents::new(terms.into_iter()).last().unwrap()
+    }
+}
+
+impl Relaxed {
+    /// Get the continued fraction expansion of the number.
+    ///
+    /// The fraction doesn't need to be reduced for the expansion,
+    /// see [RBig::continued_fraction] for details.
+    #[inline]
+    pub fn continued_fraction(&self) -> ContinuedFraction {
+        ContinuedFraction {
+            numerator: self.0.numerator.clone(),
+            denominator: self.0.denominator.clone().into(),
+        }
+    }
+}
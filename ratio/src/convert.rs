@@ -0,0 +1,165 @@
+//! Conversions between rational numbers and integers or floats.
+
+use core::convert::TryFrom;
+use dashu_float::{
+    round::{Round, Rounded},
+    Context, FBig, Repr as FloatRepr,
+};
+use dashu_int::{error::OutOfBoundsError, IBig, UBig, Word};
+
+use crate::{
+    rbig::{RBig, Relaxed},
+    repr::Repr,
+};
+
+impl From<IBig> for Repr {
+    #[inline]
+    fn from(v: IBig) -> Repr {
+        Repr {
+            numerator: v,
+            denominator: UBig::ONE,
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($t:ident, $($int:ty)*) => {$(
+        impl From<$int> for $t {
+            #[inline]
+            fn from(v: $int) -> $t {
+                $t(Repr::from(IBig::from(v)))
+            }
+        }
+    )*};
+}
+impl_from_int!(RBig, IBig UBig u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_from_int!(Relaxed, IBig UBig u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+impl From<Relaxed> for RBig {
+    #[inline]
+    fn from(v: Relaxed) -> RBig {
+        v.canonicalize()
+    }
+}
+
+impl From<RBig> for Relaxed {
+    #[inline]
+    fn from(v: RBig) -> Relaxed {
+        v.relax()
+    }
+}
+
+impl Repr {
+    /// Convert a float representation to an exact fraction, which is not reduced.
+    fn try_from_float<const B: Word>(f: &FloatRepr<B>) -> Result<Repr, OutOfBoundsError> {
+        if f.is_infinite() || f.is_nan() {
+            return Err(OutOfBoundsError);
+        }
+
+        let exp = f.exponent();
+        let repr = if exp >= 0 {
+            let scale = UBig::from(B).pow(exp as usize);
+            Repr::from(f.significand() * scale)
+        } else {
+            Repr {
+                numerator: f.significand().clone(),
+                denominator: UBig::from(B).pow(exp.unsigned_abs()),
+            }
+        };
+        Ok(repr)
+    }
+
+    /// Convert the fraction to a float with the given precision, correctly rounded.
+    #[inline]
+    fn to_float<R: Round, const B: Word>(&self, precision: usize) -> Rounded<FBig<R, B>> {
+        Context::<R>::new(precision)
+            .convert_fraction(self.numerator.clone(), self.denominator.clone())
+    }
+}
+
+macro_rules! impl_float_conversions {
+    ($t:ident, $reduce:ident) => {
+        impl<R: Round, const B: Word> TryFrom<FBig<R, B>> for $t {
+            type Error = OutOfBoundsError;
+
+            /// Convert a float number to a rational number exactly.
+            /// Returns [OutOfBoundsError] if the float is infinite or NaN.
+            #[inline]
+            fn try_from(f: FBig<R, B>) -> Result<Self, Self::Error> {
+                Repr::try_from_float(f.repr()).map(|repr| $t(repr.$reduce()))
+            }
+        }
+
+        impl<'a, R: Round, const B: Word> TryFrom<&'a FBig<R, B>> for $t {
+            type Error = OutOfBoundsError;
+
+            /// Convert a float number to a rational number exactly.
+            /// Returns [OutOfBoundsError] if the float is infinite or NaN.
+            #[inline]
+            fn try_from(f: &'a FBig<R, B>) -> Result<Self, Self::Error> {
+                Repr::try_from_float(f.repr()).map(|repr| $t(repr.$reduce()))
+            }
+        }
+
+        impl TryFrom<f32> for $t {
+            type Error = OutOfBoundsError;
+
+            /// Convert a [f32] to a rational number exactly.
+            /// Returns [OutOfBoundsError] if the float is infinite or NaN.
+            #[inline]
+            fn try_from(f: f32) -> Result<Self, Self::Error> {
+                let f = FBig::<dashu_float::round::mode::Zero, 2>::try_from(f)?;
+                Self::try_from(f)
+            }
+        }
+
+        impl TryFrom<f64> for $t {
+            type Error = OutOfBoundsError;
+
+            /// Convert a [f64] to a rational number exactly.
+            /// Returns [OutOfBoundsError] if the float is infinite or NaN.
+            #[inline]
+            fn try_from(f: f64) -> Result<Self, Self::Error> {
+                let f = FBig::<dashu_float::round::mode::Zero, 2>::try_from(f)?;
+                Self::try_from(f)
+            }
+        }
+
+        impl $t {
+            /// Convert the rational number to a float number with the given precision.
+            ///
+            /// The result is correctly rounded according to the rounding mode `R`, and
+            /// the rounding direction is returned along with the result. For a
+            /// custom context, use [Context::convert_fraction] directly.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use dashu_int::error::ParseError;
+            /// use dashu_base::Approximation::*;
+            /// use dashu_float::{round::{mode::HalfAway, Rounding::*}, DBig};
+            #[doc = concat!("use dashu_ratio::", stringify!($t), ";")]
+            ///
+            #[doc = concat!("let a: ", stringify!($t), " = \"-2/3\".parse()?;")]
+            /// assert_eq!(
+            ///     a.to_float::<HalfAway, 10>(4),
+            ///     Inexact(DBig::from_str_native("-0.6667")?, SubOne)
+            /// );
+            /// # Ok::<(), ParseError>(())
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// Panics if the precision is 0 (unlimited) and the number is not an integer.
+            #[inline]
+            pub fn to_float<R: Round, const B: Word>(
+                &self,
+                precision: usize,
+            ) -> Rounded<FBig<R, B>> {
+                self.0.to_float(precision)
+            }
+        }
+    };
+}
+impl_float_conversions!(RBig, reduce);
+impl_float_conversions!(Relaxed, reduce2);
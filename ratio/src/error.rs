@@ -0,0 +1,6 @@
+//! Panic helpers
+
+/// Panics when division by 0 is happening
+pub(crate) const fn panic_divide_by_0() -> ! {
+    panic!("divisor must not be 0")
+}
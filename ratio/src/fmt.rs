@@ -0,0 +1,38 @@
+//! Implementation of formatters
+
+use core::fmt::{self, Display, Formatter};
+
+use crate::{
+    rbig::{RBig, Relaxed},
+    repr::Repr,
+};
+
+impl Display for Repr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.denominator.is_one() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+macro_rules! impl_fmt {
+    ($t:ty) => {
+        impl Display for $t {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl fmt::Debug for $t {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+impl_fmt!(RBig);
+impl_fmt!(Relaxed);
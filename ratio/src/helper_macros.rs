@@ -0,0 +1,130 @@
+/// Implement `impl Op<B> for A` with all the combinations of references, by forwarding
+/// to the implementation `impl Op<&B> for &A`.
+macro_rules! forward_binop_to_ref_ref {
+    (impl $trait:ident<$t2:ty> for $t1:ty, $method:ident) => {
+        impl $trait<$t2> for $t1 {
+            type Output = $t1;
+            #[inline]
+            fn $method(self, rhs: $t2) -> $t1 {
+                (&self).$method(&rhs)
+            }
+        }
+        impl<'r> $trait<&'r $t2> for $t1 {
+            type Output = $t1;
+            #[inline]
+            fn $method(self, rhs: &$t2) -> $t1 {
+                (&self).$method(rhs)
+            }
+        }
+        impl<'l> $trait<$t2> for &'l $t1 {
+            type Output = $t1;
+            #[inline]
+            fn $method(self, rhs: $t2) -> $t1 {
+                self.$method(&rhs)
+            }
+        }
+    };
+}
+
+/// Implement `impl Op<I> for A` and `impl Op<A> for I` by converting the integer `I` to `A`,
+/// including operations taking by references.
+macro_rules! impl_binop_with_int {
+    (impl $trait:ident<$int:ty> for $t:ty, $method:ident) => {
+        impl $trait<$int> for $t {
+            type Output = $t;
+            #[inline]
+            fn $method(self, rhs: $int) -> $t {
+                self.$method(<$t>::from(rhs))
+            }
+        }
+        impl<'r> $trait<&'r $int> for $t {
+            type Output = $t;
+            #[inline]
+            fn $method(self, rhs: &$int) -> $t {
+                self.$method(<$t>::from(rhs.clone()))
+            }
+        }
+        impl<'l> $trait<$int> for &'l $t {
+            type Output = $t;
+            #[inline]
+            fn $method(self, rhs: $int) -> $t {
+                self.$method(<$t>::from(rhs))
+            }
+        }
+        impl<'l, 'r> $trait<&'r $int> for &'l $t {
+            type Output = $t;
+            #[inline]
+            fn $method(self, rhs: &$int) -> $t {
+                self.$method(<$t>::from(rhs.clone()))
+            }
+        }
+
+        impl $trait<$t> for $int {
+            type Output = $t;
+            #[inline]
+            fn $method(self, rhs: $t) -> $t {
+                <$t>::from(self).$method(rhs)
+            }
+        }
+        impl<'r> $trait<&'r $t> for $int {
+            type Output = $t;
+            #[inline]
+            fn $method(self, rhs: &$t) -> $t {
+                <$t>::from(self).$method(rhs)
+            }
+        }
+        impl<'l> $trait<$t> for &'l $int {
+            type Output = $t;
+            #[inline]
+            fn $method(self, rhs: $t) -> $t {
+                <$t>::from(self.clone()).$method(rhs)
+            }
+        }
+        impl<'l, 'r> $trait<&'r $t> for &'l $int {
+            type Output = $t;
+            #[inline]
+            fn $method(self, rhs: &$t) -> $t {
+                <$t>::from(self.clone()).$method(rhs)
+            }
+        }
+    };
+}
+
+/// Implement `impl OpAssign<B> for A` by forwarding to `*A = mem::take(A).op(B)`, including &B.
+macro_rules! impl_binop_assign_by_taking {
+    (impl $trait:ident<$t2:ty> for $t1:ty, $methodassign:ident, $method:ident) => {
+        impl $trait<$t2> for $t1 {
+            #[inline]
+            fn $methodassign(&mut self, rhs: $t2) {
+                *self = core::mem::take(self).$method(rhs);
+            }
+        }
+        impl $trait<&$t2> for $t1 {
+            #[inline]
+            fn $methodassign(&mut self, rhs: &$t2) {
+                *self = core::mem::take(self).$method(rhs);
+            }
+        }
+    };
+}
+
+/// Implement an arithmetic operator for [RBig][crate::RBig] and [Relaxed][crate::Relaxed], with
+/// themselves, the big integers and the primitive integers, given the implementation of
+/// `impl Op<&A> for &A`.
+macro_rules! impl_arith_ops {
+    (impl $trait:ident for $t:ty, $method:ident, $trait_assign:ident, $method_assign:ident) => {
+        crate::helper_macros::forward_binop_to_ref_ref!(impl $trait<$t> for $t, $method);
+        crate::helper_macros::impl_binop_assign_by_taking!(impl $trait_assign<$t> for $t, $method_assign, $method);
+        crate::helper_macros::impl_arith_ops!(@int impl $trait for $t, $method, $trait_assign, $method_assign,
+            UBig IBig u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+    };
+    (@int impl $trait:ident for $t:ty, $method:ident, $trait_assign:ident, $method_assign:ident, $($int:ty)*) => {$(
+        crate::helper_macros::impl_binop_with_int!(impl $trait<$int> for $t, $method);
+        crate::helper_macros::impl_binop_assign_by_taking!(impl $trait_assign<$int> for $t, $method_assign, $method);
+    )*};
+}
+
+pub(crate) use forward_binop_to_ref_ref;
+pub(crate) use impl_arith_ops;
+pub(crate) use impl_binop_assign_by_taking;
+pub(crate) use impl_binop_with_int;
@@ -0,0 +1,32 @@
+//! Implementation of core::iter traits
+
+use core::{
+    iter::{Product, Sum},
+    ops::{Add, Mul},
+};
+
+use crate::rbig::{RBig, Relaxed};
+
+macro_rules! impl_iter {
+    ($t:ty) => {
+        impl<T> Sum<T> for $t
+        where
+            Self: Add<T, Output = Self>,
+        {
+            fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+                iter.fold(<$t>::ZERO, <$t>::add)
+            }
+        }
+
+        impl<T> Product<T> for $t
+        where
+            Self: Mul<T, Output = Self>,
+        {
+            fn product<I: Iterator<Item = T>>(iter: I) -> Self {
+                iter.fold(<$t>::ONE, <$t>::mul)
+            }
+        }
+    };
+}
+impl_iter!(RBig);
+impl_iter!(Relaxed);
@@ -0,0 +1,72 @@
+// Copyright (c) 2022 Jacob Zhong
+//
+// Licensed under either of
+//
+// * Apache License, Version 2.0
+//   (LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0)
+// * MIT license
+//   (LICENSE-MIT or https://opensource.org/licenses/MIT)
+//
+// at your option.
+//
+// Unless you explicitly state otherwise, any contribution intentionally submitted
+// for inclusion in the work by you, as defined in the Apache-2.0 license, shall be
+// dual licensed as above, without any additional terms or conditions.
+
+//! A big rational library with good performance.
+//!
+//! The library implements efficient arithmetic and conversion functions in pure Rust.
+//!
+//! The two main rational types are [RBig] and [Relaxed]. Both of them represent the
+//! rational number as a pair of integers (numerator and denominator) and their APIs
+//! are mostly the same. However only with [RBig], the numerator and denominator are
+//! reduced so that they don't have any common divisors other than one. In [Relaxed],
+//! only the common factors of two are removed, which makes the operations faster.
+//!
+//! # Examples
+//!
+//! ```
+//! # use dashu_int::error::ParseError;
+//! use dashu_int::{IBig, UBig};
+//! use dashu_ratio::{RBig, Relaxed};
+//!
+//! let a = RBig::from_parts(IBig::from(22), UBig::from(7u8));
+//! let b: RBig = "-355/113".parse()?;
+//! let c = RBig::from(3);
+//!
+//! assert!(a > c && -&b > c);
+//! assert_eq!(&a + &b, "1/791".parse::<RBig>()?);
+//! assert_eq!((a * 7u8).to_string(), "22");
+//!
+//! // Relaxed only removes the common factors of two
+//! let d = Relaxed::from_parts(IBig::from(6), UBig::from(9u8));
+//! assert_eq!(d.to_string(), "6/9");
+//! assert_eq!(d.canonicalize().to_string(), "2/3");
+//! # Ok::<(), ParseError>(())
+//! ```
+//!
+//! # Optional dependencies
+//!
+//! * `std` (*default*): enable `std` for dependencies.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod add;
+mod cmp;
+mod cont_frac;
+mod convert;
+mod error;
+mod fmt;
+mod helper_macros;
+mod iter;
+mod mul;
+mod parse;
+mod rbig;
+mod repr;
+mod round;
+mod sign;
+
+pub use cont_frac::{ContinuedFraction, Convergents, QuadraticContinuedFraction};
+pub use rbig::{RBig, Relaxed};
@@ -0,0 +1,97 @@
+//! Multiplication and division operators.
+
+use core::ops::{Div, DivAssign, Mul, MulAssign};
+use dashu_base::{Gcd, UnsignedAbs};
+use dashu_int::{IBig, UBig};
+
+use crate::{
+    error::panic_divide_by_0,
+    helper_macros,
+    rbig::{RBig, Relaxed},
+    repr::Repr,
+};
+
+impl Repr {
+    /// Multiply two reduced fractions, the result is also reduced.
+    ///
+    /// The cross common factors are removed before the multiplication, so that
+    /// the intermediate numbers are kept small.
+    fn mul_reduced(&self, rhs: &Repr) -> Repr {
+        let (a, b) = (&self.numerator, &self.denominator);
+        let (c, d) = (&rhs.numerator, &rhs.denominator);
+        if a.is_zero() || c.is_zero() {
+            return Repr::zero();
+        }
+
+        let g1 = a.unsigned_abs().gcd(d);
+        let g2 = c.unsigned_abs().gcd(b);
+        let numerator = (a / &g1) * (c / &g2);
+        let denominator = (b / g2) * (d / g1);
+        Repr {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Multiply two fractions without full reduction.
+    #[inline]
+    fn mul_relaxed(&self, rhs: &Repr) -> Repr {
+        Repr {
+            numerator: &self.numerator * &rhs.numerator,
+            denominator: &self.denominator * &rhs.denominator,
+        }
+        .reduce2()
+    }
+
+    /// Get the reciprocal of a fraction by reference.
+    ///
+    /// Panics if the fraction is zero.
+    fn inv_ref(&self) -> Repr {
+        if self.numerator.is_zero() {
+            panic_divide_by_0()
+        }
+        let (sign, numerator) = self.numerator.clone().into_parts();
+        Repr {
+            numerator: sign * IBig::from(self.denominator.clone()),
+            denominator: numerator,
+        }
+    }
+}
+
+impl Mul<&RBig> for &RBig {
+    type Output = RBig;
+    #[inline]
+    fn mul(self, rhs: &RBig) -> RBig {
+        RBig(self.0.mul_reduced(&rhs.0))
+    }
+}
+
+impl Div<&RBig> for &RBig {
+    type Output = RBig;
+    #[inline]
+    fn div(self, rhs: &RBig) -> RBig {
+        // the reciprocal of a reduced fraction is still reduced
+        RBig(self.0.mul_reduced(&rhs.0.inv_ref()))
+    }
+}
+
+impl Mul<&Relaxed> for &Relaxed {
+    type Output = Relaxed;
+    #[inline]
+    fn mul(self, rhs: &Relaxed) -> Relaxed {
+        Relaxed(self.0.mul_relaxed(&rhs.0))
+    }
+}
+
+impl Div<&Relaxed> for &Relaxed {
+    type Output = Relaxed;
+    #[inline]
+    fn div(self, rhs: &Relaxed) -> Relaxed {
+        Relaxed(self.0.mul_relaxed(&rhs.0.inv_ref()))
+    }
+}
+
+helper_macros::impl_arith_ops!(impl Mul for RBig, mul, MulAssign, mul_assign);
+helper_macros::impl_arith_ops!(impl Div for RBig, div, DivAssign, div_assign);
+helper_macros::impl_arith_ops!(impl Mul for Relaxed, mul, MulAssign, mul_assign);
+helper_macros::impl_arith_ops!(impl Div for Relaxed, div, DivAssign, div_assign);
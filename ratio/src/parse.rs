@@ -0,0 +1,88 @@
+//! Implementation of parsing rational numbers from strings.
+
+use core::str::FromStr;
+use dashu_int::{error::ParseError, IBig, UBig};
+
+use crate::{
+    rbig::{RBig, Relaxed},
+    repr::Repr,
+};
+
+impl Repr {
+    fn from_str_radix(src: &str, radix: u32) -> Result<Repr, ParseError> {
+        if let Some((num, den)) = src.split_once('/') {
+            let numerator = IBig::from_str_radix(num, radix)?;
+            let denominator = UBig::from_str_radix(den, radix)?;
+            if denominator.is_zero() {
+                return Err(ParseError::InvalidDigit);
+            }
+            Ok(Repr {
+                numerator,
+                denominator,
+            })
+        } else {
+            Ok(Repr::from(IBig::from_str_radix(src, radix)?))
+        }
+    }
+}
+
+impl RBig {
+    /// Convert a string in a given base to [RBig].
+    ///
+    /// The string should be formatted as `numerator/denominator` or just `numerator`,
+    /// where the numerator can have a leading `+` or `-` sign. The numerator and the
+    /// denominator follow the format of [IBig::from_str_radix] and [UBig::from_str_radix].
+    /// The parsed fraction will be reduced.
+    ///
+    /// # Errors
+    ///
+    /// Returns [ParseError] if the numerator or the denominator is not valid, or
+    /// [ParseError::InvalidDigit] if the denominator is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_ratio::RBig;
+    ///
+    /// let a = RBig::from_str_radix("-ff/14", 16)?;
+    /// assert_eq!(a.to_string(), "-51/4");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseError> {
+        Repr::from_str_radix(src, radix).map(|repr| RBig(repr.reduce()))
+    }
+}
+
+impl Relaxed {
+    /// Convert a string in a given base to [Relaxed].
+    ///
+    /// The format is the same as [RBig::from_str_radix], but only the common factors of
+    /// two will be removed from the parsed fraction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [ParseError] if the numerator or the denominator is not valid, or
+    /// [ParseError::InvalidDigit] if the denominator is zero.
+    #[inline]
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseError> {
+        Repr::from_str_radix(src, radix).map(|repr| Relaxed(repr.reduce2()))
+    }
+}
+
+impl FromStr for RBig {
+    type Err = ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        RBig::from_str_radix(s, 10)
+    }
+}
+
+impl FromStr for Relaxed {
+    type Err = ParseError;
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Relaxed::from_str_radix(s, 10)
+    }
+}
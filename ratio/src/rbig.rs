@@ -0,0 +1,317 @@
+use crate::repr::Repr;
+use dashu_base::Sign;
+use dashu_int::{IBig, UBig};
+
+/// An arbitrary precision rational number.
+///
+/// The rational number is always canonicalized, that is, the numerator and the denominator
+/// are coprime, and the denominator is positive. Therefore the equality test and hashing can
+/// be done directly on the representation. The reduction is performed after every operation,
+/// which could be expensive for a long sequence of operations, and the [Relaxed] type can be
+/// used in that case.
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::{error::ParseError, IBig, UBig};
+/// use dashu_ratio::RBig;
+///
+/// let a = RBig::from_parts(IBig::from(-6), UBig::from(8u8));
+/// let b: RBig = "5/12".parse()?;
+/// assert_eq!(a.numerator(), &IBig::from(-3));
+/// assert_eq!(a.denominator(), &UBig::from(4u8));
+/// assert_eq!((a + b).to_string(), "-1/3");
+/// # Ok::<(), ParseError>(())
+/// ```
+#[derive(PartialEq, Eq, Hash)]
+pub struct RBig(pub(crate) Repr);
+
+/// An arbitrary precision rational number without strict reduction.
+///
+/// The numerator and the denominator of the number are not necessarily coprime, only the
+/// common factors of two are removed after each operation. This makes the operations faster,
+/// while the size of the numerator and the denominator could grow. The number can be
+/// converted to the canonicalized [RBig] using [canonicalize()][Relaxed::canonicalize].
+///
+/// The equality test and comparison are still performed on the values.
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::{error::ParseError, IBig, UBig};
+/// use dashu_ratio::{RBig, Relaxed};
+///
+/// let a = Relaxed::from_parts(IBig::from(-6), UBig::from(9u8));
+/// assert_eq!(a.denominator(), &UBig::from(9u8));
+/// assert_eq!(a, Relaxed::from_parts(IBig::from(-2), UBig::from(3u8)));
+/// assert_eq!(a.canonicalize(), RBig::from_parts(IBig::from(-2), UBig::from(3u8)));
+/// # Ok::<(), ParseError>(())
+/// ```
+pub struct Relaxed(pub(crate) Repr);
+
+impl RBig {
+    /// [RBig] with value 0
+    pub const ZERO: Self = Self(Repr::zero());
+    /// [RBig] with value 1
+    pub const ONE: Self = Self(Repr::one());
+    /// [RBig] with value -1
+    pub const NEG_ONE: Self = Self(Repr::neg_one());
+
+    /// Create a rational number from the numerator and the denominator, the fraction will be reduced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::{IBig, UBig};
+    /// use dashu_ratio::RBig;
+    ///
+    /// let a = RBig::from_parts(IBig::from(4), UBig::from(6u8));
+    /// assert_eq!(a.numerator(), &IBig::from(2));
+    /// assert_eq!(a.denominator(), &UBig::from(3u8));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero.
+    #[inline]
+    pub fn from_parts(numerator: IBig, denominator: UBig) -> Self {
+        Self(Repr::from_signed(numerator, denominator.into()).reduce())
+    }
+
+    /// Create a rational number from a signed numerator and a signed denominator, the
+    /// fraction will be reduced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::{IBig, UBig};
+    /// use dashu_ratio::RBig;
+    ///
+    /// let a = RBig::from_parts_signed(IBig::from(4), IBig::from(-6));
+    /// assert_eq!(a.numerator(), &IBig::from(-2));
+    /// assert_eq!(a.denominator(), &UBig::from(3u8));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero.
+    #[inline]
+    pub fn from_parts_signed(numerator: IBig, denominator: IBig) -> Self {
+        Self(Repr::from_signed(numerator, denominator).reduce())
+    }
+
+    /// Convert the rational number into the numerator and the denominator
+    #[inline]
+    pub fn into_parts(self) -> (IBig, UBig) {
+        (self.0.numerator, self.0.denominator)
+    }
+
+    /// Get the numerator of the rational number
+    #[inline]
+    pub const fn numerator(&self) -> &IBig {
+        &self.0.numerator
+    }
+
+    /// Get the denominator of the rational number, which is always positive
+    #[inline]
+    pub const fn denominator(&self) -> &UBig {
+        &self.0.denominator
+    }
+
+    /// Check whether the number is 0
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.0.numerator.is_zero()
+    }
+
+    /// Check whether the number is 1
+    #[inline]
+    pub const fn is_one(&self) -> bool {
+        self.0.numerator.is_one() && self.0.denominator.is_one()
+    }
+
+    /// Check whether the number is an integer
+    #[inline]
+    pub const fn is_int(&self) -> bool {
+        self.0.denominator.is_one()
+    }
+
+    /// Get the sign of the number. Zero value has a positive sign.
+    #[inline]
+    pub const fn sign(&self) -> Sign {
+        self.0.numerator.sign()
+    }
+
+    /// Convert the number to the [Relaxed] type, which is a no-op.
+    #[inline]
+    pub fn relax(self) -> Relaxed {
+        Relaxed(self.0)
+    }
+
+    /// Calculate the multiplicative inverse (reciprocal) of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_ratio::RBig;
+    ///
+    /// let a: RBig = "-2/3".parse()?;
+    /// assert_eq!(a.inv(), "-3/2".parse::<RBig>()?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero.
+    #[inline]
+    pub fn inv(&self) -> Self {
+        // the fraction is still reduced after swapping
+        Self(self.0.clone().inv())
+    }
+
+    /// Raise the number to an integer power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_ratio::RBig;
+    ///
+    /// let a: RBig = "-2/3".parse()?;
+    /// assert_eq!(a.pow(3), "-8/27".parse::<RBig>()?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn pow(&self, exp: usize) -> Self {
+        // the powers of coprime numbers are still coprime
+        Self(Repr {
+            numerator: self.0.numerator.pow(exp),
+            denominator: self.0.denominator.pow(exp),
+        })
+    }
+}
+
+impl Relaxed {
+    /// [Relaxed] with value 0
+    pub const ZERO: Self = Self(Repr::zero());
+    /// [Relaxed] with value 1
+    pub const ONE: Self = Self(Repr::one());
+    /// [Relaxed] with value -1
+    pub const NEG_ONE: Self = Self(Repr::neg_one());
+
+    /// Create a rational number from the numerator and the denominator, only the common
+    /// factors of two will be removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero.
+    #[inline]
+    pub fn from_parts(numerator: IBig, denominator: UBig) -> Self {
+        Self(Repr::from_signed(numerator, denominator.into()).reduce2())
+    }
+
+    /// Create a rational number from a signed numerator and a signed denominator, only the
+    /// common factors of two will be removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero.
+    #[inline]
+    pub fn from_parts_signed(numerator: IBig, denominator: IBig) -> Self {
+        Self(Repr::from_signed(numerator, denominator).reduce2())
+    }
+
+    /// Convert the rational number into the numerator and the denominator
+    #[inline]
+    pub fn into_parts(self) -> (IBig, UBig) {
+        (self.0.numerator, self.0.denominator)
+    }
+
+    /// Get the numerator of the rational number
+    #[inline]
+    pub const fn numerator(&self) -> &IBig {
+        &self.0.numerator
+    }
+
+    /// Get the denominator of the rational number, which is always positive
+    #[inline]
+    pub const fn denominator(&self) -> &UBig {
+        &self.0.denominator
+    }
+
+    /// Check whether the number is 0
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.0.numerator.is_zero()
+    }
+
+    /// Get the sign of the number. Zero value has a positive sign.
+    #[inline]
+    pub const fn sign(&self) -> Sign {
+        self.0.numerator.sign()
+    }
+
+    /// Convert the number to the canonicalized [RBig] type, by reducing the fraction.
+    #[inline]
+    pub fn canonicalize(self) -> RBig {
+        RBig(self.0.reduce())
+    }
+
+    /// Calculate the multiplicative inverse (reciprocal) of the number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero.
+    #[inline]
+    pub fn inv(&self) -> Self {
+        Self(self.0.clone().inv())
+    }
+
+    /// Raise the number to an integer power.
+    #[inline]
+    pub fn pow(&self, exp: usize) -> Self {
+        Self(Repr {
+            numerator: self.0.numerator.pow(exp),
+            denominator: self.0.denominator.pow(exp),
+        })
+    }
+}
+
+impl Clone for RBig {
+    #[inline]
+    fn clone(&self) -> RBig {
+        RBig(self.0.clone())
+    }
+    #[inline]
+    fn clone_from(&mut self, source: &RBig) {
+        self.0.clone_from(&source.0)
+    }
+}
+
+impl Clone for Relaxed {
+    #[inline]
+    fn clone(&self) -> Relaxed {
+        Relaxed(self.0.clone())
+    }
+    #[inline]
+    fn clone_from(&mut self, source: &Relaxed) {
+        self.0.clone_from(&source.0)
+    }
+}
+
+impl Default for RBig {
+    /// Default value: 0.
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl Default for Relaxed {
+    /// Default value: 0.
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
@@ -0,0 +1,98 @@
+use dashu_base::{Gcd, UnsignedAbs};
+use dashu_int::{IBig, UBig};
+
+use crate::error::panic_divide_by_0;
+
+/// Underlying representation of a rational number.
+///
+/// The sign of the rational number is carried by the numerator, and the denominator
+/// is always positive. Whether the fraction is reduced depends on the type wrapping it.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Repr {
+    pub(crate) numerator: IBig,
+    pub(crate) denominator: UBig,
+}
+
+impl Repr {
+    #[inline]
+    pub const fn zero() -> Repr {
+        Repr {
+            numerator: IBig::ZERO,
+            denominator: UBig::ONE,
+        }
+    }
+
+    #[inline]
+    pub const fn one() -> Repr {
+        Repr {
+            numerator: IBig::ONE,
+            denominator: UBig::ONE,
+        }
+    }
+
+    #[inline]
+    pub const fn neg_one() -> Repr {
+        Repr {
+            numerator: IBig::NEG_ONE,
+            denominator: UBig::ONE,
+        }
+    }
+
+    /// Create a fraction from a signed denominator, the sign is moved to the numerator.
+    ///
+    /// Panics if the denominator is zero.
+    pub fn from_signed(numerator: IBig, denominator: IBig) -> Repr {
+        let (sign, denominator) = denominator.into_parts();
+        if denominator.is_zero() {
+            panic_divide_by_0()
+        }
+        Repr {
+            numerator: sign * numerator,
+            denominator,
+        }
+    }
+
+    /// Remove all the common factors between the numerator and the denominator
+    pub fn reduce(self) -> Repr {
+        if self.numerator.is_zero() {
+            return Repr::zero();
+        }
+
+        let g = (&self.numerator).unsigned_abs().gcd(&self.denominator);
+        if g.is_one() {
+            return self;
+        }
+        Repr {
+            numerator: self.numerator / &g,
+            denominator: self.denominator / g,
+        }
+    }
+
+    /// Remove only the common factors of two between the numerator and the denominator,
+    /// which is much cheaper than a full reduction.
+    pub fn reduce2(self) -> Repr {
+        if self.numerator.is_zero() {
+            return Repr::zero();
+        }
+
+        // both are non-zero here
+        let n_zeros = self.numerator.trailing_zeros().unwrap();
+        let d_zeros = self.denominator.trailing_zeros().unwrap();
+        let shift = n_zeros.min(d_zeros);
+        if shift == 0 {
+            return self;
+        }
+        Repr {
+            numerator: self.numerator >> shift,
+            denominator: self.denominator >> shift,
+        }
+    }
+
+    /// Swap the numerator and the denominator.
+    ///
+    /// Panics if the fraction is zero.
+    #[inline]
+    pub fn inv(self) -> Repr {
+        Repr::from_signed(self.denominator.into(), self.numerator)
+    }
+}
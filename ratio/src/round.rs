@@ -0,0 +1,135 @@
+//! Rounding the rational numbers to integers.
+
+use dashu_base::DivRem;
+use dashu_int::IBig;
+
+use crate::{
+    rbig::{RBig, Relaxed},
+    repr::Repr,
+};
+
+impl Repr {
+    /// Split the fraction into the integral part rounded toward negative infinity,
+    /// and the remainder numerator (which is non-negative).
+    #[inline]
+    fn floor_rem(&self) -> (IBig, IBig) {
+        let (q, r) = (&self.numerator).div_rem(IBig::from(self.denominator.clone()));
+        if r < IBig::ZERO {
+            (q - IBig::ONE, r + &self.denominator)
+        } else {
+            (q, r)
+        }
+    }
+
+    fn floor(&self) -> IBig {
+        self.floor_rem().0
+    }
+
+    fn ceil(&self) -> IBig {
+        let (q, r) = self.floor_rem();
+        if r.is_zero() {
+            q
+        } else {
+            q + IBig::ONE
+        }
+    }
+
+    fn trunc(&self) -> IBig {
+        (&self.numerator)
+            .div_rem(IBig::from(self.denominator.clone()))
+            .0
+    }
+
+    fn round(&self) -> IBig {
+        // round half away from zero
+        let (q, r) = self.floor_rem();
+        let r2 = r << 1;
+        match r2.cmp(&IBig::from(self.denominator.clone())) {
+            core::cmp::Ordering::Less => q,
+            core::cmp::Ordering::Greater => q + IBig::ONE,
+            core::cmp::Ordering::Equal => {
+                if self.numerator < IBig::ZERO {
+                    q
+                } else {
+                    q + IBig::ONE
+                }
+            }
+        }
+    }
+}
+
+macro_rules! impl_round_methods {
+    ($t:ident) => {
+        impl $t {
+            /// Round the number toward negative infinity.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use dashu_int::{error::ParseError, IBig};
+            #[doc = concat!("use dashu_ratio::", stringify!($t), ";")]
+            ///
+            #[doc = concat!("let a: ", stringify!($t), " = \"-7/2\".parse()?;")]
+            /// assert_eq!(a.floor(), IBig::from(-4));
+            /// # Ok::<(), ParseError>(())
+            /// ```
+            #[inline]
+            pub fn floor(&self) -> IBig {
+                self.0.floor()
+            }
+
+            /// Round the number toward positive infinity.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use dashu_int::{error::ParseError, IBig};
+            #[doc = concat!("use dashu_ratio::", stringify!($t), ";")]
+            ///
+            #[doc = concat!("let a: ", stringify!($t), " = \"-7/2\".parse()?;")]
+            /// assert_eq!(a.ceil(), IBig::from(-3));
+            /// # Ok::<(), ParseError>(())
+            /// ```
+            #[inline]
+            pub fn ceil(&self) -> IBig {
+                self.0.ceil()
+            }
+
+            /// Round the number toward zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use dashu_int::{error::ParseError, IBig};
+            #[doc = concat!("use dashu_ratio::", stringify!($t), ";")]
+            ///
+            #[doc = concat!("let a: ", stringify!($t), " = \"-7/2\".parse()?;")]
+            /// assert_eq!(a.trunc(), IBig::from(-3));
+            /// # Ok::<(), ParseError>(())
+            /// ```
+            #[inline]
+            pub fn trunc(&self) -> IBig {
+                self.0.trunc()
+            }
+
+            /// Round the number to the nearest integer, ties are rounded away from zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use dashu_int::{error::ParseError, IBig};
+            #[doc = concat!("use dashu_ratio::", stringify!($t), ";")]
+            ///
+            #[doc = concat!("let a: ", stringify!($t), " = \"-7/2\".parse()?;")]
+            /// assert_eq!(a.round(), IBig::from(-4));
+            /// # Ok::<(), ParseError>(())
+            /// ```
+            #[inline]
+            pub fn round(&self) -> IBig {
+                self.0.round()
+            }
+        }
+    };
+}
+impl_round_methods!(RBig);
+impl_round_methods!(Relaxed);
@@ -0,0 +1,81 @@
+//! Operators on the sign of [RBig] and [Relaxed].
+
+use core::ops::{Mul, MulAssign, Neg};
+use dashu_base::{Abs, Sign};
+
+use crate::{
+    rbig::{RBig, Relaxed},
+    repr::Repr,
+};
+
+impl Repr {
+    #[inline]
+    fn neg(mut self) -> Repr {
+        self.numerator = -self.numerator;
+        self
+    }
+
+    #[inline]
+    fn abs(mut self) -> Repr {
+        self.numerator = self.numerator.abs();
+        self
+    }
+}
+
+macro_rules! impl_sign_ops {
+    ($t:ident) => {
+        impl Neg for $t {
+            type Output = $t;
+            #[inline]
+            fn neg(self) -> $t {
+                $t(self.0.neg())
+            }
+        }
+
+        impl Neg for &$t {
+            type Output = $t;
+            #[inline]
+            fn neg(self) -> $t {
+                $t(self.0.clone().neg())
+            }
+        }
+
+        impl Abs for $t {
+            type Output = $t;
+            #[inline]
+            fn abs(self) -> $t {
+                $t(self.0.abs())
+            }
+        }
+
+        impl Mul<Sign> for $t {
+            type Output = $t;
+            #[inline]
+            fn mul(self, rhs: Sign) -> $t {
+                match rhs {
+                    Sign::Positive => self,
+                    Sign::Negative => -self,
+                }
+            }
+        }
+
+        impl Mul<$t> for Sign {
+            type Output = $t;
+            #[inline]
+            fn mul(self, rhs: $t) -> $t {
+                rhs * self
+            }
+        }
+
+        impl MulAssign<Sign> for $t {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Sign) {
+                if rhs == Sign::Negative {
+                    self.0.numerator = -core::mem::take(&mut self.0.numerator);
+                }
+            }
+        }
+    };
+}
+impl_sign_ops!(RBig);
+impl_sign_ops!(Relaxed);
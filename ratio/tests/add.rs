@@ -0,0 +1,131 @@
+use core::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
+use dashu_ratio::{RBig, Relaxed};
+
+mod helper_macros;
+
+/// Test a + b = c in various ways.
+fn test_add<'a, T>(a: &'a T, b: &'a T, c: &'a T)
+where
+    T: Add<T, Output = T>,
+    T: Add<&'a T, Output = T>,
+    &'a T: Add<T, Output = T>,
+    &'a T: Add<&'a T, Output = T>,
+    T: AddAssign<T>,
+    T: AddAssign<&'a T>,
+    T: Clone,
+    T: Debug,
+    T: Eq,
+{
+    assert_eq!(a + b, *c);
+    assert_eq!(a.clone() + b, *c);
+    assert_eq!(a + b.clone(), *c);
+    assert_eq!(a.clone() + b.clone(), *c);
+
+    let mut x = a.clone();
+    x += b;
+    assert_eq!(x, *c);
+
+    let mut x = a.clone();
+    x += b.clone();
+    assert_eq!(x, *c);
+}
+
+/// Test a - b = c in various ways.
+fn test_sub<'a, T>(a: &'a T, b: &'a T, c: &'a T)
+where
+    T: Sub<T, Output = T>,
+    T: Sub<&'a T, Output = T>,
+    &'a T: Sub<T, Output = T>,
+    &'a T: Sub<&'a T, Output = T>,
+    T: SubAssign<T>,
+    T: SubAssign<&'a T>,
+    T: Clone,
+    T: Debug,
+    T: Eq,
+{
+    assert_eq!(a - b, *c);
+    assert_eq!(a.clone() - b, *c);
+    assert_eq!(a - b.clone(), *c);
+    assert_eq!(a.clone() - b.clone(), *c);
+
+    let mut x = a.clone();
+    x -= b;
+    assert_eq!(x, *c);
+
+    let mut x = a.clone();
+    x -= b.clone();
+    assert_eq!(x, *c);
+}
+
+#[test]
+fn test_add_sub() {
+    let test_cases = [
+        (rbig!(0), rbig!(0), rbig!(0)),
+        (rbig!(1), rbig!(-1), rbig!(0)),
+        (rbig!(1 / 2), rbig!(1 / 2), rbig!(1)),
+        (rbig!(1 / 2), rbig!(1 / 3), rbig!(5 / 6)),
+        (rbig!(-1 / 2), rbig!(1 / 3), rbig!(-1 / 6)),
+        (rbig!(1 / 6), rbig!(1 / 10), rbig!(4 / 15)),
+        (rbig!(5 / 12), rbig!(-1 / 12), rbig!(1 / 3)),
+        (rbig!(7 / 12), rbig!(5 / 12), rbig!(1)),
+        (rbig!(3 / 8), rbig!(5 / 6), rbig!(29 / 24)),
+        (
+            rbig!(123456789012345678901234567891 / 7),
+            rbig!(-1 / 98765432109876543210),
+            rbig!(12193263113702179522496570642336229233221140070103 / 691358024769135802470),
+        ),
+    ];
+
+    for (a, b, c) in &test_cases {
+        test_add(a, b, c);
+        test_add(b, a, c);
+        test_sub(c, a, b);
+        test_sub(c, b, a);
+
+        let (ra, rb, rc) = (a.clone().relax(), b.clone().relax(), c.clone().relax());
+        test_add(&ra, &rb, &rc);
+        test_add(&rb, &ra, &rc);
+        test_sub(&rc, &ra, &rb);
+        test_sub(&rc, &rb, &ra);
+    }
+}
+
+#[test]
+fn test_add_sub_reduced() {
+    // the results of RBig are always reduced
+    let a = rbig!(1 / 6) + rbig!(1 / 3);
+    assert_eq!(a.numerator(), &ibig!(1));
+    assert_eq!(a.denominator(), &ubig!(2));
+
+    let b = rbig!(5 / 6) - rbig!(1 / 6);
+    assert_eq!(b.numerator(), &ibig!(2));
+    assert_eq!(b.denominator(), &ubig!(3));
+
+    // the results of Relaxed only have common factors of two removed
+    let c = rbig_relaxed!(1 / 6) + rbig_relaxed!(1 / 3);
+    assert_eq!(c.numerator(), &ibig!(9));
+    assert_eq!(c.denominator(), &ubig!(18));
+    assert_eq!(c.canonicalize(), rbig!(1 / 2));
+}
+
+#[test]
+fn test_add_sub_with_int() {
+    let a = rbig!(1 / 3);
+    assert_eq!(&a + 1u8, rbig!(4 / 3));
+    assert_eq!(1u8 + &a, rbig!(4 / 3));
+    assert_eq!(&a - 1i32, rbig!(-2 / 3));
+    assert_eq!(1i32 - &a, rbig!(2 / 3));
+    assert_eq!(&a + ibig!(-2), rbig!(-5 / 3));
+    assert_eq!(ubig!(2) - &a, rbig!(5 / 3));
+
+    let mut b = a.clone();
+    b += 2u64;
+    b -= ibig!(1);
+    assert_eq!(b, rbig!(4 / 3));
+
+    let c = Relaxed::from(3) - rbig_relaxed!(1 / 2);
+    assert_eq!(c, RBig::from_parts(ibig!(5), ubig!(2)));
+}
@@ -0,0 +1,97 @@
+use core::cmp::Ordering;
+use dashu_float::{round::mode::HalfAway, DBig, FBig};
+use dashu_ratio::Relaxed;
+
+mod helper_macros;
+
+#[test]
+fn test_eq_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    assert_eq!(rbig!(2 / 4), rbig!(1 / 2));
+    assert_eq!(hash(&rbig!(2 / 4)), hash(&rbig!(1 / 2)));
+    assert_ne!(rbig!(1 / 2), rbig!(-1 / 2));
+    assert_eq!(rbig!(-0 / 3), rbig!(0));
+
+    // Relaxed is compared by values
+    assert_eq!(rbig_relaxed!(3 / 9), rbig_relaxed!(1 / 3));
+    assert_ne!(rbig_relaxed!(3 / 9), rbig_relaxed!(-1 / 3));
+    assert_eq!(rbig_relaxed!(3 / 9), rbig!(1 / 3));
+    assert_eq!(rbig!(1 / 3), rbig_relaxed!(3 / 9));
+}
+
+#[test]
+fn test_cmp() {
+    let sorted = [
+        rbig!(-7 / 2),
+        rbig!(-3),
+        rbig!(-2 / 3),
+        rbig!(-3 / 5),
+        rbig!(0),
+        rbig!(1 / 1000000000000000000000),
+        rbig!(3 / 5),
+        rbig!(2 / 3),
+        rbig!(1),
+        rbig!(7 / 2),
+    ];
+    for (i, a) in sorted.iter().enumerate() {
+        for (j, b) in sorted.iter().enumerate() {
+            assert_eq!(a.cmp(b), i.cmp(&j), "{} vs {}", a, b);
+            let (ra, rb) = (Relaxed::from(a.clone()), Relaxed::from(b.clone()));
+            assert_eq!(ra.cmp(&rb), i.cmp(&j), "{} vs {}", a, b);
+            assert_eq!(a.partial_cmp(&rb), Some(i.cmp(&j)));
+        }
+    }
+}
+
+#[test]
+fn test_cmp_with_int() {
+    assert_eq!(rbig!(4 / 2), ibig!(2));
+    assert_eq!(ubig!(2), rbig!(4 / 2));
+    assert_ne!(rbig!(5 / 2), ibig!(2));
+    assert_ne!(rbig!(-2), ubig!(2));
+    assert!(rbig!(5 / 2) > ibig!(2));
+    assert!(rbig!(5 / 2) < ubig!(3));
+    assert!(ibig!(-3) < rbig!(-5 / 2));
+    assert!(ubig!(0) > rbig!(-1 / 2));
+    assert_eq!(rbig_relaxed!(6 / 3), ubig!(2));
+    assert!(rbig_relaxed!(6 / 4) > ibig!(1));
+    assert_eq!(ibig!(-1).partial_cmp(&rbig_relaxed!(-4 / 4)), Some(Ordering::Equal));
+}
+
+#[test]
+fn test_cmp_with_float() {
+    type DBigHalfAway = FBig<HalfAway, 10>;
+
+    let a: DBig = "1.25".parse().unwrap();
+    let b: FBig = FBig::from_parts(ibig!(3), -2);
+    let c: DBigHalfAway = "-12e3".parse().unwrap();
+
+    assert_eq!(rbig!(5 / 4), a);
+    assert_eq!(a, rbig!(5 / 4));
+    assert!(rbig!(4 / 3) > a);
+    assert!(a > rbig!(6 / 5));
+    assert_eq!(rbig!(3 / 4), b);
+    assert!(rbig_relaxed!(2 / 3) < b);
+    assert!(b > rbig_relaxed!(2 / 3));
+    assert_eq!(rbig!(-12000), c);
+    assert!(rbig!(-12001) < c);
+
+    assert!(rbig!(1000000) < FBig::<HalfAway, 2>::INFINITY);
+    assert!(rbig!(-1000000) > DBig::NEG_INFINITY);
+    assert!(DBig::INFINITY > rbig!(1000000));
+
+    // the NaN is greater than any fraction
+    assert!(rbig!(0) != DBig::NAN);
+    assert!(DBig::NAN != rbig!(0));
+    assert!(rbig!(1000000) < DBig::NAN);
+    assert!(FBig::<HalfAway, 2>::NAN > rbig_relaxed!(-1 / 3));
+    assert_eq!(rbig!(0).partial_cmp(&DBig::NAN), Some(Ordering::Less));
+}
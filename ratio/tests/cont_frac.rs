@@ -0,0 +1,237 @@
+use core::convert::TryFrom;
+use dashu_base::Abs;
+use dashu_float::{round::mode::HalfEven, DBig, FBig};
+use dashu_int::{IBig, UBig};
+use dashu_ratio::{ContinuedFraction, QuadraticContinuedFraction, RBig, Relaxed};
+
+mod helper_macros;
+
+fn terms(values: &[i64]) -> Vec<IBig> {
+    values.iter().map(|&v| IBig::from(v)).collect()
+}
+
+#[test]
+fn test_rational_cf() {
+    let test_cases = [
+        (rbig!(0), terms(&[0])),
+        (rbig!(5), terms(&[5])),
+        (rbig!(-5), terms(&[-5])),
+        (rbig!(1 / 2), terms(&[0, 2])),
+        (rbig!(-1 / 2), terms(&[-1, 2])),
+        (rbig!(415 / 93), terms(&[4, 2, 6, 7])),
+        (rbig!(-415 / 93), terms(&[-5, 1, 1, 6, 7])),
+        (rbig!(355 / 113), terms(&[3, 7, 16])),
+        (rbig!(89 / 55), terms(&[1, 1, 1, 1, 1, 1, 1, 1, 2])),
+    ];
+    for (x, expected) in &test_cases {
+        assert_eq!(&x.continued_fraction().collect::<Vec<_>>(), expected);
+        assert_eq!(&x.clone().relax().continued_fraction().collect::<Vec<_>>(), expected);
+
+        // the last convergent is the number itself
+        assert_eq!(&x.continued_fraction().convergents().last().unwrap(), x);
+    }
+
+    // non-reduced fractions
+    let cf = ContinuedFraction::new(ibig!(830), ubig!(186));
+    assert_eq!(cf.collect::<Vec<_>>(), terms(&[4, 2, 6, 7]));
+    let cf = Relaxed::from_parts(ibig!(-9), ubig!(6)).continued_fraction();
+    assert_eq!(cf.collect::<Vec<_>>(), terms(&[-2, 2]));
+}
+
+#[test]
+#[should_panic]
+fn test_rational_cf_by_0() {
+    let _ = ContinuedFraction::new(ibig!(1), ubig!(0));
+}
+
+#[test]
+fn test_float_cf() {
+    let a = DBig::from_str_native("3.14159").unwrap();
+    let cf = ContinuedFraction::try_from(&a).unwrap();
+    assert_eq!(cf.collect::<Vec<_>>(), terms(&[3, 7, 15, 1, 25, 1, 7, 4]));
+
+    let b = FBig::<HalfEven, 2>::try_from(-0.375f64).unwrap();
+    let cf = ContinuedFraction::try_from(b).unwrap();
+    assert_eq!(cf.collect::<Vec<_>>(), terms(&[-1, 1, 1, 1, 2]));
+
+    assert!(ContinuedFraction::try_from(DBig::INFINITY).is_err());
+    assert!(ContinuedFraction::try_from(DBig::NAN).is_err());
+    assert!(ContinuedFraction::try_from(&FBig::<HalfEven, 2>::NAN).is_err());
+
+    // recover the cube root of 2 from a high precision result
+    let c = DBig::from_str_native("1.2599210498948731647672106072782283505702514647015").unwrap();
+    let cf = ContinuedFraction::try_from(c).unwrap();
+    assert_eq!(
+        cf.take(15).collect::<Vec<_>>(),
+        terms(&[1, 3, 1, 5, 1, 1, 4, 1, 1, 8, 1, 14, 1, 10, 2])
+    );
+}
+
+#[test]
+fn test_quadratic_cf() {
+    let test_cases = [
+        // (p, d, q, terms)
+        (0, 2, 1, terms(&[1, 2, 2, 2, 2, 2, 2, 2])),
+        (0, 7, 1, terms(&[2, 1, 1, 1, 4, 1, 1, 1])),
+        (0, 7, -1, terms(&[-3, 2, 1, 4, 1, 1, 1, 4])),
+        (1, 5, 2, terms(&[1, 1, 1, 1, 1, 1, 1, 1])),
+        (1, 3, 2, terms(&[1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2])),
+        (-3, 13, -5, terms(&[-1, 1, 7, 3, 1, 8, 3, 1, 8, 3, 1, 8])),
+        (5, 11, 3, terms(&[2, 1, 3, 2, 1, 1, 3, 2, 1, 1, 3, 2])),
+        (2, 10, -7, terms(&[-1, 3, 1, 4, 4, 4, 1, 2, 7, 44, 7, 2])),
+    ];
+    for (p, d, q, expected) in &test_cases {
+        let cf =
+            QuadraticContinuedFraction::new(IBig::from(*p), UBig::from(*d as u8), IBig::from(*q));
+        assert_eq!(&cf.take(expected.len()).collect::<Vec<_>>(), expected);
+    }
+
+    // perfect squares result in finite expansions, (3 + √16) / 2 = 7/2
+    let cf = QuadraticContinuedFraction::new(ibig!(3), ubig!(16), ibig!(2));
+    assert_eq!(cf.collect::<Vec<_>>(), terms(&[3, 2]));
+    let cf = QuadraticContinuedFraction::new(ibig!(3), ubig!(16), ibig!(-7));
+    assert_eq!(cf.collect::<Vec<_>>(), terms(&[-1]));
+
+    let convergents: Vec<_> = QuadraticContinuedFraction::new(ibig!(0), ubig!(3), ibig!(1))
+        .convergents()
+        .take(6)
+        .collect();
+    assert_eq!(
+        convergents,
+        [
+            rbig!(1),
+            rbig!(2),
+            rbig!(5 / 3),
+            rbig!(7 / 4),
+            rbig!(19 / 11),
+            rbig!(26 / 15)
+        ]
+    );
+}
+
+/// Find the best approximation by brute force
+fn brute_force_best_approx(x: &RBig, max_den: u32) -> RBig {
+    let mut best = RBig::from(x.floor());
+    let mut best_diff = (x - &best).abs();
+    for q in 1..=max_den {
+        let scaled = x * q;
+        for p in [scaled.floor(), scaled.ceil()] {
+            let candidate = RBig::from_parts(p, UBig::from(q));
+            let diff = (x - &candidate).abs();
+            if diff < best_diff {
+                best = candidate;
+                best_diff = diff;
+            }
+        }
+    }
+    best
+}
+
+/// Find the simplest rational in [lo, hi] by brute force
+fn brute_force_simplest(lo: &RBig, hi: &RBig) -> RBig {
+    for q in 1u32.. {
+        let lo_num = (lo * q).ceil();
+        let hi_num = (hi * q).floor();
+        if lo_num <= hi_num {
+            let p = if lo_num > IBig::ZERO {
+                lo_num
+            } else if hi_num < IBig::ZERO {
+                hi_num
+            } else {
+                IBig::ZERO
+            };
+            return RBig::from_parts(p, UBig::from(q));
+        }
+    }
+    unreachable!()
+}
+
+#[test]
+fn test_best_rational_approx() {
+    let pi = rbig!(3141592653589793 / 1000000000000000);
+    assert_eq!(pi.best_rational_approx(&ubig!(1)), rbig!(3));
+    assert_eq!(pi.best_rational_approx(&ubig!(7)), rbig!(22 / 7));
+    assert_eq!(pi.best_rational_approx(&ubig!(100)), rbig!(311 / 99));
+    assert_eq!(pi.best_rational_approx(&ubig!(113)), rbig!(355 / 113));
+    assert_eq!(pi.best_rational_approx(&ubig!(16000)), rbig!(355 / 113));
+    assert_eq!(pi.best_rational_approx(&ubig!(30000)), rbig!(94053 / 29938));
+    assert_eq!(pi.best_rational_approx(&ubig!(40000)), rbig!(104348 / 33215));
+    assert_eq!((-&pi).best_rational_approx(&ubig!(100)), rbig!(-311 / 99));
+    assert_eq!(pi.best_rational_approx(&ubig!(10000000000000000)), pi);
+
+    // ties prefer the smaller denominator
+    assert_eq!(rbig!(1 / 4).best_rational_approx(&ubig!(2)), rbig!(0));
+    assert_eq!(rbig!(3 / 4).best_rational_approx(&ubig!(3)), rbig!(2 / 3));
+
+    for (num, den) in [
+        (7, 31),
+        (-23, 97),
+        (100, 37),
+        (-1, 101),
+        (355, 226),
+        (1234, 567),
+    ] {
+        let x = RBig::from_parts(IBig::from(num), UBig::from(den as u32));
+        for max_den in 1..20u32 {
+            assert_eq!(
+                x.best_rational_approx(&UBig::from(max_den)),
+                brute_force_best_approx(&x, max_den),
+                "{} with {}",
+                x,
+                max_den
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_best_rational_approx_0() {
+    let _ = rbig!(1 / 3).best_rational_approx(&ubig!(0));
+}
+
+#[test]
+fn test_simplest_between() {
+    assert_eq!(
+        RBig::simplest_between(&rbig!(3141 / 1000), &rbig!(3142 / 1000)),
+        rbig!(245 / 78)
+    );
+    assert_eq!(
+        RBig::simplest_between(&rbig!(3142 / 1000), &rbig!(3141 / 1000)),
+        rbig!(245 / 78)
+    );
+    assert_eq!(RBig::simplest_between(&rbig!(-1 / 3), &rbig!(1 / 2)), rbig!(0));
+    assert_eq!(RBig::simplest_between(&rbig!(0), &rbig!(1 / 2)), rbig!(0));
+    assert_eq!(RBig::simplest_between(&rbig!(1 / 3), &rbig!(1 / 3)), rbig!(1 / 3));
+    assert_eq!(RBig::simplest_between(&rbig!(5 / 2), &rbig!(3)), rbig!(3));
+    assert_eq!(RBig::simplest_between(&rbig!(-7 / 2), &rbig!(-3)), rbig!(-3));
+
+    let values: Vec<RBig> = [
+        (-7, 3),
+        (-5, 4),
+        (-2, 7),
+        (1, 10),
+        (2, 9),
+        (1, 3),
+        (5, 13),
+        (3, 7),
+        (7, 5),
+        (19, 8),
+        (22, 7),
+    ]
+    .iter()
+    .map(|&(n, d)| RBig::from_parts(IBig::from(n), UBig::from(d as u8)))
+    .collect();
+    for a in &values {
+        for b in &values {
+            let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+            assert_eq!(
+                RBig::simplest_between(a, b),
+                brute_force_simplest(lo, hi),
+                "[{}, {}]",
+                lo,
+                hi
+            );
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use core::convert::TryFrom;
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{
+        mode::{HalfAway, HalfEven, Up, Zero},
+        Rounding::*,
+    },
+    DBig, FBig,
+};
+use dashu_ratio::{RBig, Relaxed};
+
+mod helper_macros;
+
+#[test]
+fn test_from_fbig() {
+    let a = FBig::<Zero, 2>::from_parts(ibig!(-3), -3);
+    assert_eq!(RBig::try_from(&a), Ok(rbig!(-3 / 8)));
+    assert_eq!(Relaxed::try_from(a), Ok(rbig_relaxed!(-3 / 8)));
+
+    let b = FBig::<Zero, 2>::from_parts(ibig!(5), 4);
+    assert_eq!(RBig::try_from(b), Ok(rbig!(80)));
+
+    let c: DBig = "-1.25".parse().unwrap();
+    assert_eq!(RBig::try_from(&c), Ok(rbig!(-5 / 4)));
+    // Relaxed only removes the common factors of two
+    let d = Relaxed::try_from(c).unwrap();
+    assert_eq!(d.numerator(), &ibig!(-125));
+    assert_eq!(d.denominator(), &ubig!(100));
+
+    let e: DBig = "12e3".parse().unwrap();
+    assert_eq!(RBig::try_from(e), Ok(rbig!(12000)));
+
+    assert!(RBig::try_from(DBig::INFINITY).is_err());
+    assert!(Relaxed::try_from(FBig::<Zero, 2>::NEG_INFINITY).is_err());
+    assert!(RBig::try_from(DBig::NAN).is_err());
+    assert!(Relaxed::try_from(FBig::<Zero, 2>::NAN).is_err());
+}
+
+#[test]
+fn test_from_f32_f64() {
+    assert_eq!(RBig::try_from(0f32), Ok(rbig!(0)));
+    assert_eq!(RBig::try_from(-0.375f32), Ok(rbig!(-3 / 8)));
+    assert_eq!(RBig::try_from(1e10f32), Ok(rbig!(10000000000)));
+    assert_eq!(RBig::try_from(0.1f32), Ok(rbig!(13421773 / 134217728)));
+    assert_eq!(
+        RBig::try_from(f32::MIN_POSITIVE),
+        Ok(RBig::from_parts(ibig!(1), ubig!(1) << 126))
+    );
+    assert_eq!(
+        RBig::try_from(f32::from_bits(1)),
+        Ok(RBig::from_parts(ibig!(1), ubig!(1) << 149))
+    );
+    assert!(RBig::try_from(f32::INFINITY).is_err());
+    assert!(RBig::try_from(f32::NAN).is_err());
+
+    assert_eq!(Relaxed::try_from(-0.375f64), Ok(rbig_relaxed!(-3 / 8)));
+    assert_eq!(RBig::try_from(0.1f64), Ok(rbig!(3602879701896397 / 36028797018963968)));
+    assert_eq!(RBig::try_from(1e100f64).unwrap().denominator(), &ubig!(1));
+    assert_eq!(
+        RBig::try_from(-f64::from_bits(1)),
+        Ok(RBig::from_parts(ibig!(-1), ubig!(1) << 1074))
+    );
+    assert!(Relaxed::try_from(f64::NEG_INFINITY).is_err());
+    assert!(Relaxed::try_from(f64::NAN).is_err());
+}
+
+#[test]
+fn test_to_float() {
+    assert_eq!(rbig!(0).to_float::<HalfAway, 10>(3), Exact(DBig::ZERO));
+    assert_eq!(rbig!(-3 / 8).to_float::<HalfAway, 10>(3), Exact("-0.375".parse().unwrap()));
+    assert_eq!(
+        rbig!(1 / 3).to_float::<HalfAway, 10>(3),
+        Inexact("0.333".parse().unwrap(), NoOp)
+    );
+    assert_eq!(
+        rbig!(2 / 3).to_float::<HalfAway, 10>(3),
+        Inexact("0.667".parse().unwrap(), AddOne)
+    );
+    assert_eq!(
+        rbig!(-2 / 3).to_float::<Zero, 2>(8),
+        Inexact(FBig::from_parts(ibig!(-0xaa), -8), NoOp)
+    );
+    assert_eq!(
+        rbig!(2 / 3).to_float::<Up, 2>(8),
+        Inexact(FBig::from_parts(ibig!(0xab), -8), AddOne)
+    );
+    assert_eq!(
+        rbig!(-2 / 3).to_float::<Up, 2>(8),
+        Inexact(FBig::from_parts(ibig!(-0xaa), -8), NoOp)
+    );
+    // ties are rounded according to the rounding mode
+    assert_eq!(
+        rbig!(5 / 2).to_float::<HalfEven, 2>(2),
+        Inexact(FBig::from_parts(ibig!(2), 0), NoOp)
+    );
+    assert_eq!(
+        rbig!(7 / 2).to_float::<HalfEven, 2>(2),
+        Inexact(FBig::from_parts(ibig!(4), 0), AddOne)
+    );
+    // integers are allowed with unlimited precision
+    assert_eq!(rbig!(12345).to_float::<Zero, 10>(0), Exact(FBig::from_parts(ibig!(12345), 0)));
+    assert_eq!(
+        rbig_relaxed!(-12345 / 7).to_float::<HalfAway, 10>(5),
+        Inexact("-1763.6".parse().unwrap(), SubOne)
+    );
+
+    // round trip with f64
+    let x = RBig::try_from(0.1f64).unwrap();
+    assert_eq!(x.to_float::<HalfEven, 2>(53).value().to_f64(), Exact(0.1));
+}
+
+#[test]
+#[should_panic]
+fn test_to_float_unlimited_precision() {
+    let _ = rbig!(1 / 3).to_float::<Zero, 2>(0);
+}
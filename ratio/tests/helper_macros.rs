@@ -0,0 +1,50 @@
+//! Helper macros for constructing numbers
+//!
+//! These macros rely on string parsing, so do not use
+//! these macros when testing string parsing!
+
+#[macro_export]
+macro_rules! ubig {
+    ($val:tt) => {{
+        const STR: &::core::primitive::str = ::core::stringify!($val);
+        ::core::result::Result::expect(
+            ::dashu_int::UBig::from_str_with_radix_prefix(STR),
+            "invalid number",
+        )
+        .0
+    }};
+}
+
+#[macro_export]
+macro_rules! ibig {
+    (- $val:tt) => {
+        -<::dashu_int::IBig as ::core::convert::From<::dashu_int::UBig>>::from($crate::ubig!($val))
+    };
+    ($val:tt) => {
+        <::dashu_int::IBig as ::core::convert::From<::dashu_int::UBig>>::from($crate::ubig!($val))
+    };
+}
+
+/// Create a RBig instance from a fraction literal like `-3/4`
+#[macro_export]
+macro_rules! rbig {
+    ($($val:tt)+) => {{
+        let s: ::std::string::String = ::core::stringify!($($val)+).split_whitespace().collect();
+        ::core::result::Result::expect(
+            <::dashu_ratio::RBig as ::core::str::FromStr>::from_str(&s),
+            "invalid number",
+        )
+    }};
+}
+
+/// Create a Relaxed instance from a fraction literal like `-3/4`
+#[macro_export]
+macro_rules! rbig_relaxed {
+    ($($val:tt)+) => {{
+        let s: ::std::string::String = ::core::stringify!($($val)+).split_whitespace().collect();
+        ::core::result::Result::expect(
+            <::dashu_ratio::Relaxed as ::core::str::FromStr>::from_str(&s),
+            "invalid number",
+        )
+    }};
+}
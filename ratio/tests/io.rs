@@ -0,0 +1,81 @@
+use dashu_int::{error::ParseError, IBig};
+use dashu_ratio::{RBig, Relaxed};
+
+mod helper_macros;
+
+#[test]
+fn test_parse() {
+    assert_eq!("0".parse::<RBig>().unwrap(), RBig::ZERO);
+    assert_eq!("-1".parse::<RBig>().unwrap(), RBig::NEG_ONE);
+    assert_eq!("+3/4".parse::<RBig>().unwrap(), RBig::from_parts(ibig!(3), ubig!(4)));
+    assert_eq!("-6/8".parse::<RBig>().unwrap(), RBig::from_parts(ibig!(-3), ubig!(4)));
+    assert_eq!(
+        RBig::from_str_radix("-a/14", 16).unwrap(),
+        RBig::from_parts(ibig!(-1), ubig!(2))
+    );
+    assert_eq!(
+        RBig::from_str_radix("z/10", 36).unwrap(),
+        RBig::from_parts(ibig!(35), ubig!(36))
+    );
+
+    // Relaxed only removes common factors of two
+    let a = "-6/12".parse::<Relaxed>().unwrap();
+    assert_eq!(a.numerator(), &ibig!(-3));
+    assert_eq!(a.denominator(), &ubig!(6));
+
+    assert_eq!("".parse::<RBig>(), Err(ParseError::NoDigits));
+    assert_eq!("1/".parse::<RBig>(), Err(ParseError::NoDigits));
+    assert_eq!("/2".parse::<RBig>(), Err(ParseError::NoDigits));
+    assert_eq!("1/-2".parse::<RBig>(), Err(ParseError::InvalidDigit));
+    assert_eq!("1/2/3".parse::<RBig>(), Err(ParseError::InvalidDigit));
+    assert_eq!("1.5".parse::<RBig>(), Err(ParseError::InvalidDigit));
+    assert_eq!("1/0".parse::<RBig>(), Err(ParseError::InvalidDigit));
+    assert_eq!("1/0".parse::<Relaxed>(), Err(ParseError::InvalidDigit));
+}
+
+#[test]
+fn test_print() {
+    assert_eq!(RBig::ZERO.to_string(), "0");
+    assert_eq!(rbig!(-5).to_string(), "-5");
+    assert_eq!(rbig!(3 / 4).to_string(), "3/4");
+    assert_eq!(rbig!(-10 / 4).to_string(), "-5/2");
+    assert_eq!(format!("{:?}", rbig!(-5 / 2)), "-5/2");
+    assert_eq!(rbig_relaxed!(10 / 15).to_string(), "10/15");
+    assert_eq!(rbig_relaxed!(-10 / 4).to_string(), "-5/2");
+}
+
+#[test]
+fn test_round() {
+    let cases = [
+        // (value, floor, ceil, trunc, round)
+        (rbig!(0), 0, 0, 0, 0),
+        (rbig!(3), 3, 3, 3, 3),
+        (rbig!(7 / 2), 3, 4, 3, 4),
+        (rbig!(-7 / 2), -4, -3, -3, -4),
+        (rbig!(5 / 3), 1, 2, 1, 2),
+        (rbig!(-5 / 3), -2, -1, -1, -2),
+        (rbig!(4 / 3), 1, 2, 1, 1),
+        (rbig!(-4 / 3), -2, -1, -1, -1),
+    ];
+    for (v, floor, ceil, trunc, round) in cases {
+        assert_eq!(v.floor(), IBig::from(floor));
+        assert_eq!(v.ceil(), IBig::from(ceil));
+        assert_eq!(v.trunc(), IBig::from(trunc));
+        assert_eq!(v.round(), IBig::from(round));
+        let r = v.relax();
+        assert_eq!(r.floor(), IBig::from(floor));
+        assert_eq!(r.round(), IBig::from(round));
+    }
+}
+
+#[test]
+fn test_iter_sign() {
+    use dashu_base::Abs;
+
+    let v = [rbig!(1 / 2), rbig!(1 / 3), rbig!(1 / 6)];
+    assert_eq!(v.iter().sum::<RBig>(), RBig::ONE);
+    assert_eq!(v.iter().product::<RBig>(), rbig!(1 / 36));
+    assert_eq!(-rbig!(1 / 2), rbig!(-1 / 2));
+    assert_eq!(-&rbig_relaxed!(-1 / 2), rbig_relaxed!(1 / 2));
+    assert_eq!(rbig!(-1 / 2).abs(), rbig!(1 / 2));
+}
@@ -0,0 +1,136 @@
+use core::{
+    fmt::Debug,
+    ops::{Div, DivAssign, Mul, MulAssign},
+};
+use dashu_ratio::{RBig, Relaxed};
+
+mod helper_macros;
+
+/// Test a * b = c in various ways.
+fn test_mul<'a, T>(a: &'a T, b: &'a T, c: &'a T)
+where
+    T: Mul<T, Output = T>,
+    T: Mul<&'a T, Output = T>,
+    &'a T: Mul<T, Output = T>,
+    &'a T: Mul<&'a T, Output = T>,
+    T: MulAssign<T>,
+    T: MulAssign<&'a T>,
+    T: Clone,
+    T: Debug,
+    T: Eq,
+{
+    assert_eq!(a * b, *c);
+    assert_eq!(a.clone() * b, *c);
+    assert_eq!(a * b.clone(), *c);
+    assert_eq!(a.clone() * b.clone(), *c);
+
+    let mut x = a.clone();
+    x *= b;
+    assert_eq!(x, *c);
+
+    let mut x = a.clone();
+    x *= b.clone();
+    assert_eq!(x, *c);
+}
+
+/// Test a / b = c in various ways.
+fn test_div<'a, T>(a: &'a T, b: &'a T, c: &'a T)
+where
+    T: Div<T, Output = T>,
+    T: Div<&'a T, Output = T>,
+    &'a T: Div<T, Output = T>,
+    &'a T: Div<&'a T, Output = T>,
+    T: DivAssign<T>,
+    T: DivAssign<&'a T>,
+    T: Clone,
+    T: Debug,
+    T: Eq,
+{
+    assert_eq!(a / b, *c);
+    assert_eq!(a.clone() / b, *c);
+    assert_eq!(a / b.clone(), *c);
+    assert_eq!(a.clone() / b.clone(), *c);
+
+    let mut x = a.clone();
+    x /= b;
+    assert_eq!(x, *c);
+
+    let mut x = a.clone();
+    x /= b.clone();
+    assert_eq!(x, *c);
+}
+
+#[test]
+fn test_mul_div() {
+    let test_cases = [
+        (rbig!(1), rbig!(1), rbig!(1)),
+        (rbig!(1), rbig!(-1), rbig!(-1)),
+        (rbig!(2 / 3), rbig!(3 / 4), rbig!(1 / 2)),
+        (rbig!(-2 / 3), rbig!(-9 / 4), rbig!(3 / 2)),
+        (rbig!(5 / 7), rbig!(-7 / 5), rbig!(-1)),
+        (rbig!(6 / 35), rbig!(14 / 15), rbig!(4 / 25)),
+        (
+            rbig!(123456789012345678901234567891 / 7),
+            rbig!(-49 / 98765432109876543210),
+            rbig!(-864197523086419752308641975237 / 98765432109876543210),
+        ),
+    ];
+
+    for (a, b, c) in &test_cases {
+        test_mul(a, b, c);
+        test_mul(b, a, c);
+        test_div(c, a, b);
+        test_div(c, b, a);
+
+        let (ra, rb, rc) = (a.clone().relax(), b.clone().relax(), c.clone().relax());
+        test_mul(&ra, &rb, &rc);
+        test_mul(&rb, &ra, &rc);
+        test_div(&rc, &ra, &rb);
+        test_div(&rc, &rb, &ra);
+    }
+
+    // multiplying by zero
+    assert_eq!(rbig!(0) * rbig!(-3 / 4), rbig!(0));
+    assert_eq!(rbig_relaxed!(0) / rbig_relaxed!(-3 / 4), rbig_relaxed!(0));
+}
+
+#[test]
+fn test_mul_div_with_int() {
+    let a = rbig!(3 / 4);
+    assert_eq!(&a * 2u8, rbig!(3 / 2));
+    assert_eq!(4i32 * &a, rbig!(3));
+    assert_eq!(&a / -3i64, rbig!(-1 / 4));
+    assert_eq!(3u8 / &a, rbig!(4));
+    assert_eq!(&a * ibig!(-8), rbig!(-6));
+    assert_eq!(ubig!(6) / &a, rbig!(8));
+
+    let mut b = a.clone();
+    b *= 5u32;
+    b /= ibig!(-10);
+    assert_eq!(b, rbig!(-3 / 8));
+
+    let c = Relaxed::from(3) / rbig_relaxed!(6);
+    assert_eq!(c, RBig::from_parts(ibig!(1), ubig!(2)));
+}
+
+#[test]
+fn test_inv_pow() {
+    assert_eq!(rbig!(-2 / 3).inv(), rbig!(-3 / 2));
+    assert_eq!(rbig!(5).inv(), rbig!(1 / 5));
+    assert_eq!(rbig_relaxed!(-4 / 6).inv(), rbig_relaxed!(-3 / 2));
+    assert_eq!(rbig!(-2 / 3).pow(0), rbig!(1));
+    assert_eq!(rbig!(-2 / 3).pow(5), rbig!(-32 / 243));
+    assert_eq!(rbig_relaxed!(6 / 4).pow(2), rbig_relaxed!(9 / 4));
+}
+
+#[test]
+#[should_panic]
+fn test_div_by_0() {
+    let _ = rbig!(1 / 2) / rbig!(0);
+}
+
+#[test]
+#[should_panic]
+fn test_inv_0() {
+    let _ = rbig_relaxed!(0).inv();
+}